@@ -13,7 +13,8 @@ fn main() {
     let alpha: f64 = 1.0;
     let expected: f64 = -4.0;
 
-    let (_, result, error) = w.qags(|x| (alpha * x).ln() / x.sqrt(), 0., 1., 0., 1e-7, 1000);
+    let outcome = w.qags(|x| (alpha * x).ln() / x.sqrt(), 0., 1., 0., 1e-7, 1000);
+    let (result, error) = (outcome.result, outcome.abserr);
 
     println!("== Adaptive integration ==");
     println!("result          = {}", result);
@@ -21,6 +22,7 @@ fn main() {
     println!("estimated error = {}", error);
     println!("actual error    = {}", result - expected);
     println!("intervals       = {}", w.size());
+    println!("evaluations     = {}", outcome.neval);
 
     // This is the example from https://www.gnu.org/software/gsl/doc/html/integration.html#fixed-point-quadrature-example
     let n = 6;
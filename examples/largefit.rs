@@ -6,7 +6,7 @@ extern crate rgsl;
 
 use rgsl::{
     blas, error, MatrixF64, MultilargeLinearType, MultilargeLinearWorkspace, Rng, RngType,
-    VectorF64,
+    VectorF64, VectorF64ViewMut,
 };
 
 // number of observations
@@ -27,7 +27,7 @@ fn func(t: f64) -> f64 {
     (x * x * x).exp()
 }
 
-fn build_row(t: f64, row: &mut VectorF64) {
+fn build_row(t: f64, row: &mut VectorF64ViewMut) {
     let p = row.len();
     let mut xj = 1.;
 
@@ -57,47 +57,34 @@ fn solve_system(print_data: bool, t: MultilargeLinearType, c: &mut VectorF64) {
         // number of rows in this block
         let nr = if NROWS > nleft { nleft } else { NROWS };
 
-        let mut xv = x.submatrix(0, 0, nr, P);
-        let mut yv = y.subvector(0, nr);
+        let mut xv = x
+            .submatrix_mut(0, 0, nr, P)
+            .expect("Failed to get submatrix");
+        let mut yv = y.subvector_mut(0, nr).expect("Failed to get subvector");
 
         // build (X,y) block with 'nr' rows
         for i in 0..nr {
-            xv.matrix_mut(|mat| {
-                mat.expect("Failed to get matrix").row(i, |row| {
-                    let mut row = row.expect("Failed to get row...");
-                    let fi = func(t);
-                    // noise
-                    let ei = r.gaussian(0.1 * fi);
-                    let yi = fi + ei;
-
-                    // construct this row of LS matrix
-                    row.vector_mut(|vector| {
-                        build_row(t, vector.expect("Failed to get vector"));
-                    });
-
-                    // set right hand side value with added noise
-                    yv.vector_mut(|vector| {
-                        vector.expect("Failed to get vector").set(i, yi);
-                    });
-
-                    if print_data && i % 100 == 0 {
-                        println!("{} {}", t, yi);
-                    }
-                });
-
-                t += DT;
-            });
+            let mut row = xv.row_mut(i).expect("Failed to get row...");
+            let fi = func(t);
+            // noise
+            let ei = r.gaussian(0.1 * fi);
+            let yi = fi + ei;
+
+            // construct this row of LS matrix
+            build_row(t, &mut row);
+
+            // set right hand side value with added noise
+            yv.set(i, yi);
+
+            if print_data && i % 100 == 0 {
+                println!("{} {}", t, yi);
+            }
+
+            t += DT;
         }
 
         // accumulate (X,y) block into LS system
-        xv.matrix_mut(|matrix| {
-            yv.vector_mut(|vector| {
-                w.accumulate(
-                    matrix.expect("Failed to get matrix"),
-                    vector.expect("Failed to get vector"),
-                );
-            });
-        });
+        w.accumulate(&mut xv, &mut yv);
 
         rowidx += nr;
     }
@@ -154,7 +141,7 @@ fn main() {
 
     while t <= 1. {
         let f_exact = func(t);
-        build_row(t, &mut v);
+        build_row(t, &mut v.view_mut());
 
         let (_, f_tsqr) = blas::level1::ddot(&v, &c_tsqr);
         let (_, f_normal) = blas::level1::ddot(&v, &c_normal);
@@ -7,10 +7,10 @@ extern crate rgsl;
 fn main() {
     let data: [f64; 5] = [17.2, 18.1, 16.5, 18.3, 12.6];
 
-    let mean = rgsl::statistics::mean(&data, 1, 5);
-    let variance = rgsl::statistics::variance(&data, 1, 5);
-    let largest = rgsl::statistics::max(&data, 1, 5);
-    let smallest = rgsl::statistics::min(&data, 1, 5);
+    let mean = rgsl::statistics::mean(&data);
+    let variance = rgsl::statistics::variance(&data);
+    let largest = rgsl::statistics::max(&data).unwrap();
+    let smallest = rgsl::statistics::min(&data).unwrap();
 
     println!(
         "The dataset is {}, {}, {}, {}, {}",
@@ -2,17 +2,280 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
+use std::os::raw::c_int;
+use types::complex::CFFI;
+use types::{ComplexF32, ComplexF64, Element};
+
+/// Element types supported by the type-generic BLAS functions ([`level1::dot`],
+/// [`level2::gemv`], [`level3::gemm`], ...): `f32`, `f64`, `ComplexF32` and `ComplexF64`.
+pub trait BlasElement: Element + Default {
+    /// The type of the norms of the vectors: `f32` for `f32` and `ComplexF32`, `f64` for `f64`
+    /// and `ComplexF64`.
+    type Real;
+
+    #[doc(hidden)]
+    unsafe fn dot(x: *const Self::RawVector, y: *const Self::RawVector, result: *mut Self)
+        -> c_int;
+    #[doc(hidden)]
+    unsafe fn nrm2(x: *const Self::RawVector) -> Self::Real;
+    #[doc(hidden)]
+    unsafe fn asum(x: *const Self::RawVector) -> Self::Real;
+    #[doc(hidden)]
+    unsafe fn iamax(x: *const Self::RawVector) -> usize;
+    #[doc(hidden)]
+    unsafe fn swap(x: *mut Self::RawVector, y: *mut Self::RawVector) -> c_int;
+    #[doc(hidden)]
+    unsafe fn copy(x: *const Self::RawVector, y: *mut Self::RawVector) -> c_int;
+    #[doc(hidden)]
+    unsafe fn axpy(alpha: Self, x: *const Self::RawVector, y: *mut Self::RawVector) -> c_int;
+    #[doc(hidden)]
+    unsafe fn scal(alpha: Self, x: *mut Self::RawVector);
+    #[doc(hidden)]
+    unsafe fn gemv(
+        trans: ::enums::CblasTranspose,
+        alpha: Self,
+        a: *const Self::RawMatrix,
+        x: *const Self::RawVector,
+        beta: Self,
+        y: *mut Self::RawVector,
+    ) -> c_int;
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn gemm(
+        trans_a: ::enums::CblasTranspose,
+        trans_b: ::enums::CblasTranspose,
+        alpha: Self,
+        a: *const Self::RawMatrix,
+        b: *const Self::RawMatrix,
+        beta: Self,
+        c: *mut Self::RawMatrix,
+    ) -> c_int;
+}
+
+macro_rules! blas_scalar {
+    (real, $x:expr) => {
+        $x
+    };
+    (complex, $x:expr) => {
+        CFFI::unwrap($x)
+    };
+}
+
+macro_rules! impl_blas_element {
+    ($kind:ident, $ty:ty, $real:ty, $dot:ident, $nrm2:ident, $asum:ident, $iamax:ident,
+     $swap:ident, $copy:ident, $axpy:ident, $scal:ident, $gemv:ident, $gemm:ident) => {
+        impl BlasElement for $ty {
+            type Real = $real;
+
+            unsafe fn dot(
+                x: *const Self::RawVector,
+                y: *const Self::RawVector,
+                result: *mut Self,
+            ) -> c_int {
+                sys::$dot(x, y, result as *mut _)
+            }
+
+            unsafe fn nrm2(x: *const Self::RawVector) -> $real {
+                sys::$nrm2(x)
+            }
+
+            unsafe fn asum(x: *const Self::RawVector) -> $real {
+                sys::$asum(x)
+            }
+
+            unsafe fn iamax(x: *const Self::RawVector) -> usize {
+                sys::$iamax(x)
+            }
+
+            unsafe fn swap(x: *mut Self::RawVector, y: *mut Self::RawVector) -> c_int {
+                sys::$swap(x, y)
+            }
+
+            unsafe fn copy(x: *const Self::RawVector, y: *mut Self::RawVector) -> c_int {
+                sys::$copy(x, y)
+            }
+
+            unsafe fn axpy(
+                alpha: Self,
+                x: *const Self::RawVector,
+                y: *mut Self::RawVector,
+            ) -> c_int {
+                sys::$axpy(blas_scalar!($kind, alpha), x, y)
+            }
+
+            unsafe fn scal(alpha: Self, x: *mut Self::RawVector) {
+                sys::$scal(blas_scalar!($kind, alpha), x)
+            }
+
+            unsafe fn gemv(
+                trans: ::enums::CblasTranspose,
+                alpha: Self,
+                a: *const Self::RawMatrix,
+                x: *const Self::RawVector,
+                beta: Self,
+                y: *mut Self::RawVector,
+            ) -> c_int {
+                sys::$gemv(
+                    trans.into(),
+                    blas_scalar!($kind, alpha),
+                    a,
+                    x,
+                    blas_scalar!($kind, beta),
+                    y,
+                )
+            }
+
+            unsafe fn gemm(
+                trans_a: ::enums::CblasTranspose,
+                trans_b: ::enums::CblasTranspose,
+                alpha: Self,
+                a: *const Self::RawMatrix,
+                b: *const Self::RawMatrix,
+                beta: Self,
+                c: *mut Self::RawMatrix,
+            ) -> c_int {
+                sys::$gemm(
+                    trans_a.into(),
+                    trans_b.into(),
+                    blas_scalar!($kind, alpha),
+                    a,
+                    b,
+                    blas_scalar!($kind, beta),
+                    c,
+                )
+            }
+        }
+    };
+}
+
+impl_blas_element!(
+    real,
+    f32,
+    f32,
+    gsl_blas_sdot,
+    gsl_blas_snrm2,
+    gsl_blas_sasum,
+    gsl_blas_isamax,
+    gsl_blas_sswap,
+    gsl_blas_scopy,
+    gsl_blas_saxpy,
+    gsl_blas_sscal,
+    gsl_blas_sgemv,
+    gsl_blas_sgemm
+);
+impl_blas_element!(
+    real,
+    f64,
+    f64,
+    gsl_blas_ddot,
+    gsl_blas_dnrm2,
+    gsl_blas_dasum,
+    gsl_blas_idamax,
+    gsl_blas_dswap,
+    gsl_blas_dcopy,
+    gsl_blas_daxpy,
+    gsl_blas_dscal,
+    gsl_blas_dgemv,
+    gsl_blas_dgemm
+);
+impl_blas_element!(
+    complex,
+    ComplexF32,
+    f32,
+    gsl_blas_cdotu,
+    gsl_blas_scnrm2,
+    gsl_blas_scasum,
+    gsl_blas_icamax,
+    gsl_blas_cswap,
+    gsl_blas_ccopy,
+    gsl_blas_caxpy,
+    gsl_blas_cscal,
+    gsl_blas_cgemv,
+    gsl_blas_cgemm
+);
+impl_blas_element!(
+    complex,
+    ComplexF64,
+    f64,
+    gsl_blas_zdotu,
+    gsl_blas_dznrm2,
+    gsl_blas_dzasum,
+    gsl_blas_izamax,
+    gsl_blas_zswap,
+    gsl_blas_zcopy,
+    gsl_blas_zaxpy,
+    gsl_blas_zscal,
+    gsl_blas_zgemv,
+    gsl_blas_zgemm
+);
+
 pub mod level1 {
     use crate::Value;
-    use ffi::FFI;
     use types::complex::CFFI;
+    use types::{ComplexF32, ComplexF64, GslVector, GslVectorMut};
+
+    use super::BlasElement;
+
+    /// This function computes the scalar product x^T y for the vectors x and y of any
+    /// [`BlasElement`] type. For complex vectors, this is the unconjugated product (see `zdotu`).
+    ///
+    /// Returns `(Value, result)`.
+    pub fn dot<T: BlasElement>(x: &impl GslVector<T>, y: &impl GslVector<T>) -> (Value, T) {
+        let mut result = T::default();
+        let ret = unsafe { T::dot(x.unwrap_shared(), y.unwrap_shared(), &mut result) };
+        (Value::from(ret), result)
+    }
+
+    /// This function computes the Euclidean norm of the vector x (see `dnrm2` and `dznrm2`).
+    pub fn nrm2<T: BlasElement>(x: &impl GslVector<T>) -> T::Real {
+        unsafe { T::nrm2(x.unwrap_shared()) }
+    }
+
+    /// This function computes the absolute sum of the elements of the vector x (see `dasum` and
+    /// `dzasum`).
+    pub fn asum<T: BlasElement>(x: &impl GslVector<T>) -> T::Real {
+        unsafe { T::asum(x.unwrap_shared()) }
+    }
+
+    /// This function returns the index of the largest element of the vector x (see `idamax` and
+    /// `izamax`).
+    pub fn iamax<T: BlasElement>(x: &impl GslVector<T>) -> usize {
+        unsafe { T::iamax(x.unwrap_shared()) }
+    }
+
+    /// This function exchanges the elements of the vectors x and y.
+    pub fn swap<T: BlasElement>(
+        x: &mut impl GslVectorMut<T>,
+        y: &mut impl GslVectorMut<T>,
+    ) -> Value {
+        Value::from(unsafe { T::swap(x.unwrap_unique(), y.unwrap_unique()) })
+    }
+
+    /// This function copies the elements of the vector x into the vector y.
+    pub fn copy<T: BlasElement>(x: &impl GslVector<T>, y: &mut impl GslVectorMut<T>) -> Value {
+        Value::from(unsafe { T::copy(x.unwrap_shared(), y.unwrap_unique()) })
+    }
+
+    /// This function computes the sum y = \alpha x + y for the vectors x and y.
+    pub fn axpy<T: BlasElement>(
+        alpha: T,
+        x: &impl GslVector<T>,
+        y: &mut impl GslVectorMut<T>,
+    ) -> Value {
+        Value::from(unsafe { T::axpy(alpha, x.unwrap_shared(), y.unwrap_unique()) })
+    }
+
+    /// This function rescales the vector x by the multiplicative factor alpha.
+    pub fn scal<T: BlasElement>(alpha: T, x: &mut impl GslVectorMut<T>) {
+        unsafe { T::scal(alpha, x.unwrap_unique()) }
+    }
 
     /// This function computes the sum \alpha + x^T y for the vectors x and y, returning the result
     /// in result.
     ///
     /// Returns `result`.
     #[doc(alias = "gsl_blas_sdsdot")]
-    pub fn sdsdot(alpha: f32, x: &::types::VectorF32, y: &::types::VectorF32) -> (Value, f32) {
+    pub fn sdsdot(alpha: f32, x: &impl GslVector<f32>, y: &impl GslVector<f32>) -> (Value, f32) {
         let mut result = 0.;
         let ret = unsafe {
             sys::gsl_blas_sdsdot(alpha, x.unwrap_shared(), y.unwrap_shared(), &mut result)
@@ -25,7 +288,7 @@ pub mod level1 {
     ///
     /// Returns `result`.
     #[doc(alias = "gsl_blas_sdot")]
-    pub fn sdot(x: &::types::VectorF32, y: &::types::VectorF32) -> (Value, f32) {
+    pub fn sdot(x: &impl GslVector<f32>, y: &impl GslVector<f32>) -> (Value, f32) {
         let mut result = 0.;
         let ret = unsafe { sys::gsl_blas_sdot(x.unwrap_shared(), y.unwrap_shared(), &mut result) };
         (::Value::from(ret), result)
@@ -36,7 +299,7 @@ pub mod level1 {
     ///
     /// Returns `result`.
     #[doc(alias = "gsl_blas_dsdot")]
-    pub fn dsdot(x: &::types::VectorF32, y: &::types::VectorF32) -> (Value, f64) {
+    pub fn dsdot(x: &impl GslVector<f32>, y: &impl GslVector<f32>) -> (Value, f64) {
         let mut result = 0.;
         let ret = unsafe { sys::gsl_blas_dsdot(x.unwrap_shared(), y.unwrap_shared(), &mut result) };
         (::Value::from(ret), result)
@@ -47,7 +310,7 @@ pub mod level1 {
     ///
     /// Returns `result`.
     #[doc(alias = "gsl_blas_ddot")]
-    pub fn ddot(x: &::types::VectorF64, y: &::types::VectorF64) -> (Value, f64) {
+    pub fn ddot(x: &impl GslVector<f64>, y: &impl GslVector<f64>) -> (Value, f64) {
         let mut result = 0.;
         let ret = unsafe { sys::gsl_blas_ddot(x.unwrap_shared(), y.unwrap_shared(), &mut result) };
         (::Value::from(ret), result)
@@ -59,8 +322,8 @@ pub mod level1 {
     /// Returns `dotu`.
     #[doc(alias = "gsl_blas_cdotu")]
     pub fn cdotu(
-        x: &::types::VectorComplexF32,
-        y: &::types::VectorComplexF32,
+        x: &impl GslVector<ComplexF32>,
+        y: &impl GslVector<ComplexF32>,
     ) -> (Value, ::types::ComplexF32) {
         let mut dotu = ::types::ComplexF32::default().unwrap();
         let ret = unsafe { sys::gsl_blas_cdotu(x.unwrap_shared(), y.unwrap_shared(), &mut dotu) };
@@ -73,8 +336,8 @@ pub mod level1 {
     /// Returns `dotu`.
     #[doc(alias = "gsl_blas_zdotu")]
     pub fn zdotu(
-        x: &::types::VectorComplexF64,
-        y: &::types::VectorComplexF64,
+        x: &impl GslVector<ComplexF64>,
+        y: &impl GslVector<ComplexF64>,
     ) -> (Value, ::types::ComplexF64) {
         let mut dotu = ::types::ComplexF64::default().unwrap();
         let ret = unsafe { sys::gsl_blas_zdotu(x.unwrap_shared(), y.unwrap_shared(), &mut dotu) };
@@ -87,8 +350,8 @@ pub mod level1 {
     /// Returns `dotc`.
     #[doc(alias = "gsl_blas_cdotc")]
     pub fn cdotc(
-        x: &::types::VectorComplexF32,
-        y: &::types::VectorComplexF32,
+        x: &impl GslVector<ComplexF32>,
+        y: &impl GslVector<ComplexF32>,
     ) -> (Value, ::types::ComplexF32) {
         let mut dotc = ::types::ComplexF32::default().unwrap();
         let ret = unsafe { sys::gsl_blas_cdotc(x.unwrap_shared(), y.unwrap_shared(), &mut dotc) };
@@ -101,8 +364,8 @@ pub mod level1 {
     /// Returns `dotc`.
     #[doc(alias = "gsl_blas_zdotc")]
     pub fn zdotc(
-        x: &::types::VectorComplexF64,
-        y: &::types::VectorComplexF64,
+        x: &impl GslVector<ComplexF64>,
+        y: &impl GslVector<ComplexF64>,
     ) -> (Value, ::types::ComplexF64) {
         let mut dotc = ::types::ComplexF64::default().unwrap();
         let ret = unsafe { sys::gsl_blas_zdotc(x.unwrap_shared(), y.unwrap_shared(), &mut dotc) };
@@ -111,13 +374,13 @@ pub mod level1 {
 
     /// This function computes the Euclidean norm ||x||_2 = \sqrt {\sum x_i^2} of the vector x.
     #[doc(alias = "gsl_blas_snrm2")]
-    pub fn snrm2(x: &::types::VectorF32) -> f32 {
+    pub fn snrm2(x: &impl GslVector<f32>) -> f32 {
         unsafe { sys::gsl_blas_snrm2(x.unwrap_shared()) }
     }
 
     /// This function computes the Euclidean norm ||x||_2 = \sqrt {\sum x_i^2} of the vector x.
     #[doc(alias = "gsl_blas_dnrm2")]
-    pub fn dnrm2(x: &::types::VectorF64) -> f64 {
+    pub fn dnrm2(x: &impl GslVector<f64>) -> f64 {
         unsafe { sys::gsl_blas_dnrm2(x.unwrap_shared()) }
     }
 
@@ -125,7 +388,7 @@ pub mod level1 {
     ///
     /// ||x||_2 = \sqrt {\sum (\Re(x_i)^2 + \Im(x_i)^2)}.
     #[doc(alias = "gsl_blas_scnrm2")]
-    pub fn scnrm2(x: &::types::VectorComplexF32) -> f32 {
+    pub fn scnrm2(x: &impl GslVector<ComplexF32>) -> f32 {
         unsafe { sys::gsl_blas_scnrm2(x.unwrap_shared()) }
     }
 
@@ -133,31 +396,31 @@ pub mod level1 {
     ///
     /// ||x||_2 = \sqrt {\sum (\Re(x_i)^2 + \Im(x_i)^2)}.
     #[doc(alias = "gsl_blas_dznrm2")]
-    pub fn dznrm2(x: &::types::VectorComplexF64) -> f64 {
+    pub fn dznrm2(x: &impl GslVector<ComplexF64>) -> f64 {
         unsafe { sys::gsl_blas_dznrm2(x.unwrap_shared()) }
     }
 
     /// This function computes the absolute sum \sum |x_i| of the elements of the vector x.
     #[doc(alias = "gsl_blas_sasum")]
-    pub fn sasum(x: &::types::VectorF32) -> f32 {
+    pub fn sasum(x: &impl GslVector<f32>) -> f32 {
         unsafe { sys::gsl_blas_sasum(x.unwrap_shared()) }
     }
 
     /// This function computes the absolute sum \sum |x_i| of the elements of the vector x.
     #[doc(alias = "gsl_blas_dasum")]
-    pub fn dasum(x: &::types::VectorF64) -> f64 {
+    pub fn dasum(x: &impl GslVector<f64>) -> f64 {
         unsafe { sys::gsl_blas_dasum(x.unwrap_shared()) }
     }
 
     /// This function computes the sum of the magnitudes of the real and imaginary parts of the complex vector x, \sum |\Re(x_i)| + |\Im(x_i)|.
     #[doc(alias = "gsl_blas_scasum")]
-    pub fn scasum(x: &::types::VectorComplexF32) -> f32 {
+    pub fn scasum(x: &impl GslVector<ComplexF32>) -> f32 {
         unsafe { sys::gsl_blas_scasum(x.unwrap_shared()) }
     }
 
     /// This function computes the sum of the magnitudes of the real and imaginary parts of the complex vector x, \sum |\Re(x_i)| + |\Im(x_i)|.
     #[doc(alias = "gsl_blas_dzasum")]
-    pub fn dzasum(x: &::types::VectorComplexF64) -> f64 {
+    pub fn dzasum(x: &impl GslVector<ComplexF64>) -> f64 {
         unsafe { sys::gsl_blas_dzasum(x.unwrap_shared()) }
     }
 
@@ -165,7 +428,7 @@ pub mod level1 {
     /// The largest element is determined by its absolute magnitude for real vectors and by the sum of the magnitudes of the real and imaginary parts |\Re(x_i)| + |\Im(x_i)| for complex vectors.
     /// If the largest value occurs several times then the index of the first occurrence is returned.
     #[doc(alias = "gsl_blas_isamax")]
-    pub fn isamax(x: &::types::VectorF32) -> usize {
+    pub fn isamax(x: &impl GslVector<f32>) -> usize {
         unsafe { sys::gsl_blas_isamax(x.unwrap_shared()) }
     }
 
@@ -173,7 +436,7 @@ pub mod level1 {
     /// The largest element is determined by its absolute magnitude for real vectors and by the sum of the magnitudes of the real and imaginary parts |\Re(x_i)| + |\Im(x_i)| for complex vectors.
     /// If the largest value occurs several times then the index of the first occurrence is returned.
     #[doc(alias = "gsl_blas_idamax")]
-    pub fn idamax(x: &::types::VectorF64) -> usize {
+    pub fn idamax(x: &impl GslVector<f64>) -> usize {
         unsafe { sys::gsl_blas_idamax(x.unwrap_shared()) }
     }
 
@@ -181,7 +444,7 @@ pub mod level1 {
     /// The largest element is determined by its absolute magnitude for real vectors and by the sum of the magnitudes of the real and imaginary parts |\Re(x_i)| + |\Im(x_i)| for complex vectors.
     /// If the largest value occurs several times then the index of the first occurrence is returned.
     #[doc(alias = "gsl_blas_icamax")]
-    pub fn icamax(x: &::types::VectorComplexF32) -> usize {
+    pub fn icamax(x: &impl GslVector<ComplexF32>) -> usize {
         unsafe { sys::gsl_blas_icamax(x.unwrap_shared()) }
     }
 
@@ -189,67 +452,79 @@ pub mod level1 {
     /// The largest element is determined by its absolute magnitude for real vectors and by the sum of the magnitudes of the real and imaginary parts |\Re(x_i)| + |\Im(x_i)| for complex vectors.
     /// If the largest value occurs several times then the index of the first occurrence is returned.
     #[doc(alias = "gsl_blas_izamax")]
-    pub fn izamax(x: &::types::VectorComplexF64) -> usize {
+    pub fn izamax(x: &impl GslVector<ComplexF64>) -> usize {
         unsafe { sys::gsl_blas_izamax(x.unwrap_shared()) }
     }
 
     /// This function exchanges the elements of the vectors x and y.
     #[doc(alias = "gsl_blas_sswap")]
-    pub fn sswap(x: &mut ::types::VectorF32, y: &mut ::types::VectorF32) -> Value {
+    pub fn sswap(x: &mut impl GslVectorMut<f32>, y: &mut impl GslVectorMut<f32>) -> Value {
         Value::from(unsafe { sys::gsl_blas_sswap(x.unwrap_unique(), y.unwrap_unique()) })
     }
 
     /// This function exchanges the elements of the vectors x and y.
     #[doc(alias = "gsl_blas_dswap")]
-    pub fn dswap(x: &mut ::types::VectorF64, y: &mut ::types::VectorF64) -> Value {
+    pub fn dswap(x: &mut impl GslVectorMut<f64>, y: &mut impl GslVectorMut<f64>) -> Value {
         Value::from(unsafe { sys::gsl_blas_dswap(x.unwrap_unique(), y.unwrap_unique()) })
     }
 
     /// This function exchanges the elements of the vectors x and y.
     #[doc(alias = "gsl_blas_cswap")]
-    pub fn cswap(x: &mut ::types::VectorComplexF32, y: &mut ::types::VectorComplexF32) -> Value {
+    pub fn cswap(
+        x: &mut impl GslVectorMut<ComplexF32>,
+        y: &mut impl GslVectorMut<ComplexF32>,
+    ) -> Value {
         Value::from(unsafe { sys::gsl_blas_cswap(x.unwrap_unique(), y.unwrap_unique()) })
     }
 
     /// This function exchanges the elements of the vectors x and y.
     #[doc(alias = "gsl_blas_zswap")]
-    pub fn zswap(x: &mut ::types::VectorComplexF64, y: &mut ::types::VectorComplexF64) -> Value {
+    pub fn zswap(
+        x: &mut impl GslVectorMut<ComplexF64>,
+        y: &mut impl GslVectorMut<ComplexF64>,
+    ) -> Value {
         Value::from(unsafe { sys::gsl_blas_zswap(x.unwrap_unique(), y.unwrap_unique()) })
     }
 
     /// This function copy the elements of the vector x into the vector y.
     #[doc(alias = "gsl_blas_scopy")]
-    pub fn scopy(x: &mut ::types::VectorF32, y: &mut ::types::VectorF32) -> Value {
+    pub fn scopy(x: &mut impl GslVectorMut<f32>, y: &mut impl GslVectorMut<f32>) -> Value {
         Value::from(unsafe { sys::gsl_blas_scopy(x.unwrap_unique(), y.unwrap_unique()) })
     }
 
     /// This function copy the elements of the vector x into the vector y.
     #[doc(alias = "gsl_blas_dcopy")]
-    pub fn dcopy(x: &mut ::types::VectorF64, y: &mut ::types::VectorF64) -> Value {
+    pub fn dcopy(x: &mut impl GslVectorMut<f64>, y: &mut impl GslVectorMut<f64>) -> Value {
         Value::from(unsafe { sys::gsl_blas_dcopy(x.unwrap_unique(), y.unwrap_unique()) })
     }
 
     /// This function copy the elements of the vector x into the vector y.
     #[doc(alias = "gsl_blas_ccopy")]
-    pub fn ccopy(x: &mut ::types::VectorComplexF32, y: &mut ::types::VectorComplexF32) -> Value {
+    pub fn ccopy(
+        x: &mut impl GslVectorMut<ComplexF32>,
+        y: &mut impl GslVectorMut<ComplexF32>,
+    ) -> Value {
         Value::from(unsafe { sys::gsl_blas_ccopy(x.unwrap_unique(), y.unwrap_unique()) })
     }
 
     /// This function copy the elements of the vector x into the vector y.
     #[doc(alias = "gsl_blas_zcopy")]
-    pub fn zcopy(x: &mut ::types::VectorComplexF64, y: &mut ::types::VectorComplexF64) -> Value {
+    pub fn zcopy(
+        x: &mut impl GslVectorMut<ComplexF64>,
+        y: &mut impl GslVectorMut<ComplexF64>,
+    ) -> Value {
         Value::from(unsafe { sys::gsl_blas_zcopy(x.unwrap_unique(), y.unwrap_unique()) })
     }
 
     /// This function computes the sum y = \alpha x + y for the vectors x and y.
     #[doc(alias = "gsl_blas_saxpy")]
-    pub fn saxpy(alpha: f32, x: &::types::VectorF32, y: &mut ::types::VectorF32) -> Value {
+    pub fn saxpy(alpha: f32, x: &impl GslVector<f32>, y: &mut impl GslVectorMut<f32>) -> Value {
         Value::from(unsafe { sys::gsl_blas_saxpy(alpha, x.unwrap_shared(), y.unwrap_unique()) })
     }
 
     /// This function computes the sum y = \alpha x + y for the vectors x and y.
     #[doc(alias = "gsl_blas_daxpy")]
-    pub fn daxpy(alpha: f64, x: &::types::VectorF64, y: &mut ::types::VectorF64) -> Value {
+    pub fn daxpy(alpha: f64, x: &impl GslVector<f64>, y: &mut impl GslVectorMut<f64>) -> Value {
         Value::from(unsafe { sys::gsl_blas_daxpy(alpha, x.unwrap_shared(), y.unwrap_unique()) })
     }
 
@@ -257,8 +532,8 @@ pub mod level1 {
     #[doc(alias = "gsl_blas_caxpy")]
     pub fn caxpy(
         alpha: &::types::ComplexF32,
-        x: &::types::VectorComplexF32,
-        y: &mut ::types::VectorComplexF32,
+        x: &impl GslVector<ComplexF32>,
+        y: &mut impl GslVectorMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_caxpy(
@@ -273,8 +548,8 @@ pub mod level1 {
     #[doc(alias = "gsl_blas_zaxpy")]
     pub fn zaxpy(
         alpha: &::types::ComplexF64,
-        x: &::types::VectorComplexF64,
-        y: &mut ::types::VectorComplexF64,
+        x: &impl GslVector<ComplexF64>,
+        y: &mut impl GslVectorMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zaxpy(
@@ -287,37 +562,37 @@ pub mod level1 {
 
     /// This function rescales the vector x by the multiplicative factor alpha.
     #[doc(alias = "gsl_blas_sscal")]
-    pub fn sscal(alpha: f32, x: &mut ::types::VectorF32) {
+    pub fn sscal(alpha: f32, x: &mut impl GslVectorMut<f32>) {
         unsafe { sys::gsl_blas_sscal(alpha, x.unwrap_unique()) }
     }
 
     /// This function rescales the vector x by the multiplicative factor alpha.
     #[doc(alias = "gsl_blas_dscal")]
-    pub fn dscal(alpha: f64, x: &mut ::types::VectorF64) {
+    pub fn dscal(alpha: f64, x: &mut impl GslVectorMut<f64>) {
         unsafe { sys::gsl_blas_dscal(alpha, x.unwrap_unique()) }
     }
 
     /// This function rescales the vector x by the multiplicative factor alpha.
     #[doc(alias = "gsl_blas_cscal")]
-    pub fn cscal(alpha: &::types::ComplexF32, x: &mut ::types::VectorComplexF32) {
+    pub fn cscal(alpha: &::types::ComplexF32, x: &mut impl GslVectorMut<ComplexF32>) {
         unsafe { sys::gsl_blas_cscal(::std::mem::transmute(*alpha), x.unwrap_unique()) }
     }
 
     /// This function rescales the vector x by the multiplicative factor alpha.
     #[doc(alias = "gsl_blas_zscal")]
-    pub fn zscal(alpha: &::types::ComplexF64, x: &mut ::types::VectorComplexF64) {
+    pub fn zscal(alpha: &::types::ComplexF64, x: &mut impl GslVectorMut<ComplexF64>) {
         unsafe { sys::gsl_blas_zscal(::std::mem::transmute(*alpha), x.unwrap_unique()) }
     }
 
     /// This function rescales the vector x by the multiplicative factor alpha.
     #[doc(alias = "gsl_blas_csscal")]
-    pub fn csscal(alpha: f32, x: &mut ::types::VectorComplexF32) {
+    pub fn csscal(alpha: f32, x: &mut impl GslVectorMut<ComplexF32>) {
         unsafe { sys::gsl_blas_csscal(alpha, x.unwrap_unique()) }
     }
 
     /// This function rescales the vector x by the multiplicative factor alpha.
     #[doc(alias = "gsl_blas_zdscal")]
-    pub fn zdscal(alpha: f64, x: &mut ::types::VectorComplexF64) {
+    pub fn zdscal(alpha: f64, x: &mut impl GslVectorMut<ComplexF64>) {
         unsafe { sys::gsl_blas_zdscal(alpha, x.unwrap_unique()) }
     }
 
@@ -365,13 +640,23 @@ pub mod level1 {
 
     /// This function applies a Givens rotation (x', y') = (c x + s y, -s x + c y) to the vectors x, y.
     #[doc(alias = "gsl_blas_srot")]
-    pub fn srot(a: &mut ::types::VectorF32, b: &mut ::types::VectorF32, c: f32, d: f32) -> Value {
+    pub fn srot(
+        a: &mut impl GslVectorMut<f32>,
+        b: &mut impl GslVectorMut<f32>,
+        c: f32,
+        d: f32,
+    ) -> Value {
         Value::from(unsafe { sys::gsl_blas_srot(a.unwrap_unique(), b.unwrap_unique(), c, d) })
     }
 
     /// This function applies a Givens rotation (x', y') = (c x + s y, -s x + c y) to the vectors x, y.
     #[doc(alias = "gsl_blas_drot")]
-    pub fn drot(a: &mut ::types::VectorF64, b: &mut ::types::VectorF64, c: f64, d: f64) -> Value {
+    pub fn drot(
+        a: &mut impl GslVectorMut<f64>,
+        b: &mut impl GslVectorMut<f64>,
+        c: f64,
+        d: f64,
+    ) -> Value {
         Value::from(unsafe { sys::gsl_blas_drot(a.unwrap_unique(), b.unwrap_unique(), c, d) })
     }
 
@@ -407,7 +692,11 @@ pub mod level1 {
 
     /// This function applies a modified Givens transformation.
     #[doc(alias = "gsl_blas_srotm")]
-    pub fn srotm(x: &mut ::types::VectorF32, y: &mut ::types::VectorF32, P: &mut [f32]) -> Value {
+    pub fn srotm(
+        x: &mut impl GslVectorMut<f32>,
+        y: &mut impl GslVectorMut<f32>,
+        P: &mut [f32],
+    ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_srotm(x.unwrap_unique(), y.unwrap_unique(), P.as_mut_ptr())
         })
@@ -415,7 +704,11 @@ pub mod level1 {
 
     /// This function applies a modified Givens transformation.
     #[doc(alias = "gsl_blas_drotm")]
-    pub fn drotm(x: &mut ::types::VectorF64, y: &mut ::types::VectorF64, P: &mut [f64]) -> Value {
+    pub fn drotm(
+        x: &mut impl GslVectorMut<f64>,
+        y: &mut impl GslVectorMut<f64>,
+        P: &mut [f64],
+    ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_drotm(x.unwrap_unique(), y.unwrap_unique(), P.as_mut_ptr())
         })
@@ -425,17 +718,41 @@ pub mod level1 {
 pub mod level2 {
     use crate::enums;
     use crate::Value;
-    use ffi::FFI;
+    use types::{ComplexF32, ComplexF64, GslMatrix, GslMatrixMut, GslVector, GslVectorMut};
+
+    use super::BlasElement;
+
+    /// This function computes the matrix-vector product and sum y = \alpha op(A) x + \beta y for
+    /// any [`BlasElement`] type (see `dgemv` and `zgemv`).
+    pub fn gemv<T: BlasElement>(
+        transA: enums::CblasTranspose,
+        alpha: T,
+        A: &impl GslMatrix<T>,
+        x: &impl GslVector<T>,
+        beta: T,
+        y: &mut impl GslVectorMut<T>,
+    ) -> Value {
+        Value::from(unsafe {
+            T::gemv(
+                transA,
+                alpha,
+                A.unwrap_shared(),
+                x.unwrap_shared(),
+                beta,
+                y.unwrap_unique(),
+            )
+        })
+    }
 
     /// This function computes the matrix-vector product and sum y = \alpha op(A) x + \beta y, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
     #[doc(alias = "gsl_blas_sgemv")]
     pub fn sgemv(
         transA: enums::CblasTranspose,
         alpha: f32,
-        A: &::types::MatrixF32,
-        x: &::types::VectorF32,
+        A: &impl GslMatrix<f32>,
+        x: &impl GslVector<f32>,
         beta: f32,
-        y: &mut ::types::VectorF32,
+        y: &mut impl GslVectorMut<f32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_sgemv(
@@ -454,10 +771,10 @@ pub mod level2 {
     pub fn dgemv(
         transA: enums::CblasTranspose,
         alpha: f64,
-        A: &::types::MatrixF64,
-        x: &::types::VectorF64,
+        A: &impl GslMatrix<f64>,
+        x: &impl GslVector<f64>,
         beta: f64,
-        y: &mut ::types::VectorF64,
+        y: &mut impl GslVectorMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_dgemv(
@@ -476,10 +793,10 @@ pub mod level2 {
     pub fn cgemv(
         transA: enums::CblasTranspose,
         alpha: &::types::ComplexF32,
-        A: &::types::MatrixComplexF32,
-        x: &::types::VectorComplexF32,
+        A: &impl GslMatrix<ComplexF32>,
+        x: &impl GslVector<ComplexF32>,
         beta: &::types::ComplexF32,
-        y: &mut ::types::VectorComplexF32,
+        y: &mut impl GslVectorMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_cgemv(
@@ -498,10 +815,10 @@ pub mod level2 {
     pub fn zgemv(
         transA: enums::CblasTranspose,
         alpha: &::types::ComplexF64,
-        A: &::types::MatrixComplexF64,
-        x: &::types::VectorComplexF64,
+        A: &impl GslMatrix<ComplexF64>,
+        x: &impl GslVector<ComplexF64>,
         beta: &::types::ComplexF64,
-        y: &mut ::types::VectorComplexF64,
+        y: &mut impl GslVectorMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zgemv(
@@ -523,8 +840,8 @@ pub mod level2 {
         uplo: enums::CblasUplo,
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
-        A: &::types::MatrixF32,
-        x: &mut ::types::VectorF32,
+        A: &impl GslMatrix<f32>,
+        x: &mut impl GslVectorMut<f32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_strmv(
@@ -545,8 +862,8 @@ pub mod level2 {
         uplo: enums::CblasUplo,
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
-        A: &::types::MatrixF64,
-        x: &mut ::types::VectorF64,
+        A: &impl GslMatrix<f64>,
+        x: &mut impl GslVectorMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_dtrmv(
@@ -567,8 +884,8 @@ pub mod level2 {
         uplo: enums::CblasUplo,
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
-        A: &::types::MatrixComplexF32,
-        x: &mut ::types::VectorComplexF32,
+        A: &impl GslMatrix<ComplexF32>,
+        x: &mut impl GslVectorMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ctrmv(
@@ -589,8 +906,8 @@ pub mod level2 {
         uplo: enums::CblasUplo,
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
-        A: &::types::MatrixComplexF64,
-        x: &mut ::types::VectorComplexF64,
+        A: &impl GslMatrix<ComplexF64>,
+        x: &mut impl GslVectorMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ztrmv(
@@ -611,8 +928,8 @@ pub mod level2 {
         uplo: enums::CblasUplo,
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
-        A: &::types::MatrixF32,
-        x: &mut ::types::VectorF32,
+        A: &impl GslMatrix<f32>,
+        x: &mut impl GslVectorMut<f32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_strsv(
@@ -633,8 +950,8 @@ pub mod level2 {
         uplo: enums::CblasUplo,
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
-        A: &::types::MatrixF64,
-        x: &mut ::types::VectorF64,
+        A: &impl GslMatrix<f64>,
+        x: &mut impl GslVectorMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_dtrsv(
@@ -655,8 +972,8 @@ pub mod level2 {
         uplo: enums::CblasUplo,
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
-        A: &::types::MatrixComplexF32,
-        x: &mut ::types::VectorComplexF32,
+        A: &impl GslMatrix<ComplexF32>,
+        x: &mut impl GslVectorMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ctrsv(
@@ -677,8 +994,8 @@ pub mod level2 {
         uplo: enums::CblasUplo,
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
-        A: &::types::MatrixComplexF64,
-        x: &mut ::types::VectorComplexF64,
+        A: &impl GslMatrix<ComplexF64>,
+        x: &mut impl GslVectorMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ztrsv(
@@ -698,10 +1015,10 @@ pub mod level2 {
     pub fn ssymv(
         uplo: enums::CblasUplo,
         alpha: f32,
-        A: &::types::MatrixF32,
-        x: &::types::VectorF32,
+        A: &impl GslMatrix<f32>,
+        x: &impl GslVector<f32>,
         beta: f32,
-        y: &mut ::types::VectorF32,
+        y: &mut impl GslVectorMut<f32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ssymv(
@@ -722,10 +1039,10 @@ pub mod level2 {
     pub fn dsymv(
         uplo: enums::CblasUplo,
         alpha: f64,
-        A: &::types::MatrixF64,
-        x: &::types::VectorF64,
+        A: &impl GslMatrix<f64>,
+        x: &impl GslVector<f64>,
         beta: f64,
-        y: &mut ::types::VectorF64,
+        y: &mut impl GslVectorMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_dsymv(
@@ -746,10 +1063,10 @@ pub mod level2 {
     pub fn chemv(
         uplo: enums::CblasUplo,
         alpha: &::types::ComplexF32,
-        A: &::types::MatrixComplexF32,
-        x: &::types::VectorComplexF32,
+        A: &impl GslMatrix<ComplexF32>,
+        x: &impl GslVector<ComplexF32>,
         beta: &::types::ComplexF32,
-        y: &mut ::types::VectorComplexF32,
+        y: &mut impl GslVectorMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_chemv(
@@ -770,10 +1087,10 @@ pub mod level2 {
     pub fn zhemv(
         uplo: enums::CblasUplo,
         alpha: &::types::ComplexF64,
-        A: &::types::MatrixComplexF64,
-        x: &::types::VectorComplexF64,
+        A: &impl GslMatrix<ComplexF64>,
+        x: &impl GslVector<ComplexF64>,
         beta: &::types::ComplexF64,
-        y: &mut ::types::VectorComplexF64,
+        y: &mut impl GslVectorMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zhemv(
@@ -791,9 +1108,9 @@ pub mod level2 {
     #[doc(alias = "gsl_blas_sger")]
     pub fn sger(
         alpha: f32,
-        x: &::types::VectorF32,
-        y: &::types::VectorF32,
-        A: &mut ::types::MatrixF32,
+        x: &impl GslVector<f32>,
+        y: &impl GslVector<f32>,
+        A: &mut impl GslMatrixMut<f32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_sger(
@@ -809,9 +1126,9 @@ pub mod level2 {
     #[doc(alias = "gsl_blas_dger")]
     pub fn dger(
         alpha: f64,
-        x: &::types::VectorF64,
-        y: &::types::VectorF64,
-        A: &mut ::types::MatrixF64,
+        x: &impl GslVector<f64>,
+        y: &impl GslVector<f64>,
+        A: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_dger(
@@ -827,9 +1144,9 @@ pub mod level2 {
     #[doc(alias = "gsl_blas_cgeru")]
     pub fn cgeru(
         alpha: &::types::ComplexF32,
-        x: &::types::VectorComplexF32,
-        y: &::types::VectorComplexF32,
-        A: &mut ::types::MatrixComplexF32,
+        x: &impl GslVector<ComplexF32>,
+        y: &impl GslVector<ComplexF32>,
+        A: &mut impl GslMatrixMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_cgeru(
@@ -845,9 +1162,9 @@ pub mod level2 {
     #[doc(alias = "gsl_blas_zgeru")]
     pub fn zgeru(
         alpha: &::types::ComplexF64,
-        x: &::types::VectorComplexF64,
-        y: &::types::VectorComplexF64,
-        A: &mut ::types::MatrixComplexF64,
+        x: &impl GslVector<ComplexF64>,
+        y: &impl GslVector<ComplexF64>,
+        A: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zgeru(
@@ -863,9 +1180,9 @@ pub mod level2 {
     #[doc(alias = "gsl_blas_cgerc")]
     pub fn cgerc(
         alpha: &::types::ComplexF32,
-        x: &::types::VectorComplexF32,
-        y: &::types::VectorComplexF32,
-        A: &mut ::types::MatrixComplexF32,
+        x: &impl GslVector<ComplexF32>,
+        y: &impl GslVector<ComplexF32>,
+        A: &mut impl GslMatrixMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_cgerc(
@@ -881,9 +1198,9 @@ pub mod level2 {
     #[doc(alias = "gsl_blas_zgerc")]
     pub fn zgerc(
         alpha: &::types::ComplexF64,
-        x: &::types::VectorComplexF64,
-        y: &::types::VectorComplexF64,
-        A: &mut ::types::MatrixComplexF64,
+        x: &impl GslVector<ComplexF64>,
+        y: &impl GslVector<ComplexF64>,
+        A: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zgerc(
@@ -901,8 +1218,8 @@ pub mod level2 {
     pub fn ssyr(
         uplo: enums::CblasUplo,
         alpha: f32,
-        x: &::types::VectorF32,
-        A: &mut ::types::MatrixF32,
+        x: &impl GslVector<f32>,
+        A: &mut impl GslMatrixMut<f32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ssyr(uplo.into(), alpha, x.unwrap_shared(), A.unwrap_unique())
@@ -915,8 +1232,8 @@ pub mod level2 {
     pub fn dsyr(
         uplo: enums::CblasUplo,
         alpha: f64,
-        x: &::types::VectorF64,
-        A: &mut ::types::MatrixF64,
+        x: &impl GslVector<f64>,
+        A: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_dsyr(uplo.into(), alpha, x.unwrap_shared(), A.unwrap_unique())
@@ -931,8 +1248,8 @@ pub mod level2 {
     pub fn cher(
         uplo: enums::CblasUplo,
         alpha: f32,
-        x: &::types::VectorComplexF32,
-        A: &mut ::types::MatrixComplexF32,
+        x: &impl GslVector<ComplexF32>,
+        A: &mut impl GslMatrixMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_cher(uplo.into(), alpha, x.unwrap_shared(), A.unwrap_unique())
@@ -947,8 +1264,8 @@ pub mod level2 {
     pub fn zher(
         uplo: enums::CblasUplo,
         alpha: f64,
-        x: &::types::VectorComplexF64,
-        A: &mut ::types::MatrixComplexF64,
+        x: &impl GslVector<ComplexF64>,
+        A: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zher(uplo.into(), alpha, x.unwrap_shared(), A.unwrap_unique())
@@ -962,9 +1279,9 @@ pub mod level2 {
     pub fn ssyr2(
         uplo: enums::CblasUplo,
         alpha: f32,
-        x: &::types::VectorF32,
-        y: &::types::VectorF32,
-        A: &mut ::types::MatrixF32,
+        x: &impl GslVector<f32>,
+        y: &impl GslVector<f32>,
+        A: &mut impl GslMatrixMut<f32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ssyr2(
@@ -984,9 +1301,9 @@ pub mod level2 {
     pub fn dsyr2(
         uplo: enums::CblasUplo,
         alpha: f64,
-        x: &::types::VectorF64,
-        y: &::types::VectorF64,
-        A: &mut ::types::MatrixF64,
+        x: &impl GslVector<f64>,
+        y: &impl GslVector<f64>,
+        A: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_dsyr2(
@@ -1007,9 +1324,9 @@ pub mod level2 {
     pub fn cher2(
         uplo: enums::CblasUplo,
         alpha: &::types::ComplexF32,
-        x: &::types::VectorComplexF32,
-        y: &::types::VectorComplexF32,
-        A: &mut ::types::MatrixComplexF32,
+        x: &impl GslVector<ComplexF32>,
+        y: &impl GslVector<ComplexF32>,
+        A: &mut impl GslMatrixMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_cher2(
@@ -1030,9 +1347,9 @@ pub mod level2 {
     pub fn zher2(
         uplo: enums::CblasUplo,
         alpha: &::types::ComplexF64,
-        x: &::types::VectorComplexF64,
-        y: &::types::VectorComplexF64,
-        A: &mut ::types::MatrixComplexF64,
+        x: &impl GslVector<ComplexF64>,
+        y: &impl GslVector<ComplexF64>,
+        A: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zher2(
@@ -1049,7 +1366,33 @@ pub mod level2 {
 pub mod level3 {
     use crate::enums;
     use crate::Value;
-    use ffi::FFI;
+    use types::{ComplexF32, ComplexF64, GslMatrix, GslMatrixMut};
+
+    use super::BlasElement;
+
+    /// This function computes the matrix-matrix product and sum C = \alpha op(A) op(B) + \beta C
+    /// for any [`BlasElement`] type (see `dgemm` and `zgemm`).
+    pub fn gemm<T: BlasElement>(
+        transA: enums::CblasTranspose,
+        transB: enums::CblasTranspose,
+        alpha: T,
+        A: &impl GslMatrix<T>,
+        B: &impl GslMatrix<T>,
+        beta: T,
+        C: &mut impl GslMatrixMut<T>,
+    ) -> Value {
+        Value::from(unsafe {
+            T::gemm(
+                transA,
+                transB,
+                alpha,
+                A.unwrap_shared(),
+                B.unwrap_shared(),
+                beta,
+                C.unwrap_unique(),
+            )
+        })
+    }
 
     /// This function computes the matrix-matrix product and sum C = \alpha op(A) op(B) + \beta C where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans and similarly for the parameter TransB.
     #[doc(alias = "gsl_blas_sgemm")]
@@ -1057,10 +1400,10 @@ pub mod level3 {
         transA: enums::CblasTranspose,
         transB: enums::CblasTranspose,
         alpha: f32,
-        A: &::types::MatrixF32,
-        B: &::types::MatrixF32,
+        A: &impl GslMatrix<f32>,
+        B: &impl GslMatrix<f32>,
         beta: f32,
-        C: &mut ::types::MatrixF32,
+        C: &mut impl GslMatrixMut<f32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_sgemm(
@@ -1081,10 +1424,10 @@ pub mod level3 {
         transA: enums::CblasTranspose,
         transB: enums::CblasTranspose,
         alpha: f64,
-        A: &::types::MatrixF64,
-        B: &::types::MatrixF64,
+        A: &impl GslMatrix<f64>,
+        B: &impl GslMatrix<f64>,
         beta: f64,
-        C: &mut ::types::MatrixF64,
+        C: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_dgemm(
@@ -1105,10 +1448,10 @@ pub mod level3 {
         transA: enums::CblasTranspose,
         transB: enums::CblasTranspose,
         alpha: &::types::ComplexF32,
-        A: &::types::MatrixComplexF32,
-        B: &::types::MatrixComplexF32,
+        A: &impl GslMatrix<ComplexF32>,
+        B: &impl GslMatrix<ComplexF32>,
         beta: &::types::ComplexF32,
-        C: &mut ::types::MatrixComplexF32,
+        C: &mut impl GslMatrixMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_cgemm(
@@ -1129,10 +1472,10 @@ pub mod level3 {
         transA: enums::CblasTranspose,
         transB: enums::CblasTranspose,
         alpha: &::types::ComplexF64,
-        A: &::types::MatrixComplexF64,
-        B: &::types::MatrixComplexF64,
+        A: &impl GslMatrix<ComplexF64>,
+        B: &impl GslMatrix<ComplexF64>,
         beta: &::types::ComplexF64,
-        C: &mut ::types::MatrixComplexF64,
+        C: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zgemm(
@@ -1154,10 +1497,10 @@ pub mod level3 {
         side: enums::CblasSide,
         uplo: enums::CblasUplo,
         alpha: f32,
-        A: &::types::MatrixF32,
-        B: &::types::MatrixF32,
+        A: &impl GslMatrix<f32>,
+        B: &impl GslMatrix<f32>,
         beta: f32,
-        C: &mut ::types::MatrixF32,
+        C: &mut impl GslMatrixMut<f32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ssymm(
@@ -1179,10 +1522,10 @@ pub mod level3 {
         side: enums::CblasSide,
         uplo: enums::CblasUplo,
         alpha: f64,
-        A: &::types::MatrixF64,
-        B: &::types::MatrixF64,
+        A: &impl GslMatrix<f64>,
+        B: &impl GslMatrix<f64>,
         beta: f64,
-        C: &mut ::types::MatrixF64,
+        C: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_dsymm(
@@ -1204,10 +1547,10 @@ pub mod level3 {
         side: enums::CblasSide,
         uplo: enums::CblasUplo,
         alpha: &::types::ComplexF32,
-        A: &::types::MatrixComplexF32,
-        B: &::types::MatrixComplexF32,
+        A: &impl GslMatrix<ComplexF32>,
+        B: &impl GslMatrix<ComplexF32>,
         beta: &::types::ComplexF32,
-        C: &mut ::types::MatrixComplexF32,
+        C: &mut impl GslMatrixMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_csymm(
@@ -1229,10 +1572,10 @@ pub mod level3 {
         side: enums::CblasSide,
         uplo: enums::CblasUplo,
         alpha: &::types::ComplexF64,
-        A: &::types::MatrixComplexF64,
-        B: &::types::MatrixComplexF64,
+        A: &impl GslMatrix<ComplexF64>,
+        B: &impl GslMatrix<ComplexF64>,
         beta: &::types::ComplexF64,
-        C: &mut ::types::MatrixComplexF64,
+        C: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zsymm(
@@ -1255,10 +1598,10 @@ pub mod level3 {
         side: enums::CblasSide,
         uplo: enums::CblasUplo,
         alpha: &::types::ComplexF32,
-        A: &::types::MatrixComplexF32,
-        B: &::types::MatrixComplexF32,
+        A: &impl GslMatrix<ComplexF32>,
+        B: &impl GslMatrix<ComplexF32>,
         beta: &::types::ComplexF32,
-        C: &mut ::types::MatrixComplexF32,
+        C: &mut impl GslMatrixMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_chemm(
@@ -1281,10 +1624,10 @@ pub mod level3 {
         side: enums::CblasSide,
         uplo: enums::CblasUplo,
         alpha: &::types::ComplexF64,
-        A: &::types::MatrixComplexF64,
-        B: &::types::MatrixComplexF64,
+        A: &impl GslMatrix<ComplexF64>,
+        B: &impl GslMatrix<ComplexF64>,
         beta: &::types::ComplexF64,
-        C: &mut ::types::MatrixComplexF64,
+        C: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zhemm(
@@ -1310,8 +1653,8 @@ pub mod level3 {
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
         alpha: f32,
-        A: &::types::MatrixF32,
-        B: &mut ::types::MatrixF32,
+        A: &impl GslMatrix<f32>,
+        B: &mut impl GslMatrixMut<f32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_strmm(
@@ -1337,8 +1680,8 @@ pub mod level3 {
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
         alpha: f64,
-        A: &::types::MatrixF64,
-        B: &mut ::types::MatrixF64,
+        A: &impl GslMatrix<f64>,
+        B: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_dtrmm(
@@ -1364,8 +1707,8 @@ pub mod level3 {
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
         alpha: &::types::ComplexF32,
-        A: &::types::MatrixComplexF32,
-        B: &mut ::types::MatrixComplexF32,
+        A: &impl GslMatrix<ComplexF32>,
+        B: &mut impl GslMatrixMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ctrmm(
@@ -1391,8 +1734,8 @@ pub mod level3 {
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
         alpha: &::types::ComplexF64,
-        A: &::types::MatrixComplexF64,
-        B: &mut ::types::MatrixComplexF64,
+        A: &impl GslMatrix<ComplexF64>,
+        B: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ztrmm(
@@ -1418,8 +1761,8 @@ pub mod level3 {
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
         alpha: f32,
-        A: &::types::MatrixF32,
-        B: &mut ::types::MatrixF32,
+        A: &impl GslMatrix<f32>,
+        B: &mut impl GslMatrixMut<f32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_strsm(
@@ -1445,8 +1788,8 @@ pub mod level3 {
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
         alpha: f64,
-        A: &::types::MatrixF64,
-        B: &mut ::types::MatrixF64,
+        A: &impl GslMatrix<f64>,
+        B: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_dtrsm(
@@ -1472,8 +1815,8 @@ pub mod level3 {
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
         alpha: &::types::ComplexF32,
-        A: &::types::MatrixComplexF32,
-        B: &mut ::types::MatrixComplexF32,
+        A: &impl GslMatrix<ComplexF32>,
+        B: &mut impl GslMatrixMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ctrsm(
@@ -1499,8 +1842,8 @@ pub mod level3 {
         transA: enums::CblasTranspose,
         diag: enums::CblasDiag,
         alpha: &::types::ComplexF64,
-        A: &::types::MatrixComplexF64,
-        B: &mut ::types::MatrixComplexF64,
+        A: &impl GslMatrix<ComplexF64>,
+        B: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ztrsm(
@@ -1523,9 +1866,9 @@ pub mod level3 {
         uplo: enums::CblasUplo,
         trans: enums::CblasTranspose,
         alpha: f32,
-        A: &::types::MatrixF32,
+        A: &impl GslMatrix<f32>,
         beta: f32,
-        C: &mut ::types::MatrixF32,
+        C: &mut impl GslMatrixMut<f32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ssyrk(
@@ -1547,9 +1890,9 @@ pub mod level3 {
         uplo: enums::CblasUplo,
         trans: enums::CblasTranspose,
         alpha: f64,
-        A: &::types::MatrixF64,
+        A: &impl GslMatrix<f64>,
         beta: f64,
-        C: &mut ::types::MatrixF64,
+        C: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_dsyrk(
@@ -1571,9 +1914,9 @@ pub mod level3 {
         uplo: enums::CblasUplo,
         trans: enums::CblasTranspose,
         alpha: &::types::ComplexF32,
-        A: &::types::MatrixComplexF32,
+        A: &impl GslMatrix<ComplexF32>,
         beta: &::types::ComplexF32,
-        C: &mut ::types::MatrixComplexF32,
+        C: &mut impl GslMatrixMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_csyrk(
@@ -1595,9 +1938,9 @@ pub mod level3 {
         uplo: enums::CblasUplo,
         trans: enums::CblasTranspose,
         alpha: &::types::ComplexF64,
-        A: &::types::MatrixComplexF64,
+        A: &impl GslMatrix<ComplexF64>,
         beta: &::types::ComplexF64,
-        C: &mut ::types::MatrixComplexF64,
+        C: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zsyrk(
@@ -1620,9 +1963,9 @@ pub mod level3 {
         uplo: enums::CblasUplo,
         trans: enums::CblasTranspose,
         alpha: f32,
-        A: &::types::MatrixComplexF32,
+        A: &impl GslMatrix<ComplexF32>,
         beta: f32,
-        C: &mut ::types::MatrixComplexF32,
+        C: &mut impl GslMatrixMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_cherk(
@@ -1645,9 +1988,9 @@ pub mod level3 {
         uplo: enums::CblasUplo,
         trans: enums::CblasTranspose,
         alpha: f64,
-        A: &::types::MatrixComplexF64,
+        A: &impl GslMatrix<ComplexF64>,
         beta: f64,
-        C: &mut ::types::MatrixComplexF64,
+        C: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zherk(
@@ -1669,10 +2012,10 @@ pub mod level3 {
         uplo: enums::CblasUplo,
         trans: enums::CblasTranspose,
         alpha: f32,
-        A: &::types::MatrixF32,
-        B: &::types::MatrixF32,
+        A: &impl GslMatrix<f32>,
+        B: &impl GslMatrix<f32>,
         beta: f32,
-        C: &mut ::types::MatrixF32,
+        C: &mut impl GslMatrixMut<f32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_ssyr2k(
@@ -1695,10 +2038,10 @@ pub mod level3 {
         uplo: enums::CblasUplo,
         trans: enums::CblasTranspose,
         alpha: f64,
-        A: &::types::MatrixF64,
-        B: &::types::MatrixF64,
+        A: &impl GslMatrix<f64>,
+        B: &impl GslMatrix<f64>,
         beta: f64,
-        C: &mut ::types::MatrixF64,
+        C: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_dsyr2k(
@@ -1721,10 +2064,10 @@ pub mod level3 {
         uplo: enums::CblasUplo,
         trans: enums::CblasTranspose,
         alpha: &::types::ComplexF32,
-        A: &::types::MatrixComplexF32,
-        B: &::types::MatrixComplexF32,
+        A: &impl GslMatrix<ComplexF32>,
+        B: &impl GslMatrix<ComplexF32>,
         beta: &::types::ComplexF32,
-        C: &mut ::types::MatrixComplexF32,
+        C: &mut impl GslMatrixMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_csyr2k(
@@ -1747,10 +2090,10 @@ pub mod level3 {
         uplo: enums::CblasUplo,
         trans: enums::CblasTranspose,
         alpha: &::types::ComplexF64,
-        A: &::types::MatrixComplexF64,
-        B: &::types::MatrixComplexF64,
+        A: &impl GslMatrix<ComplexF64>,
+        B: &impl GslMatrix<ComplexF64>,
         beta: &::types::ComplexF64,
-        C: &mut ::types::MatrixComplexF64,
+        C: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zsyr2k(
@@ -1774,10 +2117,10 @@ pub mod level3 {
         uplo: enums::CblasUplo,
         trans: enums::CblasTranspose,
         alpha: &::types::ComplexF32,
-        A: &::types::MatrixComplexF32,
-        B: &::types::MatrixComplexF32,
+        A: &impl GslMatrix<ComplexF32>,
+        B: &impl GslMatrix<ComplexF32>,
         beta: f32,
-        C: &mut ::types::MatrixComplexF32,
+        C: &mut impl GslMatrixMut<ComplexF32>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_cher2k(
@@ -1801,10 +2144,10 @@ pub mod level3 {
         uplo: enums::CblasUplo,
         trans: enums::CblasTranspose,
         alpha: &::types::ComplexF64,
-        A: &::types::MatrixComplexF64,
-        B: &::types::MatrixComplexF64,
+        A: &impl GslMatrix<ComplexF64>,
+        B: &impl GslMatrix<ComplexF64>,
         beta: f64,
-        C: &mut ::types::MatrixComplexF64,
+        C: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_blas_zher2k(
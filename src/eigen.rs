@@ -20,13 +20,16 @@ The LAPACK source code can be found at the website above along with an online co
 !*/
 
 use crate::Value;
-use ffi::FFI;
-use types::{MatrixComplexF64, MatrixF64, VectorComplexF64, VectorF64};
+use types::{ComplexF64, GslMatrixMut, GslVectorMut};
 
 /// This function simultaneously sorts the eigenvalues stored in the vector eval and the corresponding real eigenvectors stored in the columns
 /// of the matrix evec into ascending or descending order according to the value of the parameter sort_type
 #[doc(alias = "gsl_eigen_symmv_sort")]
-pub fn symmv_sort(eval: &mut VectorF64, evec: &mut MatrixF64, sort_type: ::EigenSort) -> Value {
+pub fn symmv_sort(
+    eval: &mut impl GslVectorMut<f64>,
+    evec: &mut impl GslMatrixMut<f64>,
+    sort_type: ::EigenSort,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_eigen_symmv_sort(eval.unwrap_unique(), evec.unwrap_unique(), sort_type.into())
     })
@@ -36,8 +39,8 @@ pub fn symmv_sort(eval: &mut VectorF64, evec: &mut MatrixF64, sort_type: ::Eigen
 /// of the matrix evec into ascending or descending order according to the value of the parameter sort_type.
 #[doc(alias = "gsl_eigen_hermv_sort")]
 pub fn hermv_sort(
-    eval: &mut VectorF64,
-    evec: &mut MatrixComplexF64,
+    eval: &mut impl GslVectorMut<f64>,
+    evec: &mut impl GslMatrixMut<ComplexF64>,
     sort_type: ::EigenSort,
 ) -> Value {
     Value::from(unsafe {
@@ -50,8 +53,8 @@ pub fn hermv_sort(
 /// EigenSort::AbsDesc are supported due to the eigenvalues being complex.
 #[doc(alias = "gsl_eigen_nonsymmv_sort")]
 pub fn nonsymmv_sort(
-    eval: &mut VectorComplexF64,
-    evec: &mut MatrixComplexF64,
+    eval: &mut impl GslVectorMut<ComplexF64>,
+    evec: &mut impl GslMatrixMut<ComplexF64>,
     sort_type: ::EigenSort,
 ) -> Value {
     Value::from(unsafe {
@@ -62,7 +65,11 @@ pub fn nonsymmv_sort(
 /// This function simultaneously sorts the eigenvalues stored in the vector eval and the corresponding real eigenvectors stored in the columns
 /// of the matrix evec into ascending or descending order according to the value of the parameter sort_type.
 #[doc(alias = "gsl_eigen_gensymmv_sort")]
-pub fn gensymmv_sort(eval: &mut VectorF64, evec: &mut MatrixF64, sort_type: ::EigenSort) -> Value {
+pub fn gensymmv_sort(
+    eval: &mut impl GslVectorMut<f64>,
+    evec: &mut impl GslMatrixMut<f64>,
+    sort_type: ::EigenSort,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_eigen_gensymmv_sort(eval.unwrap_unique(), evec.unwrap_unique(), sort_type.into())
     })
@@ -72,8 +79,8 @@ pub fn gensymmv_sort(eval: &mut VectorF64, evec: &mut MatrixF64, sort_type: ::Ei
 /// columns of the matrix evec into ascending or descending order according to the value of the parameter sort_type.
 #[doc(alias = "gsl_eigen_genhermv_sort")]
 pub fn genhermv_sort(
-    eval: &mut VectorF64,
-    evec: &mut MatrixComplexF64,
+    eval: &mut impl GslVectorMut<f64>,
+    evec: &mut impl GslMatrixMut<ComplexF64>,
     sort_type: ::EigenSort,
 ) -> Value {
     Value::from(unsafe {
@@ -86,9 +93,9 @@ pub fn genhermv_sort(
 /// EigenSort::AbsAsc and EigenSort::AbsDesc are supported due to the eigenvalues being complex.
 #[doc(alias = "gsl_eigen_genv_sort")]
 pub fn genv_sort(
-    alpha: &mut VectorComplexF64,
-    beta: &mut VectorF64,
-    evec: &mut MatrixComplexF64,
+    alpha: &mut impl GslVectorMut<ComplexF64>,
+    beta: &mut impl GslVectorMut<f64>,
+    evec: &mut impl GslMatrixMut<ComplexF64>,
     sort_type: ::EigenSort,
 ) -> Value {
     Value::from(unsafe {
@@ -35,6 +35,7 @@ impl From<sys::gsl_mode_t> for Mode {
 }
 
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub enum Value {
     Success,
     Failure,
@@ -67,6 +67,11 @@ singularity. As the subintervals decrease in size the successive approximations
 approach to the limit can be accelerated using an extrapolation procedure. The QAGS algorithm combines adaptive bisection with the Wynn
 epsilon-algorithm to speed up the integration of many types of integrable singularities.
 
+##Multidimensional integration
+
+[`integrate_nd`] computes integrals over regions of a few dimensions by nesting the adaptive QAGS
+or CQUAD routines, one per dimension. The bounds of each variable can depend on the outer ones.
+
 ##References and Further Reading
 
 The following book is the definitive reference for QUADPACK, and was written by the original authors. It provides descriptions of the
@@ -82,6 +87,8 @@ P. Gonnet, “Increasing the Reliability of Adaptive Quadrature Using Explicit I
 
 use crate::Value;
 use ffi::FFI;
+use std::cell::{Cell, RefCell};
+use types::integration::NestedQuadrature;
 
 /// This function applies the Gauss-Kronrod 10-point, 21-point, 43-point and 87-point integration
 /// rules in succession until an estimate of the integral of f over (a,b) is achieved within the
@@ -384,3 +391,196 @@ pub fn qawf<F: Fn(f64) -> f64>(
     };
     (::Value::from(ret), result, abs_err)
 }
+
+/// Computes the integral of `f` over a region of dimension `levels.len()` by nesting
+/// one-dimensional adaptive integrations: `levels[0]` describes the outermost variable and the
+/// last level the innermost one. `f` and the bounds of each level receive the values of the
+/// variables in the same order, so the region can have variable bounds such as
+/// `y_lo(x) <= y <= y_hi(x)`.
+///
+/// Each level uses its own tolerances and workspace. The errors of the inner integrals are
+/// propagated upward: the error of a level is its own error estimate plus the largest inner error
+/// times the length of its interval, and the first inner failure is returned as the status if the
+/// outermost integration itself succeeded. `neval` is the number of evaluations of `f`.
+///
+/// Panics if `levels` is empty.
+///
+/// ```no_run
+/// use rgsl::{integration::integrate_nd, CquadWorkspace, IntegrationLevel, IntegrationWorkspace};
+///
+/// // Integral of x * y over the triangle 0 <= x <= 1, 0 <= y <= x.
+/// let mut wx = IntegrationWorkspace::new(1000).unwrap();
+/// let mut wy = CquadWorkspace::new(100).unwrap();
+/// let outcome = integrate_nd(
+///     |p| p[0] * p[1],
+///     &mut [
+///         IntegrationLevel::qags(|_| 0., |_| 1., 0., 1e-8, 1000, &mut wx),
+///         IntegrationLevel::cquad(|_| 0., |p| p[0], 0., 1e-10, &mut wy),
+///     ],
+/// );
+/// println!("{} +/- {}", outcome.result, outcome.abserr);
+/// ```
+pub fn integrate_nd<F: Fn(&[f64]) -> f64>(
+    f: F,
+    levels: &mut [::IntegrationLevel],
+) -> ::IntegrationOutcome {
+    assert!(
+        !levels.is_empty(),
+        "rust-GSL: integrate_nd needs at least one level"
+    );
+    let point = RefCell::new(vec![0.; levels.len()]);
+    let neval = Cell::new(0);
+    let (status, result, abserr) = integrate_level(&f, levels, &point, &neval);
+    ::IntegrationOutcome {
+        status,
+        result,
+        abserr,
+        neval: neval.get(),
+    }
+}
+
+// Integrates over the first of `levels`, the values of the outer variables being the first
+// elements of `point`. Returns `(status, result, abserr)`.
+fn integrate_level<F: Fn(&[f64]) -> f64>(
+    f: &F,
+    levels: &mut [::IntegrationLevel],
+    point: &RefCell<Vec<f64>>,
+    neval: &Cell<usize>,
+) -> (Value, f64, f64) {
+    let depth = point.borrow().len() - levels.len();
+    let (level, rest) = levels.split_first_mut().expect("no level left");
+    let (a, b) = {
+        let outer = &point.borrow()[..depth];
+        ((level.lower)(outer), (level.upper)(outer))
+    };
+
+    let rest = RefCell::new(rest);
+    let inner_status = Cell::new(Value::Success);
+    let inner_abserr = Cell::new(0f64);
+    let g = |x: f64| {
+        point.borrow_mut()[depth] = x;
+        let mut rest = rest.borrow_mut();
+        if rest.is_empty() {
+            neval.set(neval.get() + 1);
+            f(&point.borrow())
+        } else {
+            let (status, result, abserr) = integrate_level(f, &mut rest, point, neval);
+            if status != Value::Success && inner_status.get() == Value::Success {
+                inner_status.set(status);
+            }
+            inner_abserr.set(inner_abserr.get().max(abserr));
+            result
+        }
+    };
+
+    let (status, result, abserr) = match level.quadrature {
+        NestedQuadrature::Qags {
+            ref mut workspace,
+            limit,
+        } => {
+            let outcome = workspace.qags(g, a, b, level.epsabs, level.epsrel, limit);
+            (outcome.status, outcome.result, outcome.abserr)
+        }
+        NestedQuadrature::Cquad(ref mut workspace) => {
+            let (status, result, abserr, _) = workspace.cquad(g, a, b, level.epsabs, level.epsrel);
+            (status, result, abserr)
+        }
+    };
+    let status = if status == Value::Success {
+        inner_status.get()
+    } else {
+        status
+    };
+    (status, result, abserr + (b - a).abs() * inner_abserr.get())
+}
+
+#[test]
+fn integrate_nd_separable() {
+    use types::{CquadWorkspace, IntegrationLevel, IntegrationWorkspace};
+
+    // Integral of x y^2 over [0, 1] x [0, 2], which is 1/2 * 8/3.
+    let mut wx = IntegrationWorkspace::new(1000).unwrap();
+    let mut wy = CquadWorkspace::new(100).unwrap();
+    let outcome = integrate_nd(
+        |p| p[0] * p[1] * p[1],
+        &mut [
+            IntegrationLevel::qags(|_| 0., |_| 1., 0., 1e-10, 1000, &mut wx),
+            IntegrationLevel::cquad(|_| 0., |_| 2., 0., 1e-12, &mut wy),
+        ],
+    );
+    assert_eq!(outcome.status, Value::Success);
+    assert!(
+        (outcome.result - 4. / 3.).abs() < 1e-10,
+        "{}",
+        outcome.result
+    );
+    assert!(outcome.abserr < 1e-8);
+    assert!(outcome.neval > 21);
+
+    // Integral of exp(x + y + z) over the unit cube, which is (e - 1)^3.
+    let mut w = [
+        IntegrationWorkspace::new(100).unwrap(),
+        IntegrationWorkspace::new(100).unwrap(),
+        IntegrationWorkspace::new(100).unwrap(),
+    ];
+    let mut levels: Vec<_> = w
+        .iter_mut()
+        .map(|w| IntegrationLevel::qags(|_| 0., |_| 1., 0., 1e-10, 100, w))
+        .collect();
+    let outcome = integrate_nd(|p| (p[0] + p[1] + p[2]).exp(), &mut levels);
+    assert_eq!(outcome.status, Value::Success);
+    let expected = (::std::f64::consts::E - 1.).powi(3);
+    assert!(
+        (outcome.result - expected).abs() < 1e-9,
+        "{}",
+        outcome.result
+    );
+    // At least one 21-point rule per level.
+    assert!(outcome.neval >= 21 * 21 * 21);
+}
+
+#[test]
+fn integrate_nd_variable_bounds() {
+    use types::{IntegrationLevel, IntegrationWorkspace};
+
+    // Integral of x y over the triangle 0 <= x <= 1, 0 <= y <= x, which is 1/8.
+    let mut wx = IntegrationWorkspace::new(1000).unwrap();
+    let mut wy = IntegrationWorkspace::new(1000).unwrap();
+    let outcome = integrate_nd(
+        |p| p[0] * p[1],
+        &mut [
+            IntegrationLevel::qags(|_| 0., |_| 1., 0., 1e-10, 1000, &mut wx),
+            IntegrationLevel::qags(|_| 0., |p| p[0], 0., 1e-12, 1000, &mut wy),
+        ],
+    );
+    assert_eq!(outcome.status, Value::Success);
+    assert!((outcome.result - 0.125).abs() < 1e-10, "{}", outcome.result);
+}
+
+#[test]
+fn integrate_nd_one_level() {
+    use types::{IntegrationLevel, IntegrationWorkspace};
+
+    let mut w = IntegrationWorkspace::new(1000).unwrap();
+    let outcome = integrate_nd(
+        |p| p[0].sin(),
+        &mut [IntegrationLevel::qags(
+            |_| 0.,
+            |_| ::std::f64::consts::PI,
+            0.,
+            1e-10,
+            1000,
+            &mut w,
+        )],
+    );
+    let mut w = IntegrationWorkspace::new(1000).unwrap();
+    let expected = w.qags(|x| x.sin(), 0., ::std::f64::consts::PI, 0., 1e-10, 1000);
+    assert_eq!(outcome, expected);
+    assert!((outcome.result - 2.).abs() < 1e-10);
+}
+
+#[test]
+#[should_panic(expected = "integrate_nd needs at least one level")]
+fn integrate_nd_no_level() {
+    integrate_nd(|_| 1., &mut []);
+}
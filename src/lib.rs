@@ -16,7 +16,19 @@
 #![doc = include_str!("../README.md")]
 
 extern crate gsl_sys as sys;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
+#[cfg(feature = "num-complex")]
+extern crate num_complex;
 extern crate paste;
+#[cfg(feature = "rand")]
+extern crate rand_core;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
 
 pub use types::*;
 
@@ -31,6 +43,14 @@ pub use self::enums::*;
 
 mod enums;
 mod macros;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impls;
+#[cfg(feature = "ndarray")]
+mod ndarray_impls;
+#[cfg(feature = "rand")]
+mod rand_impls;
+#[cfg(feature = "serde")]
+mod serde_impls;
 mod utilities;
 
 #[doc(hidden)]
@@ -88,6 +108,7 @@ pub mod psi;
 pub mod roots;
 pub mod sort;
 pub mod statistics;
+#[deprecated(note = "use the `statistics` module instead")]
 pub mod stats;
 pub mod synchrotron;
 pub mod transport;
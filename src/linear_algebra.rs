@@ -166,6 +166,7 @@ use crate::Value;
 use ffi::FFI;
 
 use types::complex::FFFI;
+use types::{ComplexF64, GslMatrix, GslMatrixMut, GslVector, GslVectorMut};
 
 /// Factorise a general N x N matrix A into,
 ///
@@ -183,7 +184,7 @@ use types::complex::FFFI;
 ///
 /// See Golub & Van Loan, Matrix Computations, Algorithm 3.4.1 (Gauss Elimination with Partial Pivoting).
 #[doc(alias = "gsl_linalg_LU_decomp")]
-pub fn LU_decomp(a: &mut ::MatrixF64, p: &mut ::Permutation, signum: &mut i32) -> Value {
+pub fn LU_decomp(a: &mut impl GslMatrixMut<f64>, p: &mut ::Permutation, signum: &mut i32) -> Value {
     Value::from(unsafe { sys::gsl_linalg_LU_decomp(a.unwrap_unique(), p.unwrap_unique(), signum) })
 }
 
@@ -204,7 +205,7 @@ pub fn LU_decomp(a: &mut ::MatrixF64, p: &mut ::Permutation, signum: &mut i32) -
 /// See Golub & Van Loan, Matrix Computations, Algorithm 3.4.1 (Gauss Elimination with Partial Pivoting).
 #[doc(alias = "gsl_linalg_complex_LU_decomp")]
 pub fn complex_LU_decomp(
-    a: &mut ::MatrixComplexF64,
+    a: &mut impl GslMatrixMut<ComplexF64>,
     p: &mut ::Permutation,
     signum: &mut i32,
 ) -> Value {
@@ -216,10 +217,10 @@ pub fn complex_LU_decomp(
 /// This function solves the square system A x = b using the LU decomposition of A into (LU, p) given by LU_decomp or LU_decomp as input.
 #[doc(alias = "gsl_linalg_LU_solve")]
 pub fn LU_solve(
-    lu: &::MatrixF64,
+    lu: &impl GslMatrix<f64>,
     p: &::Permutation,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_LU_solve(
@@ -234,10 +235,10 @@ pub fn LU_solve(
 /// This function solves the square system A x = b using the LU decomposition of A into (LU, p) given by LU_decomp or LU_decomp as input.
 #[doc(alias = "gsl_linalg_complex_LU_solve")]
 pub fn complex_LU_solve(
-    lu: &::MatrixComplexF64,
+    lu: &impl GslMatrix<ComplexF64>,
     p: &::Permutation,
-    b: &::VectorComplexF64,
-    x: &mut ::VectorComplexF64,
+    b: &impl GslVector<ComplexF64>,
+    x: &mut impl GslVectorMut<ComplexF64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_complex_LU_solve(
@@ -252,7 +253,11 @@ pub fn complex_LU_solve(
 /// This function solves the square system A x = b in-place using the precomputed LU decomposition of A into (LU,p). On input x should contain
 /// the right-hand side b, which is replaced by the solution on output.
 #[doc(alias = "gsl_linalg_LU_svx")]
-pub fn LU_svx(lu: &::MatrixF64, p: &::Permutation, x: &mut ::VectorF64) -> Value {
+pub fn LU_svx(
+    lu: &impl GslMatrix<f64>,
+    p: &::Permutation,
+    x: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_LU_svx(lu.unwrap_shared(), p.unwrap_shared(), x.unwrap_unique())
     })
@@ -262,9 +267,9 @@ pub fn LU_svx(lu: &::MatrixF64, p: &::Permutation, x: &mut ::VectorF64) -> Value
 /// the right-hand side b, which is replaced by the solution on output.
 #[doc(alias = "gsl_linalg_complex_LU_svx")]
 pub fn complex_LU_svx(
-    lu: &::MatrixComplexF64,
+    lu: &impl GslMatrix<ComplexF64>,
     p: &::Permutation,
-    x: &mut ::VectorComplexF64,
+    x: &mut impl GslVectorMut<ComplexF64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_complex_LU_svx(lu.unwrap_shared(), p.unwrap_shared(), x.unwrap_unique())
@@ -275,12 +280,12 @@ pub fn complex_LU_svx(
 /// initial residual r = A x - b is also computed and stored in residual.
 #[doc(alias = "gsl_linalg_LU_refine")]
 pub fn LU_refine(
-    a: &::MatrixF64,
-    lu: &::MatrixF64,
+    a: &impl GslMatrix<f64>,
+    lu: &impl GslMatrix<f64>,
     p: &::Permutation,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
-    residual: &mut ::VectorF64,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
+    residual: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_LU_refine(
@@ -298,12 +303,12 @@ pub fn LU_refine(
 /// initial residual r = A x - b is also computed and stored in residual.
 #[doc(alias = "gsl_linalg_complex_LU_refine")]
 pub fn complex_LU_refine(
-    a: &mut ::MatrixComplexF64,
-    lu: &::MatrixComplexF64,
+    a: &mut impl GslMatrixMut<ComplexF64>,
+    lu: &impl GslMatrix<ComplexF64>,
     p: &::Permutation,
-    b: &::VectorComplexF64,
-    x: &mut ::VectorComplexF64,
-    residual: &mut ::VectorComplexF64,
+    b: &impl GslVector<ComplexF64>,
+    x: &mut impl GslVectorMut<ComplexF64>,
+    residual: &mut impl GslVectorMut<ComplexF64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_complex_LU_refine(
@@ -322,7 +327,11 @@ pub fn complex_LU_refine(
 /// whenever possible, as the linear solver functions can obtain the same result more efficiently and reliably (consult any introductory
 /// textbook on numerical linear algebra for details).
 #[doc(alias = "gsl_linalg_LU_invert")]
-pub fn LU_invert(lu: &::MatrixF64, p: &::Permutation, inverse: &mut ::MatrixF64) -> Value {
+pub fn LU_invert(
+    lu: &impl GslMatrix<f64>,
+    p: &::Permutation,
+    inverse: &mut impl GslMatrixMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_LU_invert(
             lu.unwrap_shared(),
@@ -338,9 +347,9 @@ pub fn LU_invert(lu: &::MatrixF64, p: &::Permutation, inverse: &mut ::MatrixF64)
 /// textbook on numerical linear algebra for details).
 #[doc(alias = "gsl_linalg_complex_LU_invert")]
 pub fn complex_LU_invert(
-    lu: &::MatrixComplexF64,
+    lu: &impl GslMatrix<ComplexF64>,
     p: &::Permutation,
-    inverse: &mut ::MatrixComplexF64,
+    inverse: &mut impl GslMatrixMut<ComplexF64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_complex_LU_invert(
@@ -354,39 +363,39 @@ pub fn complex_LU_invert(
 /// This function computes the determinant of a matrix A from its LU decomposition, LU. The determinant is computed as the product of the
 /// diagonal elements of U and the sign of the row permutation signum.
 #[doc(alias = "gsl_linalg_LU_det")]
-pub fn LU_det(lu: &mut ::MatrixF64, signum: i32) -> f64 {
+pub fn LU_det(lu: &mut impl GslMatrixMut<f64>, signum: i32) -> f64 {
     unsafe { sys::gsl_linalg_LU_det(lu.unwrap_unique(), signum) }
 }
 
 /// This function computes the determinant of a matrix A from its LU decomposition, LU. The determinant is computed as the product of the
 /// diagonal elements of U and the sign of the row permutation signum.
 #[doc(alias = "gsl_linalg_complex_LU_det")]
-pub fn complex_LU_det(lu: &mut ::MatrixComplexF64, signum: i32) -> ::ComplexF64 {
+pub fn complex_LU_det(lu: &mut impl GslMatrixMut<ComplexF64>, signum: i32) -> ::ComplexF64 {
     unsafe { sys::gsl_linalg_complex_LU_det(lu.unwrap_unique(), signum).wrap() }
 }
 
 /// These functions compute the logarithm of the absolute value of the determinant of a matrix A, \ln|\det(A)|, from its LU decomposition,
 /// LU. This function may be useful if the direct computation of the determinant would overflow or underflow.
 #[doc(alias = "gsl_linalg_LU_lndet")]
-pub fn LU_lndet(lu: &mut ::MatrixF64) -> f64 {
+pub fn LU_lndet(lu: &mut impl GslMatrixMut<f64>) -> f64 {
     unsafe { sys::gsl_linalg_LU_lndet(lu.unwrap_unique()) }
 }
 
 /// This function computes the sign or phase factor of the determinant of a matrix A, \det(A)/|\det(A)|, from its LU decomposition, LU.
 #[doc(alias = "gsl_linalg_complex_LU_lndet")]
-pub fn complex_LU_lndet(lu: &mut ::MatrixComplexF64) -> f64 {
+pub fn complex_LU_lndet(lu: &mut impl GslMatrixMut<ComplexF64>) -> f64 {
     unsafe { sys::gsl_linalg_complex_LU_lndet(lu.unwrap_unique()) }
 }
 
 /// This function computes the sign or phase factor of the determinant of a matrix A, \det(A)/|\det(A)|, from its LU decomposition, LU.
 #[doc(alias = "gsl_linalg_LU_sgndet")]
-pub fn LU_sgndet(lu: &mut ::MatrixF64, signum: i32) -> i32 {
+pub fn LU_sgndet(lu: &mut impl GslMatrixMut<f64>, signum: i32) -> i32 {
     unsafe { sys::gsl_linalg_LU_sgndet(lu.unwrap_unique(), signum) }
 }
 
 /// This function computes the sign or phase factor of the determinant of a matrix A, \det(A)/|\det(A)|, from its LU decomposition, LU.
 #[doc(alias = "gsl_linalg_complex_LU_sgndet")]
-pub fn complex_LU_sgndet(lu: &mut ::MatrixComplexF64, signum: i32) -> ::ComplexF64 {
+pub fn complex_LU_sgndet(lu: &mut impl GslMatrixMut<ComplexF64>, signum: i32) -> ::ComplexF64 {
     unsafe { sys::gsl_linalg_complex_LU_sgndet(lu.unwrap_unique(), signum).wrap() }
 }
 
@@ -398,7 +407,7 @@ pub fn complex_LU_sgndet(lu: &mut ::MatrixComplexF64, signum: i32) -> ::ComplexF
 ///
 /// The algorithm used to perform the decomposition is Householder QR (Golub & Van Loan, Matrix Computations, Algorithm 5.2.1).
 #[doc(alias = "gsl_linalg_QR_decomp")]
-pub fn QR_decomp(a: &mut ::MatrixF64, tau: &mut ::VectorF64) -> Value {
+pub fn QR_decomp(a: &mut impl GslMatrixMut<f64>, tau: &mut impl GslVectorMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_QR_decomp(a.unwrap_unique(), tau.unwrap_unique()) })
 }
 
@@ -406,10 +415,10 @@ pub fn QR_decomp(a: &mut ::MatrixF64, tau: &mut ::VectorF64) -> Value {
 /// with gsl_linalg_QR_decomp. The least-squares solution for rectangular systems can be found using QR_lssolve.
 #[doc(alias = "gsl_linalg_QR_solve")]
 pub fn QR_solve(
-    qr: &::MatrixF64,
-    tau: &::VectorF64,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    qr: &impl GslMatrix<f64>,
+    tau: &impl GslVector<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QR_solve(
@@ -424,7 +433,11 @@ pub fn QR_solve(
 /// This function solves the square system A x = b in-place using the QR decomposition of A held in (QR,tau) which must have been computed
 /// previously by gsl_linalg_QR_decomp. On input x should contain the right-hand side b, which is replaced by the solution on output.
 #[doc(alias = "gsl_linalg_QR_svx")]
-pub fn QR_svx(qr: &::MatrixF64, tau: &::VectorF64, x: &mut ::VectorF64) -> Value {
+pub fn QR_svx(
+    qr: &impl GslMatrix<f64>,
+    tau: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QR_svx(qr.unwrap_shared(), tau.unwrap_shared(), x.unwrap_unique())
     })
@@ -436,11 +449,11 @@ pub fn QR_svx(qr: &::MatrixF64, tau: &::VectorF64, x: &mut ::VectorF64) -> Value
 /// residual.
 #[doc(alias = "gsl_linalg_QR_lssolve")]
 pub fn QR_lssolve(
-    qr: &::MatrixF64,
-    tau: &::VectorF64,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
-    residual: &mut ::VectorF64,
+    qr: &impl GslMatrix<f64>,
+    tau: &impl GslVector<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
+    residual: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QR_lssolve(
@@ -456,7 +469,11 @@ pub fn QR_lssolve(
 /// This function applies the matrix Q^T encoded in the decomposition (QR,tau) to the vector v, storing the result Q^T v in v. The matrix
 /// multiplication is carried out directly using the encoding of the Householder vectors without needing to form the full matrix Q^T.
 #[doc(alias = "gsl_linalg_QR_QTvec")]
-pub fn QR_QTvec(qr: &::MatrixF64, tau: &::VectorF64, v: &mut ::VectorF64) -> Value {
+pub fn QR_QTvec(
+    qr: &impl GslMatrix<f64>,
+    tau: &impl GslVector<f64>,
+    v: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QR_QTvec(qr.unwrap_shared(), tau.unwrap_shared(), v.unwrap_unique())
     })
@@ -465,7 +482,11 @@ pub fn QR_QTvec(qr: &::MatrixF64, tau: &::VectorF64, v: &mut ::VectorF64) -> Val
 /// This function applies the matrix Q encoded in the decomposition (QR,tau) to the vector v, storing the result Q v in v. The matrix
 /// multiplication is carried out directly using the encoding of the Householder vectors without needing to form the full matrix Q.
 #[doc(alias = "gsl_linalg_QR_Qvec")]
-pub fn QR_Qvec(qr: &::MatrixF64, tau: &::VectorF64, v: &mut ::VectorF64) -> Value {
+pub fn QR_Qvec(
+    qr: &impl GslMatrix<f64>,
+    tau: &impl GslVector<f64>,
+    v: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QR_Qvec(qr.unwrap_shared(), tau.unwrap_shared(), v.unwrap_unique())
     })
@@ -474,7 +495,11 @@ pub fn QR_Qvec(qr: &::MatrixF64, tau: &::VectorF64, v: &mut ::VectorF64) -> Valu
 /// This function applies the matrix Q^T encoded in the decomposition (QR,tau) to the matrix A, storing the result Q^T A in A. The matrix
 /// multiplication is carried out directly using the encoding of the Householder vectors without needing to form the full matrix Q^T.
 #[doc(alias = "gsl_linalg_QR_QTmat")]
-pub fn QR_QTmat(qr: &::MatrixF64, tau: &::VectorF64, v: &mut ::MatrixF64) -> Value {
+pub fn QR_QTmat(
+    qr: &impl GslMatrix<f64>,
+    tau: &impl GslVector<f64>,
+    v: &mut impl GslMatrixMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QR_QTmat(qr.unwrap_shared(), tau.unwrap_shared(), v.unwrap_unique())
     })
@@ -483,7 +508,11 @@ pub fn QR_QTmat(qr: &::MatrixF64, tau: &::VectorF64, v: &mut ::MatrixF64) -> Val
 /// This function solves the triangular system R x = b for x. It may be useful if the product b' = Q^T b has already been computed using
 /// gsl_linalg_QR_QTvec.
 #[doc(alias = "gsl_linalg_QR_Rsolve")]
-pub fn QR_Rsolve(qr: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> Value {
+pub fn QR_Rsolve(
+    qr: &impl GslMatrix<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QR_Rsolve(qr.unwrap_shared(), b.unwrap_shared(), x.unwrap_unique())
     })
@@ -492,17 +521,17 @@ pub fn QR_Rsolve(qr: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> Valu
 /// This function solves the triangular system R x = b for x in-place. On input x should contain the right-hand side b and is replaced by
 /// the solution on output. This function may be useful if the product b' = Q^T b has already been computed using gsl_linalg_QR_QTvec.
 #[doc(alias = "gsl_linalg_QR_Rsvx")]
-pub fn QR_Rsvx(qr: &::MatrixF64, x: &mut ::VectorF64) -> Value {
+pub fn QR_Rsvx(qr: &impl GslMatrix<f64>, x: &mut impl GslVectorMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_QR_Rsvx(qr.unwrap_shared(), x.unwrap_unique()) })
 }
 
 /// This function unpacks the encoded QR decomposition (QR,tau) into the matrices Q and R, where Q is M-by-M and R is M-by-N.
 #[doc(alias = "gsl_linalg_QR_unpack")]
 pub fn QR_unpack(
-    qr: &::MatrixF64,
-    tau: &::VectorF64,
-    q: &mut ::MatrixF64,
-    r: &mut ::MatrixF64,
+    qr: &impl GslMatrix<f64>,
+    tau: &impl GslVector<f64>,
+    q: &mut impl GslMatrixMut<f64>,
+    r: &mut impl GslMatrixMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QR_unpack(
@@ -518,10 +547,10 @@ pub fn QR_unpack(
 /// form as (Q, R).
 #[doc(alias = "gsl_linalg_QR_QRsolve")]
 pub fn QR_QRsolve(
-    q: &mut ::MatrixF64,
-    r: &mut ::MatrixF64,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    q: &mut impl GslMatrixMut<f64>,
+    r: &mut impl GslMatrixMut<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QR_QRsolve(
@@ -537,16 +566,16 @@ pub fn QR_QRsolve(
 /// output matrices Q' and R' are also orthogonal and right triangular. Note that w is destroyed by the update.
 #[doc(alias = "gsl_linalg_QR_update")]
 pub fn QR_update(
-    q: &mut ::MatrixF64,
-    r: &mut ::MatrixF64,
+    q: &mut impl GslMatrixMut<f64>,
+    r: &mut impl GslMatrixMut<f64>,
     mut w: ::VectorF64,
-    v: &::VectorF64,
+    v: &impl GslVector<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QR_update(
             q.unwrap_unique(),
             r.unwrap_unique(),
-            w.unwrap_unique(),
+            FFI::unwrap_unique(&mut w),
             v.unwrap_shared(),
         )
     })
@@ -554,7 +583,11 @@ pub fn QR_update(
 
 /// This function solves the triangular system R x = b for the N-by-N matrix R.
 #[doc(alias = "gsl_linalg_R_solve")]
-pub fn R_solve(r: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> Value {
+pub fn R_solve(
+    r: &impl GslMatrix<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_R_solve(r.unwrap_shared(), b.unwrap_shared(), x.unwrap_unique())
     })
@@ -563,7 +596,7 @@ pub fn R_solve(r: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> Value {
 /// This function solves the triangular system R x = b in-place. On input x should contain the right-hand side b, which is replaced by
 /// the solution on output.
 #[doc(alias = "gsl_linalg_R_svx")]
-pub fn R_svx(r: &::MatrixF64, x: &mut ::VectorF64) -> Value {
+pub fn R_svx(r: &impl GslMatrix<f64>, x: &mut impl GslVectorMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_R_svx(r.unwrap_shared(), x.unwrap_unique()) })
 }
 
@@ -578,11 +611,11 @@ pub fn R_svx(r: &::MatrixF64, x: &mut ::VectorF64) -> Value {
 /// The algorithm used to perform the decomposition is Householder QR with column pivoting (Golub & Van Loan, Matrix Computations, Algorithm 5.4.1).
 #[doc(alias = "gsl_linalg_QRPT_decomp")]
 pub fn QRPT_decomp(
-    a: &mut ::MatrixF64,
-    tau: &mut ::VectorF64,
+    a: &mut impl GslMatrixMut<f64>,
+    tau: &mut impl GslVectorMut<f64>,
     p: &mut ::Permutation,
     signum: &mut i32,
-    norm: &mut ::VectorF64,
+    norm: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QRPT_decomp(
@@ -599,13 +632,13 @@ pub fn QRPT_decomp(
 /// matrices q and r.
 #[doc(alias = "gsl_linalg_QRPT_decomp2")]
 pub fn QRPT_decomp2(
-    a: &::MatrixF64,
-    q: &mut ::MatrixF64,
-    r: &mut ::MatrixF64,
-    tau: &mut ::VectorF64,
+    a: &impl GslMatrix<f64>,
+    q: &mut impl GslMatrixMut<f64>,
+    r: &mut impl GslMatrixMut<f64>,
+    tau: &mut impl GslVectorMut<f64>,
     p: &mut ::Permutation,
     signum: &mut i32,
-    norm: &mut ::VectorF64,
+    norm: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QRPT_decomp2(
@@ -624,11 +657,11 @@ pub fn QRPT_decomp2(
 /// by QRPT_decomp.
 #[doc(alias = "gsl_linalg_QRPT_solve")]
 pub fn QRPT_solve(
-    qr: &::MatrixF64,
-    tau: &::VectorF64,
+    qr: &impl GslMatrix<f64>,
+    tau: &impl GslVector<f64>,
     p: &::Permutation,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QRPT_solve(
@@ -645,10 +678,10 @@ pub fn QRPT_solve(
 /// right-hand side b, which is replaced by the solution on output.
 #[doc(alias = "gsl_linalg_QRPT_svx")]
 pub fn QRPT_svx(
-    qr: &::MatrixF64,
-    tau: &::VectorF64,
+    qr: &impl GslMatrix<f64>,
+    tau: &impl GslVector<f64>,
     p: &::Permutation,
-    x: &mut ::VectorF64,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QRPT_svx(
@@ -664,11 +697,11 @@ pub fn QRPT_svx(
 /// form as (Q, R).
 #[doc(alias = "gsl_linalg_QRPT_QRsolve")]
 pub fn QRPT_QRsolve(
-    q: &::MatrixF64,
-    r: &::MatrixF64,
+    q: &impl GslMatrix<f64>,
+    r: &impl GslMatrix<f64>,
     p: &::Permutation,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QRPT_QRsolve(
@@ -685,11 +718,11 @@ pub fn QRPT_QRsolve(
 /// output matrices Q' and R' are also orthogonal and right triangular. Note that w is destroyed by the update. The permutation p is not changed.
 #[doc(alias = "gsl_linalg_QRPT_update")]
 pub fn QRPT_update(
-    q: &mut ::MatrixF64,
-    r: &mut ::MatrixF64,
+    q: &mut impl GslMatrixMut<f64>,
+    r: &mut impl GslMatrixMut<f64>,
     p: &::Permutation,
-    w: &mut ::VectorF64,
-    v: &::VectorF64,
+    w: &mut impl GslVectorMut<f64>,
+    v: &impl GslVector<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QRPT_update(
@@ -705,10 +738,10 @@ pub fn QRPT_update(
 /// This function solves the triangular system R P^T x = b for the N-by-N matrix R contained in QR.
 #[doc(alias = "gsl_linalg_QRPT_Rsolve")]
 pub fn QRPT_Rsolve(
-    qr: &::MatrixF64,
+    qr: &impl GslMatrix<f64>,
     p: &::Permutation,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QRPT_Rsolve(
@@ -723,7 +756,11 @@ pub fn QRPT_Rsolve(
 /// This function solves the triangular system R P^T x = b in-place for the N-by-N matrix R contained in QR. On input x should contain the
 /// right-hand side b, which is replaced by the solution on output.
 #[doc(alias = "gsl_linalg_QRPT_Rsvx")]
-pub fn QRPT_Rsvx(qr: &::MatrixF64, p: &::Permutation, x: &mut ::VectorF64) -> Value {
+pub fn QRPT_Rsvx(
+    qr: &impl GslMatrix<f64>,
+    p: &::Permutation,
+    x: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_QRPT_Rsvx(qr.unwrap_shared(), p.unwrap_shared(), x.unwrap_unique())
     })
@@ -737,10 +774,10 @@ pub fn QRPT_Rsvx(qr: &::MatrixF64, p: &::Permutation, x: &mut ::VectorF64) -> Va
 /// This routine uses the Golub-Reinsch SVD algorithm.
 #[doc(alias = "gsl_linalg_SV_decomp")]
 pub fn SV_decomp(
-    a: &mut ::MatrixF64,
-    v: &mut ::MatrixF64,
-    s: &mut ::VectorF64,
-    work: &mut ::VectorF64,
+    a: &mut impl GslMatrixMut<f64>,
+    v: &mut impl GslMatrixMut<f64>,
+    s: &mut impl GslVectorMut<f64>,
+    work: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_SV_decomp(
@@ -756,11 +793,11 @@ pub fn SV_decomp(
 /// N and the N-by-N matrix X as additional working space.
 #[doc(alias = "gsl_linalg_SV_decomp_mod")]
 pub fn SV_decomp_mod(
-    a: &mut ::MatrixF64,
-    x: &mut ::MatrixF64,
-    v: &mut ::MatrixF64,
-    s: &mut ::VectorF64,
-    work: &mut ::VectorF64,
+    a: &mut impl GslMatrixMut<f64>,
+    x: &mut impl GslMatrixMut<f64>,
+    v: &mut impl GslMatrixMut<f64>,
+    s: &mut impl GslVectorMut<f64>,
+    work: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_SV_decomp_mod(
@@ -776,7 +813,11 @@ pub fn SV_decomp_mod(
 /// This function computes the SVD of the M-by-N matrix A using one-sided Jacobi orthogonalization for M >= N. The Jacobi method can compute
 /// singular values to higher relative accuracy than Golub-Reinsch algorithms (see references for details).
 #[doc(alias = "gsl_linalg_SV_decomp_jacobi")]
-pub fn SV_decomp_jacobi(a: &mut ::MatrixF64, v: &mut ::MatrixF64, s: &mut ::VectorF64) -> Value {
+pub fn SV_decomp_jacobi(
+    a: &mut impl GslMatrixMut<f64>,
+    v: &mut impl GslMatrixMut<f64>,
+    s: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_SV_decomp_jacobi(a.unwrap_unique(), v.unwrap_unique(), s.unwrap_unique())
     })
@@ -792,11 +833,11 @@ pub fn SV_decomp_jacobi(a: &mut ::MatrixF64, v: &mut ::MatrixF64, s: &mut ::Vect
 /// x which minimizes ||A x - b||_2.
 #[doc(alias = "gsl_linalg_SV_solve")]
 pub fn SV_solve(
-    u: &::MatrixF64,
-    v: &::MatrixF64,
-    s: &::VectorF64,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    u: &impl GslMatrix<f64>,
+    v: &impl GslMatrix<f64>,
+    s: &impl GslVector<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_SV_solve(
@@ -813,7 +854,7 @@ pub fn SV_solve(
 /// with gsl_linalg_SV_decomp. h_i are the diagonal values of the matrix A (A^T A)^{-1} A^T and depend only on the matrix U which is the input to
 /// this function.
 #[doc(alias = "gsl_linalg_SV_leverage")]
-pub fn SV_leverage(u: &::MatrixF64, h: &mut ::VectorF64) -> Value {
+pub fn SV_leverage(u: &impl GslMatrix<f64>, h: &mut impl GslVectorMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_SV_leverage(u.unwrap_shared(), h.unwrap_unique()) })
 }
 
@@ -825,7 +866,7 @@ pub fn SV_leverage(u: &::MatrixF64, h: &mut ::VectorF64) -> Value {
 ///
 /// When testing whether a matrix is positive-definite, disable the error handler first to avoid triggering an error.
 #[doc(alias = "gsl_linalg_cholesky_decomp")]
-pub fn cholesky_decomp(a: &mut ::MatrixF64) -> Value {
+pub fn cholesky_decomp(a: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_cholesky_decomp(a.unwrap_unique()) })
 }
 
@@ -837,14 +878,18 @@ pub fn cholesky_decomp(a: &mut ::MatrixF64) -> Value {
 ///
 /// When testing whether a matrix is positive-definite, disable the error handler first to avoid triggering an error.
 #[doc(alias = "gsl_linalg_complex_cholesky_decomp")]
-pub fn complex_cholesky_decomp(a: &mut ::MatrixComplexF64) -> Value {
+pub fn complex_cholesky_decomp(a: &mut impl GslMatrixMut<ComplexF64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_complex_cholesky_decomp(a.unwrap_unique()) })
 }
 
 /// This function solves the system A x = b using the Cholesky decomposition of A held in the matrix cholesky which must have been previously
 /// computed by gsl_linalg_cholesky_decomp or gsl_linalg_complex_cholesky_decomp.
 #[doc(alias = "gsl_linalg_cholesky_solve")]
-pub fn cholesky_solve(cholesky: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> Value {
+pub fn cholesky_solve(
+    cholesky: &impl GslMatrix<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_cholesky_solve(
             cholesky.unwrap_shared(),
@@ -858,9 +903,9 @@ pub fn cholesky_solve(cholesky: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF
 /// computed by gsl_linalg_cholesky_decomp or gsl_linalg_complex_cholesky_decomp.
 #[doc(alias = "gsl_linalg_complex_cholesky_solve")]
 pub fn complex_cholesky_solve(
-    cholesky: &::MatrixComplexF64,
-    b: &::VectorComplexF64,
-    x: &mut ::VectorComplexF64,
+    cholesky: &impl GslMatrix<ComplexF64>,
+    b: &impl GslVector<ComplexF64>,
+    x: &mut impl GslVectorMut<ComplexF64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_complex_cholesky_solve(
@@ -875,7 +920,7 @@ pub fn complex_cholesky_solve(
 /// previously computed by gsl_linalg_cholesky_decomp or gsl_linalg_complex_cholesky_decomp. On input x should contain the right-hand side
 /// b, which is replaced by the solution on output.
 #[doc(alias = "gsl_linalg_cholesky_svx")]
-pub fn cholesky_svx(cholesky: &::MatrixF64, x: &mut ::VectorF64) -> Value {
+pub fn cholesky_svx(cholesky: &impl GslMatrix<f64>, x: &mut impl GslVectorMut<f64>) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_cholesky_svx(cholesky.unwrap_shared(), x.unwrap_unique())
     })
@@ -885,7 +930,10 @@ pub fn cholesky_svx(cholesky: &::MatrixF64, x: &mut ::VectorF64) -> Value {
 /// previously computed by gsl_linalg_cholesky_decomp or gsl_linalg_complex_cholesky_decomp. On input x should contain the right-hand side
 /// b, which is replaced by the solution on output.
 #[doc(alias = "gsl_linalg_complex_cholesky_svx")]
-pub fn complex_cholesky_svx(cholesky: &::MatrixComplexF64, x: &mut ::VectorComplexF64) -> Value {
+pub fn complex_cholesky_svx(
+    cholesky: &impl GslMatrix<ComplexF64>,
+    x: &mut impl GslVectorMut<ComplexF64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_complex_cholesky_svx(cholesky.unwrap_shared(), x.unwrap_unique())
     })
@@ -894,14 +942,14 @@ pub fn complex_cholesky_svx(cholesky: &::MatrixComplexF64, x: &mut ::VectorCompl
 /// This function computes the inverse of a matrix from its Cholesky decomposition cholesky, which must have been previously computed by
 /// gsl_linalg_cholesky_decomp or gsl_linalg_complex_cholesky_decomp. On output, the inverse is stored in-place in cholesky.
 #[doc(alias = "gsl_linalg_cholesky_invert")]
-pub fn cholesky_invert(cholesky: &mut ::MatrixF64) -> Value {
+pub fn cholesky_invert(cholesky: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_cholesky_invert(cholesky.unwrap_unique()) })
 }
 
 /// This function computes the inverse of a matrix from its Cholesky decomposition cholesky, which must have been previously computed by
 /// gsl_linalg_cholesky_decomp or gsl_linalg_complex_cholesky_decomp. On output, the inverse is stored in-place in cholesky.
 #[doc(alias = "gsl_linalg_complex_cholesky_invert")]
-pub fn complex_cholesky_invert(cholesky: &mut ::MatrixComplexF64) -> Value {
+pub fn complex_cholesky_invert(cholesky: &mut impl GslMatrixMut<ComplexF64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_complex_cholesky_invert(cholesky.unwrap_unique()) })
 }
 
@@ -910,7 +958,7 @@ pub fn complex_cholesky_invert(cholesky: &mut ::MatrixComplexF64) -> Value {
 /// the Householder vectors which, together with the Householder coefficients tau, encode the orthogonal matrix Q. This storage scheme is
 /// the same as used by LAPACK. The upper triangular part of A is not referenced.
 #[doc(alias = "gsl_linalg_symmtd_decomp")]
-pub fn symmtd_decomp(a: &mut ::MatrixF64, tau: &mut ::VectorF64) -> Value {
+pub fn symmtd_decomp(a: &mut impl GslMatrixMut<f64>, tau: &mut impl GslVectorMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_symmtd_decomp(a.unwrap_unique(), tau.unwrap_unique()) })
 }
 
@@ -918,11 +966,11 @@ pub fn symmtd_decomp(a: &mut ::MatrixF64, tau: &mut ::VectorF64) -> Value {
 /// matrix Q, the vector of diagonal elements diag and the vector of subdiagonal elements subdiag.
 #[doc(alias = "gsl_linalg_symmtd_unpack")]
 pub fn symmtd_unpack(
-    a: &::MatrixF64,
-    tau: &::VectorF64,
-    q: &mut ::MatrixF64,
-    diag: &mut ::VectorF64,
-    subdiag: &mut ::VectorF64,
+    a: &impl GslMatrix<f64>,
+    tau: &impl GslVector<f64>,
+    q: &mut impl GslMatrixMut<f64>,
+    diag: &mut impl GslVectorMut<f64>,
+    subdiag: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_symmtd_unpack(
@@ -939,9 +987,9 @@ pub fn symmtd_unpack(
 /// gsl_linalg_symmtd_decomp into the vectors diag and subdiag.
 #[doc(alias = "gsl_linalg_symmtd_unpack_T")]
 pub fn symmtd_unpack_T(
-    a: &::MatrixF64,
-    diag: &mut ::VectorF64,
-    subdiag: &mut ::VectorF64,
+    a: &impl GslMatrix<f64>,
+    diag: &mut impl GslVectorMut<f64>,
+    subdiag: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_symmtd_unpack_T(
@@ -957,7 +1005,10 @@ pub fn symmtd_unpack_T(
 /// matrix contains the Householder vectors which, together with the Householder coefficients tau, encode the unitary matrix U. This storage
 /// scheme is the same as used by LAPACK. The upper triangular part of A and imaginary parts of the diagonal are not referenced.
 #[doc(alias = "gsl_linalg_hermtd_decomp")]
-pub fn hermtd_decomp(a: &mut ::MatrixComplexF64, tau: &mut ::VectorComplexF64) -> Value {
+pub fn hermtd_decomp(
+    a: &mut impl GslMatrixMut<ComplexF64>,
+    tau: &mut impl GslVectorMut<ComplexF64>,
+) -> Value {
     Value::from(unsafe { sys::gsl_linalg_hermtd_decomp(a.unwrap_unique(), tau.unwrap_unique()) })
 }
 
@@ -965,11 +1016,11 @@ pub fn hermtd_decomp(a: &mut ::MatrixComplexF64, tau: &mut ::VectorComplexF64) -
 /// the real vector of diagonal elements diag and the real vector of subdiagonal elements subdiag.
 #[doc(alias = "gsl_linalg_hermtd_unpack")]
 pub fn hermtd_unpack(
-    a: &::MatrixComplexF64,
-    tau: &::VectorComplexF64,
-    u: &mut ::MatrixComplexF64,
-    diag: &mut ::VectorF64,
-    subdiag: &mut ::VectorF64,
+    a: &impl GslMatrix<ComplexF64>,
+    tau: &impl GslVector<ComplexF64>,
+    u: &mut impl GslMatrixMut<ComplexF64>,
+    diag: &mut impl GslVectorMut<f64>,
+    subdiag: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_hermtd_unpack(
@@ -986,9 +1037,9 @@ pub fn hermtd_unpack(
 /// gsl_linalg_hermtd_decomp into the real vectors diag and subdiag.
 #[doc(alias = "gsl_linalg_hermtd_unpack_T")]
 pub fn hermtd_unpack_T(
-    a: &::MatrixComplexF64,
-    diag: &mut ::VectorF64,
-    subdiag: &mut ::VectorF64,
+    a: &impl GslMatrix<ComplexF64>,
+    diag: &mut impl GslVectorMut<f64>,
+    subdiag: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_hermtd_unpack_T(
@@ -1004,7 +1055,10 @@ pub fn hermtd_unpack_T(
 /// U is a product of N - 2 Householder matrices. The Householder vectors are stored in the lower portion of A (below the subdiagonal) and
 /// the Householder coefficients are stored in the vector tau. tau must be of length N.
 #[doc(alias = "gsl_linalg_hessenberg_decomp")]
-pub fn hessenberg_decomp(a: &mut ::MatrixF64, tau: &mut ::VectorF64) -> Value {
+pub fn hessenberg_decomp(
+    a: &mut impl GslMatrixMut<f64>,
+    tau: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_hessenberg_decomp(a.unwrap_unique(), tau.unwrap_unique())
     })
@@ -1013,7 +1067,11 @@ pub fn hessenberg_decomp(a: &mut ::MatrixF64, tau: &mut ::VectorF64) -> Value {
 /// This function constructs the orthogonal matrix U from the information stored in the Hessenberg matrix H along with the vector tau. H and
 /// tau are outputs from gsl_linalg_hessenberg_decomp.
 #[doc(alias = "gsl_linalg_hessenberg_unpack")]
-pub fn hessenberg_unpack(h: &mut ::MatrixF64, tau: &mut ::VectorF64, u: &mut ::MatrixF64) -> Value {
+pub fn hessenberg_unpack(
+    h: &mut impl GslMatrixMut<f64>,
+    tau: &mut impl GslVectorMut<f64>,
+    u: &mut impl GslMatrixMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_hessenberg_unpack(h.unwrap_unique(), tau.unwrap_unique(), u.unwrap_unique())
     })
@@ -1024,9 +1082,9 @@ pub fn hessenberg_unpack(h: &mut ::MatrixF64, tau: &mut ::VectorF64, u: &mut ::M
 /// If H is order N, then V must have N columns but may have any number of rows.
 #[doc(alias = "gsl_linalg_hessenberg_unpack_accum")]
 pub fn hessenberg_unpack_accum(
-    h: &mut ::MatrixF64,
-    tau: &mut ::VectorF64,
-    v: &mut ::MatrixF64,
+    h: &mut impl GslMatrixMut<f64>,
+    tau: &mut impl GslVectorMut<f64>,
+    v: &mut impl GslMatrixMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_hessenberg_unpack_accum(
@@ -1040,7 +1098,7 @@ pub fn hessenberg_unpack_accum(
 /// This function sets the lower triangular portion of H, below the subdiagonal, to zero. It is useful for clearing out the Householder
 /// vectors after calling gsl_linalg_hessenberg_decomp.
 #[doc(alias = "gsl_linalg_hessenberg_set_zero")]
-pub fn hessenberg_set_zero(h: &mut ::MatrixF64) -> Value {
+pub fn hessenberg_set_zero(h: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_hessenberg_set_zero(h.unwrap_unique()) })
 }
 
@@ -1049,11 +1107,11 @@ pub fn hessenberg_set_zero(h: &mut ::MatrixF64) -> Value {
 /// is needed in work.
 #[doc(alias = "gsl_linalg_hesstri_decomp")]
 pub fn hesstri_decomp(
-    a: &mut ::MatrixF64,
-    b: &mut ::MatrixF64,
-    u: &mut ::MatrixF64,
-    v: &mut ::MatrixF64,
-    work: &mut ::VectorF64,
+    a: &mut impl GslMatrixMut<f64>,
+    b: &mut impl GslMatrixMut<f64>,
+    u: &mut impl GslMatrixMut<f64>,
+    v: &mut impl GslMatrixMut<f64>,
+    work: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_hesstri_decomp(
@@ -1072,9 +1130,9 @@ pub fn hesstri_decomp(
 /// the diagonal of A and the length of tau_V should be one element shorter.
 #[doc(alias = "gsl_linalg_bidiag_decomp")]
 pub fn bidiag_decomp(
-    a: &mut ::MatrixF64,
-    tau_u: &mut ::VectorF64,
-    tau_v: &mut ::VectorF64,
+    a: &mut impl GslMatrixMut<f64>,
+    tau_u: &mut impl GslVectorMut<f64>,
+    tau_v: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_bidiag_decomp(
@@ -1090,13 +1148,13 @@ pub fn bidiag_decomp(
 /// U^T U = I for efficiency.
 #[doc(alias = "gsl_linalg_bidiag_unpack")]
 pub fn bidiag_unpack(
-    a: &mut ::MatrixF64,
-    tau_u: &::VectorF64,
-    u: &mut ::MatrixF64,
-    tau_v: &::VectorF64,
-    v: &mut ::MatrixF64,
-    diag: &mut ::VectorF64,
-    superdiag: &mut ::VectorF64,
+    a: &mut impl GslMatrixMut<f64>,
+    tau_u: &impl GslVector<f64>,
+    u: &mut impl GslMatrixMut<f64>,
+    tau_v: &impl GslVector<f64>,
+    v: &mut impl GslMatrixMut<f64>,
+    diag: &mut impl GslVectorMut<f64>,
+    superdiag: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_bidiag_unpack(
@@ -1115,10 +1173,10 @@ pub fn bidiag_unpack(
 /// matrices U, V and the diagonal vector diag and superdiagonal superdiag. The matrix U is stored in-place in A.
 #[doc(alias = "gsl_linalg_bidiag_unpack2")]
 pub fn bidiag_unpack2(
-    a: &mut ::MatrixF64,
-    tau_u: &mut ::VectorF64,
-    tau_v: &mut ::VectorF64,
-    v: &mut ::MatrixF64,
+    a: &mut impl GslMatrixMut<f64>,
+    tau_u: &mut impl GslVectorMut<f64>,
+    tau_v: &mut impl GslVectorMut<f64>,
+    v: &mut impl GslMatrixMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_bidiag_unpack2(
@@ -1134,9 +1192,9 @@ pub fn bidiag_unpack2(
 /// vector diag and superdiagonal vector superdiag.
 #[doc(alias = "gsl_linalg_bidiag_unpack_B")]
 pub fn bidiag_unpack_B(
-    a: &::MatrixF64,
-    diag: &mut ::VectorF64,
-    superdiag: &mut ::VectorF64,
+    a: &impl GslMatrix<f64>,
+    diag: &mut impl GslVectorMut<f64>,
+    superdiag: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_bidiag_unpack_B(
@@ -1150,14 +1208,14 @@ pub fn bidiag_unpack_B(
 /// This function prepares a Householder transformation P = I - \tau v v^T which can be used to zero all the elements of the input vector except
 /// the first. On output the transformation is stored in the vector v and the scalar \tau is returned.
 #[doc(alias = "gsl_linalg_householder_transform")]
-pub fn householder_transform(v: &mut ::VectorF64) -> f64 {
+pub fn householder_transform(v: &mut impl GslVectorMut<f64>) -> f64 {
     unsafe { sys::gsl_linalg_householder_transform(v.unwrap_unique()) }
 }
 
 /// This function prepares a Householder transformation P = I - \tau v v^T which can be used to zero all the elements of the input vector except
 /// the first. On output the transformation is stored in the vector v and the scalar \tau is returned.
 #[doc(alias = "gsl_linalg_complex_householder_transform")]
-pub fn complex_householder_transform(v: &mut ::VectorComplexF64) -> ::ComplexF64 {
+pub fn complex_householder_transform(v: &mut impl GslVectorMut<ComplexF64>) -> ::ComplexF64 {
     unsafe {
         ::std::mem::transmute(sys::gsl_linalg_complex_householder_transform(
             v.unwrap_unique(),
@@ -1168,7 +1226,7 @@ pub fn complex_householder_transform(v: &mut ::VectorComplexF64) -> ::ComplexF64
 /// This function applies the Householder matrix P defined by the scalar tau and the vector v to the left-hand side of the matrix A. On output
 /// the result P A is stored in A.
 #[doc(alias = "gsl_linalg_householder_hm")]
-pub fn householder_hm(tau: f64, v: &::VectorF64, a: &mut ::MatrixF64) -> Value {
+pub fn householder_hm(tau: f64, v: &impl GslVector<f64>, a: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_householder_hm(tau, v.unwrap_shared(), a.unwrap_unique())
     })
@@ -1179,8 +1237,8 @@ pub fn householder_hm(tau: f64, v: &::VectorF64, a: &mut ::MatrixF64) -> Value {
 #[doc(alias = "gsl_linalg_complex_householder_hm")]
 pub fn complex_householder_hm(
     tau: &::ComplexF64,
-    v: &::VectorComplexF64,
-    a: &mut ::MatrixComplexF64,
+    v: &impl GslVector<ComplexF64>,
+    a: &mut impl GslMatrixMut<ComplexF64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_complex_householder_hm(
@@ -1194,7 +1252,7 @@ pub fn complex_householder_hm(
 /// This function applies the Householder matrix P defined by the scalar tau and the vector v to the right-hand side of the matrix A. On output
 /// the result A P is stored in A.
 #[doc(alias = "gsl_linalg_householder_mh")]
-pub fn householder_mh(tau: f64, v: &::VectorF64, a: &mut ::MatrixF64) -> Value {
+pub fn householder_mh(tau: f64, v: &impl GslVector<f64>, a: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_householder_mh(tau, v.unwrap_shared(), a.unwrap_unique())
     })
@@ -1205,8 +1263,8 @@ pub fn householder_mh(tau: f64, v: &::VectorF64, a: &mut ::MatrixF64) -> Value {
 #[doc(alias = "gsl_linalg_complex_householder_mh")]
 pub fn complex_householder_mh(
     tau: &::ComplexF64,
-    v: &::VectorComplexF64,
-    a: &mut ::MatrixComplexF64,
+    v: &impl GslVector<ComplexF64>,
+    a: &mut impl GslMatrixMut<ComplexF64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_complex_householder_mh(
@@ -1220,7 +1278,7 @@ pub fn complex_householder_mh(
 /// This function applies the Householder transformation P defined by the scalar tau and the vector v to the vector w. On output the result P
 /// w is stored in w.
 #[doc(alias = "gsl_linalg_householder_hv")]
-pub fn householder_hv(tau: f64, v: &::VectorF64, w: &mut ::VectorF64) -> Value {
+pub fn householder_hv(tau: f64, v: &impl GslVector<f64>, w: &mut impl GslVectorMut<f64>) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_householder_hv(tau, v.unwrap_shared(), w.unwrap_unique())
     })
@@ -1231,8 +1289,8 @@ pub fn householder_hv(tau: f64, v: &::VectorF64, w: &mut ::VectorF64) -> Value {
 #[doc(alias = "gsl_linalg_complex_householder_hv")]
 pub fn complex_householder_hv(
     tau: &::ComplexF64,
-    v: &::VectorComplexF64,
-    w: &mut ::VectorComplexF64,
+    v: &impl GslVector<ComplexF64>,
+    w: &mut impl GslVectorMut<ComplexF64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_complex_householder_hv(
@@ -1246,17 +1304,25 @@ pub fn complex_householder_hv(
 /// This function solves the system A x = b directly using Householder transformations. On output the solution is stored in x and b is not
 /// modified. The matrix A is destroyed by the Householder transformations.
 #[doc(alias = "gsl_linalg_HH_solve")]
-pub fn HH_solve(mut a: ::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> Value {
+pub fn HH_solve(
+    mut a: ::MatrixF64,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
-        sys::gsl_linalg_HH_solve(a.unwrap_unique(), b.unwrap_shared(), x.unwrap_unique())
+        sys::gsl_linalg_HH_solve(
+            FFI::unwrap_unique(&mut a),
+            b.unwrap_shared(),
+            x.unwrap_unique(),
+        )
     })
 }
 
 /// This function solves the system A x = b in-place using Householder transformations. On input x should contain the right-hand side b,
 /// which is replaced by the solution on output. The matrix A is destroyed by the Householder transformations.
 #[doc(alias = "gsl_linalg_HH_svx")]
-pub fn HH_svx(mut a: ::MatrixF64, x: &mut ::VectorF64) -> Value {
-    Value::from(unsafe { sys::gsl_linalg_HH_svx(a.unwrap_unique(), x.unwrap_unique()) })
+pub fn HH_svx(mut a: ::MatrixF64, x: &mut impl GslVectorMut<f64>) -> Value {
+    Value::from(unsafe { sys::gsl_linalg_HH_svx(FFI::unwrap_unique(&mut a), x.unwrap_unique()) })
 }
 
 /// This function solves the general N-by-N system A x = b where A is tridiagonal (N >= 2). The super-diagonal and sub-diagonal vectors
@@ -1270,11 +1336,11 @@ pub fn HH_svx(mut a: ::MatrixF64, x: &mut ::VectorF64) -> Value {
 /// ```
 #[doc(alias = "gsl_linalg_solve_tridiag")]
 pub fn solve_tridiag(
-    diag: &::VectorF64,
-    e: &::VectorF64,
-    f: &::VectorF64,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    diag: &impl GslVector<f64>,
+    e: &impl GslVector<f64>,
+    f: &impl GslVector<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_solve_tridiag(
@@ -1298,10 +1364,10 @@ pub fn solve_tridiag(
 /// ```
 #[doc(alias = "gsl_linalg_solve_symm_tridiag")]
 pub fn solve_symm_tridiag(
-    diag: &::VectorF64,
-    e: &::VectorF64,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    diag: &impl GslVector<f64>,
+    e: &impl GslVector<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_solve_symm_tridiag(
@@ -1324,11 +1390,11 @@ pub fn solve_symm_tridiag(
 /// ```
 #[doc(alias = "gsl_linalg_solve_cyc_tridiag")]
 pub fn solve_cyc_tridiag(
-    diag: &::VectorF64,
-    e: &::VectorF64,
-    f: &::VectorF64,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    diag: &impl GslVector<f64>,
+    e: &impl GslVector<f64>,
+    f: &impl GslVector<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_solve_cyc_tridiag(
@@ -1352,10 +1418,10 @@ pub fn solve_cyc_tridiag(
 /// ```
 #[doc(alias = "gsl_linalg_solve_symm_cyc_tridiag")]
 pub fn solve_symm_cyc_tridiag(
-    diag: &::VectorF64,
-    e: &::VectorF64,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    diag: &impl GslVector<f64>,
+    e: &impl GslVector<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_solve_symm_cyc_tridiag(
@@ -1370,14 +1436,14 @@ pub fn solve_symm_cyc_tridiag(
 /// This function replaces the matrix A with its balanced counterpart and stores the diagonal elements of the similarity transformation into
 /// the vector D.
 #[doc(alias = "gsl_linalg_balance_matrix")]
-pub fn balance_matrix(a: &mut ::MatrixF64, d: &mut ::VectorF64) -> Value {
+pub fn balance_matrix(a: &mut impl GslMatrixMut<f64>, d: &mut impl GslVectorMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_balance_matrix(a.unwrap_unique(), d.unwrap_unique()) })
 }
 
 #[cfg(feature = "v2_2")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_decomp")]
-pub fn pcholesky_decomp(a: &mut ::MatrixF64, p: &mut ::Permutation) -> Value {
+pub fn pcholesky_decomp(a: &mut impl GslMatrixMut<f64>, p: &mut ::Permutation) -> Value {
     Value::from(unsafe { sys::gsl_linalg_pcholesky_decomp(a.unwrap_unique(), p.unwrap_unique()) })
 }
 
@@ -1385,10 +1451,10 @@ pub fn pcholesky_decomp(a: &mut ::MatrixF64, p: &mut ::Permutation) -> Value {
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_solve")]
 pub fn pcholesky_solve(
-    LDLT: &::MatrixF64,
+    LDLT: &impl GslMatrix<f64>,
     p: &::Permutation,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_pcholesky_solve(
@@ -1403,7 +1469,11 @@ pub fn pcholesky_solve(
 #[cfg(feature = "v2_2")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_svx")]
-pub fn pcholesky_svx(LDLT: &::MatrixF64, p: &::Permutation, x: &mut ::VectorF64) -> Value {
+pub fn pcholesky_svx(
+    LDLT: &impl GslMatrix<f64>,
+    p: &::Permutation,
+    x: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_pcholesky_svx(LDLT.unwrap_shared(), p.unwrap_shared(), x.unwrap_unique())
     })
@@ -1412,7 +1482,11 @@ pub fn pcholesky_svx(LDLT: &::MatrixF64, p: &::Permutation, x: &mut ::VectorF64)
 #[cfg(feature = "v2_2")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_decomp2")]
-pub fn pcholesky_decomp2(A: &mut ::MatrixF64, p: &mut ::Permutation, S: &mut ::VectorF64) -> Value {
+pub fn pcholesky_decomp2(
+    A: &mut impl GslMatrixMut<f64>,
+    p: &mut ::Permutation,
+    S: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_pcholesky_decomp2(A.unwrap_unique(), p.unwrap_unique(), S.unwrap_unique())
     })
@@ -1422,11 +1496,11 @@ pub fn pcholesky_decomp2(A: &mut ::MatrixF64, p: &mut ::Permutation, S: &mut ::V
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_solve2")]
 pub fn pcholesky_solve2(
-    LDLT: &::MatrixF64,
+    LDLT: &impl GslMatrix<f64>,
     p: &::Permutation,
-    S: &::VectorF64,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    S: &impl GslVector<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_pcholesky_solve2(
@@ -1443,10 +1517,10 @@ pub fn pcholesky_solve2(
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_svx2")]
 pub fn pcholesky_svx2(
-    LDLT: &::MatrixF64,
+    LDLT: &impl GslMatrix<f64>,
     p: &::Permutation,
-    S: &::VectorF64,
-    x: &mut ::VectorF64,
+    S: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_pcholesky_svx2(
@@ -1461,7 +1535,11 @@ pub fn pcholesky_svx2(
 #[cfg(feature = "v2_2")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_invert")]
-pub fn pcholesky_invert(LDLT: &::MatrixF64, p: &::Permutation, Ainv: &mut ::MatrixF64) -> Value {
+pub fn pcholesky_invert(
+    LDLT: &impl GslMatrix<f64>,
+    p: &::Permutation,
+    Ainv: &mut impl GslMatrixMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_pcholesky_invert(
             LDLT.unwrap_shared(),
@@ -1476,9 +1554,9 @@ pub fn pcholesky_invert(LDLT: &::MatrixF64, p: &::Permutation, Ainv: &mut ::Matr
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_pcholesky_rcond")]
 pub fn pcholesky_rcond(
-    LDLT: &::MatrixF64,
+    LDLT: &impl GslMatrix<f64>,
     p: &::Permutation,
-    work: &mut ::VectorF64,
+    work: &mut impl GslVectorMut<f64>,
 ) -> (Value, f64) {
     let mut rcond = 0.;
     let ret = unsafe {
@@ -1495,7 +1573,11 @@ pub fn pcholesky_rcond(
 #[cfg(feature = "v2_2")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_mcholesky_decomp")]
-pub fn mcholesky_decomp(A: &mut ::MatrixF64, p: &mut ::Permutation, E: &mut ::VectorF64) -> Value {
+pub fn mcholesky_decomp(
+    A: &mut impl GslMatrixMut<f64>,
+    p: &mut ::Permutation,
+    E: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_mcholesky_decomp(A.unwrap_unique(), p.unwrap_unique(), E.unwrap_unique())
     })
@@ -1505,10 +1587,10 @@ pub fn mcholesky_decomp(A: &mut ::MatrixF64, p: &mut ::Permutation, E: &mut ::Ve
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_mcholesky_solve")]
 pub fn mcholesky_solve(
-    LDLT: &::MatrixF64,
+    LDLT: &impl GslMatrix<f64>,
     p: &::Permutation,
-    b: &::VectorF64,
-    x: &mut ::VectorF64,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_mcholesky_solve(
@@ -1523,7 +1605,11 @@ pub fn mcholesky_solve(
 #[cfg(feature = "v2_2")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_mcholesky_svx")]
-pub fn mcholesky_svx(LDLT: &::MatrixF64, p: &::Permutation, x: &mut ::VectorF64) -> Value {
+pub fn mcholesky_svx(
+    LDLT: &impl GslMatrix<f64>,
+    p: &::Permutation,
+    x: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_mcholesky_svx(LDLT.unwrap_shared(), p.unwrap_shared(), x.unwrap_unique())
     })
@@ -1534,9 +1620,9 @@ pub fn mcholesky_svx(LDLT: &::MatrixF64, p: &::Permutation, x: &mut ::VectorF64)
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_mcholesky_rcond")]
 pub fn mcholesky_rcond(
-    LDLT: &::MatrixF64,
+    LDLT: &impl GslMatrix<f64>,
     p: &::Permutation,
-    work: &mut ::VectorF64,
+    work: &mut impl GslVectorMut<f64>,
 ) -> (Value, f64) {
     let mut rcond = 0.;
     let ret = unsafe {
@@ -1553,7 +1639,11 @@ pub fn mcholesky_rcond(
 #[cfg(feature = "v2_2")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_mcholesky_invert")]
-pub fn mcholesky_invert(LDLT: &::MatrixF64, p: &::Permutation, Ainv: &mut ::MatrixF64) -> Value {
+pub fn mcholesky_invert(
+    LDLT: &impl GslMatrix<f64>,
+    p: &::Permutation,
+    Ainv: &mut impl GslMatrixMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_mcholesky_invert(
             LDLT.unwrap_shared(),
@@ -1566,14 +1656,18 @@ pub fn mcholesky_invert(LDLT: &::MatrixF64, p: &::Permutation, Ainv: &mut ::Matr
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_cholesky_band_decomp")]
-pub fn cholesky_band_decomp(A: &mut ::MatrixF64) -> Value {
+pub fn cholesky_band_decomp(A: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_cholesky_band_decomp(A.unwrap_unique()) })
 }
 
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_cholesky_band_solve")]
-pub fn cholesky_band_solve(LLT: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> Value {
+pub fn cholesky_band_solve(
+    LLT: &impl GslMatrix<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_cholesky_band_solve(
             LLT.unwrap_shared(),
@@ -1586,7 +1680,7 @@ pub fn cholesky_band_solve(LLT: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_cholesky_band_svx")]
-pub fn cholesky_band_svx(LLT: &::MatrixF64, x: &mut ::VectorF64) -> Value {
+pub fn cholesky_band_svx(LLT: &impl GslMatrix<f64>, x: &mut impl GslVectorMut<f64>) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_cholesky_band_svx(LLT.unwrap_shared(), x.unwrap_unique())
     })
@@ -1595,7 +1689,11 @@ pub fn cholesky_band_svx(LLT: &::MatrixF64, x: &mut ::VectorF64) -> Value {
 #[cfg(feature = "v2_7")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
 #[doc(alias = "gsl_linalg_cholesky_band_solvem")]
-pub fn cholesky_band_solvem(LLT: &::MatrixF64, B: &::MatrixF64, X: &mut ::MatrixF64) -> Value {
+pub fn cholesky_band_solvem(
+    LLT: &impl GslMatrix<f64>,
+    B: &impl GslMatrix<f64>,
+    X: &mut impl GslMatrixMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_cholesky_band_solvem(
             LLT.unwrap_shared(),
@@ -1608,7 +1706,7 @@ pub fn cholesky_band_solvem(LLT: &::MatrixF64, B: &::MatrixF64, X: &mut ::Matrix
 #[cfg(feature = "v2_7")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
 #[doc(alias = "gsl_linalg_cholesky_band_svxm")]
-pub fn cholesky_band_svxm(LLT: &::MatrixF64, X: &mut ::MatrixF64) -> Value {
+pub fn cholesky_band_svxm(LLT: &impl GslMatrix<f64>, X: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_cholesky_band_svxm(LLT.unwrap_shared(), X.unwrap_unique())
     })
@@ -1617,7 +1715,7 @@ pub fn cholesky_band_svxm(LLT: &::MatrixF64, X: &mut ::MatrixF64) -> Value {
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_cholesky_band_invert")]
-pub fn cholesky_band_invert(LLT: &::MatrixF64, Ainv: &mut ::MatrixF64) -> Value {
+pub fn cholesky_band_invert(LLT: &impl GslMatrix<f64>, Ainv: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_cholesky_band_invert(LLT.unwrap_shared(), Ainv.unwrap_unique())
     })
@@ -1626,7 +1724,7 @@ pub fn cholesky_band_invert(LLT: &::MatrixF64, Ainv: &mut ::MatrixF64) -> Value
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_cholesky_band_unpack")]
-pub fn cholesky_band_unpack(LLT: &::MatrixF64, L: &mut ::MatrixF64) -> Value {
+pub fn cholesky_band_unpack(LLT: &impl GslMatrix<f64>, L: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_cholesky_band_unpack(LLT.unwrap_shared(), L.unwrap_unique())
     })
@@ -1636,7 +1734,10 @@ pub fn cholesky_band_unpack(LLT: &::MatrixF64, L: &mut ::MatrixF64) -> Value {
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_cholesky_band_rcond")]
-pub fn cholesky_band_rcond(LLT: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f64) {
+pub fn cholesky_band_rcond(
+    LLT: &impl GslMatrix<f64>,
+    work: &mut impl GslVectorMut<f64>,
+) -> (Value, f64) {
     let mut rcond = 0.;
     let ret = unsafe {
         sys::gsl_linalg_cholesky_band_rcond(LLT.unwrap_shared(), &mut rcond, work.unwrap_unique())
@@ -1647,14 +1748,18 @@ pub fn cholesky_band_rcond(LLT: &::MatrixF64, work: &mut ::VectorF64) -> (Value,
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_decomp")]
-pub fn ldlt_decomp(A: &mut ::MatrixF64) -> Value {
+pub fn ldlt_decomp(A: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_ldlt_decomp(A.unwrap_unique()) })
 }
 
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_solve")]
-pub fn ldlt_solve(LDLT: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> Value {
+pub fn ldlt_solve(
+    LDLT: &impl GslMatrix<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_ldlt_solve(LDLT.unwrap_shared(), b.unwrap_shared(), x.unwrap_unique())
     })
@@ -1663,7 +1768,7 @@ pub fn ldlt_solve(LDLT: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> V
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_svx")]
-pub fn ldlt_svx(LDLT: &::MatrixF64, x: &mut ::VectorF64) -> Value {
+pub fn ldlt_svx(LDLT: &impl GslMatrix<f64>, x: &mut impl GslVectorMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_ldlt_svx(LDLT.unwrap_shared(), x.unwrap_unique()) })
 }
 
@@ -1671,7 +1776,7 @@ pub fn ldlt_svx(LDLT: &::MatrixF64, x: &mut ::VectorF64) -> Value {
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_rcond")]
-pub fn ldlt_rcond(LDLT: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f64) {
+pub fn ldlt_rcond(LDLT: &impl GslMatrix<f64>, work: &mut impl GslVectorMut<f64>) -> (Value, f64) {
     let mut rcond = 0.;
     let ret = unsafe {
         sys::gsl_linalg_ldlt_rcond(LDLT.unwrap_shared(), &mut rcond, work.unwrap_unique())
@@ -1682,14 +1787,18 @@ pub fn ldlt_rcond(LDLT: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f64) {
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_band_decomp")]
-pub fn ldlt_band_decomp(A: &mut ::MatrixF64) -> Value {
+pub fn ldlt_band_decomp(A: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_ldlt_band_decomp(A.unwrap_unique()) })
 }
 
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_band_solve")]
-pub fn ldlt_band_solve(LDLT: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64) -> Value {
+pub fn ldlt_band_solve(
+    LDLT: &impl GslMatrix<f64>,
+    b: &impl GslVector<f64>,
+    x: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_ldlt_band_solve(LDLT.unwrap_shared(), b.unwrap_shared(), x.unwrap_unique())
     })
@@ -1698,14 +1807,18 @@ pub fn ldlt_band_solve(LDLT: &::MatrixF64, b: &::VectorF64, x: &mut ::VectorF64)
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_band_svx")]
-pub fn ldlt_band_svx(LDLT: &::MatrixF64, x: &mut ::VectorF64) -> Value {
+pub fn ldlt_band_svx(LDLT: &impl GslMatrix<f64>, x: &mut impl GslVectorMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_ldlt_band_svx(LDLT.unwrap_shared(), x.unwrap_unique()) })
 }
 
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_band_unpack")]
-pub fn ldlt_band_unpack(LDLT: &::MatrixF64, L: &mut ::MatrixF64, D: &mut ::VectorF64) -> Value {
+pub fn ldlt_band_unpack(
+    LDLT: &impl GslMatrix<f64>,
+    L: &mut impl GslMatrixMut<f64>,
+    D: &mut impl GslVectorMut<f64>,
+) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_ldlt_band_unpack(LDLT.unwrap_shared(), L.unwrap_unique(), D.unwrap_unique())
     })
@@ -1715,7 +1828,10 @@ pub fn ldlt_band_unpack(LDLT: &::MatrixF64, L: &mut ::MatrixF64, D: &mut ::Vecto
 #[cfg(feature = "v2_6")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 #[doc(alias = "gsl_linalg_ldlt_band_rcond")]
-pub fn ldlt_band_rcond(LDLT: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f64) {
+pub fn ldlt_band_rcond(
+    LDLT: &impl GslMatrix<f64>,
+    work: &mut impl GslVectorMut<f64>,
+) -> (Value, f64) {
     let mut rcond = 0.;
     let ret = unsafe {
         sys::gsl_linalg_ldlt_band_rcond(LDLT.unwrap_shared(), &mut rcond, work.unwrap_unique())
@@ -1726,28 +1842,28 @@ pub fn ldlt_band_rcond(LDLT: &::MatrixF64, work: &mut ::VectorF64) -> (Value, f6
 #[cfg(feature = "v2_2")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_tri_upper_invert")]
-pub fn tri_upper_invert(T: &mut ::MatrixF64) -> Value {
+pub fn tri_upper_invert(T: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_tri_upper_invert(T.unwrap_unique()) })
 }
 
 #[cfg(feature = "v2_2")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_tri_lower_invert")]
-pub fn tri_lower_invert(T: &mut ::MatrixF64) -> Value {
+pub fn tri_lower_invert(T: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_tri_lower_invert(T.unwrap_unique()) })
 }
 
 #[cfg(feature = "v2_2")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_tri_upper_unit_invert")]
-pub fn tri_upper_unit_invert(T: &mut ::MatrixF64) -> Value {
+pub fn tri_upper_unit_invert(T: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_tri_upper_unit_invert(T.unwrap_unique()) })
 }
 
 #[cfg(feature = "v2_2")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_tri_lower_unit_invert")]
-pub fn tri_lower_unit_invert(T: &mut ::MatrixF64) -> Value {
+pub fn tri_lower_unit_invert(T: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_tri_lower_unit_invert(T.unwrap_unique()) })
 }
 
@@ -1757,7 +1873,7 @@ pub fn tri_lower_unit_invert(T: &mut ::MatrixF64) -> Value {
 pub fn tri_invert(
     Uplo: enums::CblasUplo,
     Diag: enums::CblasDiag,
-    T: &mut ::MatrixComplexF64,
+    T: &mut impl GslMatrixMut<ComplexF64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_complex_tri_invert(Uplo.into(), Diag.into(), T.unwrap_unique())
@@ -1768,7 +1884,7 @@ pub fn tri_invert(
 pub fn complex_tri_invert(
     Uplo: enums::CblasUplo,
     Diag: enums::CblasDiag,
-    T: &mut ::MatrixComplexF64,
+    T: &mut impl GslMatrixMut<ComplexF64>,
 ) -> Value {
     Value::from(unsafe {
         sys::gsl_linalg_complex_tri_invert(Uplo.into(), Diag.into(), T.unwrap_unique())
@@ -1778,24 +1894,24 @@ pub fn complex_tri_invert(
 #[cfg(feature = "v2_2")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_tri_LTL")]
-pub fn tri_LTL(L: &mut ::MatrixF64) -> Value {
+pub fn tri_LTL(L: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_tri_LTL(L.unwrap_unique()) })
 }
 
 #[cfg(feature = "v2_2")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
 #[doc(alias = "gsl_linalg_tri_UL")]
-pub fn tri_UL(LU: &mut ::MatrixF64) -> Value {
+pub fn tri_UL(LU: &mut impl GslMatrixMut<f64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_tri_UL(LU.unwrap_unique()) })
 }
 
 #[doc(alias = "gsl_linalg_complex_tri_LHL")]
-pub fn complex_tri_LHL(L: &mut ::MatrixComplexF64) -> Value {
+pub fn complex_tri_LHL(L: &mut impl GslMatrixMut<ComplexF64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_complex_tri_LHL(L.unwrap_unique()) })
 }
 
 #[doc(alias = "gsl_linalg_complex_tri_UL")]
-pub fn complex_tri_UL(LU: &mut ::MatrixComplexF64) -> Value {
+pub fn complex_tri_UL(LU: &mut impl GslMatrixMut<ComplexF64>) -> Value {
     Value::from(unsafe { sys::gsl_linalg_complex_tri_UL(LU.unwrap_unique()) })
 }
 
@@ -1809,6 +1925,6 @@ pub fn givens(a: f64, b: f64) -> (f64, f64) {
 }
 
 #[doc(alias = "gsl_linalg_givens_gv")]
-pub fn givens_gv(v: &mut ::VectorF64, i: usize, j: usize, c: f64, s: f64) {
+pub fn givens_gv(v: &mut impl GslVectorMut<f64>, i: usize, j: usize, c: f64, s: f64) {
     unsafe { sys::gsl_linalg_givens_gv(v.unwrap_unique(), i, j, c, s) }
 }
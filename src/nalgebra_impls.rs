@@ -0,0 +1,361 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+//! Conversions between the GSL containers and `nalgebra` matrices, enabled by the `nalgebra`
+//! feature.
+//!
+//! `nalgebra` stores its matrices in column-major order while GSL uses row-major order, so a
+//! `nalgebra` matrix can only be viewed without copying as the *transpose* of a GSL matrix: a
+//! `r × c` `DMatrix` becomes a `c × r` `MatrixF64View` (and conversely). The `from_nalgebra` and
+//! `to_nalgebra` methods of the owned types copy the data and keep the orientation.
+//!
+//! The complex variants work on `num_complex::Complex` matrices and additionally require the
+//! `num-complex` feature.
+
+use ffi::FFI;
+use nalgebra::{
+    DMatrix, DVector, Dim, Dyn, Matrix, RawStorage, RawStorageMut, ViewStorage, ViewStorageMut, U1,
+};
+use std::ptr;
+
+type NalgebraVectorView<'a, T> = Matrix<T, Dyn, U1, ViewStorage<'a, T, Dyn, U1, Dyn, Dyn>>;
+type NalgebraVectorViewMut<'a, T> = Matrix<T, Dyn, U1, ViewStorageMut<'a, T, Dyn, U1, Dyn, Dyn>>;
+type NalgebraMatrixView<'a, T> = Matrix<T, Dyn, Dyn, ViewStorage<'a, T, Dyn, Dyn, U1, Dyn>>;
+type NalgebraMatrixViewMut<'a, T> = Matrix<T, Dyn, Dyn, ViewStorageMut<'a, T, Dyn, Dyn, U1, Dyn>>;
+
+// Converts a `nalgebra` stride into a GSL one. Single elements can have any stride.
+fn gsl_stride(len: usize, stride: usize) -> Option<usize> {
+    if len <= 1 {
+        Some(1)
+    } else if stride > 0 {
+        Some(stride)
+    } else {
+        None
+    }
+}
+
+// Returns the GSL `tda` of the transpose of a `nrows × ncols` column-major matrix.
+fn gsl_tda(nrows: usize, ncols: usize, (rstride, cstride): (usize, usize)) -> Option<usize> {
+    if nrows > 1 && rstride != 1 {
+        return None;
+    }
+    if ncols <= 1 {
+        Some(nrows)
+    } else if cstride >= nrows {
+        Some(cstride)
+    } else {
+        None
+    }
+}
+
+macro_rules! nalgebra_conversions {
+    (
+        $elem:ty, $data:ty,
+        $vec:ident, $vec_sys:ident, $vec_view:ident, $vec_view_mut:ident,
+        $mat:ident, $mat_sys:ident, $mat_view:ident, $mat_view_mut:ident
+    ) => {
+        impl<'a> ::types::$vec_view<'a> {
+            /// Creates a view of a `nalgebra` column vector (a `DVector` or any of its views),
+            /// without copying it. The stride of the vector is kept.
+            ///
+            /// Returns `None` if the vector has a zero stride.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+            pub fn from_nalgebra<R: Dim, S: RawStorage<$elem, R>>(
+                v: &'a Matrix<$elem, R, U1, S>,
+            ) -> Option<Self> {
+                let stride = gsl_stride(v.nrows(), v.strides().0)?;
+                Self::from_raw(sys::$vec_sys {
+                    size: v.nrows(),
+                    stride,
+                    data: v.as_ptr() as *mut $data,
+                    block: ptr::null_mut(),
+                    owner: 0,
+                })
+            }
+        }
+
+        impl<'a> ::types::$vec_view_mut<'a> {
+            /// Creates a mutable view of a `nalgebra` column vector (a `DVector` or any of its
+            /// views), without copying it. The stride of the vector is kept.
+            ///
+            /// Returns `None` if the vector has a zero stride.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+            pub fn from_nalgebra<R: Dim, S: RawStorageMut<$elem, R>>(
+                v: &'a mut Matrix<$elem, R, U1, S>,
+            ) -> Option<Self> {
+                let stride = gsl_stride(v.nrows(), v.strides().0)?;
+                Self::from_raw(sys::$vec_sys {
+                    size: v.nrows(),
+                    stride,
+                    data: v.as_mut_ptr() as *mut $data,
+                    block: ptr::null_mut(),
+                    owner: 0,
+                })
+            }
+        }
+
+        impl ::types::$vec {
+            /// Copies a `nalgebra` column vector into a new vector.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+            pub fn from_nalgebra<R: Dim, S: RawStorage<$elem, R>>(
+                v: &Matrix<$elem, R, U1, S>,
+            ) -> Option<Self> {
+                let mut out = Self::new(v.nrows())?;
+                for (dst, src) in out.as_nalgebra_mut().iter_mut().zip(v.iter()) {
+                    *dst = *src;
+                }
+                Some(out)
+            }
+
+            /// Copies the vector into a new `DVector`.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+            pub fn to_nalgebra(&self) -> DVector<$elem> {
+                self.as_nalgebra().into_owned()
+            }
+
+            /// Returns a `nalgebra` view of the vector, without copying it.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+            pub fn as_nalgebra(&self) -> NalgebraVectorView<'_, $elem> {
+                unsafe {
+                    let v = &*FFI::unwrap_shared(self);
+                    Matrix::from_data(ViewStorage::from_raw_parts(
+                        v.data as *const $elem,
+                        (Dyn(v.size), U1),
+                        (Dyn(v.stride), Dyn(v.size * v.stride)),
+                    ))
+                }
+            }
+
+            /// Returns a mutable `nalgebra` view of the vector, without copying it.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+            pub fn as_nalgebra_mut(&mut self) -> NalgebraVectorViewMut<'_, $elem> {
+                unsafe {
+                    let v = &*FFI::unwrap_unique(self);
+                    Matrix::from_data(ViewStorageMut::from_raw_parts(
+                        v.data as *mut $elem,
+                        (Dyn(v.size), U1),
+                        (Dyn(v.stride), Dyn(v.size * v.stride)),
+                    ))
+                }
+            }
+        }
+
+        impl<'a> ::types::$mat_view<'a> {
+            /// Creates a view of the *transpose* of a `nalgebra` matrix (a `DMatrix` or any of its
+            /// views), without copying it: the `(i, j)` element of the view is the `(j, i)` element
+            /// of `m`.
+            ///
+            /// Returns `None` if the elements of a column of `m` aren't contiguous.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+            pub fn from_nalgebra_transposed<R: Dim, C: Dim, S: RawStorage<$elem, R, C>>(
+                m: &'a Matrix<$elem, R, C, S>,
+            ) -> Option<Self> {
+                let tda = gsl_tda(m.nrows(), m.ncols(), m.strides())?;
+                Self::from_raw(sys::$mat_sys {
+                    size1: m.ncols(),
+                    size2: m.nrows(),
+                    tda,
+                    data: m.as_ptr() as *mut $data,
+                    block: ptr::null_mut(),
+                    owner: 0,
+                })
+            }
+        }
+
+        impl<'a> ::types::$mat_view_mut<'a> {
+            /// Creates a mutable view of the *transpose* of a `nalgebra` matrix (a `DMatrix` or any
+            /// of its views), without copying it: the `(i, j)` element of the view is the `(j, i)`
+            /// element of `m`.
+            ///
+            /// Returns `None` if the elements of a column of `m` aren't contiguous.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+            pub fn from_nalgebra_transposed<R: Dim, C: Dim, S: RawStorageMut<$elem, R, C>>(
+                m: &'a mut Matrix<$elem, R, C, S>,
+            ) -> Option<Self> {
+                let tda = gsl_tda(m.nrows(), m.ncols(), m.strides())?;
+                Self::from_raw(sys::$mat_sys {
+                    size1: m.ncols(),
+                    size2: m.nrows(),
+                    tda,
+                    data: m.as_mut_ptr() as *mut $data,
+                    block: ptr::null_mut(),
+                    owner: 0,
+                })
+            }
+        }
+
+        impl ::types::$mat {
+            /// Copies a `nalgebra` matrix into a new matrix with the same number of rows and
+            /// columns.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+            pub fn from_nalgebra<R: Dim, C: Dim, S: RawStorage<$elem, R, C>>(
+                m: &Matrix<$elem, R, C, S>,
+            ) -> Option<Self> {
+                let mut out = Self::new(m.nrows(), m.ncols())?;
+                let mut t = out.as_nalgebra_transposed_mut();
+                for i in 0..m.nrows() {
+                    for j in 0..m.ncols() {
+                        t[(j, i)] = m[(i, j)];
+                    }
+                }
+                Some(out)
+            }
+
+            /// Copies the matrix into a new `DMatrix` with the same number of rows and columns.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+            pub fn to_nalgebra(&self) -> DMatrix<$elem> {
+                self.as_nalgebra_transposed().transpose()
+            }
+
+            /// Returns a `nalgebra` view of the *transpose* of the matrix, without copying it.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+            pub fn as_nalgebra_transposed(&self) -> NalgebraMatrixView<'_, $elem> {
+                unsafe {
+                    let m = &*FFI::unwrap_shared(self);
+                    Matrix::from_data(ViewStorage::from_raw_parts(
+                        m.data as *const $elem,
+                        (Dyn(m.size2), Dyn(m.size1)),
+                        (U1, Dyn(m.tda)),
+                    ))
+                }
+            }
+
+            /// Returns a mutable `nalgebra` view of the *transpose* of the matrix, without copying
+            /// it.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "nalgebra")))]
+            pub fn as_nalgebra_transposed_mut(&mut self) -> NalgebraMatrixViewMut<'_, $elem> {
+                unsafe {
+                    let m = &*FFI::unwrap_unique(self);
+                    Matrix::from_data(ViewStorageMut::from_raw_parts(
+                        m.data as *mut $elem,
+                        (Dyn(m.size2), Dyn(m.size1)),
+                        (U1, Dyn(m.tda)),
+                    ))
+                }
+            }
+        }
+    };
+}
+
+nalgebra_conversions!(
+    f32,
+    f32,
+    VectorF32,
+    gsl_vector_float,
+    VectorF32View,
+    VectorF32ViewMut,
+    MatrixF32,
+    gsl_matrix_float,
+    MatrixF32View,
+    MatrixF32ViewMut
+);
+nalgebra_conversions!(
+    f64,
+    f64,
+    VectorF64,
+    gsl_vector,
+    VectorF64View,
+    VectorF64ViewMut,
+    MatrixF64,
+    gsl_matrix,
+    MatrixF64View,
+    MatrixF64ViewMut
+);
+#[cfg(feature = "num-complex")]
+nalgebra_conversions!(
+    ::num_complex::Complex<f32>,
+    f32,
+    VectorComplexF32,
+    gsl_vector_complex_float,
+    VectorComplexF32View,
+    VectorComplexF32ViewMut,
+    MatrixComplexF32,
+    gsl_matrix_complex_float,
+    MatrixComplexF32View,
+    MatrixComplexF32ViewMut
+);
+#[cfg(feature = "num-complex")]
+nalgebra_conversions!(
+    ::num_complex::Complex<f64>,
+    f64,
+    VectorComplexF64,
+    gsl_vector_complex,
+    VectorComplexF64View,
+    VectorComplexF64ViewMut,
+    MatrixComplexF64,
+    gsl_matrix_complex,
+    MatrixComplexF64View,
+    MatrixComplexF64ViewMut
+);
+
+#[test]
+fn strides_and_tda() {
+    assert_eq!(gsl_stride(1, 0), Some(1));
+    assert_eq!(gsl_stride(3, 2), Some(2));
+    assert_eq!(gsl_stride(3, 0), None);
+
+    assert_eq!(gsl_tda(3, 2, (1, 3)), Some(3));
+    assert_eq!(gsl_tda(3, 2, (1, 5)), Some(5));
+    assert_eq!(gsl_tda(3, 1, (1, 0)), Some(3));
+    assert_eq!(gsl_tda(1, 2, (7, 1)), Some(1));
+    // Row-major storage: the columns aren't contiguous.
+    assert_eq!(gsl_tda(3, 2, (2, 1)), None);
+    // Overlapping columns.
+    assert_eq!(gsl_tda(3, 2, (1, 2)), None);
+}
+
+#[test]
+fn nalgebra_vector_views() {
+    let v = DVector::from_vec(vec![0., 1., 2., 3., 4., 5.]);
+    let stepped = v.rows_with_step(1, 3, 1);
+    let view = ::types::VectorF64View::from_nalgebra(&stepped).unwrap();
+    assert_eq!(view.len(), 3);
+    assert_eq!(view.stride(), 2);
+    assert_eq!((view.get(0), view.get(1), view.get(2)), (1., 3., 5.));
+
+    let mut w = v.clone();
+    {
+        let mut stepped = w.rows_with_step_mut(0, 2, 2);
+        let mut view = ::types::VectorF64ViewMut::from_nalgebra(&mut stepped).unwrap();
+        view.set(1, 30.);
+    }
+    assert_eq!(w[3], 30.);
+
+    let copy = ::types::VectorF64::from_nalgebra(&stepped).unwrap();
+    assert_eq!(copy.to_nalgebra(), DVector::from_vec(vec![1., 3., 5.]));
+}
+
+#[test]
+fn nalgebra_matrix_views() {
+    let m = DMatrix::from_row_slice(3, 4, &[0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11.]);
+
+    let sub = m.view((1, 1), (2, 2));
+    let t = ::types::MatrixF64View::from_nalgebra_transposed(&sub).unwrap();
+    assert_eq!((t.size1(), t.size2()), (2, 2));
+    assert_eq!((t.get(0, 1), t.get(1, 0)), (9., 6.));
+
+    let row = m.row(2);
+    let t = ::types::MatrixF64View::from_nalgebra_transposed(&row).unwrap();
+    assert_eq!((t.size1(), t.size2()), (4, 1));
+    assert_eq!(t.get(3, 0), 11.);
+
+    let copy = ::types::MatrixF64::from_nalgebra(&sub).unwrap();
+    assert_eq!((copy.size1(), copy.size2()), (2, 2));
+    assert_eq!(copy.get(1, 0), 9.);
+    assert_eq!(copy.to_nalgebra(), sub.into_owned());
+
+    let mut owned = ::types::MatrixF64::new(2, 3).unwrap();
+    owned.as_nalgebra_transposed_mut()[(2, 1)] = 7.;
+    assert_eq!(owned.get(1, 2), 7.);
+}
+
+#[cfg(feature = "num-complex")]
+#[test]
+fn nalgebra_complex_vectors() {
+    use num_complex::Complex;
+
+    let v = DVector::from_vec(vec![Complex::new(1., 2.), Complex::new(3., 4.)]);
+    let copy = ::types::VectorComplexF64::from_nalgebra(&v).unwrap();
+    assert_eq!(copy.to_nalgebra(), v);
+}
@@ -0,0 +1,301 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+//! Zero-copy conversions between the GSL containers and `ndarray` arrays, enabled by the `ndarray`
+//! feature. The complex variants work on `num_complex::Complex` arrays and additionally require
+//! the `num-complex` feature.
+//!
+//! GSL strides are unsigned: arrays with negative strides, or broadcast (zero) strides, can't be
+//! viewed as GSL containers and the conversions return `None` for them. Matrices additionally
+//! need contiguous rows.
+
+use ffi::FFI;
+use ndarray::{ArrayView1, ArrayView2, ArrayViewMut1, ArrayViewMut2, ShapeBuilder};
+use std::ptr;
+
+// Converts an `ndarray` stride into a GSL one. Single elements can have any stride.
+fn gsl_stride(len: usize, stride: isize) -> Option<usize> {
+    if len <= 1 {
+        Some(1)
+    } else if stride > 0 {
+        Some(stride as usize)
+    } else {
+        None
+    }
+}
+
+// Returns the GSL `tda` of a matrix with the given shape and strides.
+fn gsl_tda(shape: &[usize], strides: &[isize]) -> Option<usize> {
+    if shape[1] > 1 && strides[1] != 1 {
+        return None;
+    }
+    if shape[0] <= 1 {
+        Some(shape[1])
+    } else if strides[0] >= shape[1] as isize {
+        Some(strides[0] as usize)
+    } else {
+        None
+    }
+}
+
+macro_rules! ndarray_conversions {
+    (
+        $elem:ty, $data:ty,
+        $vec:ident, $vec_sys:ident, $vec_view:ident, $vec_view_mut:ident,
+        $mat:ident, $mat_sys:ident, $mat_view:ident, $mat_view_mut:ident
+    ) => {
+        impl<'a> ::types::$vec_view<'a> {
+            /// Creates a view of a one-dimensional `ndarray` view, without copying it. The stride
+            /// of the array is kept.
+            ///
+            /// Returns `None` if the array has a negative or zero stride.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+            pub fn from_ndarray(a: ArrayView1<'a, $elem>) -> Option<Self> {
+                let stride = gsl_stride(a.len(), a.strides()[0])?;
+                Self::from_raw(sys::$vec_sys {
+                    size: a.len(),
+                    stride,
+                    data: a.as_ptr() as *mut $data,
+                    block: ptr::null_mut(),
+                    owner: 0,
+                })
+            }
+        }
+
+        impl<'a> ::types::$vec_view_mut<'a> {
+            /// Creates a mutable view of a one-dimensional `ndarray` view, without copying it. The
+            /// stride of the array is kept.
+            ///
+            /// Returns `None` if the array has a negative stride.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+            pub fn from_ndarray(mut a: ArrayViewMut1<'a, $elem>) -> Option<Self> {
+                let stride = gsl_stride(a.len(), a.strides()[0])?;
+                Self::from_raw(sys::$vec_sys {
+                    size: a.len(),
+                    stride,
+                    data: a.as_mut_ptr() as *mut $data,
+                    block: ptr::null_mut(),
+                    owner: 0,
+                })
+            }
+        }
+
+        impl ::types::$vec {
+            /// Returns an `ndarray` view of the vector, without copying it.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+            pub fn as_ndarray(&self) -> ArrayView1<'_, $elem> {
+                unsafe {
+                    let v = &*FFI::unwrap_shared(self);
+                    ArrayView1::from_shape_ptr(
+                        (v.size,).strides((v.stride,)),
+                        v.data as *const $elem,
+                    )
+                }
+            }
+
+            /// Returns a mutable `ndarray` view of the vector, without copying it.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+            pub fn as_ndarray_mut(&mut self) -> ArrayViewMut1<'_, $elem> {
+                unsafe {
+                    let v = &*FFI::unwrap_unique(self);
+                    ArrayViewMut1::from_shape_ptr(
+                        (v.size,).strides((v.stride,)),
+                        v.data as *mut $elem,
+                    )
+                }
+            }
+        }
+
+        impl<'a> ::types::$mat_view<'a> {
+            /// Creates a view of a two-dimensional `ndarray` view, without copying it. The rows
+            /// don't need to be adjacent in memory: their distance is used as the `tda` of the
+            /// matrix.
+            ///
+            /// Returns `None` if the elements of a row aren't contiguous (for example for a
+            /// column-major array) or if the array has a negative stride.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+            pub fn from_ndarray(a: ArrayView2<'a, $elem>) -> Option<Self> {
+                let tda = gsl_tda(a.shape(), a.strides())?;
+                Self::from_raw(sys::$mat_sys {
+                    size1: a.nrows(),
+                    size2: a.ncols(),
+                    tda,
+                    data: a.as_ptr() as *mut $data,
+                    block: ptr::null_mut(),
+                    owner: 0,
+                })
+            }
+        }
+
+        impl<'a> ::types::$mat_view_mut<'a> {
+            /// Creates a mutable view of a two-dimensional `ndarray` view, without copying it. The
+            /// rows don't need to be adjacent in memory: their distance is used as the `tda` of
+            /// the matrix.
+            ///
+            /// Returns `None` if the elements of a row aren't contiguous (for example for a
+            /// column-major array) or if the array has a negative stride.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+            pub fn from_ndarray(mut a: ArrayViewMut2<'a, $elem>) -> Option<Self> {
+                let tda = gsl_tda(a.shape(), a.strides())?;
+                Self::from_raw(sys::$mat_sys {
+                    size1: a.nrows(),
+                    size2: a.ncols(),
+                    tda,
+                    data: a.as_mut_ptr() as *mut $data,
+                    block: ptr::null_mut(),
+                    owner: 0,
+                })
+            }
+        }
+
+        impl ::types::$mat {
+            /// Returns an `ndarray` view of the matrix, without copying it.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+            pub fn as_ndarray(&self) -> ArrayView2<'_, $elem> {
+                unsafe {
+                    let m = &*FFI::unwrap_shared(self);
+                    ArrayView2::from_shape_ptr(
+                        (m.size1, m.size2).strides((m.tda, 1)),
+                        m.data as *const $elem,
+                    )
+                }
+            }
+
+            /// Returns a mutable `ndarray` view of the matrix, without copying it.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "ndarray")))]
+            pub fn as_ndarray_mut(&mut self) -> ArrayViewMut2<'_, $elem> {
+                unsafe {
+                    let m = &*FFI::unwrap_unique(self);
+                    ArrayViewMut2::from_shape_ptr(
+                        (m.size1, m.size2).strides((m.tda, 1)),
+                        m.data as *mut $elem,
+                    )
+                }
+            }
+        }
+    };
+}
+
+ndarray_conversions!(
+    f32,
+    f32,
+    VectorF32,
+    gsl_vector_float,
+    VectorF32View,
+    VectorF32ViewMut,
+    MatrixF32,
+    gsl_matrix_float,
+    MatrixF32View,
+    MatrixF32ViewMut
+);
+ndarray_conversions!(
+    f64,
+    f64,
+    VectorF64,
+    gsl_vector,
+    VectorF64View,
+    VectorF64ViewMut,
+    MatrixF64,
+    gsl_matrix,
+    MatrixF64View,
+    MatrixF64ViewMut
+);
+#[cfg(feature = "num-complex")]
+ndarray_conversions!(
+    ::num_complex::Complex<f32>,
+    f32,
+    VectorComplexF32,
+    gsl_vector_complex_float,
+    VectorComplexF32View,
+    VectorComplexF32ViewMut,
+    MatrixComplexF32,
+    gsl_matrix_complex_float,
+    MatrixComplexF32View,
+    MatrixComplexF32ViewMut
+);
+#[cfg(feature = "num-complex")]
+ndarray_conversions!(
+    ::num_complex::Complex<f64>,
+    f64,
+    VectorComplexF64,
+    gsl_vector_complex,
+    VectorComplexF64View,
+    VectorComplexF64ViewMut,
+    MatrixComplexF64,
+    gsl_matrix_complex,
+    MatrixComplexF64View,
+    MatrixComplexF64ViewMut
+);
+
+#[test]
+fn strides_and_tda() {
+    assert_eq!(gsl_stride(1, -3), Some(1));
+    assert_eq!(gsl_stride(3, 2), Some(2));
+    assert_eq!(gsl_stride(3, 0), None);
+    assert_eq!(gsl_stride(3, -1), None);
+
+    assert_eq!(gsl_tda(&[2, 3], &[3, 1]), Some(3));
+    assert_eq!(gsl_tda(&[2, 3], &[5, 1]), Some(5));
+    assert_eq!(gsl_tda(&[1, 3], &[0, 1]), Some(3));
+    // Column-major storage: the rows aren't contiguous.
+    assert_eq!(gsl_tda(&[2, 3], &[1, 2]), None);
+    // Overlapping or reversed rows.
+    assert_eq!(gsl_tda(&[2, 3], &[2, 1]), None);
+    assert_eq!(gsl_tda(&[2, 3], &[-3, 1]), None);
+}
+
+#[test]
+fn ndarray_vector_views() {
+    use ndarray::{s, Array1};
+
+    let mut a = Array1::from(vec![0., 1., 2., 3., 4., 5.]);
+    {
+        let v = ::types::VectorF64View::from_ndarray(a.slice(s![1..;2])).unwrap();
+        assert_eq!(v.len(), 3);
+        assert_eq!(v.stride(), 2);
+        assert_eq!((v.get(0), v.get(1), v.get(2)), (1., 3., 5.));
+    }
+    assert!(::types::VectorF64View::from_ndarray(a.slice(s![..;-1])).is_none());
+    let one = Array1::from(vec![1.]);
+    assert!(::types::VectorF64View::from_ndarray(one.broadcast(3).unwrap()).is_none());
+
+    {
+        let mut v = ::types::VectorF64ViewMut::from_ndarray(a.slice_mut(s![..;3])).unwrap();
+        v.set(1, 30.);
+    }
+    assert_eq!(a[3], 30.);
+
+    let mut owned = ::types::VectorF64::new(3).unwrap();
+    owned.as_ndarray_mut()[2] = 7.;
+    assert_eq!(owned.get(2), 7.);
+}
+
+#[test]
+fn ndarray_matrix_views() {
+    use ndarray::{s, Array2};
+
+    let a = Array2::from_shape_vec((3, 4), (0..12).map(f64::from).collect()).unwrap();
+
+    let m = ::types::MatrixF64View::from_ndarray(a.slice(s![1.., 1..3])).unwrap();
+    assert_eq!((m.size1(), m.size2()), (2, 2));
+    assert_eq!((m.get(0, 0), m.get(1, 1)), (5., 10.));
+
+    let m = ::types::MatrixF64View::from_ndarray(a.slice(s![..;2, ..])).unwrap();
+    assert_eq!((m.size1(), m.size2()), (2, 4));
+    assert_eq!(m.get(1, 3), 11.);
+
+    assert!(::types::MatrixF64View::from_ndarray(a.t()).is_none());
+    assert!(::types::MatrixF64View::from_ndarray(a.slice(s![.., ..;2])).is_none());
+    assert!(::types::MatrixF64View::from_ndarray(a.slice(s![..;-1, ..])).is_none());
+
+    // A single column of the transpose has contiguous "rows" of one element.
+    let column = a.t().slice_move(s![.., 1..2]);
+    let m = ::types::MatrixF64View::from_ndarray(column).unwrap();
+    assert_eq!((m.size1(), m.size2()), (4, 1));
+    assert_eq!(m.get(2, 0), 6.);
+
+    let mut owned = ::types::MatrixF64::new(2, 3).unwrap();
+    owned.as_ndarray_mut()[[1, 2]] = 7.;
+    assert_eq!(owned.get(1, 2), 7.);
+}
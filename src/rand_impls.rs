@@ -0,0 +1,161 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+//! `rand_core` traits for [`Rng`], enabled by the `rand` feature, so that a GSL generator can
+//! drive the `rand` distributions and any code generic over `rand::Rng`.
+//!
+//! GSL generators return integers in `[min, max]`, a range which is rarely `[0, 2^32)`: only the
+//! largest power of two number of values above `min` is used, the other values being rejected, so
+//! that every bit of the `u32`/`u64`/bytes produced is uniformly distributed.
+
+use rand_core::{impls, Error, RngCore, SeedableRng};
+use types::{Rng, RngType};
+
+impl Rng {
+    // Returns `bits` uniformly distributed random bits, built from as many calls to `get` as
+    // needed.
+    fn next_bits(&mut self, bits: u32) -> u64 {
+        let min = self.min() as u64;
+        let range = self.max() as u64 - min;
+        // Number of bits of `get() - min` which are uniformly distributed.
+        let available = match range.checked_add(1) {
+            Some(n) => 63 - n.leading_zeros(),
+            None => 64,
+        };
+        assert!(available > 0, "rust-GSL: the generator has a single value");
+
+        let mut out = 0;
+        let mut filled = 0;
+        while filled < bits {
+            let x = self.get() as u64 - min;
+            if available < 64 && x >> available != 0 {
+                continue;
+            }
+            let take = available.min(bits - filled);
+            let mask = if take == 64 { !0 } else { (1 << take) - 1 };
+            out |= (x & mask) << filled;
+            filled += take;
+        }
+        out
+    }
+}
+
+/// Works with every [`RngType`]. Only the largest power of two number of values above
+/// [`Rng::min`] is used, so that every bit produced is uniformly distributed whatever the range of
+/// the generator: generators providing fewer than 32 bits are called several times per `u32`.
+#[cfg_attr(feature = "dox", doc(cfg(feature = "rand")))]
+impl RngCore for Rng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_bits(32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.next_bits(64)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Creates generators of the default type ([`RngType::default`], `mt19937` unless changed with
+/// [`RngType::env_setup`]). The seed is read as a little-endian integer and given to
+/// [`Rng::set`], so `Rng::seed_from_u64(s)` produces the same stream as:
+///
+/// ```no_run
+/// # use rgsl::{Rng, RngType};
+/// # let s = 0;
+/// let mut r = Rng::new(RngType::default()).unwrap();
+/// r.set(s as _);
+/// ```
+///
+/// Generators of other types can be seeded the same way.
+#[cfg_attr(feature = "dox", doc(cfg(feature = "rand")))]
+impl SeedableRng for Rng {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Rng {
+        Rng::seed_from_u64(u64::from_le_bytes(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> Rng {
+        let mut r = Rng::new(RngType::default()).expect("rust-GSL: failed to allocate generator");
+        r.set(state as _);
+        r
+    }
+}
+
+#[test]
+fn seeding_is_deterministic() {
+    let mut a = Rng::seed_from_u64(42);
+    let mut b = Rng::seed_from_u64(42);
+    let xs: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+    let ys: Vec<u64> = (0..8).map(|_| b.next_u64()).collect();
+    assert_eq!(xs, ys);
+
+    let mut c = Rng::seed_from_u64(43);
+    let zs: Vec<u64> = (0..8).map(|_| c.next_u64()).collect();
+    assert_ne!(xs, zs);
+
+    let mut d = Rng::from_seed(42u64.to_le_bytes());
+    assert_eq!(d.next_u64(), xs[0]);
+
+    let mut e = Rng::new(RngType::default()).unwrap();
+    e.set(42);
+    let mut f = Rng::seed_from_u64(42);
+    assert_eq!(e.get(), f.get());
+}
+
+#[test]
+fn fill_bytes_matches_next_u64() {
+    let mut r = Rng::seed_from_u64(7);
+    let mut reference = r.clone();
+    let mut bytes = [0; 16];
+    r.fill_bytes(&mut bytes);
+    assert_eq!(bytes[..8], reference.next_u64().to_le_bytes());
+    assert_eq!(bytes[8..], reference.next_u64().to_le_bytes());
+
+    let mut odd = [0; 13];
+    assert!(r.try_fill_bytes(&mut odd).is_ok());
+    assert!(odd.iter().any(|&b| b != 0));
+}
+
+#[test]
+fn next_u32_from_narrow_generators() {
+    use types::rng::{algorithms, other};
+
+    // ranlxs0 returns 24 bits, so a u32 takes the 24 bits of a first call and the low 8 bits
+    // of a second one.
+    let mut r = Rng::new(algorithms::ranlxs0()).unwrap();
+    assert_eq!((r.min(), r.max()), (0, (1 << 24) - 1));
+    let mut reference = r.clone();
+    for _ in 0..100 {
+        let low = reference.get() as u32;
+        let high = reference.get() as u32 & 0xff;
+        assert_eq!(r.next_u32(), low | high << 24);
+    }
+
+    // minstd returns values in [1, 2^31 - 2]: only the values below 2^30 above the minimum are
+    // used, giving 30 bits per call.
+    let mut r = Rng::new(other::minstd()).unwrap();
+    assert_eq!((r.min(), r.max()), (1, (1 << 31) - 2));
+    let mut reference = r.clone();
+    let mut next_30_bits = || loop {
+        let x = reference.get() as u64 - 1;
+        if x < 1 << 30 {
+            return x;
+        }
+    };
+    for _ in 0..100 {
+        let low = next_30_bits();
+        let mid = next_30_bits();
+        let high = next_30_bits() & 0xf;
+        assert_eq!(r.next_u64(), low | mid << 30 | high << 60);
+    }
+}
@@ -0,0 +1,936 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+Typed random number distributions.
+
+Each distribution is a small `Copy` struct holding its parameters, implementing either
+[`ContinuousDistribution`] or [`DiscreteDistribution`]. They bring together the sampling methods
+of [`Rng`] and the density and cumulative distribution functions of the other `randist` modules,
+so that generic code (likelihoods, goodness-of-fit tests...) can work over any distribution:
+
+```no_run
+use rgsl::randist::distributions::{ContinuousDistribution, Gamma, Gaussian};
+
+fn log_likelihood(d: &impl ContinuousDistribution, data: &[f64]) -> f64 {
+    data.iter().map(|&x| d.ln_pdf(x)).sum()
+}
+
+let data = [0.5, 1.2, 2.3];
+let a = log_likelihood(&Gaussian { sigma: 1. }, &data);
+let b = log_likelihood(&Gamma { a: 2., b: 1. }, &data);
+```
+
+Large numbers of variates can be drawn at once into slices, vectors and matrices, or lazily
+through an iterator:
+
+```no_run
+use rgsl::randist::distributions::{ContinuousDistribution, DiscreteDistribution, Gaussian, Poisson};
+use rgsl::{MatrixF64, Rng, RngType};
+
+let mut r = Rng::new(RngType::default()).unwrap();
+let mut samples = vec![0.; 1_000_000];
+Gaussian { sigma: 2. }.sample_into(&mut r, &mut samples);
+
+let mut m = MatrixF64::new(100, 100).unwrap();
+Gaussian { sigma: 1. }.sample_matrix(&mut r, &mut m);
+
+let counts: Vec<u32> = Poisson { mu: 3. }.sample_iter(&mut r).take(10).collect();
+```
+!*/
+
+use super::{
+    bernoulli, beta, binomial, cauchy, chi_squared, exponential, exponential_power, f_distribution,
+    flat, gamma, gaussian, gaussian_tail, geometric, gumbel, hypergeometric, laplace, logarithmic,
+    logistic, lognormal, negative_binomial, pareto, pascal, poisson, rayleigh, rayleigh_tail,
+    t_distribution, weibull,
+};
+use types::{Element, GslMatrixMut, GslVectorMut, Rng};
+
+/// A continuous probability distribution. See the [`randist`](crate::randist) module for the
+/// definitions of the density, cumulative distribution and quantile functions.
+pub trait ContinuousDistribution {
+    /// Returns a random variate drawn from the distribution.
+    fn sample(&self, r: &mut Rng) -> f64;
+
+    /// Fills `out` with independent random variates drawn from the distribution.
+    fn sample_into(&self, r: &mut Rng, out: &mut [f64]) {
+        for x in out {
+            *x = self.sample(r);
+        }
+    }
+
+    /// Fills the vector `v` with independent random variates drawn from the distribution.
+    fn sample_vector(&self, r: &mut Rng, v: &mut impl GslVectorMut<f64>)
+    where
+        Self: Sized,
+    {
+        fill_vector(v, || self.sample(r))
+    }
+
+    /// Fills the matrix `m` with independent random variates drawn from the distribution.
+    fn sample_matrix(&self, r: &mut Rng, m: &mut impl GslMatrixMut<f64>)
+    where
+        Self: Sized,
+    {
+        fill_matrix(m, || self.sample(r))
+    }
+
+    /// Returns an infinite iterator over random variates drawn from the distribution.
+    ///
+    /// ```no_run
+    /// use rgsl::randist::distributions::{ContinuousDistribution, Exponential};
+    /// use rgsl::{Rng, RngType};
+    ///
+    /// let mut r = Rng::new(RngType::default()).unwrap();
+    /// let total: f64 = Exponential { mu: 2. }.sample_iter(&mut r).take(1000).sum();
+    /// ```
+    fn sample_iter(self, r: &mut Rng) -> ContinuousSamples<'_, Self>
+    where
+        Self: Sized,
+    {
+        ContinuousSamples { dist: self, rng: r }
+    }
+
+    /// Returns the probability density p(x).
+    fn pdf(&self, x: f64) -> f64;
+
+    /// Returns the logarithm of the probability density p(x).
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+
+    /// Returns the lower tail cumulative distribution P(x).
+    fn cdf_p(&self, x: f64) -> f64;
+
+    /// Returns the upper tail cumulative distribution Q(x).
+    fn cdf_q(&self, x: f64) -> f64;
+
+    /// Returns x such that P(x) = `p`.
+    fn quantile_p(&self, p: f64) -> f64;
+
+    /// Returns x such that Q(x) = `q`.
+    fn quantile_q(&self, q: f64) -> f64;
+}
+
+/// A discrete probability distribution. See the [`randist`](crate::randist) module for the
+/// definitions of the probability and cumulative distribution functions.
+pub trait DiscreteDistribution {
+    /// Returns a random variate drawn from the distribution.
+    fn sample(&self, r: &mut Rng) -> u32;
+
+    /// Fills `out` with independent random variates drawn from the distribution.
+    fn sample_into(&self, r: &mut Rng, out: &mut [u32]) {
+        for k in out {
+            *k = self.sample(r);
+        }
+    }
+
+    /// Fills the vector `v` with independent random variates drawn from the distribution.
+    fn sample_vector(&self, r: &mut Rng, v: &mut impl GslVectorMut<u32>)
+    where
+        Self: Sized,
+    {
+        fill_vector(v, || self.sample(r))
+    }
+
+    /// Fills the matrix `m` with independent random variates drawn from the distribution.
+    fn sample_matrix(&self, r: &mut Rng, m: &mut impl GslMatrixMut<u32>)
+    where
+        Self: Sized,
+    {
+        fill_matrix(m, || self.sample(r))
+    }
+
+    /// Returns an infinite iterator over random variates drawn from the distribution.
+    fn sample_iter(self, r: &mut Rng) -> DiscreteSamples<'_, Self>
+    where
+        Self: Sized,
+    {
+        DiscreteSamples { dist: self, rng: r }
+    }
+
+    /// Returns the probability p(k) of obtaining `k`.
+    fn pdf(&self, k: u32) -> f64;
+
+    /// Returns the logarithm of the probability p(k) of obtaining `k`.
+    fn ln_pdf(&self, k: u32) -> f64 {
+        self.pdf(k).ln()
+    }
+
+    /// Returns the lower tail cumulative distribution P(k), the probability of obtaining a value
+    /// lower than or equal to `k`.
+    fn cdf_p(&self, k: u32) -> f64;
+
+    /// Returns the upper tail cumulative distribution Q(k), the probability of obtaining a value
+    /// greater than `k`.
+    fn cdf_q(&self, k: u32) -> f64;
+
+    /// Returns the smallest k such that P(k) >= `p`, or `u32::MAX` if there is none.
+    ///
+    /// GSL doesn't provide the inverse of discrete distributions: it is found by a search over
+    /// [`cdf_p`](Self::cdf_p).
+    fn quantile_p(&self, p: f64) -> u32 {
+        first_u32(|k| self.cdf_p(k) >= p)
+    }
+
+    /// Returns the smallest k such that Q(k) <= `q`, or `u32::MAX` if there is none.
+    ///
+    /// GSL doesn't provide the inverse of discrete distributions: it is found by a search over
+    /// [`cdf_q`](Self::cdf_q).
+    fn quantile_q(&self, q: f64) -> u32 {
+        first_u32(|k| self.cdf_q(k) <= q)
+    }
+}
+
+/// Iterator over random variates of a continuous distribution, returned by
+/// [`ContinuousDistribution::sample_iter`].
+pub struct ContinuousSamples<'r, D> {
+    dist: D,
+    rng: &'r mut Rng,
+}
+
+impl<'r, D: ContinuousDistribution> Iterator for ContinuousSamples<'r, D> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        Some(self.dist.sample(self.rng))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// Iterator over random variates of a discrete distribution, returned by
+/// [`DiscreteDistribution::sample_iter`].
+pub struct DiscreteSamples<'r, D> {
+    dist: D,
+    rng: &'r mut Rng,
+}
+
+impl<'r, D: DiscreteDistribution> Iterator for DiscreteSamples<'r, D> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        Some(self.dist.sample(self.rng))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+// Sets every element of `v` to a value returned by `f`, skipping the bound checks of `set`.
+fn fill_vector<T: Element, F: FnMut() -> T>(v: &mut impl GslVectorMut<T>, mut f: F) {
+    let (data, stride, size) = unsafe { T::vector_parts(GslVectorMut::unwrap_unique(v)) };
+    for i in 0..size {
+        unsafe { *data.add(i * stride) = f() };
+    }
+}
+
+// Sets every element of `m`, row by row, to a value returned by `f`.
+fn fill_matrix<T: Element, F: FnMut() -> T>(m: &mut impl GslMatrixMut<T>, mut f: F) {
+    let (data, size1, size2, tda) = unsafe { T::matrix_parts(GslMatrixMut::unwrap_unique(m)) };
+    for i in 0..size1 {
+        for j in 0..size2 {
+            unsafe { *data.add(i * tda + j) = f() };
+        }
+    }
+}
+
+// Returns the first `k` for which the monotonous predicate `f` is true.
+fn first_u32<F: Fn(u32) -> bool>(f: F) -> u32 {
+    let mut lo = 0;
+    let mut hi = 1;
+    if f(lo) {
+        return lo;
+    }
+    while !f(hi) {
+        if hi == u32::MAX {
+            return u32::MAX;
+        }
+        lo = hi;
+        hi = hi.saturating_mul(2);
+    }
+    // `f(lo)` is false and `f(hi)` is true.
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if f(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
+
+// Returns `x` such that `f(x) = y` for an increasing function `f`, by bisection.
+fn invert_increasing<F: Fn(f64) -> f64>(f: F, y: f64) -> f64 {
+    let (mut lo, mut hi) = (-1., 1.);
+    while f(lo) > y && lo.is_finite() {
+        lo *= 2.;
+    }
+    while f(hi) < y && hi.is_finite() {
+        hi *= 2.;
+    }
+    for _ in 0..200 {
+        let mid = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        if f(mid) < y {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+macro_rules! continuous_distribution {
+    (
+        $(#[$attr:meta])*
+        $name:ident { $($field:ident),* },
+        $sample:ident, $pdf:path, $p:path, $q:path, $pinv:path, $qinv:path
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $name {
+            $(pub $field: f64,)*
+        }
+
+        impl ContinuousDistribution for $name {
+            fn sample(&self, r: &mut Rng) -> f64 {
+                r.$sample($(self.$field),*)
+            }
+
+            fn pdf(&self, x: f64) -> f64 {
+                $pdf(x, $(self.$field),*)
+            }
+
+            fn cdf_p(&self, x: f64) -> f64 {
+                $p(x, $(self.$field),*)
+            }
+
+            fn cdf_q(&self, x: f64) -> f64 {
+                $q(x, $(self.$field),*)
+            }
+
+            fn quantile_p(&self, p: f64) -> f64 {
+                $pinv(p, $(self.$field),*)
+            }
+
+            fn quantile_q(&self, q: f64) -> f64 {
+                $qinv(q, $(self.$field),*)
+            }
+        }
+    };
+}
+
+macro_rules! discrete_distribution {
+    (
+        $(#[$attr:meta])*
+        $name:ident { $($field:ident: $ty:ty),* },
+        $sample:ident, $pdf:path, $p:path, $q:path
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $name {
+            $(pub $field: $ty,)*
+        }
+
+        impl DiscreteDistribution for $name {
+            fn sample(&self, r: &mut Rng) -> u32 {
+                r.$sample($(self.$field),*)
+            }
+
+            fn pdf(&self, k: u32) -> f64 {
+                $pdf(k, $(self.$field),*)
+            }
+
+            fn cdf_p(&self, k: u32) -> f64 {
+                $p(k, $(self.$field),*)
+            }
+
+            fn cdf_q(&self, k: u32) -> f64 {
+                $q(k, $(self.$field),*)
+            }
+        }
+    };
+}
+
+/// The Gaussian distribution with mean zero and standard deviation `sigma`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gaussian {
+    pub sigma: f64,
+}
+
+impl ContinuousDistribution for Gaussian {
+    fn sample(&self, r: &mut Rng) -> f64 {
+        r.gaussian(self.sigma)
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        gaussian::gaussian_pdf(x, self.sigma)
+    }
+
+    // Computed directly so that it stays accurate far in the tails, where the density underflows.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let u = x / self.sigma;
+        -0.5 * u * u - (self.sigma * (2. * ::std::f64::consts::PI).sqrt()).ln()
+    }
+
+    fn cdf_p(&self, x: f64) -> f64 {
+        gaussian::gaussian_P(x, self.sigma)
+    }
+
+    fn cdf_q(&self, x: f64) -> f64 {
+        gaussian::gaussian_Q(x, self.sigma)
+    }
+
+    fn quantile_p(&self, p: f64) -> f64 {
+        gaussian::gaussian_Pinv(p, self.sigma)
+    }
+
+    fn quantile_q(&self, q: f64) -> f64 {
+        gaussian::gaussian_Qinv(q, self.sigma)
+    }
+}
+
+/// The upper tail x > `a` of the Gaussian distribution with standard deviation `sigma`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GaussianTail {
+    pub a: f64,
+    pub sigma: f64,
+}
+
+impl ContinuousDistribution for GaussianTail {
+    fn sample(&self, r: &mut Rng) -> f64 {
+        r.gaussian_tail(self.a, self.sigma)
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        gaussian_tail::gaussian_tail_pdf(x, self.a, self.sigma)
+    }
+
+    // Computed from the upper tails: far from the mean, P(a) rounds to 1 and P(x) - P(a) to 0.
+    fn cdf_p(&self, x: f64) -> f64 {
+        if x <= self.a {
+            0.
+        } else {
+            let tail = gaussian::gaussian_Q(self.a, self.sigma);
+            (tail - gaussian::gaussian_Q(x, self.sigma)) / tail
+        }
+    }
+
+    fn cdf_q(&self, x: f64) -> f64 {
+        if x <= self.a {
+            1.
+        } else {
+            gaussian::gaussian_Q(x, self.sigma) / gaussian::gaussian_Q(self.a, self.sigma)
+        }
+    }
+
+    fn quantile_p(&self, p: f64) -> f64 {
+        let tail = gaussian::gaussian_Q(self.a, self.sigma);
+        gaussian::gaussian_Qinv((1. - p) * tail, self.sigma)
+    }
+
+    fn quantile_q(&self, q: f64) -> f64 {
+        let tail = gaussian::gaussian_Q(self.a, self.sigma);
+        gaussian::gaussian_Qinv(q * tail, self.sigma)
+    }
+}
+
+continuous_distribution!(
+    /// The exponential distribution with mean `mu`.
+    Exponential { mu },
+    exponential,
+    exponential::exponential_pdf,
+    exponential::exponential_P,
+    exponential::exponential_Q,
+    exponential::exponential_Pinv,
+    exponential::exponential_Qinv
+);
+
+continuous_distribution!(
+    /// The Laplace distribution with width `a`.
+    Laplace { a },
+    laplace,
+    laplace::laplace_pdf,
+    laplace::laplace_P,
+    laplace::laplace_Q,
+    laplace::laplace_Pinv,
+    laplace::laplace_Qinv
+);
+
+/// The exponential power distribution with scale parameter `a` and exponent `b`.
+///
+/// GSL doesn't provide its quantile functions: they are computed by bisection.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExponentialPower {
+    pub a: f64,
+    pub b: f64,
+}
+
+impl ContinuousDistribution for ExponentialPower {
+    fn sample(&self, r: &mut Rng) -> f64 {
+        r.exppow(self.a, self.b)
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        exponential_power::exppow_pdf(x, self.a, self.b)
+    }
+
+    fn cdf_p(&self, x: f64) -> f64 {
+        exponential_power::exppow_P(x, self.a, self.b)
+    }
+
+    fn cdf_q(&self, x: f64) -> f64 {
+        exponential_power::exppow_Q(x, self.a, self.b)
+    }
+
+    fn quantile_p(&self, p: f64) -> f64 {
+        invert_increasing(|x| self.cdf_p(x), p)
+    }
+
+    fn quantile_q(&self, q: f64) -> f64 {
+        invert_increasing(|x| -self.cdf_q(x), -q)
+    }
+}
+
+continuous_distribution!(
+    /// The Cauchy distribution with scale parameter `a`.
+    Cauchy { a },
+    cauchy,
+    cauchy::cauchy_pdf,
+    cauchy::cauchy_P,
+    cauchy::cauchy_Q,
+    cauchy::cauchy_Pinv,
+    cauchy::cauchy_Qinv
+);
+
+continuous_distribution!(
+    /// The Rayleigh distribution with scale parameter `sigma`.
+    Rayleigh { sigma },
+    rayleigh,
+    rayleigh::rayleigh_pdf,
+    rayleigh::rayleigh_P,
+    rayleigh::rayleigh_Q,
+    rayleigh::rayleigh_Pinv,
+    rayleigh::rayleigh_Qinv
+);
+
+/// The upper tail x > `a` of the Rayleigh distribution with scale parameter `sigma`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayleighTail {
+    pub a: f64,
+    pub sigma: f64,
+}
+
+impl RayleighTail {
+    // The exponent of Q(x).
+    fn exponent(&self, x: f64) -> f64 {
+        (self.a * self.a - x * x) / (2. * self.sigma * self.sigma)
+    }
+}
+
+impl ContinuousDistribution for RayleighTail {
+    fn sample(&self, r: &mut Rng) -> f64 {
+        r.rayleigh_tail(self.a, self.sigma)
+    }
+
+    fn pdf(&self, x: f64) -> f64 {
+        rayleigh_tail::rayleigh_tail_pdf(x, self.a, self.sigma)
+    }
+
+    fn cdf_p(&self, x: f64) -> f64 {
+        if x <= self.a {
+            0.
+        } else {
+            -self.exponent(x).exp_m1()
+        }
+    }
+
+    fn cdf_q(&self, x: f64) -> f64 {
+        if x <= self.a {
+            1.
+        } else {
+            self.exponent(x).exp()
+        }
+    }
+
+    fn quantile_p(&self, p: f64) -> f64 {
+        (self.a * self.a - 2. * self.sigma * self.sigma * (-p).ln_1p()).sqrt()
+    }
+
+    fn quantile_q(&self, q: f64) -> f64 {
+        (self.a * self.a - 2. * self.sigma * self.sigma * q.ln()).sqrt()
+    }
+}
+
+continuous_distribution!(
+    /// The flat (uniform) distribution from `a` to `b`.
+    Flat { a, b },
+    flat,
+    flat::flat_pdf,
+    flat::flat_P,
+    flat::flat_Q,
+    flat::flat_Pinv,
+    flat::flat_Qinv
+);
+
+continuous_distribution!(
+    /// The lognormal distribution with parameters `zeta` and `sigma`.
+    Lognormal { zeta, sigma },
+    lognormal,
+    lognormal::lognormal_pdf,
+    lognormal::lognormal_P,
+    lognormal::lognormal_Q,
+    lognormal::lognormal_Pinv,
+    lognormal::lognormal_Qinv
+);
+
+continuous_distribution!(
+    /// The chi-squared distribution with `nu` degrees of freedom.
+    ChiSquared { nu },
+    chisq,
+    chi_squared::chisq_pdf,
+    chi_squared::chisq_P,
+    chi_squared::chisq_Q,
+    chi_squared::chisq_Pinv,
+    chi_squared::chisq_Qinv
+);
+
+continuous_distribution!(
+    /// The F-distribution with `nu1` and `nu2` degrees of freedom.
+    FDistribution { nu1, nu2 },
+    fdist,
+    f_distribution::fdist_pdf,
+    f_distribution::fdist_P,
+    f_distribution::fdist_Q,
+    f_distribution::fdist_Pinv,
+    f_distribution::fdist_Qinv
+);
+
+continuous_distribution!(
+    /// Student's t-distribution with `nu` degrees of freedom.
+    TDistribution { nu },
+    tdist,
+    t_distribution::tdist_pdf,
+    t_distribution::tdist_P,
+    t_distribution::tdist_Q,
+    t_distribution::tdist_Pinv,
+    t_distribution::tdist_Qinv
+);
+
+continuous_distribution!(
+    /// The beta distribution with parameters `a` and `b`.
+    Beta { a, b },
+    beta,
+    beta::beta_pdf,
+    beta::beta_P,
+    beta::beta_Q,
+    beta::beta_Pinv,
+    beta::beta_Qinv
+);
+
+continuous_distribution!(
+    /// The logistic distribution with scale parameter `a`.
+    Logistic { a },
+    logistic,
+    logistic::logistic_pdf,
+    logistic::logistic_P,
+    logistic::logistic_Q,
+    logistic::logistic_Pinv,
+    logistic::logistic_Qinv
+);
+
+continuous_distribution!(
+    /// The Pareto distribution of order `a` and scale `b`.
+    Pareto { a, b },
+    pareto,
+    pareto::pareto_pdf,
+    pareto::pareto_P,
+    pareto::pareto_Q,
+    pareto::pareto_Pinv,
+    pareto::pareto_Qinv
+);
+
+continuous_distribution!(
+    /// The Weibull distribution with scale `a` and exponent `b`.
+    Weibull { a, b },
+    weibull,
+    weibull::weibull_pdf,
+    weibull::weibull_P,
+    weibull::weibull_Q,
+    weibull::weibull_Pinv,
+    weibull::weibull_Qinv
+);
+
+continuous_distribution!(
+    /// The Type-1 Gumbel distribution with parameters `a` and `b`.
+    Gumbel1 { a, b },
+    gumbel1,
+    gumbel::type_1::gumbel1_pdf,
+    gumbel::type_1::gumbel1_P,
+    gumbel::type_1::gumbel1_Q,
+    gumbel::type_1::gumbel1_Pinv,
+    gumbel::type_1::gumbel1_Qinv
+);
+
+continuous_distribution!(
+    /// The Type-2 Gumbel distribution with parameters `a` and `b`.
+    Gumbel2 { a, b },
+    gumbel2,
+    gumbel::type_2::gumbel2_pdf,
+    gumbel::type_2::gumbel2_P,
+    gumbel::type_2::gumbel2_Q,
+    gumbel::type_2::gumbel2_Pinv,
+    gumbel::type_2::gumbel2_Qinv
+);
+
+continuous_distribution!(
+    /// The gamma distribution with shape `a` and scale `b`.
+    Gamma { a, b },
+    gamma,
+    gamma::gamma_pdf,
+    gamma::gamma_P,
+    gamma::gamma_Q,
+    gamma::gamma_Pinv,
+    gamma::gamma_Qinv
+);
+
+/// The Bernoulli distribution: 1 with probability `p`, 0 otherwise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bernoulli {
+    pub p: f64,
+}
+
+impl DiscreteDistribution for Bernoulli {
+    fn sample(&self, r: &mut Rng) -> u32 {
+        r.bernoulli(self.p)
+    }
+
+    fn pdf(&self, k: u32) -> f64 {
+        bernoulli::bernoulli_pdf(k, self.p)
+    }
+
+    fn cdf_p(&self, k: u32) -> f64 {
+        if k == 0 {
+            1. - self.p
+        } else {
+            1.
+        }
+    }
+
+    fn cdf_q(&self, k: u32) -> f64 {
+        if k == 0 {
+            self.p
+        } else {
+            0.
+        }
+    }
+}
+
+discrete_distribution!(
+    /// The binomial distribution: the number of successes in `n` independent trials with
+    /// probability `p`.
+    Binomial { p: f64, n: u32 },
+    binomial,
+    binomial::binomial_pdf,
+    binomial::binomial_P,
+    binomial::binomial_Q
+);
+
+discrete_distribution!(
+    /// The negative binomial distribution: the number of failures before `n` successes in
+    /// independent trials with probability `p`, `n` not having to be an integer.
+    NegativeBinomial { p: f64, n: f64 },
+    negative_binomial,
+    negative_binomial::negative_binomial_pdf,
+    negative_binomial::negative_binomial_P,
+    negative_binomial::negative_binomial_Q
+);
+
+discrete_distribution!(
+    /// The Pascal distribution: the negative binomial distribution with an integer `n`.
+    Pascal { p: f64, n: u32 },
+    pascal,
+    pascal::pascal_pdf,
+    pascal::pascal_P,
+    pascal::pascal_Q
+);
+
+discrete_distribution!(
+    /// The geometric distribution: the number of independent trials with probability `p` until
+    /// the first success.
+    Geometric { p: f64 },
+    geometric,
+    geometric::geometric_pdf,
+    geometric::geometric_P,
+    geometric::geometric_Q
+);
+
+discrete_distribution!(
+    /// The hypergeometric distribution: the number of elements of type 1 in `t` samples drawn
+    /// without replacement from `n1` elements of type 1 and `n2` elements of type 2.
+    Hypergeometric { n1: u32, n2: u32, t: u32 },
+    hypergeometric,
+    hypergeometric::hypergeometric_pdf,
+    hypergeometric::hypergeometric_P,
+    hypergeometric::hypergeometric_Q
+);
+
+discrete_distribution!(
+    /// The Poisson distribution with mean `mu`.
+    Poisson { mu: f64 },
+    poisson,
+    poisson::poisson_pdf,
+    poisson::poisson_P,
+    poisson::poisson_Q
+);
+
+/// The logarithmic distribution with parameter `p`, whose values start at 1.
+///
+/// GSL doesn't provide its cumulative distribution functions: they are computed by summing the
+/// probabilities.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Logarithmic {
+    pub p: f64,
+}
+
+impl DiscreteDistribution for Logarithmic {
+    fn sample(&self, r: &mut Rng) -> u32 {
+        r.logarithmic(self.p)
+    }
+
+    fn pdf(&self, k: u32) -> f64 {
+        logarithmic::logarithmic_pdf(k, self.p)
+    }
+
+    fn cdf_p(&self, k: u32) -> f64 {
+        let mut sum = 0.;
+        for i in 1..=k {
+            let term = self.pdf(i);
+            sum += term;
+            if term < sum * f64::EPSILON {
+                break;
+            }
+        }
+        sum.min(1.)
+    }
+
+    fn cdf_q(&self, k: u32) -> f64 {
+        1. - self.cdf_p(k)
+    }
+}
+
+#[test]
+fn gaussian_tail_far_from_the_mean() {
+    let d = GaussianTail { a: 10., sigma: 1. };
+
+    assert_eq!(d.cdf_p(10.), 0.);
+    let x = 10.1;
+    let p = d.cdf_p(x);
+    assert!(p > 0.5 && p < 1., "{}", p);
+    assert!((p + d.cdf_q(x) - 1.).abs() < 1e-12);
+
+    for &p in &[0.1, 0.5, 0.9] {
+        let x = d.quantile_p(p);
+        assert!(x.is_finite() && x > d.a, "{}", x);
+        assert!((d.cdf_p(x) - p).abs() < 1e-9);
+        assert!((d.quantile_q(1. - p) - x).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn bulk_samplers() {
+    use types::{MatrixF64, RngType, VectorU32};
+
+    let mut reference = Rng::new(RngType::default()).unwrap();
+    let mut r = reference.clone();
+    let d = GaussianTail { a: 2., sigma: 1. };
+
+    let mut out = [0.; 4];
+    d.sample_into(&mut r, &mut out);
+    for &x in &out {
+        assert_eq!(x, d.sample(&mut reference));
+        assert!(x > d.a);
+    }
+
+    // Only the elements of the submatrix are drawn, row by row.
+    let mut m = MatrixF64::new(3, 3).unwrap();
+    d.sample_matrix(&mut r, &mut m.submatrix_mut(1, 1, 2, 2).unwrap());
+    for &(i, j) in &[(1, 1), (1, 2), (2, 1), (2, 2)] {
+        assert_eq!(m.get(i, j), d.sample(&mut reference));
+    }
+    assert_eq!((m.get(0, 0), m.get(2, 0)), (0., 0.));
+
+    let iter: Vec<f64> = d.sample_iter(&mut r).take(3).collect();
+    for x in iter {
+        assert_eq!(x, d.sample(&mut reference));
+    }
+
+    let p = Poisson { mu: 3. };
+    let mut v = VectorU32::from_slice(&[0; 6]).unwrap();
+    p.sample_vector(&mut r, &mut v.subvector_with_stride_mut(1, 2, 3).unwrap());
+    for i in 0..6 {
+        let expected = if i % 2 == 1 {
+            p.sample(&mut reference)
+        } else {
+            0
+        };
+        assert_eq!(v.get(i), expected);
+    }
+    let counts: Vec<u32> = p.sample_iter(&mut r).take(3).collect();
+    for k in counts {
+        assert_eq!(k, p.sample(&mut reference));
+    }
+}
+
+#[test]
+fn bulk_samplers_remaining_containers() {
+    use types::{MatrixU32, RngType, VectorF64, VectorF64ViewMut};
+
+    let mut reference = Rng::new(RngType::default()).unwrap();
+    let mut r = reference.clone();
+    let e = Exponential { mu: 2. };
+
+    let mut v = VectorF64::new(3).unwrap();
+    e.sample_vector(&mut r, &mut v);
+    for i in 0..3 {
+        assert_eq!(v.get(i), e.sample(&mut reference));
+    }
+    let mut data = [-1.; 5];
+    e.sample_vector(
+        &mut r,
+        &mut VectorF64ViewMut::from_array_with_stride(&mut data, 2),
+    );
+    for (i, &x) in data.iter().enumerate() {
+        if i % 2 == 0 {
+            assert_eq!(x, e.sample(&mut reference));
+        } else {
+            assert_eq!(x, -1.);
+        }
+    }
+    // An empty output draws nothing.
+    e.sample_into(&mut r, &mut []);
+
+    let p = Poisson { mu: 3. };
+    let mut out = [0; 4];
+    p.sample_into(&mut r, &mut out);
+    for &k in &out {
+        assert_eq!(k, p.sample(&mut reference));
+    }
+    let mut m = MatrixU32::new(2, 3).unwrap();
+    p.sample_matrix(&mut r, &mut m);
+    for i in 0..2 {
+        for j in 0..3 {
+            assert_eq!(m.get(i, j), p.sample(&mut reference));
+        }
+    }
+    assert_eq!(r.get(), reference.get());
+}
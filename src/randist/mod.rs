@@ -60,6 +60,7 @@ pub mod bivariate_gaussian;
 pub mod cauchy;
 pub mod chi_squared;
 pub mod dirichlet;
+pub mod distributions;
 pub mod exponential;
 pub mod exponential_power;
 pub mod f_distribution;
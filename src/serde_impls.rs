@@ -0,0 +1,689 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+//! `Serialize`/`Deserialize` implementations for the types wrapping GSL allocated objects, enabled
+//! by the `serde` feature. The plain data types derive them directly.
+//!
+//! Vectors are serialized as sequences and matrices as `{ rows, cols, data }` with the data in
+//! row-major order, so both can be read back into `Vec`s without this crate. Views can be
+//! serialized too and are read back as the corresponding owned type.
+
+use ffi::FFI;
+use serde::de::Error;
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::slice;
+use types::{
+    Combination, Element, GslMatrix, GslMatrixMut, GslVector, GslVectorMut, Histogram, Histogram2D,
+    MatrixComplexF32, MatrixComplexF32View, MatrixComplexF32ViewMut, MatrixComplexF64,
+    MatrixComplexF64View, MatrixComplexF64ViewMut, MatrixF32, MatrixF32View, MatrixF32ViewMut,
+    MatrixF64, MatrixF64View, MatrixF64ViewMut, MatrixI32, MatrixI32View, MatrixI32ViewMut,
+    MatrixU32, MatrixU32View, MatrixU32ViewMut, MiserParams, MultiSet, Permutation,
+    VectorComplexF32, VectorComplexF32View, VectorComplexF32ViewMut, VectorComplexF64,
+    VectorComplexF64View, VectorComplexF64ViewMut, VectorF32, VectorF32View, VectorF32ViewMut,
+    VectorF64, VectorF64View, VectorF64ViewMut, VectorI32, VectorI32View, VectorI32ViewMut,
+    VectorU32, VectorU32View, VectorU32ViewMut, VegasParams,
+};
+use Value;
+
+fn allocation_failed<E: Error>() -> E {
+    E::custom("GSL allocation failed")
+}
+
+// The arguments and contents of the subsets and histograms are checked here before they reach
+// GSL: its allocators and validity checks call the error handler, which aborts by default.
+
+// Checks that the elements of `data` are lower than `n` and sorted, strictly if `distinct`.
+fn check_subset<E: Error>(n: usize, data: &[usize], distinct: bool, what: &str) -> Result<(), E> {
+    let sorted = data
+        .windows(2)
+        .all(|w| w[0] < w[1] || (!distinct && w[0] == w[1]));
+    if sorted && data.iter().all(|&x| x < n) {
+        Ok(())
+    } else {
+        Err(E::custom(format_args!("invalid {}", what)))
+    }
+}
+
+// Checks that the limits of the bins of a histogram are in strictly increasing order.
+fn check_ranges<E: Error>(range: &[f64]) -> Result<(), E> {
+    if range.len() < 2 {
+        return Err(E::invalid_length(
+            range.len(),
+            &"at least two histogram limits",
+        ));
+    }
+    if range.windows(2).all(|w| w[0] < w[1]) {
+        Ok(())
+    } else {
+        Err(E::custom("histogram ranges must be increasing"))
+    }
+}
+
+fn serialize_vector<T, V, S>(v: &V, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Element + Serialize,
+    V: GslVector<T>,
+    S: Serializer,
+{
+    let n = GslVector::len(v);
+    let mut seq = serializer.serialize_seq(Some(n))?;
+    for i in 0..n {
+        seq.serialize_element(&GslVector::get(v, i))?;
+    }
+    seq.end()
+}
+
+fn deserialize_vector<'de, T, V, D>(
+    deserializer: D,
+    new: fn(usize) -> Option<V>,
+) -> Result<V, D::Error>
+where
+    T: Element + Deserialize<'de>,
+    V: GslVectorMut<T>,
+    D: Deserializer<'de>,
+{
+    let data = Vec::<T>::deserialize(deserializer)?;
+    let mut v = new(data.len()).ok_or_else(allocation_failed::<D::Error>)?;
+    for (i, x) in data.into_iter().enumerate() {
+        GslVectorMut::set(&mut v, i, x);
+    }
+    Ok(v)
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Matrix")]
+struct MatrixData<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+fn serialize_matrix<T, M, S>(m: &M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Element + Serialize,
+    M: GslMatrix<T>,
+    S: Serializer,
+{
+    let (rows, cols) = (m.size1(), m.size2());
+    let mut data = Vec::with_capacity(rows * cols);
+    for i in 0..rows {
+        for j in 0..cols {
+            data.push(GslMatrix::get(m, i, j));
+        }
+    }
+    MatrixData { rows, cols, data }.serialize(serializer)
+}
+
+fn deserialize_matrix<'de, T, M, D>(
+    deserializer: D,
+    new: fn(usize, usize) -> Option<M>,
+) -> Result<M, D::Error>
+where
+    T: Element + Deserialize<'de>,
+    M: GslMatrixMut<T>,
+    D: Deserializer<'de>,
+{
+    let MatrixData { rows, cols, data } = MatrixData::<T>::deserialize(deserializer)?;
+    if rows.checked_mul(cols) != Some(data.len()) {
+        return Err(D::Error::invalid_length(
+            data.len(),
+            &"rows * cols matrix elements",
+        ));
+    }
+    let mut m = new(rows, cols).ok_or_else(allocation_failed::<D::Error>)?;
+    for (k, x) in data.into_iter().enumerate() {
+        GslMatrixMut::set(&mut m, k / cols, k % cols, x);
+    }
+    Ok(m)
+}
+
+macro_rules! vector_serde {
+    ($elem:ty, $owned:ident, $view:ident, $view_mut:ident) => {
+        impl Serialize for $owned {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_vector(self, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $owned {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_vector::<$elem, _, _>(deserializer, $owned::new)
+            }
+        }
+
+        impl<'a> Serialize for $view<'a> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_vector(self, serializer)
+            }
+        }
+
+        impl<'a> Serialize for $view_mut<'a> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_vector(self, serializer)
+            }
+        }
+    };
+}
+
+macro_rules! matrix_serde {
+    ($elem:ty, $owned:ident, $view:ident, $view_mut:ident) => {
+        impl Serialize for $owned {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_matrix(self, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $owned {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_matrix::<$elem, _, _>(deserializer, $owned::new)
+            }
+        }
+
+        impl<'a> Serialize for $view<'a> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_matrix(self, serializer)
+            }
+        }
+
+        impl<'a> Serialize for $view_mut<'a> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_matrix(self, serializer)
+            }
+        }
+    };
+}
+
+vector_serde!(f32, VectorF32, VectorF32View, VectorF32ViewMut);
+vector_serde!(f64, VectorF64, VectorF64View, VectorF64ViewMut);
+vector_serde!(i32, VectorI32, VectorI32View, VectorI32ViewMut);
+vector_serde!(u32, VectorU32, VectorU32View, VectorU32ViewMut);
+vector_serde!(
+    ::ComplexF32,
+    VectorComplexF32,
+    VectorComplexF32View,
+    VectorComplexF32ViewMut
+);
+vector_serde!(
+    ::ComplexF64,
+    VectorComplexF64,
+    VectorComplexF64View,
+    VectorComplexF64ViewMut
+);
+
+matrix_serde!(f32, MatrixF32, MatrixF32View, MatrixF32ViewMut);
+matrix_serde!(f64, MatrixF64, MatrixF64View, MatrixF64ViewMut);
+matrix_serde!(i32, MatrixI32, MatrixI32View, MatrixI32ViewMut);
+matrix_serde!(u32, MatrixU32, MatrixU32View, MatrixU32ViewMut);
+matrix_serde!(
+    ::ComplexF32,
+    MatrixComplexF32,
+    MatrixComplexF32View,
+    MatrixComplexF32ViewMut
+);
+matrix_serde!(
+    ::ComplexF64,
+    MatrixComplexF64,
+    MatrixComplexF64View,
+    MatrixComplexF64ViewMut
+);
+
+/// A permutation is serialized as the sequence of its elements.
+impl Serialize for Permutation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Permutation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = Vec::<usize>::deserialize(deserializer)?;
+        if data.is_empty() {
+            return Err(D::Error::invalid_length(0, &"a non-empty permutation"));
+        }
+        let mut seen = vec![false; data.len()];
+        for &x in &data {
+            if x >= data.len() || seen[x] {
+                return Err(D::Error::custom("invalid permutation"));
+            }
+            seen[x] = true;
+        }
+        let mut p = Permutation::new(data.len()).ok_or_else(allocation_failed::<D::Error>)?;
+        p.as_mut_slice().copy_from_slice(&data);
+        Ok(p)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SubsetData<T> {
+    n: usize,
+    k: usize,
+    data: T,
+}
+
+/// A combination is serialized as `{ n, k, data }`.
+impl Serialize for Combination {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SubsetData {
+            n: self.n(),
+            k: self.k(),
+            data: self.as_slice(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Combination {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SubsetData { n, k, data } = SubsetData::<Vec<usize>>::deserialize(deserializer)?;
+        if data.len() != k {
+            return Err(D::Error::invalid_length(data.len(), &"k elements"));
+        }
+        if n == 0 || k > n {
+            return Err(D::Error::custom("a combination needs 0 < n and k <= n"));
+        }
+        check_subset(n, &data, true, "combination")?;
+        let mut c = Combination::new(n, k).ok_or_else(allocation_failed::<D::Error>)?;
+        c.as_mut_slice().copy_from_slice(&data);
+        Ok(c)
+    }
+}
+
+/// A multiset is serialized as `{ n, k, data }`.
+impl Serialize for MultiSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SubsetData {
+            n: self.n(),
+            k: self.k(),
+            data: self.data(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MultiSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SubsetData { n, k, data } = SubsetData::<Vec<usize>>::deserialize(deserializer)?;
+        if data.len() != k {
+            return Err(D::Error::invalid_length(data.len(), &"k elements"));
+        }
+        if n == 0 {
+            return Err(D::Error::custom("a multiset needs 0 < n"));
+        }
+        check_subset(n, &data, false, "multiset")?;
+        let mut m = MultiSet::new(n, k).ok_or_else(allocation_failed::<D::Error>)?;
+        m.data_mut().copy_from_slice(&data);
+        Ok(m)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Histogram")]
+struct HistogramData<T> {
+    range: T,
+    bin: T,
+}
+
+/// A histogram is serialized as `{ range, bin }`, `range` having one more element than `bin`.
+impl Serialize for Histogram {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        unsafe {
+            let h = &*self.unwrap_shared();
+            HistogramData {
+                range: slice::from_raw_parts(h.range, h.n + 1),
+                bin: slice::from_raw_parts(h.bin, h.n),
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Histogram {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let HistogramData { range, bin } = HistogramData::<Vec<f64>>::deserialize(deserializer)?;
+        if range.len() != bin.len() + 1 {
+            return Err(D::Error::invalid_length(
+                range.len(),
+                &"one range more than bins",
+            ));
+        }
+        check_ranges(&range)?;
+        let mut h = Histogram::new(bin.len()).ok_or_else(allocation_failed::<D::Error>)?;
+        if h.set_ranges(&range) != Value::Success {
+            return Err(D::Error::custom("invalid histogram ranges"));
+        }
+        unsafe {
+            let raw = &*h.unwrap_unique();
+            slice::from_raw_parts_mut(raw.bin, raw.n).copy_from_slice(&bin);
+        }
+        Ok(h)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Histogram2D")]
+struct Histogram2DData<T> {
+    xrange: T,
+    yrange: T,
+    bin: T,
+}
+
+/// A 2D histogram is serialized as `{ xrange, yrange, bin }`, with the bins in row-major order
+/// (`bin[i * ny + j]` is the bin `(i, j)`).
+impl Serialize for Histogram2D {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (nx, ny) = (self.nx(), self.ny());
+        let mut bin = Vec::with_capacity(nx * ny);
+        for i in 0..nx {
+            for j in 0..ny {
+                bin.push(self.get(i, j));
+            }
+        }
+        Histogram2DData {
+            xrange: ranges(nx, |i| self.get_xrange(i)),
+            yrange: ranges(ny, |j| self.get_yrange(j)),
+            bin,
+        }
+        .serialize(serializer)
+    }
+}
+
+// Returns the `n + 1` limits of the `n` bins whose ranges are given by `range`.
+fn ranges(n: usize, range: impl Fn(usize) -> (Value, f64, f64)) -> Vec<f64> {
+    let mut limits: Vec<f64> = (0..n).map(|i| range(i).1).collect();
+    if n > 0 {
+        limits.push(range(n - 1).2);
+    }
+    limits
+}
+
+impl<'de> Deserialize<'de> for Histogram2D {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Histogram2DData {
+            xrange,
+            yrange,
+            bin,
+        } = Histogram2DData::<Vec<f64>>::deserialize(deserializer)?;
+        check_ranges(&xrange)?;
+        check_ranges(&yrange)?;
+        let (nx, ny) = (xrange.len() - 1, yrange.len() - 1);
+        if bin.len() != nx * ny {
+            return Err(D::Error::invalid_length(bin.len(), &"nx * ny bins"));
+        }
+        let mut h = Histogram2D::new(nx, ny).ok_or_else(allocation_failed::<D::Error>)?;
+        if h.set_ranges(&xrange, &yrange) != Value::Success {
+            return Err(D::Error::custom("invalid histogram ranges"));
+        }
+        // The bins are empty after `set_ranges`, and the lower limits of a bin belong to it.
+        for (k, &weight) in bin.iter().enumerate() {
+            let (i, j) = (k / ny, k % ny);
+            if h.accumulate(xrange[i], yrange[j], weight) != Value::Success {
+                return Err(D::Error::custom("invalid histogram bins"));
+            }
+        }
+        Ok(h)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "MiserParams")]
+struct MiserParamsData {
+    estimate_frac: f64,
+    min_calls: usize,
+    min_calls_per_bisection: usize,
+    alpha: f64,
+    dither: f64,
+}
+
+impl Serialize for MiserParams {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MiserParamsData {
+            estimate_frac: self.0.estimate_frac,
+            min_calls: self.0.min_calls,
+            min_calls_per_bisection: self.0.min_calls_per_bisection,
+            alpha: self.0.alpha,
+            dither: self.0.dither,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MiserParams {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let p = MiserParamsData::deserialize(deserializer)?;
+        Ok(MiserParams(sys::gsl_monte_miser_params {
+            estimate_frac: p.estimate_frac,
+            min_calls: p.min_calls,
+            min_calls_per_bisection: p.min_calls_per_bisection,
+            alpha: p.alpha,
+            dither: p.dither,
+        }))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "VegasParams")]
+struct VegasParamsData {
+    alpha: f64,
+    iterations: usize,
+    stage: i32,
+    mode: i32,
+}
+
+/// The output stream can't be serialized: deserialized parameters have their verbosity set to
+/// `Off`.
+impl<'a> Serialize for VegasParams<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        VegasParamsData {
+            alpha: self.inner.alpha,
+            iterations: self.inner.iterations,
+            stage: self.inner.stage,
+            mode: self.inner.mode,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, 'a> Deserialize<'de> for VegasParams<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let p = VegasParamsData::deserialize(deserializer)?;
+        let mode = match p.mode {
+            sys::GSL_VEGAS_MODE_IMPORTANCE => ::VegasMode::Importance,
+            sys::GSL_VEGAS_MODE_IMPORTANCE_ONLY => ::VegasMode::ImportanceOnly,
+            sys::GSL_VEGAS_MODE_STRATIFIED => ::VegasMode::Stratified,
+            _ => return Err(D::Error::custom("unknown Vegas mode")),
+        };
+        let mut params = VegasParams::default();
+        params.inner.alpha = p.alpha;
+        params.inner.iterations = p.iterations;
+        params.inner.stage = p.stage;
+        params.inner.mode = mode.into();
+        Ok(params)
+    }
+}
+
+// Checks that `x` is serialized the same way after a round-trip, and returns the copy.
+#[cfg(test)]
+fn round_trip<T: Serialize + ::serde::de::DeserializeOwned>(x: &T) -> T {
+    let json = ::serde_json::to_string(x).unwrap();
+    let y: T = ::serde_json::from_str(&json).unwrap();
+    assert_eq!(::serde_json::to_string(&y).unwrap(), json);
+    y
+}
+
+#[test]
+fn vectors_round_trip() {
+    let v = VectorF64::from_slice(&[1., -2.5, 3.]).unwrap();
+    assert_eq!(::serde_json::to_string(&v).unwrap(), "[1.0,-2.5,3.0]");
+    assert_eq!(round_trip(&v).as_slice(), Some(&[1., -2.5, 3.][..]));
+    round_trip(&VectorF32::from_slice(&[1., 2.]).unwrap());
+    round_trip(&VectorI32::from_slice(&[-1, 2]).unwrap());
+    round_trip(&VectorU32::from_slice(&[1, 2]).unwrap());
+    round_trip(&VectorComplexF64::from_slice(&[::ComplexF64::rect(1., -1.)]).unwrap());
+    round_trip(&VectorComplexF32::from_slice(&[::ComplexF32::rect(1., -1.)]).unwrap());
+
+    // A view is read back as an owned vector.
+    let data = [1., 0., 2., 0., 3.];
+    let view = VectorF64View::from_array_with_stride(&data, 2);
+    let json = ::serde_json::to_string(&view).unwrap();
+    let v: VectorF64 = ::serde_json::from_str(&json).unwrap();
+    assert_eq!(v.as_slice(), Some(&[1., 2., 3.][..]));
+}
+
+#[test]
+fn matrices_round_trip() {
+    let m = MatrixF64View::from_array(&[1., 2., 3., 4., 5., 6.], 2, 3)
+        .to_matrix()
+        .unwrap();
+    assert_eq!(
+        ::serde_json::to_string(&m).unwrap(),
+        r#"{"rows":2,"cols":3,"data":[1.0,2.0,3.0,4.0,5.0,6.0]}"#
+    );
+    let copy = round_trip(&m);
+    assert!(copy.equal(&m));
+    round_trip(&MatrixF32::new(2, 1).unwrap());
+    round_trip(&MatrixI32::new(1, 2).unwrap());
+    round_trip(&MatrixU32::new(2, 2).unwrap());
+    round_trip(&MatrixComplexF64::new(1, 2).unwrap());
+    round_trip(&MatrixComplexF32::new(2, 1).unwrap());
+
+    let bad = r#"{"rows":2,"cols":2,"data":[1.0]}"#;
+    assert!(::serde_json::from_str::<MatrixF64>(bad).is_err());
+}
+
+#[test]
+fn subsets_round_trip() {
+    let mut p = Permutation::new(4).unwrap();
+    p.as_mut_slice().copy_from_slice(&[2, 0, 3, 1]);
+    assert_eq!(round_trip(&p).as_slice(), &[2, 0, 3, 1]);
+    assert!(::serde_json::from_str::<Permutation>("[0,0]").is_err());
+
+    let mut c = Combination::new(5, 2).unwrap();
+    c.as_mut_slice().copy_from_slice(&[1, 4]);
+    let copy = round_trip(&c);
+    assert_eq!((copy.n(), copy.as_slice()), (5, &[1, 4][..]));
+    assert!(::serde_json::from_str::<Combination>(r#"{"n":5,"k":2,"data":[4,1]}"#).is_err());
+
+    let mut m = MultiSet::new(3, 3).unwrap();
+    m.data_mut().copy_from_slice(&[0, 2, 2]);
+    let copy = round_trip(&m);
+    assert_eq!((copy.n(), copy.data()), (3, &[0, 2, 2][..]));
+}
+
+#[test]
+fn histograms_round_trip() {
+    let mut h = Histogram::new(3).unwrap();
+    h.set_ranges(&[0., 1., 2., 4.]);
+    h.accumulate(1.5, 2.);
+    h.accumulate(3., 0.5);
+    let copy = round_trip(&h);
+    assert_eq!((copy.get(0), copy.get(1), copy.get(2)), (0., 2., 0.5));
+
+    let mut h = Histogram2D::new(2, 3).unwrap();
+    h.set_ranges(&[0., 1., 3.], &[-1., 0., 1., 2.]);
+    h.accumulate(2., -1., 1.5);
+    h.accumulate(0.5, 1.5, -3.);
+    let copy = round_trip(&h);
+    assert_eq!((copy.nx(), copy.ny()), (2, 3));
+    assert_eq!(copy.get_xrange(1), (Value::Success, 1., 3.));
+    assert_eq!(copy.get_yrange(2), (Value::Success, 1., 2.));
+    assert_eq!(
+        (copy.get(1, 0), copy.get(0, 2), copy.get(1, 1)),
+        (1.5, -3., 0.)
+    );
+
+    let bad = r#"{"xrange":[0.0,1.0],"yrange":[0.0,1.0],"bin":[]}"#;
+    assert!(::serde_json::from_str::<Histogram2D>(bad).is_err());
+}
+
+#[test]
+fn monte_carlo_params_round_trip() {
+    let mut miser = MiserParams(sys::gsl_monte_miser_params {
+        estimate_frac: 0.1,
+        min_calls: 16,
+        min_calls_per_bisection: 32,
+        alpha: 2.,
+        dither: 0.,
+    });
+    miser.0.dither = 0.05;
+    assert_eq!(round_trip(&miser).0.dither, 0.05);
+
+    let mut vegas = VegasParams::default();
+    vegas.inner.alpha = 1.2;
+    vegas.inner.iterations = 7;
+    let copy = round_trip(&vegas);
+    assert_eq!((copy.inner.alpha, copy.inner.iterations), (1.2, 7));
+}
+
+#[test]
+fn plain_types_round_trip() {
+    let z = ::ComplexF64::rect(1., -2.);
+    assert!(round_trip(&z) == z);
+    let z = ::ComplexF32::rect(1., -2.);
+    assert!(round_trip(&z) == z);
+    assert_eq!(round_trip(&Value::Domain), Value::Domain);
+    let r = round_trip(&::types::Result {
+        val: 1.5,
+        err: 1e-3,
+    });
+    assert_eq!((r.val, r.err), (1.5, 1e-3));
+    let r = round_trip(&::types::ResultE10 {
+        val: 1.5,
+        err: 1e-3,
+        e10: -2,
+    });
+    assert_eq!(r.e10, -2);
+    round_trip(&::SimAnnealingParams::new(
+        200, 1000, 1., 1., 0.008, 1.003, 2e-6,
+    ));
+
+    let mut dense = ::ordinary_differential_equations::DenseOutput::new(1);
+    dense.push(0., &[1.], &[0.]);
+    dense.push(1., &[2.], &[1.]);
+    assert_eq!(round_trip(&dense), dense);
+}
+
+#[test]
+fn invalid_inputs_rejected() {
+    fn rejected<T: ::serde::de::DeserializeOwned>(json: &str) -> bool {
+        ::serde_json::from_str::<T>(json).is_err()
+    }
+
+    assert!(rejected::<MatrixF64>(
+        r#"{"rows":2,"cols":2,"data":[1.0,2.0,3.0]}"#
+    ));
+    assert!(rejected::<MatrixF64>(&format!(
+        r#"{{"rows":{},"cols":2,"data":[]}}"#,
+        usize::MAX
+    )));
+
+    assert!(rejected::<Permutation>("[]"));
+    assert!(rejected::<Permutation>("[0,0,1]"));
+    assert!(rejected::<Permutation>("[0,3,1]"));
+
+    assert!(rejected::<Combination>(r#"{"n":0,"k":0,"data":[]}"#));
+    assert!(rejected::<Combination>(r#"{"n":2,"k":3,"data":[0,1,2]}"#));
+    assert!(rejected::<Combination>(r#"{"n":4,"k":2,"data":[2,1]}"#));
+    assert!(rejected::<Combination>(r#"{"n":4,"k":2,"data":[1,1]}"#));
+    assert!(rejected::<Combination>(r#"{"n":4,"k":2,"data":[1,4]}"#));
+
+    assert!(rejected::<MultiSet>(r#"{"n":0,"k":0,"data":[]}"#));
+    assert!(rejected::<MultiSet>(r#"{"n":3,"k":2,"data":[2,1]}"#));
+    assert!(rejected::<MultiSet>(r#"{"n":3,"k":2,"data":[1,3]}"#));
+
+    assert!(rejected::<Histogram>(r#"{"range":[0.0],"bin":[]}"#));
+    assert!(rejected::<Histogram>(
+        r#"{"range":[0.0,2.0,1.0],"bin":[1.0,1.0]}"#
+    ));
+
+    assert!(rejected::<Histogram2D>(
+        r#"{"xrange":[0.0],"yrange":[0.0,1.0],"bin":[]}"#
+    ));
+    assert!(rejected::<Histogram2D>(
+        r#"{"xrange":[0.0,1.0],"yrange":[1.0],"bin":[]}"#
+    ));
+    assert!(rejected::<Histogram2D>(
+        r#"{"xrange":[0.0,1.0],"yrange":[1.0,1.0],"bin":[0.0]}"#
+    ));
+}
@@ -21,6 +21,342 @@ The Heapsort algorithm is described in the following book,
 Robert Sedgewick, Algorithms in C, Addison-Wesley, ISBN 0201514257.
 !*/
 
+use crate::Value;
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::mem;
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+
+/// Element types supported by the sorting functions: `f64`, `f32`, `i32`, `u32`, `i16`, `u16`,
+/// `u8` and, where the C `long` type is 64 bits wide, `i64` and `u64`. The functions working on
+/// vectors are available for the types which have a vector type (see
+/// [`Element`](crate::types::Element)).
+///
+/// `i8` isn't supported, as the C `char` type used by GSL is unsigned on some platforms.
+///
+/// There are no functions for sorting complex arrays or vectors, since the ordering of complex
+/// numbers is not uniquely defined. Any other type can be sorted with [`heapsort`].
+pub trait SortElement: Copy {
+    #[doc(hidden)]
+    unsafe fn sort(data: *mut Self, stride: usize, n: usize);
+    #[doc(hidden)]
+    unsafe fn sort2(data1: *mut Self, stride1: usize, data2: *mut Self, stride2: usize, n: usize);
+    #[doc(hidden)]
+    unsafe fn sort_index(p: *mut usize, data: *const Self, stride: usize, n: usize);
+    #[doc(hidden)]
+    unsafe fn smallest(
+        dest: *mut Self,
+        k: usize,
+        src: *const Self,
+        stride: usize,
+        n: usize,
+    ) -> c_int;
+    #[doc(hidden)]
+    unsafe fn largest(
+        dest: *mut Self,
+        k: usize,
+        src: *const Self,
+        stride: usize,
+        n: usize,
+    ) -> c_int;
+    #[doc(hidden)]
+    unsafe fn smallest_index(
+        p: *mut usize,
+        k: usize,
+        src: *const Self,
+        stride: usize,
+        n: usize,
+    ) -> c_int;
+    #[doc(hidden)]
+    unsafe fn largest_index(
+        p: *mut usize,
+        k: usize,
+        src: *const Self,
+        stride: usize,
+        n: usize,
+    ) -> c_int;
+}
+
+macro_rules! impl_sort_element {
+    ($ty:ty, $c_ty:ty, $sort:ident, $sort2:ident, $index:ident, $smallest:ident, $largest:ident,
+     $smallest_index:ident, $largest_index:ident) => {
+        impl SortElement for $ty {
+            unsafe fn sort(data: *mut Self, stride: usize, n: usize) {
+                sys::$sort(data as *mut $c_ty, stride, n)
+            }
+
+            unsafe fn sort2(
+                data1: *mut Self,
+                stride1: usize,
+                data2: *mut Self,
+                stride2: usize,
+                n: usize,
+            ) {
+                sys::$sort2(
+                    data1 as *mut $c_ty,
+                    stride1,
+                    data2 as *mut $c_ty,
+                    stride2,
+                    n,
+                )
+            }
+
+            unsafe fn sort_index(p: *mut usize, data: *const Self, stride: usize, n: usize) {
+                sys::$index(p, data as *const $c_ty, stride, n)
+            }
+
+            unsafe fn smallest(
+                dest: *mut Self,
+                k: usize,
+                src: *const Self,
+                stride: usize,
+                n: usize,
+            ) -> c_int {
+                sys::$smallest(dest as *mut $c_ty, k, src as *const $c_ty, stride, n)
+            }
+
+            unsafe fn largest(
+                dest: *mut Self,
+                k: usize,
+                src: *const Self,
+                stride: usize,
+                n: usize,
+            ) -> c_int {
+                sys::$largest(dest as *mut $c_ty, k, src as *const $c_ty, stride, n)
+            }
+
+            unsafe fn smallest_index(
+                p: *mut usize,
+                k: usize,
+                src: *const Self,
+                stride: usize,
+                n: usize,
+            ) -> c_int {
+                sys::$smallest_index(p, k, src as *const $c_ty, stride, n)
+            }
+
+            unsafe fn largest_index(
+                p: *mut usize,
+                k: usize,
+                src: *const Self,
+                stride: usize,
+                n: usize,
+            ) -> c_int {
+                sys::$largest_index(p, k, src as *const $c_ty, stride, n)
+            }
+        }
+    };
+}
+
+impl_sort_element!(
+    f64,
+    f64,
+    gsl_sort,
+    gsl_sort2,
+    gsl_sort_index,
+    gsl_sort_smallest,
+    gsl_sort_largest,
+    gsl_sort_smallest_index,
+    gsl_sort_largest_index
+);
+impl_sort_element!(
+    f32,
+    f32,
+    gsl_sort_float,
+    gsl_sort2_float,
+    gsl_sort_float_index,
+    gsl_sort_float_smallest,
+    gsl_sort_float_largest,
+    gsl_sort_float_smallest_index,
+    gsl_sort_float_largest_index
+);
+impl_sort_element!(
+    i32,
+    c_int,
+    gsl_sort_int,
+    gsl_sort2_int,
+    gsl_sort_int_index,
+    gsl_sort_int_smallest,
+    gsl_sort_int_largest,
+    gsl_sort_int_smallest_index,
+    gsl_sort_int_largest_index
+);
+impl_sort_element!(
+    u32,
+    std::os::raw::c_uint,
+    gsl_sort_uint,
+    gsl_sort2_uint,
+    gsl_sort_uint_index,
+    gsl_sort_uint_smallest,
+    gsl_sort_uint_largest,
+    gsl_sort_uint_smallest_index,
+    gsl_sort_uint_largest_index
+);
+impl_sort_element!(
+    i16,
+    std::os::raw::c_short,
+    gsl_sort_short,
+    gsl_sort2_short,
+    gsl_sort_short_index,
+    gsl_sort_short_smallest,
+    gsl_sort_short_largest,
+    gsl_sort_short_smallest_index,
+    gsl_sort_short_largest_index
+);
+impl_sort_element!(
+    u16,
+    std::os::raw::c_ushort,
+    gsl_sort_ushort,
+    gsl_sort2_ushort,
+    gsl_sort_ushort_index,
+    gsl_sort_ushort_smallest,
+    gsl_sort_ushort_largest,
+    gsl_sort_ushort_smallest_index,
+    gsl_sort_ushort_largest_index
+);
+impl_sort_element!(
+    u8,
+    std::os::raw::c_uchar,
+    gsl_sort_uchar,
+    gsl_sort2_uchar,
+    gsl_sort_uchar_index,
+    gsl_sort_uchar_smallest,
+    gsl_sort_uchar_largest,
+    gsl_sort_uchar_smallest_index,
+    gsl_sort_uchar_largest_index
+);
+#[cfg(all(target_pointer_width = "64", not(windows)))]
+impl_sort_element!(
+    i64,
+    std::os::raw::c_long,
+    gsl_sort_long,
+    gsl_sort2_long,
+    gsl_sort_long_index,
+    gsl_sort_long_smallest,
+    gsl_sort_long_largest,
+    gsl_sort_long_smallest_index,
+    gsl_sort_long_largest_index
+);
+#[cfg(all(target_pointer_width = "64", not(windows)))]
+impl_sort_element!(
+    u64,
+    std::os::raw::c_ulong,
+    gsl_sort_ulong,
+    gsl_sort2_ulong,
+    gsl_sort_ulong_index,
+    gsl_sort_ulong_smallest,
+    gsl_sort_ulong_largest,
+    gsl_sort_ulong_smallest_index,
+    gsl_sort_ulong_largest_index
+);
+
+// Panics if a slice of `len` elements doesn't hold `n` elements with a step of `stride`.
+fn check_strided(len: usize, stride: usize, n: usize) {
+    assert!(stride > 0, "the stride must be positive");
+    assert!(
+        n == 0 || matches!((n - 1).checked_mul(stride), Some(last) if last < len),
+        "{} elements with a stride of {} don't fit in {} elements",
+        n,
+        stride,
+        len,
+    );
+}
+
+// Returns the number of elements with a step of `stride` in a slice of `len` elements.
+fn strided_len(len: usize, stride: usize) -> usize {
+    assert!(stride > 0, "the stride must be positive");
+    if len == 0 {
+        0
+    } else {
+        (len - 1) / stride + 1
+    }
+}
+
+thread_local! {
+    // `const` initializers require Rust 1.59.
+    #[allow(clippy::missing_const_for_thread_local)]
+    static COMPARATOR: Cell<*mut c_void> = Cell::new(ptr::null_mut());
+}
+
+/// Makes `compare` the comparator used by [`compare_trampoline`] until it is dropped, restoring the
+/// previous one (if `heapsort` is called from a comparator).
+struct ComparatorGuard(*mut c_void);
+
+impl ComparatorGuard {
+    fn new<F>(compare: &mut F) -> Self {
+        let compare = compare as *mut F as *mut c_void;
+        ComparatorGuard(COMPARATOR.with(|c| c.replace(compare)))
+    }
+}
+
+impl Drop for ComparatorGuard {
+    fn drop(&mut self) {
+        COMPARATOR.with(|c| c.set(self.0));
+    }
+}
+
+unsafe extern "C" fn compare_trampoline<T, F: FnMut(&T, &T) -> Ordering>(
+    a: *const c_void,
+    b: *const c_void,
+) -> c_int {
+    let compare = &mut *(COMPARATOR.with(|c| c.get()) as *mut F);
+    match compare(&*(a as *const T), &*(b as *const T)) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+/// This function sorts the elements of `data` into ascending order, as defined by the comparison
+/// function `compare`, using the heapsort algorithm. Unlike the other functions of this module it
+/// works for any element type.
+///
+/// ```
+/// use rgsl::sort::heapsort;
+///
+/// let mut words = ["pear", "fig", "banana"];
+/// heapsort(&mut words, |a, b| a.len().cmp(&b.len()));
+/// assert_eq!(words, ["fig", "pear", "banana"]);
+/// ```
+#[doc(alias = "gsl_heapsort")]
+pub fn heapsort<T, F: FnMut(&T, &T) -> Ordering>(data: &mut [T], mut compare: F) {
+    let _guard = ComparatorGuard::new(&mut compare);
+    unsafe {
+        sys::gsl_heapsort(
+            data.as_mut_ptr() as *mut c_void,
+            data.len(),
+            mem::size_of::<T>(),
+            Some(compare_trampoline::<T, F>),
+        )
+    }
+}
+
+/// This function indirectly sorts the elements of `data` into ascending order, as defined by the
+/// comparison function `compare`, storing the resulting permutation in `p`. The elements of `p`
+/// give the index of the element which would have been stored in that position if `data` had been
+/// sorted in place. `data` is not changed, and `p` must have the same length as `data`.
+#[doc(alias = "gsl_heapsort_index")]
+pub fn heapsort_index<T, F: FnMut(&T, &T) -> Ordering>(
+    p: &mut [usize],
+    data: &[T],
+    mut compare: F,
+) -> Value {
+    if p.len() != data.len() {
+        return Value::BadLength;
+    }
+    let _guard = ComparatorGuard::new(&mut compare);
+    Value::from(unsafe {
+        sys::gsl_heapsort_index(
+            p.as_mut_ptr(),
+            data.as_ptr() as *const c_void,
+            data.len(),
+            mem::size_of::<T>(),
+            Some(compare_trampoline::<T, F>),
+        )
+    })
+}
+
 /// The following functions will sort the elements of an array or vector, either directly or indirectly. They are defined for all real and
 /// integer types using the normal suffix rules. For example, the float versions of the array functions are gsl_sort_float and gsl_sort_float_index.
 /// The corresponding vector functions are gsl_sort_vector_float and gsl_sort_vector_float_index. The prototypes are available in the header files
@@ -30,49 +366,93 @@ Robert Sedgewick, Algorithms in C, Addison-Wesley, ISBN 0201514257.
 /// vector by magnitude compute a real vector containing the magnitudes of the complex elements, and sort this vector indirectly. The resulting index
 /// gives the appropriate ordering of the original complex vector.
 pub mod vectors {
+    use super::{check_strided, SortElement};
     use crate::Value;
     use ffi::FFI;
-    use types::{Permutation, VectorF64};
+    use types::{Element, GslVector, GslVectorMut, Permutation};
 
     /// This function sorts the n elements of the array data with stride stride into ascending numerical order.
+    ///
+    /// Panics if `data` doesn't hold `n` elements with a step of `stride`.
     #[doc(alias = "gsl_sort")]
-    pub fn sort(data: &mut [f64], stride: usize, n: usize) {
-        unsafe { sys::gsl_sort(data.as_mut_ptr(), stride, n) }
+    pub fn sort<T: SortElement>(data: &mut [T], stride: usize, n: usize) {
+        check_strided(data.len(), stride, n);
+        unsafe { T::sort(data.as_mut_ptr(), stride, n) }
     }
 
     /// This function sorts the n elements of the array data1 with stride stride1 into ascending numerical order, while making the same rearrangement
     /// of the array data2 with stride stride2, also of size n.
+    ///
+    /// Panics if `data1` or `data2` doesn't hold `n` elements with a step of its stride.
     #[doc(alias = "gsl_sort2")]
-    pub fn sort2(data1: &mut [f64], stride1: usize, data2: &mut [f64], stride2: usize, n: usize) {
-        unsafe { sys::gsl_sort2(data1.as_mut_ptr(), stride1, data2.as_mut_ptr(), stride2, n) }
+    pub fn sort2<T: SortElement>(
+        data1: &mut [T],
+        stride1: usize,
+        data2: &mut [T],
+        stride2: usize,
+        n: usize,
+    ) {
+        check_strided(data1.len(), stride1, n);
+        check_strided(data2.len(), stride2, n);
+        unsafe { T::sort2(data1.as_mut_ptr(), stride1, data2.as_mut_ptr(), stride2, n) }
     }
 
     /// This function sorts the elements of the vector v into ascending numerical order.
     #[doc(alias = "gsl_sort_vector")]
-    pub fn sort_vector(v: &mut VectorF64) {
-        unsafe { sys::gsl_sort_vector(v.unwrap_unique()) }
+    pub fn sort_vector<T: SortElement + Element>(v: &mut impl GslVectorMut<T>) {
+        unsafe {
+            let (data, stride, n) = T::vector_parts(v.unwrap_unique());
+            T::sort(data, stride, n)
+        }
     }
 
     /// This function sorts the elements of the vector v1 into ascending numerical order, while making the same rearrangement of the vector v2.
+    ///
+    /// To rearrange a vector of another element type (for example integer labels attached to
+    /// floating point data), compute the permutation with [`sort_vector_index`] and apply it with
+    /// [`Permutation::permute_vector`].
+    ///
+    /// Panics if the two vectors don't have the same length.
     #[doc(alias = "gsl_sort_vector2")]
-    pub fn sort_vector2(v1: &mut VectorF64, v2: &mut VectorF64) {
-        unsafe { sys::gsl_sort_vector2(v1.unwrap_unique(), v2.unwrap_unique()) }
+    pub fn sort_vector2<T: SortElement + Element>(
+        v1: &mut impl GslVectorMut<T>,
+        v2: &mut impl GslVectorMut<T>,
+    ) {
+        unsafe {
+            let (data1, stride1, n1) = T::vector_parts(v1.unwrap_unique());
+            let (data2, stride2, n2) = T::vector_parts(v2.unwrap_unique());
+            assert_eq!(n1, n2, "vectors must have the same length");
+            T::sort2(data1, stride1, data2, stride2, n1)
+        }
     }
 
     /// This function indirectly sorts the n elements of the array data with stride stride into ascending order, storing the resulting
     /// permutation in p. The array p must be allocated with a sufficient length to store the n elements of the permutation. The elements of p
     /// give the index of the array element which would have been stored in that position if the array had been sorted in place. The array data is not changed.
+    ///
+    /// Panics if `data` doesn't hold `n` elements with a step of `stride`, or if `p` has fewer
+    /// than `n` elements.
     #[doc(alias = "gsl_sort_index")]
-    pub fn sort_index(p: &mut [usize], data: &[f64], stride: usize, n: usize) {
-        unsafe { sys::gsl_sort_index(p.as_mut_ptr(), data.as_ptr(), stride, n) }
+    pub fn sort_index<T: SortElement>(p: &mut [usize], data: &[T], stride: usize, n: usize) {
+        check_strided(data.len(), stride, n);
+        assert!(p.len() >= n, "p is too short");
+        unsafe { T::sort_index(p.as_mut_ptr(), data.as_ptr(), stride, n) }
     }
 
     /// This function indirectly sorts the elements of the vector v into ascending order, storing the resulting permutation in p. The elements of p give the
     /// index of the vector element which would have been stored in that position if the vector had been sorted in place. The first element of p gives the index
     /// of the least element in v, and the last element of p gives the index of the greatest element in v. The vector v is not changed.
     #[doc(alias = "gsl_sort_vector_index")]
-    pub fn sort_vector_index(p: &mut Permutation, v: &VectorF64) -> Value {
-        Value::from(unsafe { sys::gsl_sort_vector_index(p.unwrap_unique(), v.unwrap_shared()) })
+    pub fn sort_vector_index<T: SortElement + Element>(
+        p: &mut Permutation,
+        v: &impl GslVector<T>,
+    ) -> Value {
+        let (data, stride, n) = unsafe { T::vector_parts(v.unwrap_shared()) };
+        if p.size() != n {
+            return Value::BadLength;
+        }
+        unsafe { T::sort_index((*p.unwrap_unique()).data, data, stride, n) };
+        Value::Success
     }
 }
 
@@ -81,77 +461,310 @@ pub mod vectors {
 /// 10 largest values from one million data points, but not for selecting the largest 100,000 values. If the subset is a significant part of the total dataset
 /// it may be faster to sort all the elements of the dataset directly with an O(N \log N) algorithm and obtain the smallest or largest values that way.
 pub mod select {
+    use super::{strided_len, SortElement};
     use crate::Value;
-    use ffi::FFI;
-    use types::VectorF64;
+    use types::{Element, GslVector};
 
     /// This function copies the k smallest elements of the array src, of size n and stride stride, in ascending numerical order into the array dest. The size
     /// k of the subset must be less than or equal to n. The data src is not modified by this operation.
+    ///
+    /// The data set is made of every `stride`-th element of `src`, starting with the first one.
+    ///
+    /// Panics if `dest` has fewer than `k` elements.
     #[doc(alias = "gsl_sort_smallest")]
-    pub fn sort_smallest(dest: &mut [f64], k: usize, src: &[f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_smallest(dest.as_mut_ptr(), k, src.as_ptr(), stride, src.len() as _)
-        })
+    pub fn sort_smallest<T: SortElement>(
+        dest: &mut [T],
+        k: usize,
+        src: &[T],
+        stride: usize,
+    ) -> Value {
+        assert!(dest.len() >= k, "dest is too short");
+        let n = strided_len(src.len(), stride);
+        Value::from(unsafe { T::smallest(dest.as_mut_ptr(), k, src.as_ptr(), stride, n) })
     }
 
     /// This function copies the k largest elements of the array src, of size n and stride stride, in descending numerical order into the array dest. k must
     /// be less than or equal to n. The data src is not modified by this operation.
+    ///
+    /// The data set is made of every `stride`-th element of `src`, starting with the first one.
+    ///
+    /// Panics if `dest` has fewer than `k` elements.
     #[doc(alias = "gsl_sort_largest")]
-    pub fn sort_largest(dest: &mut [f64], k: usize, src: &[f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_largest(dest.as_mut_ptr(), k, src.as_ptr(), stride, src.len() as _)
-        })
+    pub fn sort_largest<T: SortElement>(
+        dest: &mut [T],
+        k: usize,
+        src: &[T],
+        stride: usize,
+    ) -> Value {
+        assert!(dest.len() >= k, "dest is too short");
+        let n = strided_len(src.len(), stride);
+        Value::from(unsafe { T::largest(dest.as_mut_ptr(), k, src.as_ptr(), stride, n) })
     }
 
     /// This function copies the k smallest or largest elements of the vector v into the array dest. k must be less than or equal to the length of the vector v.
+    ///
+    /// Panics if `dest` has fewer than `k` elements.
     #[doc(alias = "gsl_sort_vector_smallest")]
-    pub fn sort_vector_smallest(dest: &mut [f64], k: usize, v: &VectorF64) -> Value {
+    pub fn sort_vector_smallest<T: SortElement + Element>(
+        dest: &mut [T],
+        k: usize,
+        v: &impl GslVector<T>,
+    ) -> Value {
+        assert!(dest.len() >= k, "dest is too short");
         Value::from(unsafe {
-            sys::gsl_sort_vector_smallest(dest.as_mut_ptr(), k, v.unwrap_shared())
+            let (data, stride, n) = T::vector_parts(v.unwrap_shared());
+            T::smallest(dest.as_mut_ptr(), k, data, stride, n)
         })
     }
 
     /// This function copies the k smallest or largest elements of the vector v into the array dest. k must be less than or equal to the length of the vector v.
+    ///
+    /// Panics if `dest` has fewer than `k` elements.
     #[doc(alias = "gsl_sort_vector_largest")]
-    pub fn sort_vector_largest(dest: &mut [f64], k: usize, v: &VectorF64) -> Value {
+    pub fn sort_vector_largest<T: SortElement + Element>(
+        dest: &mut [T],
+        k: usize,
+        v: &impl GslVector<T>,
+    ) -> Value {
+        assert!(dest.len() >= k, "dest is too short");
         Value::from(unsafe {
-            sys::gsl_sort_vector_largest(dest.as_mut_ptr(), k, v.unwrap_shared())
+            let (data, stride, n) = T::vector_parts(v.unwrap_shared());
+            T::largest(dest.as_mut_ptr(), k, data, stride, n)
         })
     }
 
     /// This function stores the indices of the k smallest elements of the array src, of size n and stride stride, in the array p. The indices are chosen so that
     /// the corresponding data is in ascending numerical order. k must be less than or equal to n. The data src is not modified by this operation.
+    ///
+    /// The data set is made of every `stride`-th element of `src`, starting with the first one.
+    ///
+    /// Panics if `p` has fewer than `k` elements.
     #[doc(alias = "gsl_sort_smallest_index")]
-    pub fn sort_smallest_index(p: &mut [usize], k: usize, src: &[f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_smallest_index(p.as_mut_ptr(), k, src.as_ptr(), stride, src.len() as _)
-        })
+    pub fn sort_smallest_index<T: SortElement>(
+        p: &mut [usize],
+        k: usize,
+        src: &[T],
+        stride: usize,
+    ) -> Value {
+        assert!(p.len() >= k, "p is too short");
+        let n = strided_len(src.len(), stride);
+        Value::from(unsafe { T::smallest_index(p.as_mut_ptr(), k, src.as_ptr(), stride, n) })
     }
 
     /// This function stores the indices of the k largest elements of the array src, of size n and stride stride, in the array p. The indices are chosen so that
     /// the corresponding data is in descending numerical order. k must be less than or equal to n. The data src is not modified by this operation.
+    ///
+    /// The data set is made of every `stride`-th element of `src`, starting with the first one.
+    ///
+    /// Panics if `p` has fewer than `k` elements.
     #[doc(alias = "gsl_sort_largest_index")]
-    pub fn sort_largest_index(p: &mut [usize], k: usize, src: &[f64], stride: usize) -> Value {
-        Value::from(unsafe {
-            sys::gsl_sort_largest_index(p.as_mut_ptr(), k, src.as_ptr(), stride, src.len() as _)
-        })
+    pub fn sort_largest_index<T: SortElement>(
+        p: &mut [usize],
+        k: usize,
+        src: &[T],
+        stride: usize,
+    ) -> Value {
+        assert!(p.len() >= k, "p is too short");
+        let n = strided_len(src.len(), stride);
+        Value::from(unsafe { T::largest_index(p.as_mut_ptr(), k, src.as_ptr(), stride, n) })
     }
 
     /// This function stores the indices of the k smallest or largest elements of the vector v in the array p. k must be less than or equal to the length of
     /// the vector v.
+    ///
+    /// Panics if `p` has fewer than `k` elements.
     #[doc(alias = "gsl_sort_vector_smallest_index")]
-    pub fn sort_vector_smallest_index(p: &mut [usize], k: usize, v: &VectorF64) -> Value {
+    pub fn sort_vector_smallest_index<T: SortElement + Element>(
+        p: &mut [usize],
+        k: usize,
+        v: &impl GslVector<T>,
+    ) -> Value {
+        assert!(p.len() >= k, "p is too short");
         Value::from(unsafe {
-            sys::gsl_sort_vector_smallest_index(p.as_mut_ptr(), k, v.unwrap_shared())
+            let (data, stride, n) = T::vector_parts(v.unwrap_shared());
+            T::smallest_index(p.as_mut_ptr(), k, data, stride, n)
         })
     }
 
     /// This function stores the indices of the k smallest or largest elements of the vector v in the array p. k must be less than or equal to the length of
     /// the vector v.
+    ///
+    /// Panics if `p` has fewer than `k` elements.
     #[doc(alias = "gsl_sort_vector_largest_index")]
-    pub fn sort_vector_largest_index(p: &mut [usize], k: usize, v: &VectorF64) -> Value {
+    pub fn sort_vector_largest_index<T: SortElement + Element>(
+        p: &mut [usize],
+        k: usize,
+        v: &impl GslVector<T>,
+    ) -> Value {
+        assert!(p.len() >= k, "p is too short");
         Value::from(unsafe {
-            sys::gsl_sort_vector_largest_index(p.as_mut_ptr(), k, v.unwrap_shared())
+            let (data, stride, n) = T::vector_parts(v.unwrap_shared());
+            T::largest_index(p.as_mut_ptr(), k, data, stride, n)
         })
     }
 }
+
+#[test]
+fn sort_typed() {
+    let mut x = [3., -1., 2.5, 0.];
+    vectors::sort(&mut x, 1, 4);
+    assert_eq!(x, [-1., 0., 2.5, 3.]);
+
+    let mut x = [3f32, -1., 2.];
+    vectors::sort(&mut x, 1, 3);
+    assert_eq!(x, [-1., 2., 3.]);
+
+    let mut x = [7i32, -8, 9, 0];
+    vectors::sort(&mut x, 1, 4);
+    assert_eq!(x, [-8, 0, 7, 9]);
+
+    let mut x = [7u32, 8, 1];
+    vectors::sort(&mut x, 1, 3);
+    assert_eq!(x, [1, 7, 8]);
+
+    let mut x = [7i16, -8, 1];
+    vectors::sort(&mut x, 1, 3);
+    assert_eq!(x, [-8, 1, 7]);
+
+    let mut x = [7u16, 8, 1];
+    vectors::sort(&mut x, 1, 3);
+    assert_eq!(x, [1, 7, 8]);
+
+    let mut x = [200u8, 8, 1];
+    vectors::sort(&mut x, 1, 3);
+    assert_eq!(x, [1, 8, 200]);
+}
+
+#[test]
+fn sort_strided() {
+    // Only the elements at even indices are sorted.
+    let mut x = [5., 10., 3., 20., 4., 30.];
+    vectors::sort(&mut x, 2, 3);
+    assert_eq!(x, [3., 10., 4., 20., 5., 30.]);
+
+    let mut x = [3, 0, 1, 0, 2];
+    let mut y = [30, 10, 20];
+    vectors::sort2(&mut x, 2, &mut y, 1, 3);
+    assert_eq!(x, [1, 0, 2, 0, 3]);
+    assert_eq!(y, [10, 20, 30]);
+}
+
+#[test]
+#[should_panic(expected = "don't fit")]
+fn sort_strided_too_short() {
+    let mut x = [5., 10., 3., 20., 4.];
+    vectors::sort(&mut x, 2, 4);
+}
+
+#[test]
+fn sort_vectors() {
+    use types::{Permutation, VectorF64, VectorF64ViewMut, VectorI32};
+
+    let mut v = VectorF64::from_slice(&[3., -1., 2.]).unwrap();
+    let mut w = VectorF64::from_slice(&[30., -10., 20.]).unwrap();
+    vectors::sort_vector2(&mut v, &mut w);
+    assert_eq!(v.as_slice(), Some(&[-1., 2., 3.][..]));
+    assert_eq!(w.as_slice(), Some(&[-10., 20., 30.][..]));
+
+    let mut data = [3., 0., 1., 0., 2.];
+    vectors::sort_vector(&mut VectorF64ViewMut::from_array_with_stride(&mut data, 2));
+    assert_eq!(data, [1., 0., 2., 0., 3.]);
+
+    let mut v = VectorI32::from_slice(&[7, -8, 9]).unwrap();
+    vectors::sort_vector(&mut v);
+    assert_eq!(v.as_slice(), Some(&[-8, 7, 9][..]));
+
+    let v = VectorF64::from_slice(&[3., -1., 2.]).unwrap();
+    let mut p = Permutation::new(3).unwrap();
+    assert_eq!(vectors::sort_vector_index(&mut p, &v), Value::Success);
+    assert_eq!(p.as_slice(), &[1, 2, 0]);
+    let mut p = Permutation::new(2).unwrap();
+    assert_eq!(vectors::sort_vector_index(&mut p, &v), Value::BadLength);
+}
+
+#[test]
+fn sort_index() {
+    let data = [3., 0., -1., 0., 2.];
+    let mut p = [0; 3];
+    vectors::sort_index(&mut p, &data, 2, 3);
+    assert_eq!(p, [1, 2, 0]);
+
+    let mut p = [0; 4];
+    vectors::sort_index(&mut p, &[4u8, 1, 3, 2], 1, 4);
+    assert_eq!(p, [1, 3, 2, 0]);
+}
+
+#[test]
+#[should_panic(expected = "p is too short")]
+fn sort_index_too_short() {
+    let mut p = [0; 2];
+    vectors::sort_index(&mut p, &[3., 1., 2.], 1, 3);
+}
+
+#[test]
+fn sort_smallest_largest() {
+    use types::VectorF64View;
+
+    let src = [5., -1., 3., 8., 0.];
+    let mut dest = [0.; 2];
+    assert_eq!(select::sort_smallest(&mut dest, 2, &src, 1), Value::Success);
+    assert_eq!(dest, [-1., 0.]);
+    assert_eq!(select::sort_largest(&mut dest, 2, &src, 1), Value::Success);
+    assert_eq!(dest, [8., 5.]);
+    // The elements at even indices: 5, 3 and 0.
+    assert_eq!(select::sort_largest(&mut dest, 2, &src, 2), Value::Success);
+    assert_eq!(dest, [5., 3.]);
+
+    let mut p = [0; 2];
+    assert_eq!(
+        select::sort_smallest_index(&mut p, 2, &src, 1),
+        Value::Success
+    );
+    assert_eq!(p, [1, 4]);
+    assert_eq!(
+        select::sort_largest_index(&mut p, 2, &src, 1),
+        Value::Success
+    );
+    assert_eq!(p, [3, 0]);
+    assert_eq!(
+        select::sort_smallest_index(&mut p, 2, &src, 2),
+        Value::Success
+    );
+    assert_eq!(p, [2, 1]);
+
+    let v = VectorF64View::from_array_with_stride(&src, 2);
+    assert_eq!(
+        select::sort_vector_smallest(&mut dest, 2, &v),
+        Value::Success
+    );
+    assert_eq!(dest, [0., 3.]);
+    assert_eq!(
+        select::sort_vector_largest(&mut dest, 2, &v),
+        Value::Success
+    );
+    assert_eq!(dest, [5., 3.]);
+    assert_eq!(
+        select::sort_vector_smallest_index(&mut p, 2, &v),
+        Value::Success
+    );
+    assert_eq!(p, [2, 1]);
+    assert_eq!(
+        select::sort_vector_largest_index(&mut p, 2, &v),
+        Value::Success
+    );
+    assert_eq!(p, [0, 1]);
+
+    let mut dest = [0i32; 3];
+    assert_eq!(
+        select::sort_smallest(&mut dest, 3, &[4, -2, 7, 1], 1),
+        Value::Success
+    );
+    assert_eq!(dest, [-2, 1, 4]);
+}
+
+#[test]
+#[should_panic(expected = "dest is too short")]
+fn sort_smallest_too_short() {
+    let mut dest = [0.; 1];
+    select::sort_smallest(&mut dest, 2, &[3., 1., 2.], 1);
+}
@@ -44,7 +44,1117 @@ Review of Particle Properties R.M. Barnett et al., Physical Review D54, 1 (1996)
 The Review of Particle Physics is available online at the website http://pdg.lbl.gov/.
 !*/
 
-/// This function returns the arithmetic mean of data, a dataset of length n with stride stride. The
+use crate::paste::paste;
+#[cfg(feature = "v2_5")]
+use std::os::raw::c_int;
+use types::{Element, GslVector, GslVectorMut};
+
+/// Element types supported by the statistics functions: `f64`, `f32`, `i32`, `u32`, `i16`, `u16`,
+/// `u8` and, where the C `long` type is 64 bits wide, `i64` and `u64`. The `char` functions of GSL
+/// aren't available since the signedness of `char` depends on the platform.
+///
+/// The functions returning a mean, a variance or any other derived quantity always return an
+/// `f64`, even for integer data, whereas [`max`], [`min`] and [`select`] return the element type.
+pub trait StatsElement: Copy + Default {
+    #[doc(hidden)]
+    unsafe fn mean(data: *const Self, stride: usize, n: usize) -> f64;
+    #[doc(hidden)]
+    unsafe fn variance(data: *const Self, stride: usize, n: usize) -> f64;
+    #[doc(hidden)]
+    unsafe fn sd(data: *const Self, stride: usize, n: usize) -> f64;
+    #[doc(hidden)]
+    unsafe fn tss(data: *const Self, stride: usize, n: usize) -> f64;
+    #[doc(hidden)]
+    unsafe fn absdev(data: *const Self, stride: usize, n: usize) -> f64;
+    #[doc(hidden)]
+    unsafe fn skew(data: *const Self, stride: usize, n: usize) -> f64;
+    #[doc(hidden)]
+    unsafe fn kurtosis(data: *const Self, stride: usize, n: usize) -> f64;
+    #[doc(hidden)]
+    unsafe fn lag1_autocorrelation(data: *const Self, stride: usize, n: usize) -> f64;
+    #[doc(hidden)]
+    unsafe fn median_from_sorted_data(data: *const Self, stride: usize, n: usize) -> f64;
+    #[doc(hidden)]
+    #[cfg(feature = "v2_5")]
+    unsafe fn gastwirth_from_sorted_data(data: *const Self, stride: usize, n: usize) -> f64;
+    #[doc(hidden)]
+    unsafe fn variance_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn sd_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn tss_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn variance_with_fixed_mean(
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        mean: f64,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn sd_with_fixed_mean(data: *const Self, stride: usize, n: usize, mean: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn absdev_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn lag1_autocorrelation_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn skew_m_sd(data: *const Self, stride: usize, n: usize, mean: f64, sd: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn kurtosis_m_sd(data: *const Self, stride: usize, n: usize, mean: f64, sd: f64) -> f64;
+    #[doc(hidden)]
+    unsafe fn covariance(
+        data1: *const Self,
+        stride1: usize,
+        data2: *const Self,
+        stride2: usize,
+        n: usize,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn correlation(
+        data1: *const Self,
+        stride1: usize,
+        data2: *const Self,
+        stride2: usize,
+        n: usize,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn covariance_m(
+        data1: *const Self,
+        stride1: usize,
+        data2: *const Self,
+        stride2: usize,
+        n: usize,
+        mean1: f64,
+        mean2: f64,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn spearman(
+        data1: *const Self,
+        stride1: usize,
+        data2: *const Self,
+        stride2: usize,
+        n: usize,
+        work: *mut f64,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn pvariance(
+        data1: *const Self,
+        stride1: usize,
+        n1: usize,
+        data2: *const Self,
+        stride2: usize,
+        n2: usize,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn ttest(
+        data1: *const Self,
+        stride1: usize,
+        n1: usize,
+        data2: *const Self,
+        stride2: usize,
+        n2: usize,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn max(data: *const Self, stride: usize, n: usize) -> Self;
+    #[doc(hidden)]
+    unsafe fn min(data: *const Self, stride: usize, n: usize) -> Self;
+    #[doc(hidden)]
+    unsafe fn minmax(min: *mut Self, max: *mut Self, data: *const Self, stride: usize, n: usize);
+    #[doc(hidden)]
+    unsafe fn max_index(data: *const Self, stride: usize, n: usize) -> usize;
+    #[doc(hidden)]
+    unsafe fn min_index(data: *const Self, stride: usize, n: usize) -> usize;
+    #[doc(hidden)]
+    unsafe fn minmax_index(
+        min_index: *mut usize,
+        max_index: *mut usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+    );
+    #[doc(hidden)]
+    unsafe fn quantile_from_sorted_data(data: *const Self, stride: usize, n: usize, f: f64) -> f64;
+    #[doc(hidden)]
+    #[cfg(feature = "v2_5")]
+    unsafe fn trmean_from_sorted_data(trim: f64, data: *const Self, stride: usize, n: usize)
+        -> f64;
+    #[doc(hidden)]
+    #[cfg(feature = "v2_5")]
+    unsafe fn mad0(data: *const Self, stride: usize, n: usize, work: *mut f64) -> f64;
+    #[doc(hidden)]
+    #[cfg(feature = "v2_5")]
+    unsafe fn mad(data: *const Self, stride: usize, n: usize, work: *mut f64) -> f64;
+    #[doc(hidden)]
+    #[cfg(feature = "v2_5")]
+    unsafe fn sn0_from_sorted_data(
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        work: *mut Self,
+    ) -> Self;
+    #[doc(hidden)]
+    #[cfg(feature = "v2_5")]
+    unsafe fn sn_from_sorted_data(
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        work: *mut Self,
+    ) -> f64;
+    #[doc(hidden)]
+    #[cfg(feature = "v2_5")]
+    unsafe fn qn0_from_sorted_data(
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        work: *mut Self,
+        work_int: *mut c_int,
+    ) -> Self;
+    #[doc(hidden)]
+    #[cfg(feature = "v2_5")]
+    unsafe fn qn_from_sorted_data(
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        work: *mut Self,
+        work_int: *mut c_int,
+    ) -> f64;
+    #[doc(hidden)]
+    #[cfg(feature = "v2_5")]
+    unsafe fn median(data: *mut Self, stride: usize, n: usize) -> f64;
+    #[doc(hidden)]
+    #[cfg(feature = "v2_5")]
+    unsafe fn select(data: *mut Self, stride: usize, n: usize, k: usize) -> Self;
+}
+
+/// Element types supported by the weighted statistics functions: `f64` and `f32`.
+pub trait WeightedStatsElement: StatsElement {
+    #[doc(hidden)]
+    unsafe fn wmean(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wvariance(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wsd(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wtss(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wabsdev(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wskew(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wkurtosis(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wvariance_m(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        wmean: f64,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wsd_m(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        wmean: f64,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wtss_m(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        wmean: f64,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wabsdev_m(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        wmean: f64,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wvariance_with_fixed_mean(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        mean: f64,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wsd_with_fixed_mean(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        mean: f64,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wskew_m_sd(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        wmean: f64,
+        wsd: f64,
+    ) -> f64;
+    #[doc(hidden)]
+    unsafe fn wkurtosis_m_sd(
+        w: *const Self,
+        wstride: usize,
+        data: *const Self,
+        stride: usize,
+        n: usize,
+        wmean: f64,
+        wsd: f64,
+    ) -> f64;
+}
+
+macro_rules! impl_stats_element {
+    ($ty:ty, $prefix:ident) => {
+        paste! {
+            impl StatsElement for $ty {
+                unsafe fn mean(data: *const Self, stride: usize, n: usize) -> f64 {
+                    sys::[<$prefix _mean>](data as *const _, stride, n)
+                }
+
+                unsafe fn variance(data: *const Self, stride: usize, n: usize) -> f64 {
+                    sys::[<$prefix _variance>](data as *const _, stride, n)
+                }
+
+                unsafe fn sd(data: *const Self, stride: usize, n: usize) -> f64 {
+                    sys::[<$prefix _sd>](data as *const _, stride, n)
+                }
+
+                unsafe fn tss(data: *const Self, stride: usize, n: usize) -> f64 {
+                    sys::[<$prefix _tss>](data as *const _, stride, n)
+                }
+
+                unsafe fn absdev(data: *const Self, stride: usize, n: usize) -> f64 {
+                    sys::[<$prefix _absdev>](data as *const _, stride, n)
+                }
+
+                unsafe fn skew(data: *const Self, stride: usize, n: usize) -> f64 {
+                    sys::[<$prefix _skew>](data as *const _, stride, n)
+                }
+
+                unsafe fn kurtosis(data: *const Self, stride: usize, n: usize) -> f64 {
+                    sys::[<$prefix _kurtosis>](data as *const _, stride, n)
+                }
+
+                unsafe fn lag1_autocorrelation(data: *const Self, stride: usize, n: usize) -> f64 {
+                    sys::[<$prefix _lag1_autocorrelation>](data as *const _, stride, n)
+                }
+
+                unsafe fn median_from_sorted_data(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$prefix _median_from_sorted_data>](data as *const _, stride, n)
+                }
+
+                #[cfg(feature = "v2_5")]
+                unsafe fn gastwirth_from_sorted_data(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$prefix _gastwirth_from_sorted_data>](data as *const _, stride, n)
+                }
+
+                unsafe fn variance_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64 {
+                    sys::[<$prefix _variance_m>](data as *const _, stride, n, mean)
+                }
+
+                unsafe fn sd_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64 {
+                    sys::[<$prefix _sd_m>](data as *const _, stride, n, mean)
+                }
+
+                unsafe fn tss_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64 {
+                    sys::[<$prefix _tss_m>](data as *const _, stride, n, mean)
+                }
+
+                unsafe fn variance_with_fixed_mean(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    mean: f64,
+                ) -> f64 {
+                    sys::[<$prefix _variance_with_fixed_mean>](data as *const _, stride, n, mean)
+                }
+
+                unsafe fn sd_with_fixed_mean(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    mean: f64,
+                ) -> f64 {
+                    sys::[<$prefix _sd_with_fixed_mean>](data as *const _, stride, n, mean)
+                }
+
+                unsafe fn absdev_m(data: *const Self, stride: usize, n: usize, mean: f64) -> f64 {
+                    sys::[<$prefix _absdev_m>](data as *const _, stride, n, mean)
+                }
+
+                unsafe fn lag1_autocorrelation_m(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    mean: f64,
+                ) -> f64 {
+                    sys::[<$prefix _lag1_autocorrelation_m>](data as *const _, stride, n, mean)
+                }
+
+                unsafe fn skew_m_sd(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    mean: f64,
+                    sd: f64,
+                ) -> f64 {
+                    sys::[<$prefix _skew_m_sd>](data as *const _, stride, n, mean, sd)
+                }
+
+                unsafe fn kurtosis_m_sd(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    mean: f64,
+                    sd: f64,
+                ) -> f64 {
+                    sys::[<$prefix _kurtosis_m_sd>](data as *const _, stride, n, mean, sd)
+                }
+
+                unsafe fn covariance(
+                    data1: *const Self,
+                    stride1: usize,
+                    data2: *const Self,
+                    stride2: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$prefix _covariance>](
+                        data1 as *const _,
+                        stride1,
+                        data2 as *const _,
+                        stride2,
+                        n,
+                    )
+                }
+
+                unsafe fn correlation(
+                    data1: *const Self,
+                    stride1: usize,
+                    data2: *const Self,
+                    stride2: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$prefix _correlation>](
+                        data1 as *const _,
+                        stride1,
+                        data2 as *const _,
+                        stride2,
+                        n,
+                    )
+                }
+
+                unsafe fn covariance_m(
+                    data1: *const Self,
+                    stride1: usize,
+                    data2: *const Self,
+                    stride2: usize,
+                    n: usize,
+                    mean1: f64,
+                    mean2: f64,
+                ) -> f64 {
+                    sys::[<$prefix _covariance_m>](
+                        data1 as *const _,
+                        stride1,
+                        data2 as *const _,
+                        stride2,
+                        n,
+                        mean1,
+                        mean2,
+                    )
+                }
+
+                unsafe fn spearman(
+                    data1: *const Self,
+                    stride1: usize,
+                    data2: *const Self,
+                    stride2: usize,
+                    n: usize,
+                    work: *mut f64,
+                ) -> f64 {
+                    sys::[<$prefix _spearman>](
+                        data1 as *const _,
+                        stride1,
+                        data2 as *const _,
+                        stride2,
+                        n,
+                        work,
+                    )
+                }
+
+                unsafe fn pvariance(
+                    data1: *const Self,
+                    stride1: usize,
+                    n1: usize,
+                    data2: *const Self,
+                    stride2: usize,
+                    n2: usize,
+                ) -> f64 {
+                    sys::[<$prefix _pvariance>](
+                        data1 as *const _,
+                        stride1,
+                        n1,
+                        data2 as *const _,
+                        stride2,
+                        n2,
+                    )
+                }
+
+                unsafe fn ttest(
+                    data1: *const Self,
+                    stride1: usize,
+                    n1: usize,
+                    data2: *const Self,
+                    stride2: usize,
+                    n2: usize,
+                ) -> f64 {
+                    sys::[<$prefix _ttest>](
+                        data1 as *const _,
+                        stride1,
+                        n1,
+                        data2 as *const _,
+                        stride2,
+                        n2,
+                    )
+                }
+
+                unsafe fn max(data: *const Self, stride: usize, n: usize) -> Self {
+                    sys::[<$prefix _max>](data as *const _, stride, n) as Self
+                }
+
+                unsafe fn min(data: *const Self, stride: usize, n: usize) -> Self {
+                    sys::[<$prefix _min>](data as *const _, stride, n) as Self
+                }
+
+                unsafe fn minmax(
+                    min: *mut Self,
+                    max: *mut Self,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) {
+                    sys::[<$prefix _minmax>](
+                        min as *mut _,
+                        max as *mut _,
+                        data as *const _,
+                        stride,
+                        n,
+                    )
+                }
+
+                unsafe fn max_index(data: *const Self, stride: usize, n: usize) -> usize {
+                    sys::[<$prefix _max_index>](data as *const _, stride, n)
+                }
+
+                unsafe fn min_index(data: *const Self, stride: usize, n: usize) -> usize {
+                    sys::[<$prefix _min_index>](data as *const _, stride, n)
+                }
+
+                unsafe fn minmax_index(
+                    min_index: *mut usize,
+                    max_index: *mut usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) {
+                    sys::[<$prefix _minmax_index>](
+                        min_index,
+                        max_index,
+                        data as *const _,
+                        stride,
+                        n,
+                    )
+                }
+
+                unsafe fn quantile_from_sorted_data(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    f: f64,
+                ) -> f64 {
+                    sys::[<$prefix _quantile_from_sorted_data>](data as *const _, stride, n, f)
+                }
+
+                #[cfg(feature = "v2_5")]
+                unsafe fn trmean_from_sorted_data(
+                    trim: f64,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$prefix _trmean_from_sorted_data>](trim, data as *const _, stride, n)
+                }
+
+                #[cfg(feature = "v2_5")]
+                unsafe fn mad0(data: *const Self, stride: usize, n: usize, work: *mut f64) -> f64 {
+                    sys::[<$prefix _mad0>](data as *const _, stride, n, work)
+                }
+
+                #[cfg(feature = "v2_5")]
+                unsafe fn mad(data: *const Self, stride: usize, n: usize, work: *mut f64) -> f64 {
+                    sys::[<$prefix _mad>](data as *const _, stride, n, work)
+                }
+
+                #[cfg(feature = "v2_5")]
+                unsafe fn sn0_from_sorted_data(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    work: *mut Self,
+                ) -> Self {
+                    sys::[<$prefix _Sn0_from_sorted_data>](
+                        data as *const _,
+                        stride,
+                        n,
+                        work as *mut _,
+                    ) as Self
+                }
+
+                #[cfg(feature = "v2_5")]
+                unsafe fn sn_from_sorted_data(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    work: *mut Self,
+                ) -> f64 {
+                    sys::[<$prefix _Sn_from_sorted_data>](
+                        data as *const _,
+                        stride,
+                        n,
+                        work as *mut _,
+                    )
+                }
+
+                #[cfg(feature = "v2_5")]
+                unsafe fn qn0_from_sorted_data(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    work: *mut Self,
+                    work_int: *mut c_int,
+                ) -> Self {
+                    sys::[<$prefix _Qn0_from_sorted_data>](
+                        data as *const _,
+                        stride,
+                        n,
+                        work as *mut _,
+                        work_int,
+                    ) as Self
+                }
+
+                #[cfg(feature = "v2_5")]
+                unsafe fn qn_from_sorted_data(
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    work: *mut Self,
+                    work_int: *mut c_int,
+                ) -> f64 {
+                    sys::[<$prefix _Qn_from_sorted_data>](
+                        data as *const _,
+                        stride,
+                        n,
+                        work as *mut _,
+                        work_int,
+                    )
+                }
+
+                #[cfg(feature = "v2_5")]
+                unsafe fn median(data: *mut Self, stride: usize, n: usize) -> f64 {
+                    sys::[<$prefix _median>](data as *mut _, stride, n)
+                }
+
+                #[cfg(feature = "v2_5")]
+                unsafe fn select(data: *mut Self, stride: usize, n: usize, k: usize) -> Self {
+                    sys::[<$prefix _select>](data as *mut _, stride, n, k) as Self
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_weighted_stats_element {
+    ($ty:ty, $prefix:ident) => {
+        paste! {
+            impl WeightedStatsElement for $ty {
+                unsafe fn wmean(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$prefix _wmean>](w as *const _, wstride, data as *const _, stride, n)
+                }
+
+                unsafe fn wvariance(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$prefix _wvariance>](w as *const _, wstride, data as *const _, stride, n)
+                }
+
+                unsafe fn wsd(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$prefix _wsd>](w as *const _, wstride, data as *const _, stride, n)
+                }
+
+                unsafe fn wtss(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$prefix _wtss>](w as *const _, wstride, data as *const _, stride, n)
+                }
+
+                unsafe fn wabsdev(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$prefix _wabsdev>](w as *const _, wstride, data as *const _, stride, n)
+                }
+
+                unsafe fn wskew(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$prefix _wskew>](w as *const _, wstride, data as *const _, stride, n)
+                }
+
+                unsafe fn wkurtosis(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                ) -> f64 {
+                    sys::[<$prefix _wkurtosis>](w as *const _, wstride, data as *const _, stride, n)
+                }
+
+                unsafe fn wvariance_m(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    wmean: f64,
+                ) -> f64 {
+                    sys::[<$prefix _wvariance_m>](
+                        w as *const _,
+                        wstride,
+                        data as *const _,
+                        stride,
+                        n,
+                        wmean,
+                    )
+                }
+
+                unsafe fn wsd_m(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    wmean: f64,
+                ) -> f64 {
+                    sys::[<$prefix _wsd_m>](
+                        w as *const _,
+                        wstride,
+                        data as *const _,
+                        stride,
+                        n,
+                        wmean,
+                    )
+                }
+
+                unsafe fn wtss_m(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    wmean: f64,
+                ) -> f64 {
+                    sys::[<$prefix _wtss_m>](
+                        w as *const _,
+                        wstride,
+                        data as *const _,
+                        stride,
+                        n,
+                        wmean,
+                    )
+                }
+
+                unsafe fn wabsdev_m(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    wmean: f64,
+                ) -> f64 {
+                    sys::[<$prefix _wabsdev_m>](
+                        w as *const _,
+                        wstride,
+                        data as *const _,
+                        stride,
+                        n,
+                        wmean,
+                    )
+                }
+
+                unsafe fn wvariance_with_fixed_mean(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    mean: f64,
+                ) -> f64 {
+                    sys::[<$prefix _wvariance_with_fixed_mean>](
+                        w as *const _,
+                        wstride,
+                        data as *const _,
+                        stride,
+                        n,
+                        mean,
+                    )
+                }
+
+                unsafe fn wsd_with_fixed_mean(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    mean: f64,
+                ) -> f64 {
+                    sys::[<$prefix _wsd_with_fixed_mean>](
+                        w as *const _,
+                        wstride,
+                        data as *const _,
+                        stride,
+                        n,
+                        mean,
+                    )
+                }
+
+                unsafe fn wskew_m_sd(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    wmean: f64,
+                    wsd: f64,
+                ) -> f64 {
+                    sys::[<$prefix _wskew_m_sd>](
+                        w as *const _,
+                        wstride,
+                        data as *const _,
+                        stride,
+                        n,
+                        wmean,
+                        wsd,
+                    )
+                }
+
+                unsafe fn wkurtosis_m_sd(
+                    w: *const Self,
+                    wstride: usize,
+                    data: *const Self,
+                    stride: usize,
+                    n: usize,
+                    wmean: f64,
+                    wsd: f64,
+                ) -> f64 {
+                    sys::[<$prefix _wkurtosis_m_sd>](
+                        w as *const _,
+                        wstride,
+                        data as *const _,
+                        stride,
+                        n,
+                        wmean,
+                        wsd,
+                    )
+                }
+            }
+        }
+    };
+}
+
+impl_stats_element!(f64, gsl_stats);
+impl_stats_element!(f32, gsl_stats_float);
+impl_stats_element!(i32, gsl_stats_int);
+impl_stats_element!(u32, gsl_stats_uint);
+impl_stats_element!(i16, gsl_stats_short);
+impl_stats_element!(u16, gsl_stats_ushort);
+impl_stats_element!(u8, gsl_stats_uchar);
+#[cfg(all(target_pointer_width = "64", not(windows)))]
+impl_stats_element!(i64, gsl_stats_long);
+#[cfg(all(target_pointer_width = "64", not(windows)))]
+impl_stats_element!(u64, gsl_stats_ulong);
+
+impl_weighted_stats_element!(f64, gsl_stats);
+impl_weighted_stats_element!(f32, gsl_stats_float);
+
+/// A dataset the statistics functions can work on: slices, arrays, `Vec`s, every GSL vector and
+/// vector view, and [`Strided`] slices.
+///
+/// This trait is sealed: the statistics functions give the pointer, stride and length it returns
+/// directly to GSL, so they must describe valid memory.
+pub trait Dataset<T>: private::Sealed<T> {
+    #[doc(hidden)]
+    fn parts(&self) -> (*const T, usize, usize);
+}
+
+/// A dataset which can be rearranged in place, as needed by [`median`] and [`select`].
+pub trait DatasetMut<T>: Dataset<T> + private::SealedMut<T> {
+    #[doc(hidden)]
+    fn parts_mut(&mut self) -> (*mut T, usize, usize);
+}
+
+mod private {
+    use super::{Strided, StridedMut};
+    use types::{Element, GslVector, GslVectorMut};
+
+    pub trait Sealed<T> {}
+    pub trait SealedMut<T> {}
+
+    impl<T> Sealed<T> for [T] {}
+    impl<T, const N: usize> Sealed<T> for [T; N] {}
+    impl<T> Sealed<T> for Vec<T> {}
+    impl<T: Element, V: GslVector<T>> Sealed<T> for V {}
+    impl<'a, T> Sealed<T> for Strided<'a, T> {}
+    impl<'a, T> Sealed<T> for StridedMut<'a, T> {}
+
+    impl<T> SealedMut<T> for [T] {}
+    impl<T, const N: usize> SealedMut<T> for [T; N] {}
+    impl<T> SealedMut<T> for Vec<T> {}
+    impl<T: Element, V: GslVectorMut<T>> SealedMut<T> for V {}
+    impl<'a, T> SealedMut<T> for StridedMut<'a, T> {}
+}
+
+impl<T> Dataset<T> for [T] {
+    fn parts(&self) -> (*const T, usize, usize) {
+        (self.as_ptr(), 1, self.len())
+    }
+}
+
+impl<T> DatasetMut<T> for [T] {
+    fn parts_mut(&mut self) -> (*mut T, usize, usize) {
+        (self.as_mut_ptr(), 1, self.len())
+    }
+}
+
+impl<T, const N: usize> Dataset<T> for [T; N] {
+    fn parts(&self) -> (*const T, usize, usize) {
+        (self.as_ptr(), 1, N)
+    }
+}
+
+impl<T, const N: usize> DatasetMut<T> for [T; N] {
+    fn parts_mut(&mut self) -> (*mut T, usize, usize) {
+        (self.as_mut_ptr(), 1, N)
+    }
+}
+
+impl<T> Dataset<T> for Vec<T> {
+    fn parts(&self) -> (*const T, usize, usize) {
+        (self.as_ptr(), 1, self.len())
+    }
+}
+
+impl<T> DatasetMut<T> for Vec<T> {
+    fn parts_mut(&mut self) -> (*mut T, usize, usize) {
+        (self.as_mut_ptr(), 1, self.len())
+    }
+}
+
+impl<T: Element, V: GslVector<T>> Dataset<T> for V {
+    fn parts(&self) -> (*const T, usize, usize) {
+        let (data, stride, n) = unsafe { T::vector_parts(self.unwrap_shared()) };
+        (data, stride, n)
+    }
+}
+
+impl<T: Element, V: GslVectorMut<T>> DatasetMut<T> for V {
+    fn parts_mut(&mut self) -> (*mut T, usize, usize) {
+        unsafe { T::vector_parts(self.unwrap_unique()) }
+    }
+}
+
+/// Every `stride`-th element of a slice, starting with the first one.
+///
+/// ```
+/// use rgsl::statistics::{mean, Strided};
+///
+/// let data = [1., 10., 2., 20., 3., 30.];
+/// assert_eq!(mean(&Strided::new(&data, 2)), 2.);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Strided<'a, T> {
+    data: &'a [T],
+    stride: usize,
+}
+
+impl<'a, T> Strided<'a, T> {
+    /// Panics if `stride` is 0.
+    pub fn new(data: &'a [T], stride: usize) -> Self {
+        assert!(stride > 0, "stride must be positive");
+        Self { data, stride }
+    }
+
+    /// Returns the number of elements of the dataset.
+    pub fn len(&self) -> usize {
+        strided_len(self.data.len(), self.stride)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl<'a, T> Dataset<T> for Strided<'a, T> {
+    fn parts(&self) -> (*const T, usize, usize) {
+        let n = strided_len(self.data.len(), self.stride);
+        (self.data.as_ptr(), self.stride, n)
+    }
+}
+
+/// Mutable counterpart of [`Strided`].
+#[derive(Debug)]
+pub struct StridedMut<'a, T> {
+    data: &'a mut [T],
+    stride: usize,
+}
+
+impl<'a, T> StridedMut<'a, T> {
+    /// Panics if `stride` is 0.
+    pub fn new(data: &'a mut [T], stride: usize) -> Self {
+        assert!(stride > 0, "stride must be positive");
+        Self { data, stride }
+    }
+
+    /// Returns the number of elements of the dataset.
+    pub fn len(&self) -> usize {
+        strided_len(self.data.len(), self.stride)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl<'a, T> Dataset<T> for StridedMut<'a, T> {
+    fn parts(&self) -> (*const T, usize, usize) {
+        let n = strided_len(self.data.len(), self.stride);
+        (self.data.as_ptr(), self.stride, n)
+    }
+}
+
+impl<'a, T> DatasetMut<T> for StridedMut<'a, T> {
+    fn parts_mut(&mut self) -> (*mut T, usize, usize) {
+        let n = strided_len(self.data.len(), self.stride);
+        (self.data.as_mut_ptr(), self.stride, n)
+    }
+}
+
+fn strided_len(len: usize, stride: usize) -> usize {
+    if len == 0 {
+        0
+    } else {
+        (len - 1) / stride + 1
+    }
+}
+
+fn same_len<T>(data1: &(impl Dataset<T> + ?Sized), data2: &(impl Dataset<T> + ?Sized)) -> usize {
+    let n1 = data1.parts().2;
+    let n2 = data2.parts().2;
+    assert_eq!(n1, n2, "datasets must have the same length");
+    n1
+}
+
+/// This function returns the arithmetic mean of data. The
 /// arithmetic mean, or sample mean, is denoted by \Hat\mu and defined as,
 ///
 /// \Hat\mu = (1/N) \sum x_i
@@ -52,12 +1162,13 @@ The Review of Particle Physics is available online at the website http://pdg.lbl
 /// where x_i are the elements of the dataset data. For samples drawn from a gaussian distribution
 /// the variance of \Hat\mu is \sigma^2 / N.
 #[doc(alias = "gsl_stats_mean")]
-pub fn mean(data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_mean(data.as_ptr(), stride, n) }
+pub fn mean<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::mean(data, stride, n) }
 }
 
-/// This function returns the estimated, or sample, variance of data, a dataset of length n with
-/// stride stride. The estimated variance is denoted by \Hat\sigma^2 and is defined by,
+/// This function returns the estimated, or sample, variance of data. The estimated variance is
+/// denoted by \Hat\sigma^2 and is defined by,
 ///
 /// \Hat\sigma^2 = (1/(N-1)) \sum (x_i - \Hat\mu)^2
 ///
@@ -69,8 +1180,9 @@ pub fn mean(data: &[f64], stride: usize, n: usize) -> f64 {
 /// This function computes the mean via a call to gsl_stats_mean. If you have already computed the
 /// mean then you can pass it directly to gsl_stats_variance_m.
 #[doc(alias = "gsl_stats_variance")]
-pub fn variance(data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_variance(data.as_ptr(), stride, n) }
+pub fn variance<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::variance(data, stride, n) }
 }
 
 /// This function returns the sample variance of data relative to the given value of mean. The
@@ -78,22 +1190,25 @@ pub fn variance(data: &[f64], stride: usize, n: usize) -> f64 {
 ///
 /// \Hat\sigma^2 = (1/(N-1)) \sum (x_i - mean)^2
 #[doc(alias = "gsl_stats_variance_m")]
-pub fn variance_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
-    unsafe { sys::gsl_stats_variance_m(data.as_ptr(), stride, n, mean) }
+pub fn variance_m<T: StatsElement>(data: &(impl Dataset<T> + ?Sized), mean: f64) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::variance_m(data, stride, n, mean) }
 }
 
 /// The standard deviation is defined as the square root of the variance. This function returns the
 /// square root of the corresponding variance functions above.
 #[doc(alias = "gsl_stats_sd")]
-pub fn sd(data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_sd(data.as_ptr(), stride, n) }
+pub fn sd<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::sd(data, stride, n) }
 }
 
 /// The standard deviation is defined as the square root of the variance. This function returns the
 /// square root of the corresponding variance functions above.
 #[doc(alias = "gsl_stats_sd_m")]
-pub fn sd_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
-    unsafe { sys::gsl_stats_sd_m(data.as_ptr(), stride, n, mean) }
+pub fn sd_m<T: StatsElement>(data: &(impl Dataset<T> + ?Sized), mean: f64) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::sd_m(data, stride, n, mean) }
 }
 
 /// This function returns the total sum of squares (TSS) of data about the mean. For gsl_stats_tss_m
@@ -102,8 +1217,9 @@ pub fn sd_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
 ///
 /// TSS =  \sum (x_i - mean)^2
 #[doc(alias = "gsl_stats_tss")]
-pub fn tss(data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_tss(data.as_ptr(), stride, n) }
+pub fn tss<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::tss(data, stride, n) }
 }
 
 /// This function returns the total sum of squares (TSS) of data about the mean. For gsl_stats_tss_m
@@ -112,8 +1228,9 @@ pub fn tss(data: &[f64], stride: usize, n: usize) -> f64 {
 ///
 /// TSS =  \sum (x_i - mean)^2
 #[doc(alias = "gsl_stats_tss_m")]
-pub fn tss_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
-    unsafe { sys::gsl_stats_tss_m(data.as_ptr(), stride, n, mean) }
+pub fn tss_m<T: StatsElement>(data: &(impl Dataset<T> + ?Sized), mean: f64) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::tss_m(data, stride, n, mean) }
 }
 
 /// This function computes an unbiased estimate of the variance of data when the population mean
@@ -123,19 +1240,24 @@ pub fn tss_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
 ///
 /// \Hat\sigma^2 = (1/N) \sum (x_i - \mu)^2
 #[doc(alias = "gsl_stats_variance_with_fixed_mean")]
-pub fn variance_with_fixed_mean(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
-    unsafe { sys::gsl_stats_variance_with_fixed_mean(data.as_ptr(), stride, n, mean) }
+pub fn variance_with_fixed_mean<T: StatsElement>(
+    data: &(impl Dataset<T> + ?Sized),
+    mean: f64,
+) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::variance_with_fixed_mean(data, stride, n, mean) }
 }
 
 /// This function calculates the standard deviation of data for a fixed population mean mean. The
 /// result is the square root of the corresponding variance function.
 #[doc(alias = "gsl_stats_sd_with_fixed_mean")]
-pub fn sd_with_fixed_mean(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
-    unsafe { sys::gsl_stats_sd_with_fixed_mean(data.as_ptr(), stride, n, mean) }
+pub fn sd_with_fixed_mean<T: StatsElement>(data: &(impl Dataset<T> + ?Sized), mean: f64) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::sd_with_fixed_mean(data, stride, n, mean) }
 }
 
-/// This function computes the absolute deviation from the mean of data, a dataset of length n with
-/// stride stride. The absolute deviation from the mean is defined as,
+/// This function computes the absolute deviation from the mean of data. The absolute deviation from
+/// the mean is defined as,
 ///
 /// absdev  = (1/N) \sum |x_i - \Hat\mu|
 ///
@@ -143,8 +1265,9 @@ pub fn sd_with_fixed_mean(data: &[f64], stride: usize, n: usize, mean: f64) -> f
 /// more robust measure of the width of a distribution than the variance. This function computes the
 /// mean of data via a call to gsl_stats_mean.
 #[doc(alias = "gsl_stats_absdev")]
-pub fn absdev(data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_absdev(data.as_ptr(), stride, n) }
+pub fn absdev<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::absdev(data, stride, n) }
 }
 
 /// This function computes the absolute deviation of the dataset data relative to the given value of
@@ -156,11 +1279,12 @@ pub fn absdev(data: &[f64], stride: usize, n: usize) -> f64 {
 /// recomputing it), or wish to calculate the absolute deviation relative to another value (such as
 /// zero, or the median).
 #[doc(alias = "gsl_stats_absdev_m")]
-pub fn absdev_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
-    unsafe { sys::gsl_stats_absdev_m(data.as_ptr(), stride, n, mean) }
+pub fn absdev_m<T: StatsElement>(data: &(impl Dataset<T> + ?Sized), mean: f64) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::absdev_m(data, stride, n, mean) }
 }
 
-/// This function computes the skewness of data, a dataset of length n with stride stride. The
+/// This function computes the skewness of data. The
 /// skewness is defined as,
 ///
 /// skew = (1/N) \sum ((x_i - \Hat\mu)/\Hat\sigma)^3
@@ -171,8 +1295,9 @@ pub fn absdev_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
 /// The function computes the mean and estimated standard deviation of data via calls to [`mean`]
 /// and [`sd`].
 #[doc(alias = "gsl_stats_skew")]
-pub fn skew(data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_skew(data.as_ptr(), stride, n) }
+pub fn skew<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::skew(data, stride, n) }
 }
 
 /// This function computes the skewness of the dataset data using the given values of the mean mean
@@ -183,11 +1308,12 @@ pub fn skew(data: &[f64], stride: usize, n: usize) -> f64 {
 /// These functions are useful if you have already computed the mean and standard deviation of data
 /// and want to avoid recomputing them.
 #[doc(alias = "gsl_stats_skew_m_sd")]
-pub fn skew_m_sd(data: &[f64], stride: usize, n: usize, mean: f64, sd: f64) -> f64 {
-    unsafe { sys::gsl_stats_skew_m_sd(data.as_ptr(), stride, n, mean, sd) }
+pub fn skew_m_sd<T: StatsElement>(data: &(impl Dataset<T> + ?Sized), mean: f64, sd: f64) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::skew_m_sd(data, stride, n, mean, sd) }
 }
 
-/// This function computes the kurtosis of data, a dataset of length n with stride stride. The
+/// This function computes the kurtosis of data. The
 /// kurtosis is defined as,
 ///
 /// kurtosis = ((1/N) \sum ((x_i - \Hat\mu)/\Hat\sigma)^4)  - 3
@@ -195,8 +1321,9 @@ pub fn skew_m_sd(data: &[f64], stride: usize, n: usize, mean: f64, sd: f64) -> f
 /// The kurtosis measures how sharply peaked a distribution is, relative to its width. The kurtosis
 /// is normalized to zero for a Gaussian distribution.
 #[doc(alias = "gsl_stats_kurtosis")]
-pub fn kurtosis(data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_kurtosis(data.as_ptr(), stride, n) }
+pub fn kurtosis<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::kurtosis(data, stride, n) }
 }
 
 /// This function computes the kurtosis of the dataset data using the given values of the mean mean
@@ -207,8 +1334,13 @@ pub fn kurtosis(data: &[f64], stride: usize, n: usize) -> f64 {
 /// This function is useful if you have already computed the mean and standard deviation of data and
 /// want to avoid recomputing them.
 #[doc(alias = "gsl_stats_kurtosis_m_sd")]
-pub fn kurtosis_m_sd(data: &[f64], stride: usize, n: usize, mean: f64, sd: f64) -> f64 {
-    unsafe { sys::gsl_stats_kurtosis_m_sd(data.as_ptr(), stride, n, mean, sd) }
+pub fn kurtosis_m_sd<T: StatsElement>(
+    data: &(impl Dataset<T> + ?Sized),
+    mean: f64,
+    sd: f64,
+) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::kurtosis_m_sd(data, stride, n, mean, sd) }
 }
 
 /// This function computes the lag-1 autocorrelation of the dataset data.
@@ -217,54 +1349,55 @@ pub fn kurtosis_m_sd(data: &[f64], stride: usize, n: usize, mean: f64, sd: f64)
 ///        \over
 ///        \sum_{i = 1}^{n} (x_{i} - \Hat\mu) (x_{i} - \Hat\mu)}
 #[doc(alias = "gsl_stats_lag1_autocorrelation")]
-pub fn lag1_autocorrelation(data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_lag1_autocorrelation(data.as_ptr(), stride, n) }
+pub fn lag1_autocorrelation<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::lag1_autocorrelation(data, stride, n) }
 }
 
 /// This function computes the lag-1 autocorrelation of the dataset data using the given value of
 /// the mean mean.
 #[doc(alias = "gsl_stats_lag1_autocorrelation_m")]
-pub fn lag1_autocorrelation_m(data: &[f64], stride: usize, n: usize, mean: f64) -> f64 {
-    unsafe { sys::gsl_stats_lag1_autocorrelation_m(data.as_ptr(), stride, n, mean) }
+pub fn lag1_autocorrelation_m<T: StatsElement>(
+    data: &(impl Dataset<T> + ?Sized),
+    mean: f64,
+) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::lag1_autocorrelation_m(data, stride, n, mean) }
 }
 
 /// This function computes the covariance of the datasets data1 and data2 which must both be of the
-/// same length n.
+/// same length.
 ///
 /// covar = (1/(n - 1)) \sum_{i = 1}^{n} (x_i - \Hat x) (y_i - \Hat y)
 #[doc(alias = "gsl_stats_covariance")]
-pub fn covariance(data1: &[f64], stride1: usize, data2: &[f64], stride2: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_covariance(data1.as_ptr(), stride1, data2.as_ptr(), stride2, n) }
+pub fn covariance<T: StatsElement>(
+    data1: &(impl Dataset<T> + ?Sized),
+    data2: &(impl Dataset<T> + ?Sized),
+) -> f64 {
+    let n = same_len(data1, data2);
+    let (data1, stride1, _) = data1.parts();
+    let (data2, stride2, _) = data2.parts();
+    unsafe { T::covariance(data1, stride1, data2, stride2, n) }
 }
 
 /// This function computes the covariance of the datasets data1 and data2 using the given values of
 /// the means, mean1 and mean2. This is useful if you have already computed the means of data1 and
 /// data2 and want to avoid recomputing them.
 #[doc(alias = "gsl_stats_covariance_m")]
-pub fn covariance_m(
-    data1: &[f64],
-    stride1: usize,
-    data2: &[f64],
-    stride2: usize,
-    n: usize,
+pub fn covariance_m<T: StatsElement>(
+    data1: &(impl Dataset<T> + ?Sized),
+    data2: &(impl Dataset<T> + ?Sized),
     mean1: f64,
     mean2: f64,
 ) -> f64 {
-    unsafe {
-        sys::gsl_stats_covariance_m(
-            data1.as_ptr(),
-            stride1,
-            data2.as_ptr(),
-            stride2,
-            n,
-            mean1,
-            mean2,
-        )
-    }
+    let n = same_len(data1, data2);
+    let (data1, stride1, _) = data1.parts();
+    let (data2, stride2, _) = data2.parts();
+    unsafe { T::covariance_m(data1, stride1, data2, stride2, n, mean1, mean2) }
 }
 
 /// This function efficiently computes the Pearson correlation coefficient between the datasets
-/// data1 and data2 which must both be of the same length n.
+/// data1 and data2 which must both be of the same length.
 ///
 /// r = cov(x, y) / (\Hat\sigma_x \Hat\sigma_y)
 ///   = {1/(n-1) \sum (x_i - \Hat x) (y_i - \Hat y)
@@ -272,48 +1405,74 @@ pub fn covariance_m(
 ///      \sqrt{1/(n-1) \sum (x_i - \Hat x)^2} \sqrt{1/(n-1) \sum (y_i - \Hat y)^2}
 ///     }
 #[doc(alias = "gsl_stats_correlation")]
-pub fn correlation(data1: &[f64], stride1: usize, data2: &[f64], stride2: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_correlation(data1.as_ptr(), stride1, data2.as_ptr(), stride2, n) }
+pub fn correlation<T: StatsElement>(
+    data1: &(impl Dataset<T> + ?Sized),
+    data2: &(impl Dataset<T> + ?Sized),
+) -> f64 {
+    let n = same_len(data1, data2);
+    let (data1, stride1, _) = data1.parts();
+    let (data2, stride2, _) = data2.parts();
+    unsafe { T::correlation(data1, stride1, data2, stride2, n) }
 }
 
 /// This function computes the Spearman rank correlation coefficient between the datasets data1 and
-/// data2 which must both be of the same length n. Additional workspace of size 2*n is required in
-/// work. The Spearman rank correlation between vectors x and y is equivalent to the Pearson
-/// correlation between the ranked vectors x_R and y_R, where ranks are defined to be the average of
-/// the positions of an element in the ascending order of the values.
+/// data2 which must both be of the same length. The Spearman rank correlation between vectors x and
+/// y is equivalent to the Pearson correlation between the ranked vectors x_R and y_R, where ranks
+/// are defined to be the average of the positions of an element in the ascending order of the
+/// values.
 #[doc(alias = "gsl_stats_spearman")]
-pub fn spearman(
-    data1: &[f64],
-    stride1: usize,
-    data2: &[f64],
-    stride2: usize,
-    n: usize,
-    work: &mut [f64],
+pub fn spearman<T: StatsElement>(
+    data1: &(impl Dataset<T> + ?Sized),
+    data2: &(impl Dataset<T> + ?Sized),
 ) -> f64 {
-    unsafe {
-        sys::gsl_stats_spearman(
-            data1.as_ptr(),
-            stride1,
-            data2.as_ptr(),
-            stride2,
-            n,
-            work.as_mut_ptr(),
-        )
-    }
+    let n = same_len(data1, data2);
+    let (data1, stride1, _) = data1.parts();
+    let (data2, stride2, _) = data2.parts();
+    let mut work = vec![0.; 2 * n];
+    unsafe { T::spearman(data1, stride1, data2, stride2, n, work.as_mut_ptr()) }
+}
+
+/// This function returns the pooled variance of the two datasets data1 and data2, which may have
+/// different lengths.
+#[doc(alias = "gsl_stats_pvariance")]
+pub fn pvariance<T: StatsElement>(
+    data1: &(impl Dataset<T> + ?Sized),
+    data2: &(impl Dataset<T> + ?Sized),
+) -> f64 {
+    let (data1, stride1, n1) = data1.parts();
+    let (data2, stride2, n2) = data2.parts();
+    unsafe { T::pvariance(data1, stride1, n1, data2, stride2, n2) }
+}
+
+/// This function returns the t-test statistic for the difference of the means of the two datasets
+/// data1 and data2, which may have different lengths.
+#[doc(alias = "gsl_stats_ttest")]
+pub fn ttest<T: StatsElement>(
+    data1: &(impl Dataset<T> + ?Sized),
+    data2: &(impl Dataset<T> + ?Sized),
+) -> f64 {
+    let (data1, stride1, n1) = data1.parts();
+    let (data2, stride2, n2) = data2.parts();
+    unsafe { T::ttest(data1, stride1, n1, data2, stride2, n2) }
 }
 
-/// This function returns the weighted mean of the dataset data with stride stride and length n,
-/// using the set of weights w with stride wstride and length n. The weighted mean is defined as,
+/// This function returns the weighted mean of the dataset data, using the set of weights w. The
+/// weighted mean is defined as,
 ///
 /// \Hat\mu = (\sum w_i x_i) / (\sum w_i)
 #[doc(alias = "gsl_stats_wmean")]
-pub fn wmean(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_wmean(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
+pub fn wmean<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
+) -> f64 {
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wmean(w, wstride, data, stride, n) }
 }
 
-/// This function returns the estimated variance of the dataset data with stride stride and length
-/// n, using the set of weights w with
-/// stride wstride and length n. The estimated variance of a weighted dataset is calculated as,
+/// This function returns the estimated variance of the dataset data, using the set of weights w.
+/// The estimated variance of a weighted dataset is calculated as,
 ///
 /// \Hat\sigma^2 = ((\sum w_i)/((\sum w_i)^2 - \sum (w_i^2)))
 ///                 \sum w_i (x_i - \Hat\mu)^2
@@ -321,36 +1480,55 @@ pub fn wmean(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -
 /// Note that this expression reduces to an unweighted variance with the familiar 1/(N-1) factor
 /// when there are N equal non-zero weights.
 #[doc(alias = "gsl_stats_wvariance")]
-pub fn wvariance(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_wvariance(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
+pub fn wvariance<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
+) -> f64 {
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wvariance(w, wstride, data, stride, n) }
 }
 
 /// This function returns the estimated variance of the weighted dataset data using the given
 /// weighted mean wmean.
 #[doc(alias = "gsl_stats_wvariance_m")]
-pub fn wvariance_m(
-    w: &[f64],
-    wstride: usize,
-    data: &[f64],
-    stride: usize,
-    n: usize,
+pub fn wvariance_m<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
     wmean: f64,
 ) -> f64 {
-    unsafe { sys::gsl_stats_wvariance_m(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean) }
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wvariance_m(w, wstride, data, stride, n, wmean) }
 }
 
 /// The standard deviation is defined as the square root of the variance. This function returns the
 /// square root of the corresponding variance function [`wvariance`] above.
 #[doc(alias = "gsl_stats_wsd")]
-pub fn wsd(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_wsd(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
+pub fn wsd<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
+) -> f64 {
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wsd(w, wstride, data, stride, n) }
 }
 
 /// This function returns the square root of the corresponding variance function
 /// [`wvariance_m`] above.
 #[doc(alias = "gsl_stats_wsd_m")]
-pub fn wsd_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize, wmean: f64) -> f64 {
-    unsafe { sys::gsl_stats_wsd_m(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean) }
+pub fn wsd_m<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
+    wmean: f64,
+) -> f64 {
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wsd_m(w, wstride, data, stride, n, wmean) }
 }
 
 /// This function computes an unbiased estimate of the variance of the weighted dataset data when
@@ -359,40 +1537,29 @@ pub fn wsd_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize, w
 ///
 /// \Hat\sigma^2 = (\sum w_i (x_i - \mu)^2) / (\sum w_i)
 #[doc(alias = "gsl_stats_wvariance_with_fixed_mean")]
-pub fn wvariance_with_fixed_mean(
-    w: &[f64],
-    wstride: usize,
-    data: &[f64],
-    stride: usize,
-    n: usize,
+pub fn wvariance_with_fixed_mean<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
     mean: f64,
 ) -> f64 {
-    unsafe {
-        sys::gsl_stats_wvariance_with_fixed_mean(
-            w.as_ptr(),
-            wstride,
-            data.as_ptr(),
-            stride,
-            n,
-            mean,
-        )
-    }
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wvariance_with_fixed_mean(w, wstride, data, stride, n, mean) }
 }
 
 /// The standard deviation is defined as the square root of the variance. This function returns the
 /// square root of the corresponding variance function above.
 #[doc(alias = "gsl_stats_wsd_with_fixed_mean")]
-pub fn wsd_with_fixed_mean(
-    w: &[f64],
-    wstride: usize,
-    data: &[f64],
-    stride: usize,
-    n: usize,
+pub fn wsd_with_fixed_mean<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
     mean: f64,
 ) -> f64 {
-    unsafe {
-        sys::gsl_stats_wsd_with_fixed_mean(w.as_ptr(), wstride, data.as_ptr(), stride, n, mean)
-    }
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wsd_with_fixed_mean(w, wstride, data, stride, n, mean) }
 }
 
 /// This function returns the weighted total sum of squares (TSS) of data about the weighted mean.
@@ -401,8 +1568,14 @@ pub fn wsd_with_fixed_mean(
 ///
 /// TSS =  \sum w_i (x_i - wmean)^2
 #[doc(alias = "gsl_stats_wtss")]
-pub fn wtss(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_wtss(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
+pub fn wtss<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
+) -> f64 {
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wtss(w, wstride, data, stride, n) }
 }
 
 /// This function returns the weighted total sum of squares (TSS) of data about the weighted mean.
@@ -411,161 +1584,240 @@ pub fn wtss(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) ->
 ///
 /// TSS =  \sum w_i (x_i - wmean)^2
 #[doc(alias = "gsl_stats_wtss_m")]
-pub fn wtss_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize, wmean: f64) -> f64 {
-    unsafe { sys::gsl_stats_wtss_m(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean) }
+pub fn wtss_m<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
+    wmean: f64,
+) -> f64 {
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wtss_m(w, wstride, data, stride, n, wmean) }
 }
 
-/// This function computes the weighted absolute deviation from the weighted mean of data. The absolute deviation from the mean is defined
-/// as,
+/// This function computes the weighted absolute deviation from the weighted mean of data. The
+/// absolute deviation from the mean is defined as,
 ///
 /// absdev = (\sum w_i |x_i - \Hat\mu|) / (\sum w_i)
 #[doc(alias = "gsl_stats_wabsdev")]
-pub fn wabsdev(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_wabsdev(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
+pub fn wabsdev<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
+) -> f64 {
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wabsdev(w, wstride, data, stride, n) }
 }
 
-/// This function computes the absolute deviation of the weighted dataset data about the given weighted mean wmean.
+/// This function computes the absolute deviation of the weighted dataset data about the given
+/// weighted mean wmean.
 #[doc(alias = "gsl_stats_wabsdev_m")]
-pub fn wabsdev_m(
-    w: &[f64],
-    wstride: usize,
-    data: &[f64],
-    stride: usize,
-    n: usize,
+pub fn wabsdev_m<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
     wmean: f64,
 ) -> f64 {
-    unsafe { sys::gsl_stats_wabsdev_m(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean) }
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wabsdev_m(w, wstride, data, stride, n, wmean) }
 }
 
 /// This function computes the weighted skewness of the dataset data.
 ///
 /// skew = (\sum w_i ((x_i - \Hat x)/\Hat \sigma)^3) / (\sum w_i)
 #[doc(alias = "gsl_stats_wskew")]
-pub fn wskew(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_wskew(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
+pub fn wskew<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
+) -> f64 {
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wskew(w, wstride, data, stride, n) }
 }
 
 /// This function computes the weighted skewness of the dataset data using the given values of the
 /// weighted mean and weighted standard deviation, wmean and wsd.
 #[doc(alias = "gsl_stats_wskew_m_sd")]
-pub fn wskew_m_sd(
-    w: &[f64],
-    wstride: usize,
-    data: &[f64],
-    stride: usize,
-    n: usize,
+pub fn wskew_m_sd<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
     wmean: f64,
     wsd: f64,
 ) -> f64 {
-    unsafe { sys::gsl_stats_wskew_m_sd(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean, wsd) }
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wskew_m_sd(w, wstride, data, stride, n, wmean, wsd) }
 }
 
 /// This function computes the weighted kurtosis of the dataset data.
 ///
 /// kurtosis = ((\sum w_i ((x_i - \Hat x)/\Hat \sigma)^4) / (\sum w_i)) - 3
 #[doc(alias = "gsl_stats_wkurtosis")]
-pub fn wkurtosis(w: &[f64], wstride: usize, data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_wkurtosis(w.as_ptr(), wstride, data.as_ptr(), stride, n) }
+pub fn wkurtosis<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
+) -> f64 {
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wkurtosis(w, wstride, data, stride, n) }
 }
 
 /// This function computes the weighted kurtosis of the dataset data using the given values of the
 /// weighted mean and weighted standard deviation, wmean and wsd.
 #[doc(alias = "gsl_stats_wkurtosis_m_sd")]
-pub fn wkurtosis_m_sd(
-    w: &[f64],
-    wstride: usize,
-    data: &[f64],
-    stride: usize,
-    n: usize,
+pub fn wkurtosis_m_sd<T: WeightedStatsElement>(
+    w: &(impl Dataset<T> + ?Sized),
+    data: &(impl Dataset<T> + ?Sized),
     wmean: f64,
     wsd: f64,
 ) -> f64 {
-    unsafe {
-        sys::gsl_stats_wkurtosis_m_sd(w.as_ptr(), wstride, data.as_ptr(), stride, n, wmean, wsd)
-    }
+    let n = same_len(w, data);
+    let (w, wstride, _) = w.parts();
+    let (data, stride, _) = data.parts();
+    unsafe { T::wkurtosis_m_sd(w, wstride, data, stride, n, wmean, wsd) }
 }
 
-/// This function returns the maximum value in data, a dataset of length n with stride stride. The
+/// This function returns the maximum value in data. The
 /// maximum value is defined as the value of the element x_i which satisfies x_i >= x_j for all j.
 ///
 /// If you want instead to find the element with the largest absolute magnitude you will need to
 /// apply fabs or abs to your data before calling this function.
+///
+/// Returns `None` if the dataset is empty.
 #[doc(alias = "gsl_stats_max")]
-pub fn max(data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_max(data.as_ptr(), stride, n) }
+pub fn max<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> Option<T> {
+    let (data, stride, n) = data.parts();
+    if n == 0 {
+        return None;
+    }
+    Some(unsafe { T::max(data, stride, n) })
 }
 
-/// This function returns the minimum value in data, a dataset of length n with stride stride. The
+/// This function returns the minimum value in data. The
 /// minimum value is defined as the value of the element x_i which satisfies x_i <= x_j for all j.
 ///
 /// If you want instead to find the element with the smallest absolute magnitude you will need to
 /// apply fabs or abs to your data before calling this function.
+///
+/// Returns `None` if the dataset is empty.
 #[doc(alias = "gsl_stats_min")]
-pub fn min(data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_min(data.as_ptr(), stride, n) }
+pub fn min<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> Option<T> {
+    let (data, stride, n) = data.parts();
+    if n == 0 {
+        return None;
+    }
+    Some(unsafe { T::min(data, stride, n) })
 }
 
 /// This function finds both the minimum and maximum values min, max in data in a single pass.
 ///
-/// Returns `(min, max)`.
+/// Returns `(min, max)`, or `None` if the dataset is empty.
 #[doc(alias = "gsl_stats_minmax")]
-pub fn minmax(data: &[f64], stride: usize, n: usize) -> (f64, f64) {
-    let mut min = 0.;
-    let mut max = 0.;
-    unsafe { sys::gsl_stats_minmax(&mut min, &mut max, data.as_ptr(), stride, n) };
-    (min, max)
+pub fn minmax<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> Option<(T, T)> {
+    let (data, stride, n) = data.parts();
+    if n == 0 {
+        return None;
+    }
+    let mut min = T::default();
+    let mut max = T::default();
+    unsafe { T::minmax(&mut min, &mut max, data, stride, n) };
+    Some((min, max))
 }
 
-/// This function returns the index of the maximum value in data, a dataset of length n with stride
-/// stride. The maximum value is defined as the value of the element x_i which satisfies x_i >= x_j
-/// for all j. When there are several equal maximum elements then the first one is chosen.
+/// This function returns the index of the maximum value in data. The maximum value is defined as
+/// the value of the element x_i which satisfies x_i >= x_j for all j. When there are several equal
+/// maximum elements then the first one is chosen.
+///
+/// Returns `None` if the dataset is empty.
 #[doc(alias = "gsl_stats_max_index")]
-pub fn max_index(data: &[f64], stride: usize, n: usize) -> usize {
-    unsafe { sys::gsl_stats_max_index(data.as_ptr(), stride, n) }
+pub fn max_index<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> Option<usize> {
+    let (data, stride, n) = data.parts();
+    if n == 0 {
+        return None;
+    }
+    Some(unsafe { T::max_index(data, stride, n) })
 }
 
-/// This function returns the index of the minimum value in data, a dataset of length n with stride
-/// stride. The minimum value is defined as the value of the element x_i which satisfies x_i >= x_j
-/// for all j. When there are several equal minimum elements then the first one is chosen.
+/// This function returns the index of the minimum value in data. The minimum value is defined as
+/// the value of the element x_i which satisfies x_i >= x_j for all j. When there are several equal
+/// minimum elements then the first one is chosen.
+///
+/// Returns `None` if the dataset is empty.
 #[doc(alias = "gsl_stats_min_index")]
-pub fn min_index(data: &[f64], stride: usize, n: usize) -> usize {
-    unsafe { sys::gsl_stats_min_index(data.as_ptr(), stride, n) }
+pub fn min_index<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> Option<usize> {
+    let (data, stride, n) = data.parts();
+    if n == 0 {
+        return None;
+    }
+    Some(unsafe { T::min_index(data, stride, n) })
 }
 
 /// This function returns the indexes min_index, max_index of the minimum and maximum values in data
 /// in a single pass.
 ///
-/// Returns `(min_index, max_index)`.
+/// Returns `(min_index, max_index)`, or `None` if the dataset is empty.
 #[doc(alias = "gsl_stats_minmax_index")]
-pub fn minmax_index(data: &[f64], stride: usize, n: usize) -> (usize, usize) {
+pub fn minmax_index<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> Option<(usize, usize)> {
+    let (data, stride, n) = data.parts();
+    if n == 0 {
+        return None;
+    }
     let mut min_index = 0;
     let mut max_index = 0;
-    unsafe {
-        sys::gsl_stats_minmax_index(&mut min_index, &mut max_index, data.as_ptr(), stride, n)
-    };
-    (min_index, max_index)
+    unsafe { T::minmax_index(&mut min_index, &mut max_index, data, stride, n) };
+    Some((min_index, max_index))
 }
 
-/// This function returns the median value of sorted_data, a dataset of length n with stride stride.
-/// The elements of the array must be in ascending numerical order. There are no checks to see
-/// whether the data are sorted, so the function gsl_sort should always be used first.
+/// This function returns the median value of sorted_data. The elements of the array must be in
+/// ascending numerical order. There are no checks to see whether the data are sorted, so the
+/// functions of the [`sort`](crate::sort) module should always be used first.
 ///
 /// When the dataset has an odd number of elements the median is the value of element (n-1)/2. When
 /// the dataset has an even number of elements the median is the mean of the two nearest middle
 /// values, elements (n-1)/2 and n/2. Since the algorithm for computing the median involves
 /// interpolation this function always returns a floating-point number, even for integer data types.
 #[doc(alias = "gsl_stats_median_from_sorted_data")]
-pub fn median_from_sorted_data(data: &[f64], stride: usize, n: usize) -> f64 {
-    unsafe { sys::gsl_stats_median_from_sorted_data(data.as_ptr(), stride, n) }
+pub fn median_from_sorted_data<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::median_from_sorted_data(data, stride, n) }
+}
+
+/// This function returns the median value of data. The elements of the array are rearranged in
+/// place to find the median, using the quickselect algorithm, so there is no need to sort the
+/// data first.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_median")]
+pub fn median<T: StatsElement>(data: &mut (impl DatasetMut<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts_mut();
+    unsafe { T::median(data, stride, n) }
+}
+
+/// This function finds the k-th smallest element of data (counting from 0), using the quickselect
+/// algorithm. The elements of the array are rearranged in place.
+///
+/// Panics if `k` is out of range.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_select")]
+pub fn select<T: StatsElement>(data: &mut (impl DatasetMut<T> + ?Sized), k: usize) -> T {
+    let (data, stride, n) = data.parts_mut();
+    assert!(k < n, "k is out of range: {} >= {}", k, n);
+    unsafe { T::select(data, stride, n, k) }
 }
 
-/// This function returns a quantile value of sorted_data, a double-precision array of length n with
-/// stride stride. The elements of the array must be in ascending numerical order. The quantile is
+/// This function returns a quantile value of sorted_data. The elements of the array must be in
+/// ascending numerical order. The quantile is
 /// determined by the f, a fraction between 0 and 1. For example, to compute the value of the 75th
 /// percentile f should have the value 0.75.
 ///
-/// There are no checks to see whether the data are sorted, so the function gsl_sort should always
-/// be used first.
+/// There are no checks to see whether the data are sorted, so the functions of the
+/// [`sort`](crate::sort) module should always be used first.
 ///
 /// The quantile is found by interpolation, using the formula
 ///
@@ -573,11 +1825,301 @@ pub fn median_from_sorted_data(data: &[f64], stride: usize, n: usize) -> f64 {
 ///
 /// where i is floor((n - 1)f) and \delta is (n-1)f - i.
 ///
-/// Thus the minimum value of the array (data[0*stride]) is given by f equal to zero, the maximum
-/// value (data[(n-1)*stride]) is given by f equal to one and the median value is given by f equal
+/// Thus the minimum value of the array (data[0]) is given by f equal to zero, the maximum
+/// value (data[n-1]) is given by f equal to one and the median value is given by f equal
 /// to 0.5. Since the algorithm for computing quantiles involves interpolation this function always
 /// returns a floating-point number, even for integer data types.
+///
+/// Panics if the dataset is empty or if `f` isn't between 0 and 1.
 #[doc(alias = "gsl_stats_quantile_from_sorted_data")]
-pub fn quantile_from_sorted_data(data: &[f64], stride: usize, n: usize, f: f64) -> f64 {
-    unsafe { sys::gsl_stats_quantile_from_sorted_data(data.as_ptr(), stride, n, f) }
+pub fn quantile_from_sorted_data<T: StatsElement>(
+    data: &(impl Dataset<T> + ?Sized),
+    f: f64,
+) -> f64 {
+    let (data, stride, n) = data.parts();
+    assert!(n > 0, "the dataset is empty");
+    assert!((0. ..=1.).contains(&f), "f must be between 0 and 1: {}", f);
+    unsafe { T::quantile_from_sorted_data(data, stride, n, f) }
+}
+
+/// This function returns the trimmed mean of sorted_data, discarding the fraction trim (between 0
+/// and 0.5) of the smallest and of the largest elements. The data must be in ascending numerical
+/// order.
+///
+/// Panics if `trim` is negative or NaN.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_trmean_from_sorted_data")]
+pub fn trmean_from_sorted_data<T: StatsElement>(
+    data: &(impl Dataset<T> + ?Sized),
+    trim: f64,
+) -> f64 {
+    assert!(trim >= 0., "trim must be non-negative: {}", trim);
+    let (data, stride, n) = data.parts();
+    unsafe { T::trmean_from_sorted_data(trim, data, stride, n) }
+}
+
+/// This function returns the Gastwirth location estimator of sorted_data, a weighted sum of the
+/// 1/3, 1/2 and 2/3 quantiles. The data must be in ascending numerical order.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_gastwirth_from_sorted_data")]
+pub fn gastwirth_from_sorted_data<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    unsafe { T::gastwirth_from_sorted_data(data, stride, n) }
+}
+
+/// This function returns the median absolute deviation of data, that is the median of
+/// |x_i - median(x)|, without any scale factor.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_mad0")]
+pub fn mad0<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    let mut work = vec![0.; n];
+    unsafe { T::mad0(data, stride, n, work.as_mut_ptr()) }
+}
+
+/// This function returns the median absolute deviation of data, scaled by 1.4826 to be a
+/// consistent estimator of the standard deviation of Gaussian data.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_mad")]
+pub fn mad<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    let mut work = vec![0.; n];
+    unsafe { T::mad(data, stride, n, work.as_mut_ptr()) }
+}
+
+/// This function returns the unscaled Rousseeuw-Croux S_n scale estimate of sorted_data, in the
+/// units of the data. The data must be in ascending numerical order.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Sn0_from_sorted_data")]
+pub fn sn0_from_sorted_data<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> T {
+    let (data, stride, n) = data.parts();
+    let mut work = vec![T::default(); n];
+    unsafe { T::sn0_from_sorted_data(data, stride, n, work.as_mut_ptr()) }
+}
+
+/// This function returns the Rousseeuw-Croux S_n scale estimate of sorted_data, scaled to be a
+/// consistent estimator of the standard deviation of Gaussian data. The data must be in ascending
+/// numerical order.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Sn_from_sorted_data")]
+pub fn sn_from_sorted_data<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    let mut work = vec![T::default(); n];
+    unsafe { T::sn_from_sorted_data(data, stride, n, work.as_mut_ptr()) }
+}
+
+/// This function returns the unscaled Rousseeuw-Croux Q_n scale estimate of sorted_data, in the
+/// units of the data. The data must be in ascending numerical order.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Qn0_from_sorted_data")]
+pub fn qn0_from_sorted_data<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> T {
+    let (data, stride, n) = data.parts();
+    let mut work = vec![T::default(); 3 * n];
+    let mut work_int = vec![0; 5 * n];
+    unsafe { T::qn0_from_sorted_data(data, stride, n, work.as_mut_ptr(), work_int.as_mut_ptr()) }
+}
+
+/// This function returns the Rousseeuw-Croux Q_n scale estimate of sorted_data, scaled to be a
+/// consistent estimator of the standard deviation of Gaussian data. The data must be in ascending
+/// numerical order.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Qn_from_sorted_data")]
+pub fn qn_from_sorted_data<T: StatsElement>(data: &(impl Dataset<T> + ?Sized)) -> f64 {
+    let (data, stride, n) = data.parts();
+    let mut work = vec![T::default(); 3 * n];
+    let mut work_int = vec![0; 5 * n];
+    unsafe { T::qn_from_sorted_data(data, stride, n, work.as_mut_ptr(), work_int.as_mut_ptr()) }
+}
+
+#[test]
+fn datasets() {
+    use types::{VectorF64, VectorI32};
+
+    let data = [1., 10., 2., 20., 3., 30.];
+    let strided = Strided::new(&data, 2);
+    assert_eq!(strided.len(), 3);
+    assert_eq!(mean(&strided), 2.);
+    assert_eq!(variance(&strided), 1.);
+    assert_eq!(mean(&data), 11.);
+    assert_eq!(mean(&data[..4]), 8.25);
+    assert_eq!(mean(&data.to_vec()), 11.);
+
+    let v = VectorF64::from_slice(&data).unwrap();
+    assert_eq!(mean(&v), 11.);
+    let view = v.subvector_with_stride(1, 2, 3).unwrap();
+    assert_eq!(mean(&view), 20.);
+    assert_eq!(max(&view), Some(30.));
+    assert_eq!(minmax(&strided), Some((1., 3.)));
+    assert_eq!(max_index(&view), Some(2));
+    assert_eq!(minmax_index(&data), Some((0, 5)));
+
+    assert_eq!(covariance(&[1., 2., 3.], &strided), 1.);
+    assert!((correlation(&view, &strided) - 1.).abs() < 1e-12);
+    assert!((wmean(&[1., 1., 2.], &strided) - 2.25).abs() < 1e-12);
+
+    let ints = VectorI32::from_slice(&[3, -1, 4]).unwrap();
+    assert_eq!(min(&ints), Some(-1));
+    assert_eq!(mean(&ints), 2.);
+    assert_eq!(max(&[7u8, 200, 3]), Some(200));
+
+    let mut odd = [5., 0., 1., 0., 3.];
+    let odd = StridedMut::new(&mut odd, 2);
+    assert_eq!(odd.len(), 3);
+    assert_eq!(min(&odd), Some(1.));
+    assert_eq!(mean(&odd), 3.);
+}
+
+#[test]
+fn empty_datasets() {
+    let empty: [f64; 0] = [];
+    assert_eq!(max(&empty), None);
+    assert_eq!(min(&empty[..]), None);
+    assert_eq!(minmax(&Vec::<f64>::new()), None);
+    assert_eq!(max_index(&Strided::new(&empty, 3)), None);
+    assert_eq!(min_index(&empty), None);
+    assert_eq!(minmax_index(&empty), None);
+}
+
+#[test]
+#[should_panic(expected = "datasets must have the same length")]
+fn mismatched_lengths() {
+    let data = [1., 2., 3., 4.];
+    covariance(&data[..3], &Strided::new(&data, 1));
+}
+
+#[test]
+fn sorted_data() {
+    let data = [1., 2., 3., 4., 5.];
+    assert_eq!(median_from_sorted_data(&data), 3.);
+    assert_eq!(median_from_sorted_data(&data[..4]), 2.5);
+    assert_eq!(quantile_from_sorted_data(&data, 0.), 1.);
+    assert_eq!(quantile_from_sorted_data(&data, 0.25), 2.);
+    assert_eq!(quantile_from_sorted_data(&data, 1.), 5.);
+    assert_eq!(quantile_from_sorted_data(&Strided::new(&data, 2), 0.5), 3.);
+}
+
+#[test]
+#[should_panic(expected = "f must be between 0 and 1")]
+fn quantile_out_of_range() {
+    quantile_from_sorted_data(&[1., 2., 3.], 1.5);
+}
+
+#[test]
+#[should_panic(expected = "f must be between 0 and 1")]
+fn quantile_nan() {
+    quantile_from_sorted_data(&[1., 2., 3.], f64::NAN);
+}
+
+#[test]
+#[should_panic(expected = "the dataset is empty")]
+fn quantile_empty() {
+    quantile_from_sorted_data(&[0f64; 0], 0.5);
+}
+
+#[cfg(feature = "v2_5")]
+#[test]
+fn robust_statistics() {
+    let mut data = [3., 100., 1., 4., 2.];
+    assert_eq!(median(&mut data), 3.);
+    assert_eq!(select(&mut data, 0), 1.);
+    assert_eq!(select(&mut data, 4), 100.);
+
+    let mut interleaved = [5., -1., 1., -1., 3.];
+    assert_eq!(median(&mut StridedMut::new(&mut interleaved, 2)), 3.);
+    assert_eq!(interleaved[1], -1.);
+
+    let data = [1., 2., 3., 4., 100.];
+    assert_eq!(mad0(&data), 1.);
+    assert!((mad(&data) - 1.4826).abs() < 1e-4);
+    assert_eq!(trmean_from_sorted_data(&data, 0.2), 3.);
+    assert_eq!(trmean_from_sorted_data(&data, 0.), mean(&data));
+
+    // The scale estimators ignore the magnitude of a single outlier.
+    let other = [1., 2., 3., 4., 1000.];
+    let sn0 = sn0_from_sorted_data(&data);
+    assert!(sn0 > 0. && sn0 < 4.);
+    assert_eq!(sn0, sn0_from_sorted_data(&other));
+    assert_eq!(sn_from_sorted_data(&data), sn_from_sorted_data(&other));
+    let qn0 = qn0_from_sorted_data(&data);
+    assert!(qn0 > 0. && qn0 < 4.);
+    assert_eq!(qn0, qn0_from_sorted_data(&other));
+    assert_eq!(qn_from_sorted_data(&data), qn_from_sorted_data(&other));
+    assert_eq!(qn0_from_sorted_data(&[1, 2, 3, 4, 100]), qn0 as i32);
+}
+
+#[cfg(feature = "v2_5")]
+#[test]
+#[should_panic(expected = "trim must be non-negative")]
+fn trmean_negative_trim() {
+    trmean_from_sorted_data(&[1., 2., 3.], -0.1);
+}
+
+#[cfg(feature = "v2_5")]
+#[test]
+#[should_panic(expected = "k is out of range")]
+fn select_out_of_range() {
+    select(&mut [1., 2.], 2);
+}
+
+#[test]
+#[allow(deprecated)]
+fn stats_weighted_wrappers() {
+    // The dataset [1, 2, 3] with the weights [1, 1, 2]: the weighted mean is 2.25. The trailing
+    // weight is ignored.
+    let data = [1., 0., 2., 0., 3.];
+    let w = [1., -1., 1., -1., 2., 100.];
+    let (x, wx) = ([1., 2., 3.], [1., 1., 2.]);
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+
+    assert!(close(::stats::wtss(&w, 2, &data, 2), 2.75));
+    assert!(close(::stats::wtss_m(&w, 2, &data, 2, 2.), 3.));
+    assert!(close(::stats::wabsdev(&w, 2, &data, 2), 0.75));
+    assert!(close(::stats::wabsdev_m(&w, 2, &data, 2, 2.), 0.75));
+    assert!(close(::stats::wvariance_m(&w, 2, &data, 2, 2.), 1.2));
+    assert!(close(::stats::wskew(&w, 2, &data, 2), wskew(&wx, &x)));
+    assert!(close(
+        ::stats::wkurtosis(&w, 2, &data, 2),
+        wkurtosis(&wx, &x)
+    ));
+    assert!(close(
+        ::stats::wskew_m_sd(&w, 2, &data, 2, 2., 1.),
+        wskew_m_sd(&wx, &x, 2., 1.)
+    ));
+    assert!(close(
+        ::stats::wkurtosis_m_sd(&w, 2, &data, 2, 2., 1.),
+        wkurtosis_m_sd(&wx, &x, 2., 1.)
+    ));
+}
+
+#[test]
+#[allow(deprecated)]
+fn stats_unweighted_wrappers() {
+    let data = [4., 0., -2., 0., 7.];
+    assert_eq!(::stats::max(&data, 2), 7.);
+    assert_eq!(::stats::min(&data, 2), -2.);
+    assert_eq!(::stats::stats_minmax(&data, 2), (-2., 7.));
+    assert_eq!(::stats::max_index(&data, 2), 2);
+    assert_eq!(::stats::min_index(&data, 2), 1);
+    assert_eq!(::stats::stats_minmax_index(&data, 2), (1, 2));
+    let pooled = variance(&[4., -2., 7.]);
+    assert!((::stats::pvariance(&data, 2, &data, 2) - pooled).abs() < 1e-12);
+    assert_eq!(::stats::ttest(&data, 2, &data, 2), 0.);
+}
+
+#[cfg(feature = "v2_5")]
+#[test]
+#[allow(deprecated)]
+fn stats_selection_wrappers() {
+    let mut data = [4., 0., -2., 0., 7.];
+    assert_eq!(::stats::median(&mut data, 2), 4.);
+    assert_eq!(::stats::select(&mut data, 2, 0), -2.);
+    assert_eq!(data[1], 0.);
 }
@@ -2,66 +2,66 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
+//! The `f64` functions taking a slice and a stride, kept for compatibility. They are implemented
+//! with the [`statistics`](crate::statistics) module, which provides the same functions for every
+//! element type and every kind of dataset.
+//!
+//! The dataset is made of every `stride`-th element of `data`, starting with the first one. The
+//! weights `w` must have at least as many elements (taking `wstride` into account) as the dataset,
+//! the extra ones being ignored.
+
+use statistics::{self, Strided};
+
+// Returns the `n` elements of `w` with stride `wstride` used as weights.
+fn weights(w: &[f64], wstride: usize, n: usize) -> Strided<'_, f64> {
+    let len = if n == 0 { 0 } else { (n - 1) * wstride + 1 };
+    Strided::new(&w[..len.min(w.len())], wstride)
+}
+
+fn dataset(data: &[f64], stride: usize) -> Strided<'_, f64> {
+    Strided::new(data, stride)
+}
+
 #[doc(alias = "gsl_stats_wtss")]
 pub fn wtss(w: &[f64], wstride: usize, data: &[f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_wtss(w.as_ptr(), wstride, data.as_ptr(), stride, data.len() as _) }
+    let data = dataset(data, stride);
+    statistics::wtss(&weights(w, wstride, data.len()), &data)
 }
 
 #[doc(alias = "gsl_stats_wtss_m")]
 pub fn wtss_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, wmean: f64) -> f64 {
-    unsafe {
-        sys::gsl_stats_wtss_m(
-            w.as_ptr(),
-            wstride,
-            data.as_ptr(),
-            stride,
-            data.len() as _,
-            wmean,
-        )
-    }
+    let data = dataset(data, stride);
+    statistics::wtss_m(&weights(w, wstride, data.len()), &data, wmean)
 }
 
 #[doc(alias = "gsl_stats_wabsdev")]
 pub fn wabsdev(w: &[f64], wstride: usize, data: &[f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_wabsdev(w.as_ptr(), wstride, data.as_ptr(), stride, data.len() as _) }
+    let data = dataset(data, stride);
+    statistics::wabsdev(&weights(w, wstride, data.len()), &data)
 }
 
 #[doc(alias = "gsl_stats_wskew")]
 pub fn wskew(w: &[f64], wstride: usize, data: &[f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_wskew(w.as_ptr(), wstride, data.as_ptr(), stride, data.len() as _) }
+    let data = dataset(data, stride);
+    statistics::wskew(&weights(w, wstride, data.len()), &data)
 }
 
 #[doc(alias = "gsl_stats_wkurtosis")]
 pub fn wkurtosis(w: &[f64], wstride: usize, data: &[f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_wkurtosis(w.as_ptr(), wstride, data.as_ptr(), stride, data.len() as _) }
+    let data = dataset(data, stride);
+    statistics::wkurtosis(&weights(w, wstride, data.len()), &data)
 }
 
 #[doc(alias = "gsl_stats_wvariance_m")]
 pub fn wvariance_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, wmean: f64) -> f64 {
-    unsafe {
-        sys::gsl_stats_wvariance_m(
-            w.as_ptr(),
-            wstride,
-            data.as_ptr(),
-            stride,
-            data.len() as _,
-            wmean,
-        )
-    }
+    let data = dataset(data, stride);
+    statistics::wvariance_m(&weights(w, wstride, data.len()), &data, wmean)
 }
 
 #[doc(alias = "gsl_stats_wabsdev_m")]
 pub fn wabsdev_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, wmean: f64) -> f64 {
-    unsafe {
-        sys::gsl_stats_wabsdev_m(
-            w.as_ptr(),
-            wstride,
-            data.as_ptr(),
-            stride,
-            data.len() as _,
-            wmean,
-        )
-    }
+    let data = dataset(data, stride);
+    statistics::wabsdev_m(&weights(w, wstride, data.len()), &data, wmean)
 }
 
 #[doc(alias = "gsl_stats_wskew_m_sd")]
@@ -73,17 +73,8 @@ pub fn wskew_m_sd(
     wmean: f64,
     wsd: f64,
 ) -> f64 {
-    unsafe {
-        sys::gsl_stats_wskew_m_sd(
-            w.as_ptr(),
-            wstride,
-            data.as_ptr(),
-            stride,
-            data.len() as _,
-            wmean,
-            wsd,
-        )
-    }
+    let data = dataset(data, stride);
+    statistics::wskew_m_sd(&weights(w, wstride, data.len()), &data, wmean, wsd)
 }
 
 #[doc(alias = "gsl_stats_wkurtosis_m_sd")]
@@ -95,99 +86,71 @@ pub fn wkurtosis_m_sd(
     wmean: f64,
     wsd: f64,
 ) -> f64 {
-    unsafe {
-        sys::gsl_stats_wkurtosis_m_sd(
-            w.as_ptr(),
-            wstride,
-            data.as_ptr(),
-            stride,
-            data.len() as _,
-            wmean,
-            wsd,
-        )
-    }
+    let data = dataset(data, stride);
+    statistics::wkurtosis_m_sd(&weights(w, wstride, data.len()), &data, wmean, wsd)
 }
 
 #[doc(alias = "gsl_stats_pvariance")]
 pub fn pvariance(data1: &[f64], stride1: usize, data2: &[f64], stride2: usize) -> f64 {
-    unsafe {
-        sys::gsl_stats_pvariance(
-            data1.as_ptr(),
-            stride1,
-            data1.len() as _,
-            data2.as_ptr(),
-            stride2,
-            data2.len() as _,
-        )
-    }
+    statistics::pvariance(&dataset(data1, stride1), &dataset(data2, stride2))
 }
 
 #[doc(alias = "gsl_stats_ttest")]
 pub fn ttest(data1: &[f64], stride1: usize, data2: &[f64], stride2: usize) -> f64 {
-    unsafe {
-        sys::gsl_stats_ttest(
-            data1.as_ptr(),
-            stride1,
-            data1.len() as _,
-            data2.as_ptr(),
-            stride2,
-            data2.len() as _,
-        )
-    }
+    statistics::ttest(&dataset(data1, stride1), &dataset(data2, stride2))
 }
 
+/// Panics if `data` is empty.
 #[doc(alias = "gsl_stats_max")]
 pub fn max(data: &[f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_max(data.as_ptr(), stride, data.len() as _) }
+    statistics::max(&dataset(data, stride)).expect("empty dataset")
 }
 
+/// Panics if `data` is empty.
 #[doc(alias = "gsl_stats_min")]
 pub fn min(data: &[f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_min(data.as_ptr(), stride, data.len() as _) }
+    statistics::min(&dataset(data, stride)).expect("empty dataset")
 }
 
 /// Returns `(min, max)`.
+///
+/// Panics if `data` is empty.
 #[doc(alias = "gsl_stats_minmax")]
 pub fn stats_minmax(data: &[f64], stride: usize) -> (f64, f64) {
-    let mut min = 0.;
-    let mut max = 0.;
-
-    unsafe { sys::gsl_stats_minmax(&mut min, &mut max, data.as_ptr(), stride, data.len() as _) }
-    (min, max)
+    statistics::minmax(&dataset(data, stride)).expect("empty dataset")
 }
 
+/// Panics if `data` is empty.
 #[doc(alias = "gsl_stats_max_index")]
 pub fn max_index(data: &[f64], stride: usize) -> usize {
-    unsafe { sys::gsl_stats_max_index(data.as_ptr(), stride, data.len() as _) }
+    statistics::max_index(&dataset(data, stride)).expect("empty dataset")
 }
 
+/// Panics if `data` is empty.
 #[doc(alias = "gsl_stats_min_index")]
 pub fn min_index(data: &[f64], stride: usize) -> usize {
-    unsafe { sys::gsl_stats_min_index(data.as_ptr(), stride, data.len() as _) }
+    statistics::min_index(&dataset(data, stride)).expect("empty dataset")
 }
 
 /// Returns `(min, max)`.
+///
+/// Panics if `data` is empty.
 #[doc(alias = "gsl_stats_minmax_index")]
 pub fn stats_minmax_index(data: &[f64], stride: usize) -> (usize, usize) {
-    let mut min = 0;
-    let mut max = 0;
-
-    unsafe {
-        sys::gsl_stats_minmax_index(&mut min, &mut max, data.as_ptr(), stride, data.len() as _)
-    }
-    (min, max)
+    statistics::minmax_index(&dataset(data, stride)).expect("empty dataset")
 }
 
+/// Panics if `k` is out of range.
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 #[doc(alias = "gsl_stats_select")]
 pub fn select(data: &mut [f64], stride: usize, k: usize) -> f64 {
-    unsafe { sys::gsl_stats_select(data.as_mut_ptr(), stride, data.len() as _, k) }
+    statistics::select(&mut statistics::StridedMut::new(data, stride), k)
 }
 
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 #[doc(alias = "gsl_stats_median")]
 pub fn median(data: &mut [f64], stride: usize) -> f64 {
-    unsafe { sys::gsl_stats_median(data.as_mut_ptr(), stride, data.len() as _) }
+    statistics::median(&mut statistics::StridedMut::new(data, stride))
 }
@@ -7,6 +7,7 @@
 use std::default::Default;
 use std::fmt;
 use std::fmt::{Debug, Formatter};
+use std::ops;
 
 #[doc(hidden)]
 #[allow(clippy::upper_case_acronyms)]
@@ -24,6 +25,7 @@ pub trait FFFI<T> {
 
 #[repr(C)]
 #[derive(Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ComplexF64 {
     pub dat: [f64; 2],
 }
@@ -517,6 +519,7 @@ impl FFFI<ComplexF64> for sys::gsl_complex {
 
 #[repr(C)]
 #[derive(Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ComplexF32 {
     pub dat: [f32; 2],
 }
@@ -981,6 +984,146 @@ impl CFFI<sys::gsl_complex_float> for ComplexF32 {
     }
 }
 
+// The arithmetic operators forward to the `gsl_complex_*` functions above, so that `a + b * c`
+// gives the same results as the corresponding method calls.
+macro_rules! complex_ops {
+    ($complex:ident, $real:ident) => {
+        complex_ops!(@op $complex, $real, Add, add, AddAssign, add_assign, add, add_real);
+        complex_ops!(@op $complex, $real, Sub, sub, SubAssign, sub_assign, sub, sub_real);
+        complex_ops!(@op $complex, $real, Mul, mul, MulAssign, mul_assign, mul, mul_real);
+        complex_ops!(@op $complex, $real, Div, div, DivAssign, div_assign, div, div_real);
+
+        impl ops::Neg for $complex {
+            type Output = $complex;
+
+            fn neg(self) -> $complex {
+                self.negative()
+            }
+        }
+
+        impl ops::Add<$complex> for $real {
+            type Output = $complex;
+
+            fn add(self, other: $complex) -> $complex {
+                other.add_real(self)
+            }
+        }
+
+        impl ops::Sub<$complex> for $real {
+            type Output = $complex;
+
+            fn sub(self, other: $complex) -> $complex {
+                other.negative().add_real(self)
+            }
+        }
+
+        impl ops::Mul<$complex> for $real {
+            type Output = $complex;
+
+            fn mul(self, other: $complex) -> $complex {
+                other.mul_real(self)
+            }
+        }
+
+        impl ops::Div<$complex> for $real {
+            type Output = $complex;
+
+            fn div(self, other: $complex) -> $complex {
+                other.inverse().mul_real(self)
+            }
+        }
+    };
+    (@op $complex:ident, $real:ident, $trait:ident, $method:ident, $assign_trait:ident,
+     $assign_method:ident, $func:ident, $real_func:ident) => {
+        impl ops::$trait for $complex {
+            type Output = $complex;
+
+            fn $method(self, other: $complex) -> $complex {
+                $complex::$func(&self, &other)
+            }
+        }
+
+        impl ops::$trait<$real> for $complex {
+            type Output = $complex;
+
+            fn $method(self, x: $real) -> $complex {
+                self.$real_func(x)
+            }
+        }
+
+        impl ops::$assign_trait for $complex {
+            fn $assign_method(&mut self, other: $complex) {
+                *self = $complex::$func(self, &other);
+            }
+        }
+
+        impl ops::$assign_trait<$real> for $complex {
+            fn $assign_method(&mut self, x: $real) {
+                *self = self.$real_func(x);
+            }
+        }
+    };
+}
+
+complex_ops!(ComplexF64, f64);
+complex_ops!(ComplexF32, f32);
+
+// `ComplexF64`/`ComplexF32` and `num_complex::Complex` are both `#[repr(C)]` pairs of floats (real
+// part first), so the conversions are plain moves and slices can be reinterpreted in place.
+#[cfg(feature = "num-complex")]
+macro_rules! num_complex_conversions {
+    ($complex:ident, $real:ident) => {
+        impl From<::num_complex::Complex<$real>> for $complex {
+            fn from(z: ::num_complex::Complex<$real>) -> $complex {
+                $complex { dat: [z.re, z.im] }
+            }
+        }
+
+        impl From<$complex> for ::num_complex::Complex<$real> {
+            fn from(z: $complex) -> ::num_complex::Complex<$real> {
+                ::num_complex::Complex::new(z.dat[0], z.dat[1])
+            }
+        }
+
+        impl $complex {
+            /// Reinterprets a slice of complex numbers as a slice of `num_complex` ones, without
+            /// copying.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "num-complex")))]
+            pub fn as_num_complex_slice(s: &[$complex]) -> &[::num_complex::Complex<$real>] {
+                unsafe { ::std::slice::from_raw_parts(s.as_ptr() as *const _, s.len()) }
+            }
+
+            /// Mutable version of [`as_num_complex_slice`](Self::as_num_complex_slice).
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "num-complex")))]
+            pub fn as_num_complex_slice_mut(
+                s: &mut [$complex],
+            ) -> &mut [::num_complex::Complex<$real>] {
+                unsafe { ::std::slice::from_raw_parts_mut(s.as_mut_ptr() as *mut _, s.len()) }
+            }
+
+            /// Reinterprets a slice of `num_complex` numbers as a slice of this type, without
+            /// copying.
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "num-complex")))]
+            pub fn from_num_complex_slice(s: &[::num_complex::Complex<$real>]) -> &[$complex] {
+                unsafe { ::std::slice::from_raw_parts(s.as_ptr() as *const _, s.len()) }
+            }
+
+            /// Mutable version of [`from_num_complex_slice`](Self::from_num_complex_slice).
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "num-complex")))]
+            pub fn from_num_complex_slice_mut(
+                s: &mut [::num_complex::Complex<$real>],
+            ) -> &mut [$complex] {
+                unsafe { ::std::slice::from_raw_parts_mut(s.as_mut_ptr() as *mut _, s.len()) }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "num-complex")]
+num_complex_conversions!(ComplexF64, f64);
+#[cfg(feature = "num-complex")]
+num_complex_conversions!(ComplexF32, f32);
+
 // All these tests have been tested against the following C code:
 //
 // ```ignore
@@ -1813,3 +1956,49 @@ fn complex_f32() {
         "0.1493 -0.1336".to_owned()
     );
 }
+
+#[test]
+fn complex_ops() {
+    let a = ComplexF64::rect(3., -2.);
+    let b = ComplexF64::rect(1., 4.);
+
+    assert_eq!(a + b, a.add(&b));
+    assert_eq!(a - b, a.sub(&b));
+    assert_eq!(a * b, a.mul(&b));
+    assert_eq!(a / b, a.div(&b));
+    assert_eq!(-a, ComplexF64::rect(-3., 2.));
+    assert_eq!(a * 2., ComplexF64::rect(6., -4.));
+    assert_eq!(2. * a, ComplexF64::rect(6., -4.));
+    assert_eq!(1. - a, ComplexF64::rect(-2., 2.));
+
+    let mut c = a;
+    c += b;
+    c *= 2.;
+    assert_eq!(c, ComplexF64::rect(8., 4.));
+
+    let a = ComplexF32::rect(3., -2.);
+    let b = ComplexF32::rect(1., 4.);
+    assert_eq!(a + b * 2., ComplexF32::rect(5., 6.));
+}
+
+#[cfg(feature = "num-complex")]
+#[test]
+fn num_complex_conversions() {
+    use num_complex::Complex64;
+
+    let z = ComplexF64::rect(3., -2.);
+    let n: Complex64 = z.into();
+    assert_eq!(n, Complex64::new(3., -2.));
+    assert_eq!(ComplexF64::from(n), z);
+
+    let mut data = [z, ComplexF64::rect(1., 4.)];
+    ComplexF64::as_num_complex_slice_mut(&mut data)[1].im = 5.;
+    assert_eq!(
+        ComplexF64::as_num_complex_slice(&data),
+        &[Complex64::new(3., -2.), Complex64::new(1., 5.)]
+    );
+    assert_eq!(
+        ComplexF64::from_num_complex_slice(ComplexF64::as_num_complex_slice(&data)),
+        &data
+    );
+}
@@ -0,0 +1,1338 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Containers
+
+The owned vectors and matrices (`VectorF64`, `MatrixF64`, ...) and the views borrowing their data
+(`VectorF64View`, `VectorF64ViewMut`, `MatrixF64View`, ...) all describe the same underlying
+`gsl_vector` or `gsl_matrix` structure. The traits of this module are implemented by all of them, so
+that functions like the BLAS or linear algebra ones accept both owned containers and views:
+
+```no_run
+use rgsl::{blas, MatrixF64, VectorF64};
+
+let mut m = MatrixF64::new(3, 3).expect("MatrixF64::new failed");
+m.set_identity();
+let v = VectorF64::from_slice(&[1., 2., 3.]).expect("VectorF64::from_slice failed");
+
+let row = m.row(1).expect("row out of range");
+let (_, dot) = blas::level1::ddot(&row, &v);
+assert_eq!(dot, 2.);
+```
+
+These traits are sealed: they cannot be implemented outside of this crate.
+
+The arithmetic operators of `std::ops` are implemented for the containers as well. The operations
+between two vectors, and the additions and subtractions between two matrices, are elementwise,
+while the products of a matrix by a matrix or by a vector are computed with BLAS (`gemm` and
+`gemv`):
+
+```no_run
+use rgsl::{MatrixF64, VectorF64};
+
+let mut m = MatrixF64::new(2, 2).expect("MatrixF64::new failed");
+m.set_identity();
+let v = VectorF64::from_slice(&[1., 2.]).expect("VectorF64::from_slice failed");
+
+let w = &m * &v + &v * 2.;
+let m2 = &m * &m - 1.;
+```
+
+These operators panic if the shapes of the operands don't match, and the divisions of integer
+containers panic on a zero divisor, like Rust's `/`. The named methods (`add`, `sub`,
+`mul`, ...) report such errors through their returned `Value` instead.
+!*/
+
+#![macro_use]
+
+use types::{ComplexF32, ComplexF64};
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// Types which can be stored in a GSL vector or matrix.
+pub trait Element: sealed::Sealed + Copy {
+    #[doc(hidden)]
+    type RawVector;
+    #[doc(hidden)]
+    type RawMatrix;
+
+    /// Returns the data pointer, the stride and the size of the vector.
+    #[doc(hidden)]
+    unsafe fn vector_parts(v: *const Self::RawVector) -> (*mut Self, usize, usize);
+
+    /// Returns the data pointer, the number of rows, the number of columns and the physical row
+    /// dimension (`tda`) of the matrix.
+    #[doc(hidden)]
+    unsafe fn matrix_parts(m: *const Self::RawMatrix) -> (*mut Self, usize, usize, usize);
+
+    /// Returns zero for the integer types, whose division by zero is undefined in GSL.
+    #[doc(hidden)]
+    fn integer_zero() -> Option<Self> {
+        None
+    }
+}
+
+macro_rules! impl_element {
+    ($ty:ty, $vec:ident, $mat:ident $(, $zero:expr)?) => {
+        impl sealed::Sealed for $ty {}
+
+        impl Element for $ty {
+            type RawVector = sys::$vec;
+            type RawMatrix = sys::$mat;
+
+            unsafe fn vector_parts(v: *const Self::RawVector) -> (*mut Self, usize, usize) {
+                let v = &*v;
+                (v.data as *mut Self, v.stride, v.size)
+            }
+
+            unsafe fn matrix_parts(m: *const Self::RawMatrix) -> (*mut Self, usize, usize, usize) {
+                let m = &*m;
+                (m.data as *mut Self, m.size1, m.size2, m.tda)
+            }
+
+            $(
+            fn integer_zero() -> Option<Self> {
+                Some($zero)
+            }
+            )?
+        }
+    };
+}
+
+impl_element!(f32, gsl_vector_float, gsl_matrix_float);
+impl_element!(f64, gsl_vector, gsl_matrix);
+impl_element!(i32, gsl_vector_int, gsl_matrix_int, 0);
+impl_element!(u32, gsl_vector_uint, gsl_matrix_uint, 0);
+impl_element!(
+    ComplexF32,
+    gsl_vector_complex_float,
+    gsl_matrix_complex_float
+);
+impl_element!(ComplexF64, gsl_vector_complex, gsl_matrix_complex);
+
+/// Implemented by the owned vectors and by both the shared and mutable vector views.
+///
+/// Together with [`Element`], this trait allows to write code working on any GSL vector:
+///
+/// ```no_run
+/// use rgsl::{Element, GslVector, VectorF32, VectorI32};
+/// use std::ops::Add;
+///
+/// fn sum<T: Element + Add<Output = T> + Default>(v: &impl GslVector<T>) -> T {
+///     (0..v.len()).fold(T::default(), |acc, i| acc + v.get(i))
+/// }
+///
+/// let v = VectorI32::from_slice(&[1, 2, 3]).expect("VectorI32::from_slice failed");
+/// assert_eq!(sum(&v), 6);
+/// let v = VectorF32::from_slice(&[1., 2., 3.]).expect("VectorF32::from_slice failed");
+/// assert_eq!(sum(&v.subvector(1, 2).expect("subvector failed")), 5.);
+/// ```
+pub trait GslVector<T: Element>: sealed::Sealed {
+    #[doc(hidden)]
+    fn unwrap_shared(&self) -> *const T::RawVector;
+
+    /// Returns the number of elements of the vector.
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the step-size from one element to the next in memory.
+    fn stride(&self) -> usize {
+        unsafe { T::vector_parts(self.unwrap_shared()).1 }
+    }
+
+    /// Returns the `i`-th element of the vector.
+    ///
+    /// Panics if `i` is out of range.
+    fn get(&self, i: usize) -> T {
+        let (data, stride, size) = unsafe { T::vector_parts(self.unwrap_shared()) };
+        assert!(i < size, "index out of range: {} >= {}", i, size);
+        unsafe { *data.add(i * stride) }
+    }
+}
+
+/// Implemented by the owned vectors and by the mutable vector views.
+pub trait GslVectorMut<T: Element>: GslVector<T> {
+    #[doc(hidden)]
+    fn unwrap_unique(&mut self) -> *mut T::RawVector;
+
+    /// Sets the `i`-th element of the vector to `x`.
+    ///
+    /// Panics if `i` is out of range.
+    fn set(&mut self, i: usize, x: T) {
+        let (data, stride, size) = unsafe { T::vector_parts(self.unwrap_unique()) };
+        assert!(i < size, "index out of range: {} >= {}", i, size);
+        unsafe { *data.add(i * stride) = x };
+    }
+}
+
+/// Implemented by the owned matrices and by both the shared and mutable matrix views.
+pub trait GslMatrix<T: Element>: sealed::Sealed {
+    #[doc(hidden)]
+    fn unwrap_shared(&self) -> *const T::RawMatrix;
+
+    /// Returns the number of rows of the matrix.
+    fn size1(&self) -> usize;
+
+    /// Returns the number of columns of the matrix.
+    fn size2(&self) -> usize;
+
+    /// Returns the element `(i, j)` of the matrix.
+    ///
+    /// Panics if `(i, j)` is out of range.
+    fn get(&self, i: usize, j: usize) -> T {
+        let (data, size1, size2, tda) = unsafe { T::matrix_parts(self.unwrap_shared()) };
+        assert!(
+            i < size1 && j < size2,
+            "index out of range: ({}, {}) not in {}x{}",
+            i,
+            j,
+            size1,
+            size2,
+        );
+        unsafe { *data.add(i * tda + j) }
+    }
+}
+
+/// Implemented by the owned matrices and by the mutable matrix views.
+pub trait GslMatrixMut<T: Element>: GslMatrix<T> {
+    #[doc(hidden)]
+    fn unwrap_unique(&mut self) -> *mut T::RawMatrix;
+
+    /// Sets the element `(i, j)` of the matrix to `x`.
+    ///
+    /// Panics if `(i, j)` is out of range.
+    fn set(&mut self, i: usize, j: usize, x: T) {
+        let (data, size1, size2, tda) = unsafe { T::matrix_parts(self.unwrap_unique()) };
+        assert!(
+            i < size1 && j < size2,
+            "index out of range: ({}, {}) not in {}x{}",
+            i,
+            j,
+            size1,
+            size2,
+        );
+        unsafe { *data.add(i * tda + j) = x };
+    }
+}
+
+// The following macros generate the methods returning views which are shared by the owned
+// containers and their views. `$width` is the number of scalars making up one element (2 for the
+// complex types).
+
+#[doc(hidden)]
+macro_rules! shared_vector_views {
+    ($name:ident, $elem:ty, $view:ident) => {
+        paste! {
+        /// Returns a view of the `n` elements of the vector starting at `offset`. Mathematically,
+        /// the i-th element of the new vector v' is given by:
+        ///
+        /// ```text
+        /// v'(i) = v->data[(offset + i)*v->stride]
+        /// ```
+        ///
+        /// Returns `None` if `offset` and `n` overrun the end of the vector.
+        #[doc(alias = $name _const_subvector)]
+        pub fn subvector(&self, offset: usize, n: usize) -> Option<$view<'_>> {
+            $view::from_raw(unsafe {
+                sys::[<$name _const_subvector>](
+                    <Self as ::types::GslVector<$elem>>::unwrap_shared(self),
+                    offset,
+                    n,
+                )
+                .vector
+            })
+        }
+
+        /// Returns a view of `n` elements of the vector starting at `offset` and with a step-size
+        /// of `stride` from one element to the next. Mathematically, the i-th element of the new
+        /// vector v' is given by:
+        ///
+        /// ```text
+        /// v'(i) = v->data[(offset + i*stride)*v->stride]
+        /// ```
+        ///
+        /// Returns `None` if the parameters overrun the end of the vector.
+        #[doc(alias = $name _const_subvector_with_stride)]
+        pub fn subvector_with_stride(
+            &self,
+            offset: usize,
+            stride: usize,
+            n: usize,
+        ) -> Option<$view<'_>> {
+            $view::from_raw(unsafe {
+                sys::[<$name _const_subvector_with_stride>](
+                    <Self as ::types::GslVector<$elem>>::unwrap_shared(self),
+                    offset,
+                    stride,
+                    n,
+                )
+                .vector
+            })
+        }
+        }
+    };
+}
+
+#[doc(hidden)]
+macro_rules! mut_vector_views {
+    ($name:ident, $elem:ty, $width:expr, $view_mut:ident) => {
+        paste! {
+        /// Same as `subvector` but the returned view allows to modify the elements.
+        #[doc(alias = $name _subvector)]
+        pub fn subvector_mut(&mut self, offset: usize, n: usize) -> Option<$view_mut<'_>> {
+            $view_mut::from_raw(unsafe {
+                sys::[<$name _subvector>](
+                    <Self as ::types::GslVectorMut<$elem>>::unwrap_unique(self),
+                    offset,
+                    n,
+                )
+                .vector
+            })
+        }
+
+        /// Same as `subvector_with_stride` but the returned view allows to modify the elements.
+        #[doc(alias = $name _subvector_with_stride)]
+        pub fn subvector_with_stride_mut(
+            &mut self,
+            offset: usize,
+            stride: usize,
+            n: usize,
+        ) -> Option<$view_mut<'_>> {
+            $view_mut::from_raw(unsafe {
+                sys::[<$name _subvector_with_stride>](
+                    <Self as ::types::GslVectorMut<$elem>>::unwrap_unique(self),
+                    offset,
+                    stride,
+                    n,
+                )
+                .vector
+            })
+        }
+
+        /// Divides the vector into two mutable views: the first one contains the elements
+        /// `[0, mid)` and the second one the elements `[mid, len)`.
+        ///
+        /// Panics if `mid > len`.
+        pub fn split_at_mut(&mut self, mid: usize) -> ($view_mut<'_>, $view_mut<'_>) {
+            let v = unsafe { *<Self as ::types::GslVectorMut<$elem>>::unwrap_unique(self) };
+            assert!(mid <= v.size, "split_at_mut: mid > len");
+
+            let mut head = v;
+            head.size = mid;
+            head.owner = 0;
+            let mut tail = head;
+            tail.size = v.size - mid;
+            tail.data = v.data.wrapping_add(mid * v.stride * $width);
+            (
+                $view_mut {
+                    v: head,
+                    phantom: PhantomData,
+                },
+                $view_mut {
+                    v: tail,
+                    phantom: PhantomData,
+                },
+            )
+        }
+        }
+    };
+}
+
+#[doc(hidden)]
+macro_rules! shared_matrix_views {
+    ($name:ident, $elem:ty, $vec_view:ident, $view:ident) => {
+        paste! {
+        /// Returns a view of the `i`-th row of the matrix, or `None` if `i` is out of range.
+        #[doc(alias = $name _const_row)]
+        pub fn row(&self, i: usize) -> Option<$vec_view<'_>> {
+            $vec_view::from_raw(unsafe {
+                sys::[<$name _const_row>](
+                    <Self as ::types::GslMatrix<$elem>>::unwrap_shared(self),
+                    i,
+                )
+                .vector
+            })
+        }
+
+        /// Returns a view of the `j`-th column of the matrix, or `None` if `j` is out of range.
+        #[doc(alias = $name _const_column)]
+        pub fn column(&self, j: usize) -> Option<$vec_view<'_>> {
+            $vec_view::from_raw(unsafe {
+                sys::[<$name _const_column>](
+                    <Self as ::types::GslMatrix<$elem>>::unwrap_shared(self),
+                    j,
+                )
+                .vector
+            })
+        }
+
+        /// Returns a view of the diagonal of the matrix. The matrix is not required to be square.
+        /// For a rectangular matrix the length of the diagonal is the same as the smaller dimension
+        /// of the matrix.
+        #[doc(alias = $name _const_diagonal)]
+        pub fn diagonal(&self) -> Option<$vec_view<'_>> {
+            $vec_view::from_raw(unsafe {
+                sys::[<$name _const_diagonal>](
+                    <Self as ::types::GslMatrix<$elem>>::unwrap_shared(self),
+                )
+                .vector
+            })
+        }
+
+        /// Returns a view of the `k`-th subdiagonal of the matrix. The diagonal of the matrix
+        /// corresponds to `k = 0`.
+        #[doc(alias = $name _const_subdiagonal)]
+        pub fn subdiagonal(&self, k: usize) -> Option<$vec_view<'_>> {
+            $vec_view::from_raw(unsafe {
+                sys::[<$name _const_subdiagonal>](
+                    <Self as ::types::GslMatrix<$elem>>::unwrap_shared(self),
+                    k,
+                )
+                .vector
+            })
+        }
+
+        /// Returns a view of the `k`-th superdiagonal of the matrix. The diagonal of the matrix
+        /// corresponds to `k = 0`.
+        #[doc(alias = $name _const_superdiagonal)]
+        pub fn superdiagonal(&self, k: usize) -> Option<$vec_view<'_>> {
+            $vec_view::from_raw(unsafe {
+                sys::[<$name _const_superdiagonal>](
+                    <Self as ::types::GslMatrix<$elem>>::unwrap_shared(self),
+                    k,
+                )
+                .vector
+            })
+        }
+
+        /// Returns a view of the `n` elements of the `i`-th row of the matrix, starting at column
+        /// `offset`.
+        #[doc(alias = $name _const_subrow)]
+        pub fn subrow(&self, i: usize, offset: usize, n: usize) -> Option<$vec_view<'_>> {
+            $vec_view::from_raw(unsafe {
+                sys::[<$name _const_subrow>](
+                    <Self as ::types::GslMatrix<$elem>>::unwrap_shared(self),
+                    i,
+                    offset,
+                    n,
+                )
+                .vector
+            })
+        }
+
+        /// Returns a view of the `n` elements of the `j`-th column of the matrix, starting at row
+        /// `offset`.
+        #[doc(alias = $name _const_subcolumn)]
+        pub fn subcolumn(&self, j: usize, offset: usize, n: usize) -> Option<$vec_view<'_>> {
+            $vec_view::from_raw(unsafe {
+                sys::[<$name _const_subcolumn>](
+                    <Self as ::types::GslMatrix<$elem>>::unwrap_shared(self),
+                    j,
+                    offset,
+                    n,
+                )
+                .vector
+            })
+        }
+
+        /// Returns a view of a submatrix of the matrix. The upper-left element of the submatrix
+        /// is the element `(k1, k2)` of the original matrix. The submatrix has `n1` rows and `n2`
+        /// columns. Mathematically, the (i,j)-th element of the new matrix is given by:
+        ///
+        /// ```text
+        /// m'(i,j) = m->data[(k1*m->tda + k2) + i*m->tda + j]
+        /// ```
+        ///
+        /// Returns `None` if the parameters overrun the ends of the matrix.
+        #[doc(alias = $name _const_submatrix)]
+        pub fn submatrix(&self, k1: usize, k2: usize, n1: usize, n2: usize) -> Option<$view<'_>> {
+            $view::from_raw(unsafe {
+                sys::[<$name _const_submatrix>](
+                    <Self as ::types::GslMatrix<$elem>>::unwrap_shared(self),
+                    k1,
+                    k2,
+                    n1,
+                    n2,
+                )
+                .matrix
+            })
+        }
+        }
+    };
+}
+
+#[doc(hidden)]
+macro_rules! mut_matrix_views {
+    ($name:ident, $elem:ty, $width:expr, $vec_view_mut:ident, $view_mut:ident) => {
+        paste! {
+        /// Same as `row` but the returned view allows to modify the elements.
+        #[doc(alias = $name _row)]
+        pub fn row_mut(&mut self, i: usize) -> Option<$vec_view_mut<'_>> {
+            $vec_view_mut::from_raw(unsafe {
+                sys::[<$name _row>](
+                    <Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self),
+                    i,
+                )
+                .vector
+            })
+        }
+
+        /// Same as `column` but the returned view allows to modify the elements.
+        #[doc(alias = $name _column)]
+        pub fn column_mut(&mut self, j: usize) -> Option<$vec_view_mut<'_>> {
+            $vec_view_mut::from_raw(unsafe {
+                sys::[<$name _column>](
+                    <Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self),
+                    j,
+                )
+                .vector
+            })
+        }
+
+        /// Same as `diagonal` but the returned view allows to modify the elements.
+        #[doc(alias = $name _diagonal)]
+        pub fn diagonal_mut(&mut self) -> Option<$vec_view_mut<'_>> {
+            $vec_view_mut::from_raw(unsafe {
+                sys::[<$name _diagonal>](
+                    <Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self),
+                )
+                .vector
+            })
+        }
+
+        /// Same as `subdiagonal` but the returned view allows to modify the elements.
+        #[doc(alias = $name _subdiagonal)]
+        pub fn subdiagonal_mut(&mut self, k: usize) -> Option<$vec_view_mut<'_>> {
+            $vec_view_mut::from_raw(unsafe {
+                sys::[<$name _subdiagonal>](
+                    <Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self),
+                    k,
+                )
+                .vector
+            })
+        }
+
+        /// Same as `superdiagonal` but the returned view allows to modify the elements.
+        #[doc(alias = $name _superdiagonal)]
+        pub fn superdiagonal_mut(&mut self, k: usize) -> Option<$vec_view_mut<'_>> {
+            $vec_view_mut::from_raw(unsafe {
+                sys::[<$name _superdiagonal>](
+                    <Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self),
+                    k,
+                )
+                .vector
+            })
+        }
+
+        /// Same as `subrow` but the returned view allows to modify the elements.
+        #[doc(alias = $name _subrow)]
+        pub fn subrow_mut(&mut self, i: usize, offset: usize, n: usize) -> Option<$vec_view_mut<'_>> {
+            $vec_view_mut::from_raw(unsafe {
+                sys::[<$name _subrow>](
+                    <Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self),
+                    i,
+                    offset,
+                    n,
+                )
+                .vector
+            })
+        }
+
+        /// Same as `subcolumn` but the returned view allows to modify the elements.
+        #[doc(alias = $name _subcolumn)]
+        pub fn subcolumn_mut(
+            &mut self,
+            j: usize,
+            offset: usize,
+            n: usize,
+        ) -> Option<$vec_view_mut<'_>> {
+            $vec_view_mut::from_raw(unsafe {
+                sys::[<$name _subcolumn>](
+                    <Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self),
+                    j,
+                    offset,
+                    n,
+                )
+                .vector
+            })
+        }
+
+        /// Same as `submatrix` but the returned view allows to modify the elements.
+        #[doc(alias = $name _submatrix)]
+        pub fn submatrix_mut(
+            &mut self,
+            k1: usize,
+            k2: usize,
+            n1: usize,
+            n2: usize,
+        ) -> Option<$view_mut<'_>> {
+            $view_mut::from_raw(unsafe {
+                sys::[<$name _submatrix>](
+                    <Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self),
+                    k1,
+                    k2,
+                    n1,
+                    n2,
+                )
+                .matrix
+            })
+        }
+
+        /// Returns mutable views of all the rows of the matrix. Since the rows don't overlap,
+        /// they can all be modified at the same time.
+        pub fn rows_mut(&mut self) -> Vec<$vec_view_mut<'_>> {
+            let m = <Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self);
+            let size1 = unsafe { (*m).size1 };
+            (0..size1)
+                .filter_map(|i| $vec_view_mut::from_raw(unsafe { sys::[<$name _row>](m, i).vector }))
+                .collect()
+        }
+
+        /// Returns mutable views of all the columns of the matrix. Since the columns don't
+        /// overlap, they can all be modified at the same time.
+        pub fn columns_mut(&mut self) -> Vec<$vec_view_mut<'_>> {
+            let m = <Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self);
+            let size2 = unsafe { (*m).size2 };
+            (0..size2)
+                .filter_map(|j| {
+                    $vec_view_mut::from_raw(unsafe { sys::[<$name _column>](m, j).vector })
+                })
+                .collect()
+        }
+
+        /// Divides the matrix into two mutable views: the first one contains the rows `[0, mid)`
+        /// and the second one the rows `[mid, size1)`.
+        ///
+        /// Panics if `mid > size1`.
+        pub fn split_at_row_mut(&mut self, mid: usize) -> ($view_mut<'_>, $view_mut<'_>) {
+            let m = unsafe { *<Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self) };
+            assert!(mid <= m.size1, "split_at_row_mut: mid > size1");
+
+            let mut top = m;
+            top.size1 = mid;
+            top.owner = 0;
+            let mut bottom = top;
+            bottom.size1 = m.size1 - mid;
+            bottom.data = m.data.wrapping_add(mid * m.tda * $width);
+            (
+                $view_mut {
+                    m: top,
+                    phantom: PhantomData,
+                },
+                $view_mut {
+                    m: bottom,
+                    phantom: PhantomData,
+                },
+            )
+        }
+
+        /// Divides the matrix into two mutable views: the first one contains the columns
+        /// `[0, mid)` and the second one the columns `[mid, size2)`.
+        ///
+        /// Panics if `mid > size2`.
+        pub fn split_at_column_mut(&mut self, mid: usize) -> ($view_mut<'_>, $view_mut<'_>) {
+            let m = unsafe { *<Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self) };
+            assert!(mid <= m.size2, "split_at_column_mut: mid > size2");
+
+            let mut left = m;
+            left.size2 = mid;
+            left.owner = 0;
+            let mut right = left;
+            right.size2 = m.size2 - mid;
+            right.data = m.data.wrapping_add(mid * $width);
+            (
+                $view_mut {
+                    m: left,
+                    phantom: PhantomData,
+                },
+                $view_mut {
+                    m: right,
+                    phantom: PhantomData,
+                },
+            )
+        }
+        }
+    };
+}
+
+// The following macros implement the arithmetic operators of `std::ops` for the containers. The
+// operations between two containers are elementwise, except for the matrix products which rely
+// on BLAS. They all panic if the shapes of the operands don't match.
+
+#[doc(hidden)]
+macro_rules! vector_ops {
+    ($name:ident, $elem:ty, $owned:ident, $view:ident, $view_mut:ident) => {
+        vector_ops!(@assign $name, $elem, $owned);
+        vector_ops!(@assign $name, $elem, $view_mut<'v>);
+        vector_ops!(@binary $elem, $owned, $owned);
+        vector_ops!(@binary $elem, $owned, $view<'v>);
+        vector_ops!(@binary $elem, $owned, $view_mut<'v>);
+
+        vector_ops!(@by_value $elem, $owned, Add, add, +=);
+        vector_ops!(@by_value $elem, $owned, Sub, sub, -=);
+        vector_ops!(@by_value $elem, $owned, Mul, mul, *=);
+        vector_ops!(@by_value $elem, $owned, Div, div, /=);
+    };
+    (@neg $name:ident, $elem:ty, $owned:ident, $view:ident, $view_mut:ident) => {
+        paste! {
+        impl ::std::ops::Neg for $owned {
+            type Output = $owned;
+
+            fn neg(mut self) -> $owned {
+                let v = <Self as ::types::GslVectorMut<$elem>>::unwrap_unique(&mut self);
+                for i in 0..<Self as ::types::GslVector<$elem>>::len(&self) {
+                    unsafe {
+                        let e = sys::[<$name _ptr>](v, i) as *mut $elem;
+                        *e = -*e;
+                    }
+                }
+                self
+            }
+        }
+        }
+
+        vector_ops!(@neg_ref $elem, $owned, $owned);
+        vector_ops!(@neg_ref $elem, $owned, $view<'v>);
+        vector_ops!(@neg_ref $elem, $owned, $view_mut<'v>);
+    };
+    (@neg_ref $elem:ty, $owned:ident, $target:ident $(<$lt:lifetime>)?) => {
+        impl<'l, $($lt)?> ::std::ops::Neg for &'l $target$(<$lt>)? {
+            type Output = $owned;
+
+            fn neg(self) -> $owned {
+                -vector_ops!(@to_owned $elem, $owned, self)
+            }
+        }
+    };
+    (@to_owned $elem:ty, $owned:ident, $v:expr) => {{
+        let mut out = $owned::new(<_ as ::types::GslVector<$elem>>::len($v))
+            .expect(concat!(stringify!($owned), "::new failed"));
+        out.copy_from($v);
+        out
+    }};
+    (@assign $name:ident, $elem:ty, $target:ident $(<$lt:lifetime>)?) => {
+        vector_ops!(@assign_vec $name, $elem, $target$(<$lt>)?, AddAssign, add_assign, _add);
+        vector_ops!(@assign_vec $name, $elem, $target$(<$lt>)?, SubAssign, sub_assign, _sub);
+        vector_ops!(@assign_vec $name, $elem, $target$(<$lt>)?, MulAssign, mul_assign, _mul);
+        vector_ops!(@assign_vec $name, $elem, $target$(<$lt>)?, DivAssign, div_assign, _div);
+        vector_ops!(@assign_scalar $name, $elem, $target$(<$lt>)?, AddAssign, add_assign, +);
+        vector_ops!(@assign_scalar $name, $elem, $target$(<$lt>)?, SubAssign, sub_assign, -);
+        vector_ops!(@assign_scalar $name, $elem, $target$(<$lt>)?, MulAssign, mul_assign, *);
+        vector_ops!(@assign_scalar $name, $elem, $target$(<$lt>)?, DivAssign, div_assign, /);
+    };
+    (@assign_vec $name:ident, $elem:ty, $target:ident $(<$lt:lifetime>)?,
+     $trait:ident, $method:ident, $suffix:ident) => {
+        paste! {
+        impl<'r, $($lt,)? R: ::types::GslVector<$elem>> ::std::ops::$trait<&'r R>
+            for $target$(<$lt>)?
+        {
+            fn $method(&mut self, rhs: &'r R) {
+                let len = <Self as ::types::GslVector<$elem>>::len(self);
+                assert!(
+                    len == rhs.len(),
+                    "{}: the vectors have different lengths ({} != {})",
+                    stringify!($method),
+                    len,
+                    rhs.len(),
+                );
+                vector_ops!(@check_divisor $elem, $method, rhs, len);
+                unsafe {
+                    sys::[<$name $suffix>](
+                        <Self as ::types::GslVectorMut<$elem>>::unwrap_unique(self),
+                        rhs.unwrap_shared(),
+                    );
+                }
+            }
+        }
+        }
+    };
+    // Like Rust's `/`, the integer divisions panic on a zero divisor instead of reaching the
+    // undefined behaviour of GSL.
+    (@check_divisor $elem:ty, div_assign, $rhs:expr, $len:expr) => {
+        if let Some(zero) = <$elem as ::types::Element>::integer_zero() {
+            assert!(
+                (0..$len).all(|i| $rhs.get(i) != zero),
+                "attempt to divide by zero"
+            );
+        }
+    };
+    (@check_divisor $elem:ty, $method:ident, $rhs:expr, $len:expr) => {};
+    (@assign_scalar $name:ident, $elem:ty, $target:ident $(<$lt:lifetime>)?,
+     $trait:ident, $method:ident, $op:tt) => {
+        paste! {
+        impl<$($lt)?> ::std::ops::$trait<$elem> for $target$(<$lt>)? {
+            fn $method(&mut self, rhs: $elem) {
+                let v = <Self as ::types::GslVectorMut<$elem>>::unwrap_unique(self);
+                for i in 0..<Self as ::types::GslVector<$elem>>::len(self) {
+                    unsafe {
+                        let e = sys::[<$name _ptr>](v, i) as *mut $elem;
+                        *e = *e $op rhs;
+                    }
+                }
+            }
+        }
+        }
+    };
+    (@binary $elem:ty, $owned:ident, $target:ident $(<$lt:lifetime>)?) => {
+        vector_ops!(@binary_op $elem, $owned, $target$(<$lt>)?, Add, add, +=);
+        vector_ops!(@binary_op $elem, $owned, $target$(<$lt>)?, Sub, sub, -=);
+        vector_ops!(@binary_op $elem, $owned, $target$(<$lt>)?, Mul, mul, *=);
+        vector_ops!(@binary_op $elem, $owned, $target$(<$lt>)?, Div, div, /=);
+        vector_ops!(@scalar_lhs $elem, $owned, $target$(<$lt>)?, Add, add);
+        vector_ops!(@scalar_lhs $elem, $owned, $target$(<$lt>)?, Mul, mul);
+    };
+    (@binary_op $elem:ty, $owned:ident, $target:ident $(<$lt:lifetime>)?,
+     $trait:ident, $method:ident, $op:tt) => {
+        impl<'l, 'r, $($lt,)? R: ::types::GslVector<$elem>> ::std::ops::$trait<&'r R>
+            for &'l $target$(<$lt>)?
+        {
+            type Output = $owned;
+
+            fn $method(self, rhs: &'r R) -> $owned {
+                let mut out = vector_ops!(@to_owned $elem, $owned, self);
+                out $op rhs;
+                out
+            }
+        }
+
+        impl<'l, $($lt)?> ::std::ops::$trait<$owned> for &'l $target$(<$lt>)? {
+            type Output = $owned;
+
+            fn $method(self, rhs: $owned) -> $owned {
+                ::std::ops::$trait::$method(self, &rhs)
+            }
+        }
+
+        impl<'l, $($lt)?> ::std::ops::$trait<$elem> for &'l $target$(<$lt>)? {
+            type Output = $owned;
+
+            fn $method(self, rhs: $elem) -> $owned {
+                let mut out = vector_ops!(@to_owned $elem, $owned, self);
+                out $op rhs;
+                out
+            }
+        }
+    };
+    (@scalar_lhs $elem:ty, $owned:ident, $target:ident $(<$lt:lifetime>)?,
+     $trait:ident, $method:ident) => {
+        impl<'l, $($lt)?> ::std::ops::$trait<&'l $target$(<$lt>)?> for $elem {
+            type Output = $owned;
+
+            fn $method(self, rhs: &'l $target$(<$lt>)?) -> $owned {
+                ::std::ops::$trait::$method(rhs, self)
+            }
+        }
+    };
+    (@by_value $elem:ty, $owned:ident, $trait:ident, $method:ident, $op:tt) => {
+        impl<'r, R: ::types::GslVector<$elem>> ::std::ops::$trait<&'r R> for $owned {
+            type Output = $owned;
+
+            fn $method(mut self, rhs: &'r R) -> $owned {
+                self $op rhs;
+                self
+            }
+        }
+
+        impl ::std::ops::$trait for $owned {
+            type Output = $owned;
+
+            fn $method(mut self, rhs: $owned) -> $owned {
+                self $op &rhs;
+                self
+            }
+        }
+
+        impl ::std::ops::$trait<$elem> for $owned {
+            type Output = $owned;
+
+            fn $method(mut self, rhs: $elem) -> $owned {
+                self $op rhs;
+                self
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+macro_rules! matrix_ops {
+    ($name:ident, $elem:ty, $owned:ident, $view:ident, $view_mut:ident) => {
+        matrix_ops!(@assign $name, $elem, $owned);
+        matrix_ops!(@assign $name, $elem, $view_mut<'v>);
+        matrix_ops!(@binary $elem, $owned, $owned);
+        matrix_ops!(@binary $elem, $owned, $view<'v>);
+        matrix_ops!(@binary $elem, $owned, $view_mut<'v>);
+
+        matrix_ops!(@by_value $elem, $owned, Add, add, +=);
+        matrix_ops!(@by_value $elem, $owned, Sub, sub, -=);
+
+        matrix_ops!(@by_value_scalar $elem, $owned, Add, add, +=);
+        matrix_ops!(@by_value_scalar $elem, $owned, Sub, sub, -=);
+        matrix_ops!(@by_value_scalar $elem, $owned, Mul, mul, *=);
+        matrix_ops!(@by_value_scalar $elem, $owned, Div, div, /=);
+    };
+    (@neg $name:ident, $elem:ty, $owned:ident, $view:ident, $view_mut:ident) => {
+        paste! {
+        impl ::std::ops::Neg for $owned {
+            type Output = $owned;
+
+            fn neg(mut self) -> $owned {
+                let m = <Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(&mut self);
+                for i in 0..<Self as ::types::GslMatrix<$elem>>::size1(&self) {
+                    for j in 0..<Self as ::types::GslMatrix<$elem>>::size2(&self) {
+                        unsafe {
+                            let e = sys::[<$name _ptr>](m, i, j) as *mut $elem;
+                            *e = -*e;
+                        }
+                    }
+                }
+                self
+            }
+        }
+        }
+
+        matrix_ops!(@neg_ref $elem, $owned, $owned);
+        matrix_ops!(@neg_ref $elem, $owned, $view<'v>);
+        matrix_ops!(@neg_ref $elem, $owned, $view_mut<'v>);
+    };
+    (@neg_ref $elem:ty, $owned:ident, $target:ident $(<$lt:lifetime>)?) => {
+        impl<'l, $($lt)?> ::std::ops::Neg for &'l $target$(<$lt>)? {
+            type Output = $owned;
+
+            fn neg(self) -> $owned {
+                -matrix_ops!(@to_owned $elem, $owned, self)
+            }
+        }
+    };
+    (@to_owned $elem:ty, $owned:ident, $m:expr) => {{
+        let mut out = $owned::new(
+            <_ as ::types::GslMatrix<$elem>>::size1($m),
+            <_ as ::types::GslMatrix<$elem>>::size2($m),
+        )
+        .expect(concat!(stringify!($owned), "::new failed"));
+        out.copy_from($m);
+        out
+    }};
+    (@check $method:ident, $lhs:expr, $rhs:expr) => {
+        assert!(
+            $lhs == $rhs,
+            "{}: the matrices have different shapes ({:?} != {:?})",
+            stringify!($method),
+            $lhs,
+            $rhs,
+        );
+    };
+    (@assign $name:ident, $elem:ty, $target:ident $(<$lt:lifetime>)?) => {
+        matrix_ops!(@assign_mat $name, $elem, $target$(<$lt>)?, AddAssign, add_assign, _add);
+        matrix_ops!(@assign_mat $name, $elem, $target$(<$lt>)?, SubAssign, sub_assign, _sub);
+        matrix_ops!(@assign_scalar $name, $elem, $target$(<$lt>)?, AddAssign, add_assign, +);
+        matrix_ops!(@assign_scalar $name, $elem, $target$(<$lt>)?, SubAssign, sub_assign, -);
+        matrix_ops!(@assign_scalar $name, $elem, $target$(<$lt>)?, MulAssign, mul_assign, *);
+        matrix_ops!(@assign_scalar $name, $elem, $target$(<$lt>)?, DivAssign, div_assign, /);
+    };
+    (@assign_mat $name:ident, $elem:ty, $target:ident $(<$lt:lifetime>)?,
+     $trait:ident, $method:ident, $suffix:ident) => {
+        paste! {
+        impl<'r, $($lt,)? R: ::types::GslMatrix<$elem>> ::std::ops::$trait<&'r R>
+            for $target$(<$lt>)?
+        {
+            fn $method(&mut self, rhs: &'r R) {
+                matrix_ops!(
+                    @check $method,
+                    (
+                        <Self as ::types::GslMatrix<$elem>>::size1(self),
+                        <Self as ::types::GslMatrix<$elem>>::size2(self),
+                    ),
+                    (rhs.size1(), rhs.size2())
+                );
+                unsafe {
+                    sys::[<$name $suffix>](
+                        <Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self),
+                        rhs.unwrap_shared(),
+                    );
+                }
+            }
+        }
+        }
+    };
+    (@assign_scalar $name:ident, $elem:ty, $target:ident $(<$lt:lifetime>)?,
+     $trait:ident, $method:ident, $op:tt) => {
+        paste! {
+        impl<$($lt)?> ::std::ops::$trait<$elem> for $target$(<$lt>)? {
+            fn $method(&mut self, rhs: $elem) {
+                let m = <Self as ::types::GslMatrixMut<$elem>>::unwrap_unique(self);
+                for i in 0..<Self as ::types::GslMatrix<$elem>>::size1(self) {
+                    for j in 0..<Self as ::types::GslMatrix<$elem>>::size2(self) {
+                        unsafe {
+                            let e = sys::[<$name _ptr>](m, i, j) as *mut $elem;
+                            *e = *e $op rhs;
+                        }
+                    }
+                }
+            }
+        }
+        }
+    };
+    (@binary $elem:ty, $owned:ident, $target:ident $(<$lt:lifetime>)?) => {
+        matrix_ops!(@binary_mat $elem, $owned, $target$(<$lt>)?, Add, add, +=);
+        matrix_ops!(@binary_mat $elem, $owned, $target$(<$lt>)?, Sub, sub, -=);
+        matrix_ops!(@binary_scalar $elem, $owned, $target$(<$lt>)?, Add, add, +=);
+        matrix_ops!(@binary_scalar $elem, $owned, $target$(<$lt>)?, Sub, sub, -=);
+        matrix_ops!(@binary_scalar $elem, $owned, $target$(<$lt>)?, Mul, mul, *=);
+        matrix_ops!(@binary_scalar $elem, $owned, $target$(<$lt>)?, Div, div, /=);
+        matrix_ops!(@scalar_lhs $elem, $owned, $target$(<$lt>)?, Add, add);
+        matrix_ops!(@scalar_lhs $elem, $owned, $target$(<$lt>)?, Mul, mul);
+    };
+    (@binary_mat $elem:ty, $owned:ident, $target:ident $(<$lt:lifetime>)?,
+     $trait:ident, $method:ident, $op:tt) => {
+        impl<'l, 'r, $($lt,)? R: ::types::GslMatrix<$elem>> ::std::ops::$trait<&'r R>
+            for &'l $target$(<$lt>)?
+        {
+            type Output = $owned;
+
+            fn $method(self, rhs: &'r R) -> $owned {
+                let mut out = matrix_ops!(@to_owned $elem, $owned, self);
+                out $op rhs;
+                out
+            }
+        }
+
+        impl<'l, $($lt)?> ::std::ops::$trait<$owned> for &'l $target$(<$lt>)? {
+            type Output = $owned;
+
+            fn $method(self, rhs: $owned) -> $owned {
+                ::std::ops::$trait::$method(self, &rhs)
+            }
+        }
+    };
+    (@binary_scalar $elem:ty, $owned:ident, $target:ident $(<$lt:lifetime>)?,
+     $trait:ident, $method:ident, $op:tt) => {
+        impl<'l, $($lt)?> ::std::ops::$trait<$elem> for &'l $target$(<$lt>)? {
+            type Output = $owned;
+
+            fn $method(self, rhs: $elem) -> $owned {
+                let mut out = matrix_ops!(@to_owned $elem, $owned, self);
+                out $op rhs;
+                out
+            }
+        }
+    };
+    (@scalar_lhs $elem:ty, $owned:ident, $target:ident $(<$lt:lifetime>)?,
+     $trait:ident, $method:ident) => {
+        impl<'l, $($lt)?> ::std::ops::$trait<&'l $target$(<$lt>)?> for $elem {
+            type Output = $owned;
+
+            fn $method(self, rhs: &'l $target$(<$lt>)?) -> $owned {
+                ::std::ops::$trait::$method(rhs, self)
+            }
+        }
+    };
+    (@by_value $elem:ty, $owned:ident, $trait:ident, $method:ident, $op:tt) => {
+        impl<'r, R: ::types::GslMatrix<$elem>> ::std::ops::$trait<&'r R> for $owned {
+            type Output = $owned;
+
+            fn $method(mut self, rhs: &'r R) -> $owned {
+                self $op rhs;
+                self
+            }
+        }
+
+        impl ::std::ops::$trait for $owned {
+            type Output = $owned;
+
+            fn $method(mut self, rhs: $owned) -> $owned {
+                self $op &rhs;
+                self
+            }
+        }
+    };
+    (@by_value_scalar $elem:ty, $owned:ident, $trait:ident, $method:ident, $op:tt) => {
+        impl ::std::ops::$trait<$elem> for $owned {
+            type Output = $owned;
+
+            fn $method(mut self, rhs: $elem) -> $owned {
+                self $op rhs;
+                self
+            }
+        }
+    };
+}
+
+// Matrix-matrix and matrix-vector products, computed with `$gemm` and `$gemv` from BLAS. The
+// operand types are given as `([generics] Type)` and the operators are implemented for references.
+#[doc(hidden)]
+macro_rules! matrix_product {
+    ($elem:ty, $owned:ident, $vec_owned:ident, $gemm:path, $gemv:path, $one:expr, $zero:expr,
+     [$($lhs:tt)*], $rhs_mat:tt, $rhs_vec:tt) => {
+        $(
+            matrix_product!(@rhs $elem, $owned, $vec_owned, $gemm, $gemv, $one, $zero,
+                            $lhs, $rhs_mat, $rhs_vec);
+        )*
+    };
+    (@rhs $elem:ty, $owned:ident, $vec_owned:ident, $gemm:path, $gemv:path, $one:expr, $zero:expr,
+     $lhs:tt, [$($rhs_mat:tt)*], [$($rhs_vec:tt)*]) => {
+        $(
+            matrix_product!(@mat $elem, $owned, $gemm, $one, $zero, $lhs, $rhs_mat);
+        )*
+        $(
+            matrix_product!(@vec $elem, $vec_owned, $gemv, $one, $zero, $lhs, $rhs_vec);
+        )*
+    };
+    (@mat $elem:ty, $owned:ident, $gemm:path, $one:expr, $zero:expr,
+     ([$($lhs_lt:tt)*] $lhs:ty), ([$($rhs_lt:tt)*] $rhs:ty)) => {
+        impl<'l, 'r, $($lhs_lt)* $($rhs_lt)*> ::std::ops::Mul<&'r $rhs> for &'l $lhs {
+            type Output = $owned;
+
+            fn mul(self, rhs: &'r $rhs) -> $owned {
+                let (n, k) = (
+                    <$lhs as ::types::GslMatrix<$elem>>::size1(self),
+                    <$lhs as ::types::GslMatrix<$elem>>::size2(self),
+                );
+                let (rk, m) = (
+                    <$rhs as ::types::GslMatrix<$elem>>::size1(rhs),
+                    <$rhs as ::types::GslMatrix<$elem>>::size2(rhs),
+                );
+                assert!(
+                    k == rk,
+                    "mul: cannot multiply a {}x{} matrix by a {}x{} matrix",
+                    n,
+                    k,
+                    rk,
+                    m,
+                );
+                let mut out = $owned::new(n, m).expect(concat!(stringify!($owned), "::new failed"));
+                $gemm(
+                    ::enums::CblasTranspose::NoTranspose,
+                    ::enums::CblasTranspose::NoTranspose,
+                    $one,
+                    self,
+                    rhs,
+                    $zero,
+                    &mut out,
+                );
+                out
+            }
+        }
+    };
+    (@vec $elem:ty, $vec_owned:ident, $gemv:path, $one:expr, $zero:expr,
+     ([$($lhs_lt:tt)*] $lhs:ty), ([$($rhs_lt:tt)*] $rhs:ty)) => {
+        impl<'l, 'r, $($lhs_lt)* $($rhs_lt)*> ::std::ops::Mul<&'r $rhs> for &'l $lhs {
+            type Output = $vec_owned;
+
+            fn mul(self, rhs: &'r $rhs) -> $vec_owned {
+                let (n, k) = (
+                    <$lhs as ::types::GslMatrix<$elem>>::size1(self),
+                    <$lhs as ::types::GslMatrix<$elem>>::size2(self),
+                );
+                let len = <$rhs as ::types::GslVector<$elem>>::len(rhs);
+                assert!(
+                    k == len,
+                    "mul: cannot multiply a {}x{} matrix by a vector of length {}",
+                    n,
+                    k,
+                    len,
+                );
+                let mut out =
+                    $vec_owned::new(n).expect(concat!(stringify!($vec_owned), "::new failed"));
+                $gemv(
+                    ::enums::CblasTranspose::NoTranspose,
+                    $one,
+                    self,
+                    rhs,
+                    $zero,
+                    &mut out,
+                );
+                out
+            }
+        }
+    };
+}
+
+#[test]
+fn vector_ops() {
+    use types::{VectorF64, VectorF64View, VectorI32};
+
+    let a = VectorF64::from_slice(&[1., 2., 3.]).unwrap();
+    let b = VectorF64::from_slice(&[4., 5., 6.]).unwrap();
+    assert_eq!((&a + &b).as_slice(), Some(&[5., 7., 9.][..]));
+    assert_eq!((&b - &a).as_slice(), Some(&[3., 3., 3.][..]));
+    assert_eq!((&a * &b).as_slice(), Some(&[4., 10., 18.][..]));
+    assert_eq!((&b / &a).as_slice(), Some(&[4., 2.5, 2.][..]));
+    assert_eq!((2. * &a).as_slice(), Some(&[2., 4., 6.][..]));
+    assert_eq!((-&a).as_slice(), Some(&[-1., -2., -3.][..]));
+
+    // Every other element of `data`, i.e. a view with a stride of 2.
+    let data = [1., 0., 1., 0., 1., 0.];
+    let ones = VectorF64View::from_array_with_stride(&data, 2);
+    let mut c = a.clone().unwrap();
+    c += &ones;
+    c -= 1.;
+    c *= 2.;
+    assert_eq!(c.as_slice(), Some(&[2., 4., 6.][..]));
+    assert_eq!((c / 2.).as_slice(), Some(&[1., 2., 3.][..]));
+
+    let mut d = b.clone().unwrap();
+    let mut tail = d.subvector_mut(1, 2).unwrap();
+    tail += &ones.subvector(0, 2).unwrap();
+    assert_eq!(d.as_slice(), Some(&[4., 6., 7.][..]));
+
+    let i = VectorI32::from_slice(&[7, -8, 9]).unwrap();
+    let j = VectorI32::from_slice(&[2, 3, -4]).unwrap();
+    assert_eq!((&i / &j).as_slice(), Some(&[3, -2, -2][..]));
+    assert_eq!((&i / 2).as_slice(), Some(&[3, -4, 4][..]));
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn vector_integer_division_by_zero() {
+    use types::VectorU32;
+
+    let a = VectorU32::from_slice(&[1, 2]).unwrap();
+    let b = VectorU32::from_slice(&[1, 0]).unwrap();
+    let _ = &a / &b;
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn vector_ops_length_mismatch() {
+    use types::VectorF64;
+
+    let a = VectorF64::from_slice(&[1., 2.]).unwrap();
+    let b = VectorF64::from_slice(&[1., 2., 3.]).unwrap();
+    let _ = &a + &b;
+}
+
+#[test]
+fn matrix_ops() {
+    use types::{MatrixF64, MatrixF64View, VectorF64};
+
+    let mut id = MatrixF64::new(2, 2).unwrap();
+    id.set_identity();
+    let m = MatrixF64View::from_array(&[1., 2., 3., 4.], 2, 2)
+        .to_matrix()
+        .unwrap();
+
+    let sum = &m + &id;
+    assert!(sum.equal(&MatrixF64View::from_array(&[2., 2., 3., 5.], 2, 2)));
+    let diff = &m - 1.;
+    assert!(diff.equal(&MatrixF64View::from_array(&[0., 1., 2., 3.], 2, 2)));
+    let prod = &m * &m;
+    assert!(prod.equal(&MatrixF64View::from_array(&[7., 10., 15., 22.], 2, 2)));
+    assert!((&m * &id).equal(&m));
+
+    let v = VectorF64::from_slice(&[1., 1.]).unwrap();
+    assert_eq!((&m * &v).as_slice(), Some(&[3., 7.][..]));
+
+    // The left column of a 2x3 matrix, with a `tda` of 3.
+    let data = [1., 2., 9., 3., 4., 9.];
+    let left = MatrixF64View::from_array_with_tda(&data, 2, 2, 3);
+    let mut n = -&left;
+    n += &m;
+    n *= 3.;
+    assert!(n.equal(&MatrixF64View::from_array(&[0.; 4], 2, 2)));
+}
+
+#[test]
+fn vector_views() {
+    use types::{ComplexF64, VectorComplexF64, VectorF64};
+
+    let mut v = VectorF64::from_slice(&[0., 1., 2., 3., 4., 5.]).unwrap();
+    {
+        let even = v.subvector_with_stride(0, 2, 3).unwrap();
+        assert_eq!((even.len(), even.stride()), (3, 2));
+        assert_eq!((even.get(0), even.get(2)), (0., 4.));
+    }
+    {
+        let (mut head, mut tail) = v.split_at_mut(2);
+        assert_eq!((head.len(), tail.len()), (2, 4));
+        head.set(1, 10.);
+        tail.set(0, 20.);
+        // Views of views keep borrowing the vector.
+        let mut odd = tail.subvector_with_stride_mut(1, 2, 2).unwrap();
+        odd.set(1, 50.);
+    }
+    assert_eq!(v.as_slice(), Some(&[0., 10., 20., 3., 4., 50.][..]));
+
+    let (head, tail) = v.split_at_mut(6);
+    assert_eq!((head.len(), tail.len()), (6, 0));
+
+    // The offset of the second half accounts for the two scalars of a complex element.
+    let z = |x: f64| ComplexF64 { dat: [x, -x] };
+    let mut c = VectorComplexF64::from_slice(&[z(1.), z(2.), z(3.)]).unwrap();
+    {
+        let (_, mut tail) = c.split_at_mut(1);
+        assert_eq!(tail.len(), 2);
+        tail.set(0, &z(7.));
+    }
+    assert_eq!(c.get(1).dat, [7., -7.]);
+    assert_eq!(c.get(2).dat, [3., -3.]);
+}
+
+#[test]
+#[should_panic(expected = "split_at_mut: mid > len")]
+fn vector_split_out_of_range() {
+    use types::VectorF64;
+
+    let mut v = VectorF64::from_slice(&[1., 2.]).unwrap();
+    let _ = v.split_at_mut(3);
+}
+
+#[test]
+fn matrix_views() {
+    use types::{MatrixF64, MatrixF64View};
+
+    let data = [0., 1., 2., 3., 4., 5.];
+    let mut m = MatrixF64View::from_array(&data, 2, 3).to_matrix().unwrap();
+    {
+        let column = m.column(1).unwrap();
+        assert_eq!((column.len(), column.stride()), (2, 3));
+        assert_eq!(column.get(1), 4.);
+        let sub = m.submatrix(0, 1, 2, 2).unwrap();
+        assert_eq!((sub.size1(), sub.size2()), (2, 2));
+        assert_eq!(sub.get(1, 1), 5.);
+    }
+
+    for (i, mut row) in m.rows_mut().into_iter().enumerate() {
+        assert_eq!(row.len(), 3);
+        row.set(0, 10. * (i + 1) as f64);
+    }
+    assert!(m.equal(&MatrixF64View::from_array(
+        &[10., 1., 2., 20., 4., 5.],
+        2,
+        3
+    )));
+
+    let mut columns = m.columns_mut();
+    assert_eq!(columns.len(), 3);
+    columns[2].set(1, 50.);
+    drop(columns);
+    assert_eq!(m.get(1, 2), 50.);
+
+    {
+        let (mut top, mut bottom) = m.split_at_row_mut(1);
+        assert_eq!((top.size1(), bottom.size1()), (1, 1));
+        top.set(0, 1, 11.);
+        bottom.set(0, 1, 44.);
+    }
+    {
+        let (mut left, mut right) = m.split_at_column_mut(2);
+        assert_eq!((left.size2(), right.size2()), (2, 1));
+        left.set(1, 0, 0.);
+        right.set(0, 0, 22.);
+    }
+    assert!(m.equal(&MatrixF64View::from_array(
+        &[10., 11., 22., 0., 44., 50.],
+        2,
+        3
+    )));
+
+    let mut empty = MatrixF64::new(2, 2).unwrap();
+    let (top, bottom) = empty.split_at_row_mut(0);
+    assert_eq!((top.size1(), bottom.size1()), (0, 2));
+}
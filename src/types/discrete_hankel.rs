@@ -99,7 +99,7 @@ impl DiscreteHankel {
     pub fn apply(&mut self, f_in: &[f64]) -> (Value, Vec<f64>) {
         unsafe {
             assert!(
-                (*self.unwrap_shared()).size == f_in.len() as _,
+                (*self.unwrap_shared()).size == f_in.len(),
                 "f_in and f_out must have the same length as this struct"
             );
             let mut f_out: Vec<f64> = ::std::iter::repeat(0.).take(f_in.len()).collect();
@@ -97,7 +97,7 @@ used is the QZ method due to Moler and Stewart (see references).
 
 use crate::Value;
 use ffi::FFI;
-use types::{MatrixComplexF64, MatrixF64, VectorComplexF64, VectorF64};
+use types::{ComplexF64, GslMatrixMut, GslVectorMut, MatrixComplexF64, MatrixF64};
 
 ffi_wrapper!(
     EigenSymmetricWorkspace,
@@ -124,7 +124,11 @@ impl EigenSymmetricWorkspace {
     /// triangular part is not referenced. The eigenvalues are stored in the vector `eval` and are
     /// unordered.
     #[doc(alias = "gsl_eigen_symm")]
-    pub fn symm(&mut self, A: &mut MatrixF64, eval: &mut VectorF64) -> Value {
+    pub fn symm(
+        &mut self,
+        A: &mut impl GslMatrixMut<f64>,
+        eval: &mut impl GslVectorMut<f64>,
+    ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_symm(
                 A.unwrap_unique(),
@@ -165,9 +169,9 @@ impl EigenSymmetricVWorkspace {
     #[doc(alias = "gsl_eigen_symmv")]
     pub fn symmv(
         &mut self,
-        A: &mut MatrixF64,
-        eval: &mut VectorF64,
-        evec: &mut MatrixF64,
+        A: &mut impl GslMatrixMut<f64>,
+        eval: &mut impl GslVectorMut<f64>,
+        evec: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_symmv(
@@ -206,7 +210,11 @@ impl EigenHermitianWorkspace {
     /// part is not referenced. The imaginary parts of the diagonal are assumed to be zero and are
     /// not referenced. The eigenvalues are stored in the vector `eval` and are unordered.
     #[doc(alias = "gsl_eigen_herm")]
-    pub fn herm(&mut self, A: &mut MatrixComplexF64, eval: &mut VectorF64) -> Value {
+    pub fn herm(
+        &mut self,
+        A: &mut impl GslMatrixMut<ComplexF64>,
+        eval: &mut impl GslVectorMut<f64>,
+    ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_herm(
                 A.unwrap_unique(),
@@ -249,9 +257,9 @@ impl EigenHermitianVWorkspace {
     #[doc(alias = "gsl_eigen_hermv")]
     pub fn hermv(
         &mut self,
-        A: &mut MatrixComplexF64,
-        eval: &mut VectorF64,
-        evec: &mut MatrixComplexF64,
+        A: &mut impl GslMatrixMut<ComplexF64>,
+        eval: &mut impl GslVectorMut<f64>,
+        evec: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_hermv(
@@ -323,7 +331,11 @@ impl EigenNonSymmetricWorkspace {
     /// returned and the number of converged eigenvalues is stored in w->n_evals. The converged
     /// eigenvalues are stored in the beginning of `eval`.
     #[doc(alias = "gsl_eigen_nonsymm")]
-    pub fn nonsymm(&mut self, A: &mut MatrixF64, eval: &mut VectorComplexF64) -> Value {
+    pub fn nonsymm(
+        &mut self,
+        A: &mut impl GslMatrixMut<f64>,
+        eval: &mut impl GslVectorMut<ComplexF64>,
+    ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_nonsymm(
                 A.unwrap_unique(),
@@ -338,9 +350,9 @@ impl EigenNonSymmetricWorkspace {
     #[doc(alias = "gsl_eigen_nonsymm_Z")]
     pub fn nonsymm_Z(
         &mut self,
-        A: &mut MatrixF64,
-        eval: &mut VectorComplexF64,
-        Z: &mut MatrixF64,
+        A: &mut impl GslMatrixMut<f64>,
+        eval: &mut impl GslVectorMut<ComplexF64>,
+        Z: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_nonsymm_Z(
@@ -396,9 +408,9 @@ impl EigenNonSymmetricVWorkspace {
     #[doc(alias = "gsl_eigen_nonsymmv")]
     pub fn nonsymmv(
         &mut self,
-        A: &mut MatrixF64,
-        eval: &mut VectorComplexF64,
-        evec: &mut MatrixComplexF64,
+        A: &mut impl GslMatrixMut<f64>,
+        eval: &mut impl GslVectorMut<ComplexF64>,
+        evec: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_nonsymmv(
@@ -415,10 +427,10 @@ impl EigenNonSymmetricVWorkspace {
     #[doc(alias = "gsl_eigen_nonsymmv_Z")]
     pub fn nonsymmv_Z(
         &mut self,
-        A: &mut MatrixF64,
-        eval: &mut VectorComplexF64,
-        evec: &mut MatrixComplexF64,
-        Z: &mut MatrixF64,
+        A: &mut impl GslMatrixMut<f64>,
+        eval: &mut impl GslVectorMut<ComplexF64>,
+        evec: &mut impl GslMatrixMut<ComplexF64>,
+        Z: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_nonsymmv_Z(
@@ -456,10 +468,15 @@ impl EigenGenSymmWorkspace {
     /// pair (A, B), and stores them in `eval`, using the method outlined above. On output, `B`
     /// contains its Cholesky decomposition and `A` is destroyed.
     #[doc(alias = "gsl_eigen_gensymm")]
-    pub fn gensymm(&mut self, mut A: MatrixF64, B: &mut MatrixF64, eval: &mut VectorF64) -> Value {
+    pub fn gensymm(
+        &mut self,
+        mut A: MatrixF64,
+        B: &mut impl GslMatrixMut<f64>,
+        eval: &mut impl GslVectorMut<f64>,
+    ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_gensymm(
-                A.unwrap_unique(),
+                FFI::unwrap_unique(&mut A),
                 B.unwrap_unique(),
                 eval.unwrap_unique(),
                 self.unwrap_unique(),
@@ -496,13 +513,13 @@ impl EigenGenSymmVWorkspace {
     pub fn gensymmv(
         &mut self,
         mut A: MatrixF64,
-        B: &mut MatrixF64,
-        eval: &mut VectorF64,
-        evec: &mut MatrixF64,
+        B: &mut impl GslMatrixMut<f64>,
+        eval: &mut impl GslVectorMut<f64>,
+        evec: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_gensymmv(
-                A.unwrap_unique(),
+                FFI::unwrap_unique(&mut A),
                 B.unwrap_unique(),
                 eval.unwrap_unique(),
                 evec.unwrap_unique(),
@@ -539,12 +556,12 @@ impl EigenGenHermWorkspace {
     pub fn genherm(
         &mut self,
         mut A: MatrixComplexF64,
-        B: &mut MatrixComplexF64,
-        eval: &mut VectorF64,
+        B: &mut impl GslMatrixMut<ComplexF64>,
+        eval: &mut impl GslVectorMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_genherm(
-                A.unwrap_unique(),
+                FFI::unwrap_unique(&mut A),
                 B.unwrap_unique(),
                 eval.unwrap_unique(),
                 self.unwrap_unique(),
@@ -580,13 +597,13 @@ impl EigenGenHermVWorkspace {
     pub fn genhermv(
         &mut self,
         mut A: MatrixComplexF64,
-        B: &mut MatrixComplexF64,
-        eval: &mut VectorF64,
-        evec: &mut MatrixComplexF64,
+        B: &mut impl GslMatrixMut<ComplexF64>,
+        eval: &mut impl GslVectorMut<f64>,
+        evec: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_genhermv(
-                A.unwrap_unique(),
+                FFI::unwrap_unique(&mut A),
                 B.unwrap_unique(),
                 eval.unwrap_unique(),
                 evec.unwrap_unique(),
@@ -649,10 +666,10 @@ impl EigenGenWorkspace {
     #[doc(alias = "gsl_eigen_gen")]
     pub fn gen(
         &mut self,
-        A: &mut MatrixF64,
-        B: &mut MatrixF64,
-        alpha: &mut VectorComplexF64,
-        beta: &mut VectorF64,
+        A: &mut impl GslMatrixMut<f64>,
+        B: &mut impl GslMatrixMut<f64>,
+        alpha: &mut impl GslVectorMut<ComplexF64>,
+        beta: &mut impl GslVectorMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_gen(
@@ -670,12 +687,12 @@ impl EigenGenWorkspace {
     #[doc(alias = "gsl_eigen_gen_QZ")]
     pub fn gen_QZ(
         &mut self,
-        A: &mut MatrixF64,
-        B: &mut MatrixF64,
-        alpha: &mut VectorComplexF64,
-        beta: &mut VectorF64,
-        Q: &mut MatrixF64,
-        Z: &mut MatrixF64,
+        A: &mut impl GslMatrixMut<f64>,
+        B: &mut impl GslMatrixMut<f64>,
+        alpha: &mut impl GslVectorMut<ComplexF64>,
+        beta: &mut impl GslVectorMut<f64>,
+        Q: &mut impl GslMatrixMut<f64>,
+        Z: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_gen_QZ(
@@ -722,11 +739,11 @@ impl EigenGenVWorkspace {
     #[doc(alias = "gsl_eigen_genv")]
     pub fn genv(
         &mut self,
-        A: &mut MatrixF64,
-        B: &mut MatrixF64,
-        alpha: &mut VectorComplexF64,
-        beta: &mut VectorF64,
-        evec: &mut MatrixComplexF64,
+        A: &mut impl GslMatrixMut<f64>,
+        B: &mut impl GslMatrixMut<f64>,
+        alpha: &mut impl GslVectorMut<ComplexF64>,
+        beta: &mut impl GslVectorMut<f64>,
+        evec: &mut impl GslMatrixMut<ComplexF64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_genv(
@@ -745,13 +762,13 @@ impl EigenGenVWorkspace {
     #[doc(alias = "gsl_eigen_genv_QZ")]
     pub fn genv_QZ(
         &mut self,
-        A: &mut MatrixF64,
-        B: &mut MatrixF64,
-        alpha: &mut VectorComplexF64,
-        beta: &mut VectorF64,
-        evec: &mut MatrixComplexF64,
-        Q: &mut MatrixF64,
-        Z: &mut MatrixF64,
+        A: &mut impl GslMatrixMut<f64>,
+        B: &mut impl GslMatrixMut<f64>,
+        alpha: &mut impl GslVectorMut<ComplexF64>,
+        beta: &mut impl GslVectorMut<f64>,
+        evec: &mut impl GslMatrixMut<ComplexF64>,
+        Q: &mut impl GslMatrixMut<f64>,
+        Z: &mut impl GslMatrixMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_eigen_genv_QZ(
@@ -924,6 +941,7 @@ fn eigen_hermitian_workspace() {
 #[test]
 fn eigen_hermitian_vworkspace() {
     use ComplexF64;
+    use VectorF64;
 
     let mut e = EigenHermitianVWorkspace::new(3).unwrap();
     let mut m = MatrixComplexF64::new(2, 2).unwrap();
@@ -7,6 +7,8 @@
 use crate::enums;
 use crate::Value;
 use ffi::FFI;
+use std::cell::Cell;
+use std::os::raw::c_int;
 
 ffi_wrapper!(IntegrationFixedType, *const sys::gsl_integration_fixed_type);
 
@@ -101,6 +103,63 @@ impl IntegrationFixedWorkspace {
     }
 }
 
+/// The outcome of an adaptive integration using an [`IntegrationWorkspace`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IntegrationOutcome {
+    /// The status returned by GSL: [`Value::Success`] if the requested accuracy was reached.
+    pub status: Value,
+    /// The approximation of the integral.
+    pub result: f64,
+    /// The estimate of the absolute error of `result`.
+    pub abserr: f64,
+    /// The number of evaluations of the integrand.
+    pub neval: usize,
+}
+
+/// A subinterval of the last integration done with an [`IntegrationWorkspace`], as returned by
+/// [`IntegrationWorkspace::subintervals`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IntegrationSubinterval {
+    /// The lower bound of the subinterval.
+    pub a: f64,
+    /// The upper bound of the subinterval.
+    pub b: f64,
+    /// The integral over the subinterval.
+    pub result: f64,
+    /// The estimate of the absolute error of `result`.
+    pub abserr: f64,
+    /// The number of bisections which led to this subinterval.
+    pub level: usize,
+}
+
+// Calls `integrate` with a `gsl_function` wrapping `f` and pointers to the result and absolute
+// error, counting the evaluations of `f`.
+fn integrate_counted<F: Fn(f64) -> f64>(
+    f: F,
+    integrate: impl FnOnce(&mut sys::gsl_function, &mut f64, &mut f64) -> c_int,
+) -> IntegrationOutcome {
+    fn wrap<G: Fn(f64) -> f64>(g: &G) -> sys::gsl_function {
+        wrap_callback!(*g, G)
+    }
+
+    let neval = Cell::new(0);
+    let counted = |x: f64| {
+        neval.set(neval.get() + 1);
+        f(x)
+    };
+    let mut function = wrap(&counted);
+    let mut result = 0.;
+    let mut abserr = 0.;
+    let ret = integrate(&mut function, &mut result, &mut abserr);
+
+    IntegrationOutcome {
+        status: Value::from(ret),
+        result,
+        abserr,
+        neval: neval.get(),
+    }
+}
+
 ffi_wrapper!(IntegrationWorkspace, *mut sys::gsl_integration_workspace, gsl_integration_workspace_free,
 "The QAG algorithm is a simple adaptive integration procedure. The integration region is divided
 into subintervals, and on each iteration the subinterval with the largest estimated error is
@@ -140,6 +199,51 @@ impl IntegrationWorkspace {
         unsafe { (*self.unwrap_shared()).maximum_level }
     }
 
+    /// Returns the subintervals of the last integration done with this workspace, sorted by their
+    /// lower bound. The subintervals with the largest errors show where the integrand was the
+    /// hardest to integrate, which can help choosing the break-points given to
+    /// [`qagp`](Self::qagp).
+    ///
+    /// The infinite range integrations ([`qagi`](Self::qagi), [`qagiu`](Self::qagiu) and
+    /// [`qagil`](Self::qagil)) work on the transformed variable `t` in `(0, 1]`, so their
+    /// subintervals are bounds on `t` rather than on `x`.
+    ///
+    /// ```no_run
+    /// let mut w = rgsl::IntegrationWorkspace::new(1000).unwrap();
+    /// let outcome = w.qags(|x| x.ln() / x.sqrt(), 0., 1., 0., 1e-7, 1000);
+    /// println!("{} ({} evaluations)", outcome.result, outcome.neval);
+    /// for s in w.subintervals() {
+    ///     println!("[{}, {}]: error {} (level {})", s.a, s.b, s.abserr, s.level);
+    /// }
+    /// ```
+    pub fn subintervals(&self) -> Vec<IntegrationSubinterval> {
+        let w = unsafe { &*self.unwrap_shared() };
+        let n = w.size;
+        if n == 0 {
+            return Vec::new();
+        }
+        let (alist, blist, rlist, elist, level) = unsafe {
+            (
+                ::std::slice::from_raw_parts(w.alist, n),
+                ::std::slice::from_raw_parts(w.blist, n),
+                ::std::slice::from_raw_parts(w.rlist, n),
+                ::std::slice::from_raw_parts(w.elist, n),
+                ::std::slice::from_raw_parts(w.level, n),
+            )
+        };
+        let mut out = (0..n)
+            .map(|i| IntegrationSubinterval {
+                a: alist[i],
+                b: blist[i],
+                result: rlist[i],
+                abserr: elist[i],
+                level: level[i],
+            })
+            .collect::<Vec<_>>();
+        out.sort_by(|x, y| x.a.partial_cmp(&y.a).unwrap_or(::std::cmp::Ordering::Equal));
+        out
+    }
+
     /// This function applies an integration rule adaptively until an estimate of the integral of f
     /// over (a,b) is achieved within the desired absolute and relative error limits, epsabs and
     /// epsrel. The function returns the final approximation, result, and an estimate of the
@@ -167,7 +271,7 @@ impl IntegrationWorkspace {
     /// workspace. The maximum number of subintervals is given by limit, which may not exceed the
     /// allocated size of the workspace.
     ///
-    /// Returns `(result, abs_err)`.
+    /// The number of evaluations of `f` is returned in [`IntegrationOutcome::neval`].
     #[doc(alias = "gsl_integration_qag")]
     pub fn qag<F: Fn(f64) -> f64>(
         &mut self,
@@ -178,14 +282,10 @@ impl IntegrationWorkspace {
         epsrel: f64,
         limit: usize,
         key: enums::GaussKronrodRule,
-    ) -> (::Value, f64, f64) {
-        let mut result = 0.;
-        let mut abs_err = 0.;
-        let function = wrap_callback!(f, F);
-
-        let ret = unsafe {
+    ) -> IntegrationOutcome {
+        integrate_counted(f, |function, result, abserr| unsafe {
             sys::gsl_integration_qag(
-                &function,
+                function,
                 a,
                 b,
                 epsabs,
@@ -193,11 +293,10 @@ impl IntegrationWorkspace {
                 limit,
                 key.into(),
                 self.unwrap_unique(),
-                &mut result,
-                &mut abs_err,
+                result,
+                abserr,
             )
-        };
-        (::Value::from(ret), result, abs_err)
+        })
     }
 
     /// This function applies the Gauss-Kronrod 21-point integration rule adaptively until an
@@ -210,7 +309,7 @@ impl IntegrationWorkspace {
     /// number of subintervals is given by limit, which may not exceed the allocated size of the
     /// workspace.
     ///
-    /// Returns `(result, abs_err)`.
+    /// The number of evaluations of `f` is returned in [`IntegrationOutcome::neval`].
     #[doc(alias = "gsl_integration_qags")]
     pub fn qags<F: Fn(f64) -> f64>(
         &mut self,
@@ -220,25 +319,20 @@ impl IntegrationWorkspace {
         epsabs: f64,
         epsrel: f64,
         limit: usize,
-    ) -> (::Value, f64, f64) {
-        let mut result = 0.;
-        let mut abs_err = 0.;
-        let function = wrap_callback!(f, F);
-
-        let ret = unsafe {
+    ) -> IntegrationOutcome {
+        integrate_counted(f, |function, result, abserr| unsafe {
             sys::gsl_integration_qags(
-                &function,
+                function,
                 a,
                 b,
                 epsabs,
                 epsrel,
                 limit,
                 self.unwrap_unique(),
-                &mut result,
-                &mut abs_err,
+                result,
+                abserr,
             )
-        };
-        (::Value::from(ret), result, abs_err)
+        })
     }
 
     /// This function applies the adaptive integration algorithm QAGS taking account of the
@@ -261,7 +355,7 @@ impl IntegrationWorkspace {
     /// If you know the locations of the singular points in the integration region then this routine
     /// will be faster than QAGS.
     ///
-    /// Returns `(result, abs_err)`.
+    /// The number of evaluations of `f` is returned in [`IntegrationOutcome::neval`].
     #[doc(alias = "gsl_integration_qagp")]
     pub fn qagp<F: Fn(f64) -> f64>(
         &mut self,
@@ -270,25 +364,20 @@ impl IntegrationWorkspace {
         epsabs: f64,
         epsrel: f64,
         limit: usize,
-    ) -> (::Value, f64, f64) {
-        let mut result = 0.;
-        let mut abs_err = 0.;
-        let function = wrap_callback!(f, F);
-
-        let ret = unsafe {
+    ) -> IntegrationOutcome {
+        integrate_counted(f, |function, result, abserr| unsafe {
             sys::gsl_integration_qagp(
-                &function,
+                function,
                 pts.as_mut_ptr(),
                 pts.len() as _,
                 epsabs,
                 epsrel,
                 limit,
                 self.unwrap_unique(),
-                &mut result,
-                &mut abs_err,
+                result,
+                abserr,
             )
-        };
-        (::Value::from(ret), result, abs_err)
+        })
     }
 
     /// This function computes the integral of the function f over the infinite interval
@@ -306,7 +395,7 @@ impl IntegrationWorkspace {
     /// QAGS is replaced by a 15-point rule, because the transformation can generate an integrable
     /// singularity at the origin. In this case a lower-order rule is more efficient.
     ///
-    /// Returns `(result, abs_err)`.
+    /// The number of evaluations of `f` is returned in [`IntegrationOutcome::neval`].
     #[doc(alias = "gsl_integration_qagi")]
     pub fn qagi<F: Fn(f64) -> f64>(
         &mut self,
@@ -314,23 +403,18 @@ impl IntegrationWorkspace {
         epsabs: f64,
         epsrel: f64,
         limit: usize,
-    ) -> (::Value, f64, f64) {
-        let mut result = 0.;
-        let mut abs_err = 0.;
-        let mut function = wrap_callback!(f, F);
-
-        let ret = unsafe {
+    ) -> IntegrationOutcome {
+        integrate_counted(f, |function, result, abserr| unsafe {
             sys::gsl_integration_qagi(
-                &mut function,
+                function,
                 epsabs,
                 epsrel,
                 limit,
                 self.unwrap_unique(),
-                &mut result,
-                &mut abs_err,
+                result,
+                abserr,
             )
-        };
-        (::Value::from(ret), result, abs_err)
+        })
     }
 
     /// This function computes the integral of the function f over the semi-infinite interval
@@ -346,7 +430,7 @@ impl IntegrationWorkspace {
     ///
     /// and then integrated using the QAGS algorithm.
     ///
-    /// Returns `(result, abs_err)`.
+    /// The number of evaluations of `f` is returned in [`IntegrationOutcome::neval`].
     #[doc(alias = "gsl_integration_qagiu")]
     pub fn qagiu<F: Fn(f64) -> f64>(
         &mut self,
@@ -355,24 +439,19 @@ impl IntegrationWorkspace {
         epsabs: f64,
         epsrel: f64,
         limit: usize,
-    ) -> (::Value, f64, f64) {
-        let mut result = 0.;
-        let mut abs_err = 0.;
-        let mut function = wrap_callback!(f, F);
-
-        let ret = unsafe {
+    ) -> IntegrationOutcome {
+        integrate_counted(f, |function, result, abserr| unsafe {
             sys::gsl_integration_qagiu(
-                &mut function,
+                function,
                 a,
                 epsabs,
                 epsrel,
                 limit,
                 self.unwrap_unique(),
-                &mut result,
-                &mut abs_err,
+                result,
+                abserr,
             )
-        };
-        (::Value::from(ret), result, abs_err)
+        })
     }
 
     /// This function computes the integral of the function f over the semi-infinite interval
@@ -388,7 +467,7 @@ impl IntegrationWorkspace {
     ///
     /// and then integrated using the QAGS algorithm.
     ///
-    /// Returns `(result, abs_err)`.
+    /// The number of evaluations of `f` is returned in [`IntegrationOutcome::neval`].
     #[doc(alias = "gsl_integration_qagil")]
     pub fn qagil<F: Fn(f64) -> f64>(
         &mut self,
@@ -397,24 +476,19 @@ impl IntegrationWorkspace {
         epsabs: f64,
         epsrel: f64,
         limit: usize,
-    ) -> (::Value, f64, f64) {
-        let mut result = 0.;
-        let mut abs_err = 0.;
-        let mut function = wrap_callback!(f, F);
-
-        let ret = unsafe {
+    ) -> IntegrationOutcome {
+        integrate_counted(f, |function, result, abserr| unsafe {
             sys::gsl_integration_qagil(
-                &mut function,
+                function,
                 b,
                 epsabs,
                 epsrel,
                 limit,
                 self.unwrap_unique(),
-                &mut result,
-                &mut abs_err,
+                result,
+                abserr,
             )
-        };
-        (::Value::from(ret), result, abs_err)
+        })
     }
 
     /// This function computes the Cauchy principal value of the integral of f over `(a,b)`, with a
@@ -431,7 +505,7 @@ impl IntegrationWorkspace {
     /// modified Clenshaw-Curtis rule is used to control the singularity. Further away from the
     /// singularity the algorithm uses an ordinary 15-point Gauss-Kronrod integration rule.
     ///
-    /// Returns `(result, abs_err)`.
+    /// The number of evaluations of `f` is returned in [`IntegrationOutcome::neval`].
     #[doc(alias = "gsl_integration_qawc")]
     pub fn qawc<F: Fn(f64) -> f64>(
         &mut self,
@@ -442,14 +516,10 @@ impl IntegrationWorkspace {
         epsabs: f64,
         epsrel: f64,
         limit: usize,
-    ) -> (::Value, f64, f64) {
-        let mut result = 0.;
-        let mut abs_err = 0.;
-        let mut function = wrap_callback!(f, F);
-
-        let ret = unsafe {
+    ) -> IntegrationOutcome {
+        integrate_counted(f, |function, result, abserr| unsafe {
             sys::gsl_integration_qawc(
-                &mut function,
+                function,
                 a,
                 b,
                 c,
@@ -457,11 +527,10 @@ impl IntegrationWorkspace {
                 epsrel,
                 limit,
                 self.unwrap_unique(),
-                &mut result,
-                &mut abs_err,
+                result,
+                abserr,
             )
-        };
-        (::Value::from(ret), result, abs_err)
+        })
     }
 }
 
@@ -530,7 +599,7 @@ impl IntegrationQawsTable {
     /// singularities. For subintervals which do not include the endpoints an ordinary 15-point
     /// Gauss-Kronrod integration rule is used.
     ///
-    /// Returns `(result, abs_err)`
+    /// The number of evaluations of `f` is returned in [`IntegrationOutcome::neval`].
     #[doc(alias = "gsl_integration_qaws")]
     pub fn qaws<F: Fn(f64) -> f64>(
         &mut self,
@@ -541,14 +610,10 @@ impl IntegrationQawsTable {
         epsrel: f64,
         limit: usize,
         workspace: &mut IntegrationWorkspace,
-    ) -> (::Value, f64, f64) {
-        let mut result = 0.;
-        let mut abs_err = 0.;
-        let mut function = wrap_callback!(f, F);
-
-        let ret = unsafe {
+    ) -> IntegrationOutcome {
+        integrate_counted(f, |function, result, abserr| unsafe {
             sys::gsl_integration_qaws(
-                &mut function,
+                function,
                 a,
                 b,
                 self.unwrap_unique(),
@@ -556,11 +621,10 @@ impl IntegrationQawsTable {
                 epsrel,
                 limit,
                 workspace.unwrap_unique(),
-                &mut result,
-                &mut abs_err,
+                result,
+                abserr,
             )
-        };
-        (::Value::from(ret), result, abs_err)
+        })
     }
 }
 
@@ -645,7 +709,7 @@ impl IntegrationQawoTable {
     /// Clenshaw-Curtis integration rule, which handles the oscillatory behavior. Subintervals with
     /// a "small" widths where d\omega < 4 are computed using a 15-point Gauss-Kronrod integration.
     ///
-    /// Returns `(result, abserr)`.
+    /// The number of evaluations of `f` is returned in [`IntegrationOutcome::neval`].
     #[doc(alias = "gsl_integration_qawo")]
     pub fn qawo<F: Fn(f64) -> f64>(
         &mut self,
@@ -655,25 +719,20 @@ impl IntegrationQawoTable {
         epsrel: f64,
         limit: usize,
         workspace: &mut IntegrationWorkspace,
-    ) -> (::Value, f64, f64) {
-        let mut function = wrap_callback!(f, F);
-        let mut result = 0.;
-        let mut abserr = 0.;
-
-        let ret = unsafe {
+    ) -> IntegrationOutcome {
+        integrate_counted(f, |function, result, abserr| unsafe {
             sys::gsl_integration_qawo(
-                &mut function,
+                function,
                 a,
                 epsabs,
                 epsrel,
                 limit,
                 workspace.unwrap_unique(),
                 self.unwrap_unique(),
-                &mut result,
-                &mut abserr,
+                result,
+                abserr,
             )
-        };
-        (::Value::from(ret), result, abserr)
+        })
     }
 }
 
@@ -755,6 +814,67 @@ impl CquadWorkspace {
     }
 }
 
+pub(crate) enum NestedQuadrature<'a> {
+    Qags {
+        workspace: &'a mut IntegrationWorkspace,
+        limit: usize,
+    },
+    Cquad(&'a mut CquadWorkspace),
+}
+
+/// One dimension of a multidimensional integral computed by
+/// [`integrate_nd`](crate::integration::integrate_nd): the bounds of the variable, the tolerances
+/// of its integration and the workspace used for it.
+///
+/// The bounds are functions of the outer variables: the bounds of the `k`-th variable receive the
+/// values of the `k` variables integrated before it, so the bounds of the outermost variable
+/// receive an empty slice and must be constant.
+pub struct IntegrationLevel<'a> {
+    pub(crate) lower: Box<dyn Fn(&[f64]) -> f64 + 'a>,
+    pub(crate) upper: Box<dyn Fn(&[f64]) -> f64 + 'a>,
+    pub(crate) epsabs: f64,
+    pub(crate) epsrel: f64,
+    pub(crate) quadrature: NestedQuadrature<'a>,
+}
+
+impl<'a> IntegrationLevel<'a> {
+    /// Integrates the variable between `lower` and `upper` with
+    /// [`IntegrationWorkspace::qags`], using at most `limit` subintervals of `workspace`.
+    pub fn qags<L: Fn(&[f64]) -> f64 + 'a, U: Fn(&[f64]) -> f64 + 'a>(
+        lower: L,
+        upper: U,
+        epsabs: f64,
+        epsrel: f64,
+        limit: usize,
+        workspace: &'a mut IntegrationWorkspace,
+    ) -> IntegrationLevel<'a> {
+        IntegrationLevel {
+            lower: Box::new(lower),
+            upper: Box::new(upper),
+            epsabs,
+            epsrel,
+            quadrature: NestedQuadrature::Qags { workspace, limit },
+        }
+    }
+
+    /// Integrates the variable between `lower` and `upper` with [`CquadWorkspace::cquad`].
+    pub fn cquad<L: Fn(&[f64]) -> f64 + 'a, U: Fn(&[f64]) -> f64 + 'a>(
+        lower: L,
+        upper: U,
+        epsabs: f64,
+        epsrel: f64,
+        workspace: &'a mut CquadWorkspace,
+    ) -> IntegrationLevel<'a> {
+        IntegrationLevel {
+            lower: Box::new(lower),
+            upper: Box::new(upper),
+            epsabs,
+            epsrel,
+            quadrature: NestedQuadrature::Cquad(workspace),
+        }
+    }
+}
+
 ffi_wrapper!(GLFixedTable, *mut sys::gsl_integration_glfixed_table, gsl_integration_glfixed_table_free,
 "The fixed-order Gauss-Legendre integration routines are provided for fast integration of smooth
 functions with known polynomial order. The n-point Gauss-Legendre rule is exact for polynomials
@@ -817,3 +937,445 @@ impl GLFixedTable {
         })
     }
 }
+
+#[cfg(feature = "v2_5")]
+ffi_wrapper!(
+    RombergWorkspace,
+    *mut sys::gsl_integration_romberg_workspace,
+    gsl_integration_romberg_free,
+    "Romberg integration applies Richardson extrapolation to the trapezoidal rule, evaluated at
+equally spaced points with `2^k + 1` points on iteration `k`. It is efficient for smooth
+integrands, but not for integrands with singularities or discontinuities."
+);
+
+#[cfg(feature = "v2_5")]
+impl RombergWorkspace {
+    /// This function allocates a workspace for Romberg integration, specifying a maximum of n
+    /// iterations, or levels. The maximum allowed value of n is 30.
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = "gsl_integration_romberg_alloc")]
+    pub fn new(n: usize) -> Option<RombergWorkspace> {
+        let tmp = unsafe { sys::gsl_integration_romberg_alloc(n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function integrates f(x) over the interval [a,b] using Romberg integration, stopping
+    /// when the relative difference between two successive extrapolations is below epsrel, or
+    /// their absolute difference below epsabs. If the maximum number of iterations of the
+    /// workspace is reached, the error [`Value::MaxIteration`] is returned along with the last estimate.
+    ///
+    /// Returns `(result, n_eval)`.
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = "gsl_integration_romberg")]
+    pub fn romberg<F: Fn(f64) -> f64>(
+        &mut self,
+        f: F,
+        a: f64,
+        b: f64,
+        epsabs: f64,
+        epsrel: f64,
+    ) -> (::Value, f64, usize) {
+        let function = wrap_callback!(f, F);
+        let mut result = 0.;
+        let mut n_eval = 0;
+
+        let ret = unsafe {
+            sys::gsl_integration_romberg(
+                &function,
+                a,
+                b,
+                epsabs,
+                epsrel,
+                &mut result,
+                &mut n_eval,
+                self.unwrap_unique(),
+            )
+        };
+        (::Value::from(ret), result, n_eval)
+    }
+}
+
+/// The estimate of an integral returned by an [`Integrator`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IntegrationEstimate {
+    /// The approximation of the integral.
+    pub result: f64,
+    /// The estimate of the absolute error of `result`, if the method provides one.
+    pub abserr: Option<f64>,
+    /// The number of evaluations of the integrand.
+    pub neval: usize,
+}
+
+/// The tolerances of an integration through an [`Integrator`]: the integration stops when the
+/// estimated error is below `epsabs` or below `epsrel` times the absolute value of the result.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IntegrationTolerance {
+    pub epsabs: f64,
+    pub epsrel: f64,
+}
+
+impl IntegrationTolerance {
+    pub fn new(epsabs: f64, epsrel: f64) -> IntegrationTolerance {
+        IntegrationTolerance { epsabs, epsrel }
+    }
+}
+
+/// A common interface to the one-dimensional quadratures, so that the method can be chosen at
+/// runtime, for example from a configuration:
+///
+/// ```no_run
+/// use rgsl::{CquadWorkspace, IntegrationTolerance, IntegrationWorkspace, Integrator, QngIntegrator};
+///
+/// fn integrator(name: &str) -> Box<dyn Integrator> {
+///     match name {
+///         "qng" => Box::new(QngIntegrator::new()),
+///         "qags" => Box::new(IntegrationWorkspace::new(1000).unwrap()),
+///         _ => Box::new(CquadWorkspace::new(100).unwrap()),
+///     }
+/// }
+///
+/// let mut q = integrator("qags");
+/// let tolerance = IntegrationTolerance::new(0., 1e-8);
+/// let estimate = q
+///     .integrate(&|x: f64| x.sin(), 0., std::f64::consts::PI, tolerance)
+///     .unwrap();
+/// println!("{} ({} evaluations)", estimate.result, estimate.neval);
+/// ```
+///
+/// The trait is implemented by the workspaces of the methods. The fixed-order rules of
+/// [`GLFixedTable`] and [`FixedIntegrator`] ignore the tolerance.
+pub trait Integrator {
+    /// Computes the integral of `f` over `(a, b)`. Returns the status of the method if it failed,
+    /// for example because the requested accuracy couldn't be reached.
+    fn integrate(
+        &mut self,
+        f: &dyn Fn(f64) -> f64,
+        a: f64,
+        b: f64,
+        tolerance: IntegrationTolerance,
+    ) -> Result<IntegrationEstimate, Value>;
+}
+
+// Converts a GSL status and estimate into the result of `Integrator::integrate`.
+fn estimate(
+    status: Value,
+    result: f64,
+    abserr: Option<f64>,
+    neval: usize,
+) -> Result<IntegrationEstimate, Value> {
+    if status == Value::Success {
+        Ok(IntegrationEstimate {
+            result,
+            abserr,
+            neval,
+        })
+    } else {
+        Err(status)
+    }
+}
+
+/// The non-adaptive Gauss-Kronrod-Patterson integration of [`qng`](crate::integration::qng) as
+/// an [`Integrator`]. It doesn't need a workspace.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QngIntegrator;
+
+impl QngIntegrator {
+    /// Creates the integrator, which has no state.
+    pub fn new() -> QngIntegrator {
+        QngIntegrator
+    }
+}
+
+impl Integrator for QngIntegrator {
+    fn integrate(
+        &mut self,
+        f: &dyn Fn(f64) -> f64,
+        a: f64,
+        b: f64,
+        tolerance: IntegrationTolerance,
+    ) -> Result<IntegrationEstimate, Value> {
+        let (status, result, abserr, neval) =
+            ::integration::qng(f, a, b, tolerance.epsabs, tolerance.epsrel);
+        estimate(status, result, Some(abserr), neval)
+    }
+}
+
+/// The adaptive integration with extrapolation of [`IntegrationWorkspace::qags`], using all the
+/// subintervals of the workspace. Use [`IntegrationWorkspace::qag`] directly to choose the
+/// Gauss-Kronrod rule.
+impl Integrator for IntegrationWorkspace {
+    fn integrate(
+        &mut self,
+        f: &dyn Fn(f64) -> f64,
+        a: f64,
+        b: f64,
+        tolerance: IntegrationTolerance,
+    ) -> Result<IntegrationEstimate, Value> {
+        let limit = self.limit();
+        let outcome = self.qags(f, a, b, tolerance.epsabs, tolerance.epsrel, limit);
+        estimate(
+            outcome.status,
+            outcome.result,
+            Some(outcome.abserr),
+            outcome.neval,
+        )
+    }
+}
+
+/// The doubly-adaptive integration of [`CquadWorkspace::cquad`].
+impl Integrator for CquadWorkspace {
+    fn integrate(
+        &mut self,
+        f: &dyn Fn(f64) -> f64,
+        a: f64,
+        b: f64,
+        tolerance: IntegrationTolerance,
+    ) -> Result<IntegrationEstimate, Value> {
+        let (status, result, abserr, neval) =
+            self.cquad(f, a, b, tolerance.epsabs, tolerance.epsrel);
+        estimate(status, result, Some(abserr), neval)
+    }
+}
+
+/// The Romberg integration of [`RombergWorkspace::romberg`]. It doesn't provide an error
+/// estimate.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+impl Integrator for RombergWorkspace {
+    fn integrate(
+        &mut self,
+        f: &dyn Fn(f64) -> f64,
+        a: f64,
+        b: f64,
+        tolerance: IntegrationTolerance,
+    ) -> Result<IntegrationEstimate, Value> {
+        let (status, result, neval) = self.romberg(f, a, b, tolerance.epsabs, tolerance.epsrel);
+        estimate(status, result, None, neval)
+    }
+}
+
+/// The fixed-order rules of [`IntegrationFixedWorkspace`] over any interval. As the nodes depend
+/// on the interval, a workspace is allocated for each integration: `a` and `b` are given to
+/// [`IntegrationFixedWorkspace::new`], so for the rules on infinite intervals they are the
+/// parameters of the weight function rather than bounds. The tolerance is ignored and no error
+/// estimate is provided.
+///
+/// [`IntegrationFixedWorkspace`] itself doesn't implement [`Integrator`], as its nodes are only
+/// valid for the interval it was created for.
+#[derive(Clone, Copy)]
+pub struct FixedIntegrator {
+    type_: IntegrationFixedType,
+    n: usize,
+    alpha: f64,
+    beta: f64,
+}
+
+impl FixedIntegrator {
+    /// Creates an integrator using the rule `type_` with `n` nodes. `alpha` and `beta` are the
+    /// parameters of the weight function, as in [`IntegrationFixedWorkspace::new`].
+    pub fn new(type_: IntegrationFixedType, n: usize, alpha: f64, beta: f64) -> FixedIntegrator {
+        FixedIntegrator {
+            type_,
+            n,
+            alpha,
+            beta,
+        }
+    }
+}
+
+/// Returns [`Value::Invalid`] if the workspace can't be allocated for these parameters.
+impl Integrator for FixedIntegrator {
+    fn integrate(
+        &mut self,
+        f: &dyn Fn(f64) -> f64,
+        a: f64,
+        b: f64,
+        _tolerance: IntegrationTolerance,
+    ) -> Result<IntegrationEstimate, Value> {
+        let workspace =
+            IntegrationFixedWorkspace::new(self.type_, self.n, a, b, self.alpha, self.beta)
+                .ok_or(Value::Invalid)?;
+        let (status, result) = workspace.fixed(f);
+        estimate(status, result, None, self.n)
+    }
+}
+
+/// The fixed-order Gauss-Legendre rule of the table. The tolerance is ignored and no error
+/// estimate is provided.
+impl Integrator for GLFixedTable {
+    fn integrate(
+        &mut self,
+        f: &dyn Fn(f64) -> f64,
+        a: f64,
+        b: f64,
+        _tolerance: IntegrationTolerance,
+    ) -> Result<IntegrationEstimate, Value> {
+        let neval = Cell::new(0);
+        let result = self.glfixed(
+            |x| {
+                neval.set(neval.get() + 1);
+                f(x)
+            },
+            a,
+            b,
+        );
+        estimate(Value::Success, result, None, neval.get())
+    }
+}
+
+#[cfg(test)]
+fn check_integrator<I: Integrator>(mut integrator: I, has_abserr: bool) {
+    let tolerance = IntegrationTolerance::new(0., 1e-10);
+    let estimate = integrator
+        .integrate(&|x: f64| x.sin(), 0., ::std::f64::consts::PI, tolerance)
+        .unwrap();
+    assert!((estimate.result - 2.).abs() < 1e-9, "{}", estimate.result);
+    assert_eq!(estimate.abserr.is_some(), has_abserr);
+    assert!(estimate.neval > 0);
+}
+
+#[test]
+fn integrators() {
+    check_integrator(QngIntegrator::new(), true);
+    check_integrator(IntegrationWorkspace::new(1000).unwrap(), true);
+    check_integrator(CquadWorkspace::new(100).unwrap(), true);
+    check_integrator(GLFixedTable::new(20).unwrap(), false);
+    check_integrator(
+        FixedIntegrator::new(IntegrationFixedType::legendre(), 20, 0., 0.),
+        false,
+    );
+}
+
+#[test]
+fn integrators_as_trait_objects() {
+    let mut integrators: Vec<Box<dyn Integrator>> = vec![
+        Box::new(QngIntegrator::new()),
+        Box::new(IntegrationWorkspace::new(1000).unwrap()),
+        Box::new(CquadWorkspace::new(100).unwrap()),
+    ];
+    let tolerance = IntegrationTolerance::new(0., 1e-10);
+    for integrator in &mut integrators {
+        // The integral of x^2 over [-1, 2] is 3.
+        let estimate = integrator
+            .integrate(&|x: f64| x * x, -1., 2., tolerance)
+            .unwrap();
+        assert!((estimate.result - 3.).abs() < 1e-9, "{}", estimate.result);
+    }
+}
+
+#[test]
+fn fixed_integrator_interval() {
+    // The nodes are computed for each interval, so the same integrator works over [0, 1] and
+    // [1, 3].
+    let mut integrator = FixedIntegrator::new(IntegrationFixedType::legendre(), 10, 0., 0.);
+    let tolerance = IntegrationTolerance::new(0., 0.);
+    let estimate = integrator
+        .integrate(&|x: f64| x * x, 0., 1., tolerance)
+        .unwrap();
+    assert!((estimate.result - 1. / 3.).abs() < 1e-12);
+    let estimate = integrator
+        .integrate(&|x: f64| x * x, 1., 3., tolerance)
+        .unwrap();
+    assert!((estimate.result - 26. / 3.).abs() < 1e-12);
+    assert_eq!(estimate.neval, 10);
+}
+
+#[cfg(feature = "v2_5")]
+#[test]
+fn romberg() {
+    let mut w = RombergWorkspace::new(20).unwrap();
+    let (status, result, neval) = w.romberg(|x| x.sin(), 0., ::std::f64::consts::PI, 0., 1e-10);
+    assert_eq!(status, Value::Success);
+    assert!((result - 2.).abs() < 1e-9, "{}", result);
+    assert!(neval > 0);
+
+    check_integrator(w, false);
+}
+
+// Checks that the subintervals of `w` split `[a, b]`.
+#[cfg(test)]
+fn check_subintervals(w: &IntegrationWorkspace, a: f64, b: f64) {
+    let subintervals = w.subintervals();
+    assert_eq!(subintervals.len(), w.size());
+    assert_eq!(subintervals[0].a, a);
+    assert_eq!(subintervals[subintervals.len() - 1].b, b);
+    for pair in subintervals.windows(2) {
+        assert_eq!(pair[0].b, pair[1].a);
+    }
+    assert!(subintervals.iter().all(|s| s.level <= w.maximum_level()));
+}
+
+// Checks that the results of the subintervals of `w` add up to the result of a non-extrapolated
+// integration.
+#[cfg(test)]
+fn check_subinterval_results(w: &IntegrationWorkspace, outcome: &IntegrationOutcome) {
+    let sum: f64 = w.subintervals().iter().map(|s| s.result).sum();
+    assert!(
+        (sum - outcome.result).abs() < 1e-12,
+        "{} != {}",
+        sum,
+        outcome.result
+    );
+}
+
+#[test]
+fn integration_outcome() {
+    let mut w = IntegrationWorkspace::new(1000).unwrap();
+    assert!(w.subintervals().is_empty());
+
+    let outcome = w.qags(|x| x * x, 0., 1., 0., 1e-10, 1000);
+    assert_eq!(outcome.status, Value::Success);
+    assert!((outcome.result - 1. / 3.).abs() < 1e-12);
+    assert!(outcome.abserr < 1e-10);
+    // Each subinterval is integrated with the 21-point rule.
+    assert!(outcome.neval > 0);
+    assert_eq!(outcome.neval % 21, 0);
+    check_subintervals(&w, 0., 1.);
+    check_subinterval_results(&w, &outcome);
+
+    let outcome = w.qag(
+        |x| x.sin(),
+        0.,
+        ::std::f64::consts::PI,
+        0.,
+        1e-10,
+        1000,
+        enums::GaussKronrodRule::Gauss61,
+    );
+    assert_eq!(outcome.status, Value::Success);
+    assert!((outcome.result - 2.).abs() < 1e-10);
+    assert_eq!(outcome.neval % 61, 0);
+    check_subintervals(&w, 0., ::std::f64::consts::PI);
+    check_subinterval_results(&w, &outcome);
+}
+
+#[test]
+fn integration_subintervals_singularity() {
+    // The integrand is singular at 0, where the subintervals concentrate.
+    let mut w = IntegrationWorkspace::new(1000).unwrap();
+    let outcome = w.qags(|x| x.ln() / x.sqrt(), 0., 1., 0., 1e-7, 1000);
+    assert_eq!(outcome.status, Value::Success);
+    assert!((outcome.result + 4.).abs() < 1e-6);
+    check_subintervals(&w, 0., 1.);
+
+    let subintervals = w.subintervals();
+    assert!(subintervals.len() > 1);
+    assert!(w.maximum_level() > 1);
+    assert_eq!(subintervals[0].level, w.maximum_level());
+}
+
+#[test]
+fn integration_subintervals_infinite_range() {
+    // The subintervals are bounds on the transformed variable, in (0, 1].
+    let mut w = IntegrationWorkspace::new(1000).unwrap();
+    let outcome = w.qagiu(|x| (-x).exp(), 0., 0., 1e-10, 1000);
+    assert_eq!(outcome.status, Value::Success);
+    assert!((outcome.result - 1.).abs() < 1e-10);
+    assert_eq!(outcome.neval % 15, 0);
+    check_subintervals(&w, 0., 1.);
+}
@@ -57,6 +57,63 @@ use std::fmt::{self, Debug, Formatter};
 use std::marker::PhantomData;
 use types::{VectorF32, VectorF64, VectorI32, VectorU32};
 use types::{VectorF32View, VectorF64View, VectorI32View, VectorU32View};
+use types::{VectorF32ViewMut, VectorF64ViewMut, VectorI32ViewMut, VectorU32ViewMut};
+
+macro_rules! matrix_view_common {
+    ($view:ident, $rust_name:ident, $name:ident, $rust_ty:ident) => {
+        paste! {
+        impl<'a> $view<'a> {
+            pub fn size1(&self) -> usize {
+                self.m.size1
+            }
+
+            pub fn size2(&self) -> usize {
+                self.m.size2
+            }
+
+            /// This function returns the (i,j)-th element of the view. If y or x lie outside the
+            /// allowed range then the error handler is invoked and 0 is returned.
+            #[doc(alias = $name _get)]
+            pub fn get(&self, y: usize, x: usize) -> $rust_ty {
+                unsafe { sys::[<$name _get>](&self.m, y, x) }
+            }
+
+            #[doc = "Copies the elements of the view into a new " $rust_name "."]
+            pub fn to_matrix(&self) -> Option<$rust_name> {
+                let mut m = $rust_name::new(self.size1(), self.size2())?;
+                m.copy_from(self);
+                Some(m)
+            }
+        }
+
+        impl<'a> Debug for $view<'a> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                let mut list = f.debug_list();
+                for y in 0..self.size1() {
+                    list.entry(&(0..self.size2()).map(|x| self.get(y, x)).collect::<Vec<_>>());
+                }
+                list.finish()
+            }
+        }
+
+        impl<'a> ::types::container::sealed::Sealed for $view<'a> {}
+
+        impl<'a> ::types::GslMatrix<$rust_ty> for $view<'a> {
+            fn unwrap_shared(&self) -> *const sys::$name {
+                &self.m
+            }
+
+            fn size1(&self) -> usize {
+                self.m.size1
+            }
+
+            fn size2(&self) -> usize {
+                self.m.size2
+            }
+        }
+        }
+    };
+}
 
 macro_rules! gsl_matrix {
     ($rust_name:ident, $name:ident, $rust_ty:ident, $vec_name:ident, $vec_c_name:ident) => (
@@ -129,21 +186,21 @@ impl $rust_name {
     /// This function copies the elements of the other matrix into the self matrix. The two matrices
     /// must have the same size.
     #[doc(alias = $name _memcpy)]
-    pub fn copy_from(&mut self, other: &$rust_name) -> Value {
+    pub fn copy_from(&mut self, other: &impl ::types::GslMatrix<$rust_ty>) -> Value {
         Value::from(unsafe { sys::[<$name _memcpy>](self.unwrap_unique(), other.unwrap_shared()) })
     }
 
     /// This function copies the elements of the self matrix into the other matrix. The two matrices
     /// must have the same size.
     #[doc(alias = $name _memcpy)]
-    pub fn copy_to(&self, other: &mut $rust_name) -> Value {
+    pub fn copy_to(&self, other: &mut impl ::types::GslMatrixMut<$rust_ty>) -> Value {
         Value::from(unsafe { sys::[<$name _memcpy>](other.unwrap_unique(), self.unwrap_shared()) })
     }
 
     /// This function exchanges the elements of the matrices self and other by copying. The two
     /// matrices must have the same size.
     #[doc(alias = $name _swap)]
-    pub fn swap(&mut self, other: &mut $rust_name) -> Value {
+    pub fn swap(&mut self, other: &mut impl ::types::GslMatrixMut<$rust_ty>) -> Value {
         Value::from(unsafe { sys::[<$name _swap>](self.unwrap_unique(), other.unwrap_unique()) })
     }
 
@@ -178,14 +235,14 @@ impl $rust_name {
     /// This function copies the elements of the vector v into the y-th row of the matrix.
     /// The length of the vector must be the same as the length of the row.
     #[doc(alias = $name _set_row)]
-    pub fn set_row(&mut self, y: usize, v: &$vec_name) -> Value {
+    pub fn set_row(&mut self, y: usize, v: &impl ::types::GslVector<$rust_ty>) -> Value {
         Value::from(unsafe { sys::[<$name _set_row>](self.unwrap_unique(), y, v.unwrap_shared()) })
     }
 
     /// This function copies the elements of the vector v into the x-th column of the matrix.
     /// The length of the vector must be the same as the length of the column.
     #[doc(alias = $name _set_col)]
-    pub fn set_col(&mut self, x: usize, v: &$vec_name) -> Value {
+    pub fn set_col(&mut self, x: usize, v: &impl ::types::GslVector<$rust_ty>) -> Value {
         Value::from(unsafe { sys::[<$name _set_col>](self.unwrap_unique(), x, v.unwrap_shared()) })
     }
 
@@ -235,7 +292,7 @@ impl $rust_name {
     /// The result self(i,j) <- self(i,j) + other(i,j) is stored in self and other remains
     /// unchanged. The two matrices must have the same dimensions.
     #[doc(alias = $name _add)]
-    pub fn add(&mut self, other: &$rust_name) -> Value {
+    pub fn add(&mut self, other: &impl ::types::GslMatrix<$rust_ty>) -> Value {
         Value::from(unsafe { sys::[<$name _add>](self.unwrap_unique(), other.unwrap_shared()) })
     }
 
@@ -243,7 +300,7 @@ impl $rust_name {
     /// matrix. The result self(i,j) <- self(i,j) - other(i,j) is stored in self and other remains
     /// unchanged. The two matrices must have the same dimensions.
     #[doc(alias = $name _sub)]
-    pub fn sub(&mut self, other: &$rust_name) -> Value {
+    pub fn sub(&mut self, other: &impl ::types::GslMatrix<$rust_ty>) -> Value {
         Value::from(unsafe { sys::[<$name _sub>](self.unwrap_unique(), other.unwrap_shared()) })
     }
 
@@ -251,7 +308,7 @@ impl $rust_name {
     /// matrix. The result self(i,j) <- self(i,j) * other(i,j) is stored in self and other remains
     /// unchanged. The two matrices must have the same dimensions.
     #[doc(alias = $name _mul_elements)]
-    pub fn mul_elements(&mut self, other: &$rust_name) -> Value {
+    pub fn mul_elements(&mut self, other: &impl ::types::GslMatrix<$rust_ty>) -> Value {
         Value::from(unsafe {
             sys::[<$name _mul_elements>](self.unwrap_unique(), other.unwrap_shared())
         })
@@ -261,7 +318,7 @@ impl $rust_name {
     /// The result self(i,j) <- self(i,j) / other(i,j) is stored in self and other remains
     /// unchanged. The two matrices must have the same dimensions.
     #[doc(alias = $name _div_elements)]
-    pub fn div_elements(&mut self, other: &$rust_name) -> Value {
+    pub fn div_elements(&mut self, other: &impl ::types::GslMatrix<$rust_ty>) -> Value {
         Value::from(unsafe {
             sys::[<$name _div_elements>](self.unwrap_unique(), other.unwrap_shared())
         })
@@ -379,56 +436,10 @@ impl $rust_name {
 
     /// This function returns true if all elements of the two matrix are equal.
     #[doc(alias = $name _equal)]
-    pub fn equal(&self, other: &$rust_name) -> bool {
+    pub fn equal(&self, other: &impl ::types::GslMatrix<$rust_ty>) -> bool {
         unsafe { sys::[<$name _equal>](self.unwrap_shared(), other.unwrap_shared()) == 1 }
     }
 
-    #[doc(alias = $name _row)]
-    pub fn row<F: FnOnce(Option<[<$vec_name View>]>)>(&mut self, i: usize, f: F) {
-        [<$vec_name View>]::wrap(unsafe { sys::[<$name _row>](self.unwrap_unique(), i) }, f)
-    }
-
-    #[doc(alias = $name _column)]
-    pub fn column<F: FnOnce(Option<[<$vec_name View>]>)>(&mut self, j: usize, f: F) {
-        [<$vec_name View>]::wrap(unsafe { sys::[<$name _column>](self.unwrap_unique(), j) }, f)
-    }
-
-    #[doc(alias = $name _diagonal)]
-    pub fn diagonal<F: FnOnce(Option<[<$vec_name View>]>)>(&mut self, f: F) {
-        [<$vec_name View>]::wrap(unsafe { sys::[<$name _diagonal>](self.unwrap_unique()) }, f)
-    }
-
-    #[doc(alias = $name _subdiagonal)]
-    pub fn subdiagonal<F: FnOnce(Option<[<$vec_name View>]>)>(&mut self, k: usize, f: F) {
-        [<$vec_name View>]::wrap(unsafe { sys::[<$name _subdiagonal>](self.unwrap_unique(), k) }, f)
-    }
-
-    #[doc(alias = $name _superdiagonal)]
-    pub fn superdiagonal<F: FnOnce(Option<[<$vec_name View>]>)>(&mut self, k: usize, f: F) {
-        [<$vec_name View>]::wrap(unsafe { sys::[<$name _superdiagonal>](self.unwrap_unique(), k) }, f)
-    }
-
-    #[doc(alias = $name _subrow)]
-    pub fn subrow<F: FnOnce(Option<[<$vec_name View>]>)>(&mut self, i: usize, offset: usize, n: usize, f: F) {
-        [<$vec_name View>]::wrap(unsafe { sys::[<$name _subrow>](self.unwrap_unique(), i, offset, n) }, f)
-    }
-
-    #[doc(alias = $name _subcolumn)]
-    pub fn subcolumn<F: FnOnce(Option<[<$vec_name View>]>)>(&mut self, i: usize, offset: usize, n: usize, f: F) {
-        [<$vec_name View>]::wrap(unsafe { sys::[<$name _subcolumn>](self.unwrap_unique(), i, offset, n) }, f)
-    }
-
-    #[doc(alias = $name _submatrix)]
-    pub fn submatrix<'a>(
-        &'a mut self,
-        k1: usize,
-        k2: usize,
-        n1: usize,
-        n2: usize,
-    ) -> [<$rust_name View>]<'a> {
-        [<$rust_name View>]::from_matrix(self, k1, k2, n1, n2)
-    }
-
     pub fn size1(&self) -> usize {
         if self.unwrap_shared().is_null() {
             0
@@ -463,6 +474,47 @@ impl $rust_name {
     pub fn is_ptr_null(&self) -> bool {
         self.unwrap_shared().is_null()
     }
+
+    /// Returns a view of the whole matrix.
+    pub fn view(&self) -> [<$rust_name View>]<'_> {
+        [<$rust_name View>] {
+            m: unsafe { *self.unwrap_shared() },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns a mutable view of the whole matrix.
+    pub fn view_mut(&mut self) -> [<$rust_name ViewMut>]<'_> {
+        [<$rust_name ViewMut>] {
+            m: unsafe { *self.unwrap_unique() },
+            phantom: PhantomData,
+        }
+    }
+
+    shared_matrix_views!($name, $rust_ty, [<$vec_name View>], [<$rust_name View>]);
+    mut_matrix_views!($name, $rust_ty, 1, [<$vec_name ViewMut>], [<$rust_name ViewMut>]);
+}
+
+impl ::types::container::sealed::Sealed for $rust_name {}
+
+impl ::types::GslMatrix<$rust_ty> for $rust_name {
+    fn unwrap_shared(&self) -> *const sys::$name {
+        self.mat as *const _
+    }
+
+    fn size1(&self) -> usize {
+        $rust_name::size1(self)
+    }
+
+    fn size2(&self) -> usize {
+        $rust_name::size2(self)
+    }
+}
+
+impl ::types::GslMatrixMut<$rust_ty> for $rust_name {
+    fn unwrap_unique(&mut self) -> *mut sys::$name {
+        self.mat
+    }
 }
 
 impl Drop for $rust_name {
@@ -526,48 +578,126 @@ impl Debug for $rust_name {
     }
 }
 
+/// A view of the elements of a matrix, an array or a vector which doesn't allow to modify them.
+///
+/// It can be passed to all the functions expecting a matrix.
 pub struct [<$rust_name View>]<'a> {
-    mat: sys::[<$name _view>],
-    #[allow(dead_code)]
-    phantom: PhantomData<&'a ()>,
+    m: sys::$name,
+    phantom: PhantomData<&'a $rust_ty>,
+}
+
+/// A view of the elements of a matrix, an array or a vector which allows to modify them.
+///
+/// It can be passed to all the functions expecting a (mutable) matrix.
+pub struct [<$rust_name ViewMut>]<'a> {
+    m: sys::$name,
+    phantom: PhantomData<&'a mut $rust_ty>,
 }
 
 impl<'a> [<$rust_name View>]<'a> {
-    /// These functions return a matrix view of a submatrix of the matrix m. The upper-left element
-    /// of the submatrix is the element (k1,k2) of the original matrix. The submatrix has n1 rows
-    /// and n2 columns. The physical number of columns in memory given by tda is unchanged.
-    /// Mathematically, the (i,j)-th element of the new matrix is given by,
+    pub(crate) fn from_raw(m: sys::$name) -> Option<Self> {
+        if m.data.is_null() {
+            None
+        } else {
+            Some(Self {
+                m,
+                phantom: PhantomData,
+            })
+        }
+    }
+
+    /// These functions return a matrix view of the array base. The matrix has n1 rows and n2
+    /// columns. The physical number of columns in memory is also given by n2. Mathematically, the
+    /// (i,j)-th element of the new matrix is given by,
     ///
-    /// m'(i,j) = m->data[(k1*m->tda + k2) + i*m->tda + j]
+    /// m'(i,j) = base[i*n2 + j]
     ///
     /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
     ///
-    /// The data pointer of the returned matrix struct is set to null if the combined parameters
-    /// (i,j,n1,n2,tda) overrun the ends of the original
-    /// matrix.
+    /// The new matrix is only a view of the array base. The view borrows the array so it cannot
+    /// outlive it.
+    #[doc(alias = $name _const_view_array)]
+    pub fn from_array(base: &'a [$rust_ty], n1: usize, n2: usize) -> Self {
+        assert!(
+            n1 * n2 <= base.len() as _,
+            "n1 * n2 cannot be longer than base"
+        );
+        Self {
+            m: unsafe { sys::[<$name _const_view_array>](base.as_ptr(), n1, n2).matrix },
+            phantom: PhantomData,
+        }
+    }
+
+    /// These functions return a matrix view of the array base with a physical number of columns tda
+    /// which may differ from the corresponding dimension of the matrix. The matrix has n1 rows and
+    /// n2 columns, and the physical number of columns in memory is given by tda. Mathematically,
+    /// the (i,j)-th element of the new matrix is given by,
+    ///
+    /// m'(i,j) = base[i*tda + j]
+    ///
+    /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
+    #[doc(alias = $name _const_view_array_with_tda)]
+    pub fn from_array_with_tda(base: &'a [$rust_ty], n1: usize, n2: usize, tda: usize) -> Self {
+        assert!(
+            n1 == 0 || (n1 - 1) * tda + n2 <= base.len(),
+            "n1 rows of tda elements cannot be longer than base"
+        );
+        Self {
+            m: unsafe {
+                sys::[<$name _const_view_array_with_tda>](base.as_ptr(), n1, n2, tda).matrix
+            },
+            phantom: PhantomData,
+        }
+    }
+
+    /// These functions return a matrix view of the vector v. The matrix has n1 rows and n2 columns.
+    /// The vector must have unit stride. The physical number of columns in memory is also given by
+    /// n2. Mathematically, the (i,j)-th element of the new matrix is given by,
+    ///
+    /// m'(i,j) = v->data[i*n2 + j]
+    ///
+    /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
+    ///
+    /// Returns `None` if the vector doesn't have unit stride or is too short.
+    #[doc(alias = $name _const_view_vector)]
+    pub fn from_vector(v: &'a impl ::types::GslVector<$rust_ty>, n1: usize, n2: usize) -> Option<Self> {
+        Self::from_raw(unsafe { sys::[<$name _const_view_vector>](v.unwrap_shared(), n1, n2).matrix })
+    }
+
+    /// These functions return a matrix view of the vector v with a physical number of columns tda
+    /// which may differ from the corresponding matrix dimension. The vector must have unit stride.
+    /// The matrix has n1 rows and n2 columns, and the physical number of columns in memory is given
+    /// by tda. Mathematically, the (i,j)-th element of the new matrix is given by,
+    ///
+    /// m'(i,j) = v->data[i*tda + j]
     ///
-    /// The new matrix view is only a view of the block underlying the existing matrix, m. The
-    /// block containing the elements of m is not
-    /// owned by the new matrix view. When the view goes out of scope the original matrix m and its
-    /// block will continue to exist. The original memory can only be deallocated by freeing the
-    /// original matrix. Of course, the original matrix should not be deallocated while the view
-    /// is still in use.
+    /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
     ///
-    /// The function gsl_matrix_const_submatrix is equivalent to gsl_matrix_submatrix but can be
-    /// used for matrices which are declared const.
-    #[doc(alias = $name _submatrix)]
-    pub fn from_matrix(
-        m: &'a mut $rust_name,
-        k1: usize,
-        k2: usize,
+    /// Returns `None` if the vector doesn't have unit stride or is too short.
+    #[doc(alias = $name _const_view_vector_with_tda)]
+    pub fn from_vector_with_tda(
+        v: &'a impl ::types::GslVector<$rust_ty>,
         n1: usize,
         n2: usize,
-    ) -> Self {
-        unsafe {
-            Self {
-                mat: sys::[<$name _submatrix>](m.mat, k1, k2, n1, n2),
+        tda: usize,
+    ) -> Option<Self> {
+        Self::from_raw(unsafe {
+            sys::[<$name _const_view_vector_with_tda>](v.unwrap_shared(), n1, n2, tda).matrix
+        })
+    }
+
+    shared_matrix_views!($name, $rust_ty, [<$vec_name View>], [<$rust_name View>]);
+}
+
+impl<'a> [<$rust_name ViewMut>]<'a> {
+    pub(crate) fn from_raw(m: sys::$name) -> Option<Self> {
+        if m.data.is_null() {
+            None
+        } else {
+            Some(Self {
+                m,
                 phantom: PhantomData,
-            }
+            })
         }
     }
 
@@ -579,24 +709,17 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
     ///
-    /// The new matrix is only a view of the array base. When the view goes out of scope the
-    /// original array base will continue to exist. The original memory can only be deallocated by
-    /// freeing the original array. Of course, the original array should not be deallocated while
-    /// the view is still in use.
-    ///
-    /// The function gsl_matrix_const_view_array is equivalent to gsl_matrix_view_array but can be
-    /// used for matrices which are declared const.
+    /// The new matrix is only a view of the array base. The view borrows the array so it cannot
+    /// outlive it.
     #[doc(alias = $name _view_array)]
     pub fn from_array(base: &'a mut [$rust_ty], n1: usize, n2: usize) -> Self {
         assert!(
             n1 * n2 <= base.len() as _,
             "n1 * n2 cannot be longer than base"
         );
-        unsafe {
-            Self {
-                mat: sys::[<$name _view_array>](base.as_mut_ptr(), n1, n2),
-                phantom: PhantomData,
-            }
+        Self {
+            m: unsafe { sys::[<$name _view_array>](base.as_mut_ptr(), n1, n2).matrix },
+            phantom: PhantomData,
         }
     }
 
@@ -608,21 +731,17 @@ impl<'a> [<$rust_name View>]<'a> {
     /// m'(i,j) = base[i*tda + j]
     ///
     /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
-    ///
-    /// The new matrix is only a view of the array base. When the view goes out of scope the
-    /// original array base will continue to exist. The original memory can only be deallocated by
-    /// freeing the original array. Of course, the original array should not be deallocated while
-    /// the view is still in use.
-    ///
-    /// The function gsl_matrix_const_view_array_with_tda is equivalent to
-    /// gsl_matrix_view_array_with_tda but can be used for matrices which are declared const.
     #[doc(alias = $name _view_array_with_tda)]
     pub fn from_array_with_tda(base: &'a mut [$rust_ty], n1: usize, n2: usize, tda: usize) -> Self {
-        unsafe {
-            Self {
-                mat: sys::[<$name _view_array_with_tda>](base.as_mut_ptr(), n1, n2, tda),
-                phantom: PhantomData,
-            }
+        assert!(
+            n1 == 0 || (n1 - 1) * tda + n2 <= base.len(),
+            "n1 rows of tda elements cannot be longer than base"
+        );
+        Self {
+            m: unsafe {
+                sys::[<$name _view_array_with_tda>](base.as_mut_ptr(), n1, n2, tda).matrix
+            },
+            phantom: PhantomData,
         }
     }
 
@@ -634,21 +753,14 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
     ///
-    /// The new matrix is only a view of the vector v. When the view goes out of scope the original
-    /// vector v will continue to exist. The original memory can only be deallocated by freeing the
-    /// original vector. Of course, the original vector should not be deallocated while the view
-    /// is still in use.
-    ///
-    /// The function gsl_matrix_const_view_vector is equivalent to gsl_matrix_view_vector but can be
-    /// used for matrices which are declared const.
+    /// Returns `None` if the vector doesn't have unit stride or is too short.
     #[doc(alias = $name _view_vector)]
-    pub fn from_vector(v: &'a mut $vec_name, n1: usize, n2: usize) -> Self {
-        unsafe {
-            Self {
-                mat: sys::[<$name _view_vector>](v.unwrap_unique(), n1, n2),
-                phantom: PhantomData,
-            }
-        }
+    pub fn from_vector(
+        v: &'a mut impl ::types::GslVectorMut<$rust_ty>,
+        n1: usize,
+        n2: usize,
+    ) -> Option<Self> {
+        Self::from_raw(unsafe { sys::[<$name _view_vector>](v.unwrap_unique(), n1, n2).matrix })
     }
 
     /// These functions return a matrix view of the vector v with a physical number of columns tda
@@ -660,43 +772,75 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
     ///
-    /// The new matrix is only a view of the vector v. When the view goes out of scope the original
-    /// vector v will continue to exist. The original memory can only be deallocated by freeing the
-    /// original vector. Of course, the original vector should not be deallocated while the view
-    /// is still in use.
-    ///
-    /// The function gsl_matrix_const_view_vector_with_tda is equivalent to
-    /// gsl_matrix_view_vector_with_tda but can be used for matrices which are declared const.
+    /// Returns `None` if the vector doesn't have unit stride or is too short.
     #[doc(alias = $name _view_vector_with_tda)]
-    pub fn from_vector_with_tda(v: &'a mut $vec_name, n1: usize, n2: usize, tda: usize) -> Self {
-        unsafe {
-            Self {
-                mat: sys::[<$name _view_vector_with_tda>](v.unwrap_unique(), n1, n2, tda),
-                phantom: PhantomData,
-            }
-        }
+    pub fn from_vector_with_tda(
+        v: &'a mut impl ::types::GslVectorMut<$rust_ty>,
+        n1: usize,
+        n2: usize,
+        tda: usize,
+    ) -> Option<Self> {
+        Self::from_raw(unsafe {
+            sys::[<$name _view_vector_with_tda>](v.unwrap_unique(), n1, n2, tda).matrix
+        })
     }
 
-    pub fn matrix<F: FnOnce(Option<&$rust_name>)>(&self, f: F) {
-        let tmp = &self.mat.matrix;
-        let tmp_mat = $rust_name::soft_wrap(tmp as *const _ as usize as *mut _);
-        if tmp_mat.is_ptr_null() {
-            f(None)
-        } else {
-            f(Some(&tmp_mat))
-        }
+    /// This function sets the value of the (i,j)-th element of the view to value.
+    #[doc(alias = $name _set)]
+    pub fn set(&mut self, y: usize, x: usize, value: $rust_ty) -> &mut Self {
+        unsafe { sys::[<$name _set>](&mut self.m, y, x, value) };
+        self
     }
 
-    pub fn matrix_mut<F: FnOnce(Option<&mut $rust_name>)>(&mut self, f: F) {
-        let tmp = &mut self.mat.matrix;
-        let mut tmp_mat = $rust_name::soft_wrap(tmp as *mut _);
-        if tmp_mat.is_ptr_null() {
-            f(None)
-        } else {
-            f(Some(&mut tmp_mat))
-        }
+    /// This function sets all the elements of the view to the value x.
+    #[doc(alias = $name _set_all)]
+    pub fn set_all(&mut self, x: $rust_ty) -> &mut Self {
+        unsafe { sys::[<$name _set_all>](&mut self.m, x) };
+        self
+    }
+
+    /// This function sets all the elements of the view to zero.
+    #[doc(alias = $name _set_zero)]
+    pub fn set_zero(&mut self) -> &mut Self {
+        unsafe { sys::[<$name _set_zero>](&mut self.m) };
+        self
     }
-} // end of impl block
+
+    /// This function sets the elements of the view to the corresponding elements of the identity
+    /// matrix.
+    #[doc(alias = $name _set_identity)]
+    pub fn set_identity(&mut self) -> &mut Self {
+        unsafe { sys::[<$name _set_identity>](&mut self.m) };
+        self
+    }
+
+    /// This function copies the elements of the other matrix into the view. The two matrices must
+    /// have the same size.
+    #[doc(alias = $name _memcpy)]
+    pub fn copy_from(&mut self, other: &impl ::types::GslMatrix<$rust_ty>) -> Value {
+        Value::from(unsafe { sys::[<$name _memcpy>](&mut self.m, other.unwrap_shared()) })
+    }
+
+    /// This function multiplies the elements of the view by the constant factor x.
+    #[doc(alias = $name _scale)]
+    pub fn scale(&mut self, x: f64) -> Value {
+        Value::from(unsafe { sys::[<$name _scale>](&mut self.m, x) })
+    }
+
+    shared_matrix_views!($name, $rust_ty, [<$vec_name View>], [<$rust_name View>]);
+    mut_matrix_views!($name, $rust_ty, 1, [<$vec_name ViewMut>], [<$rust_name ViewMut>]);
+}
+
+matrix_view_common!([<$rust_name View>], $rust_name, $name, $rust_ty);
+matrix_view_common!([<$rust_name ViewMut>], $rust_name, $name, $rust_ty);
+
+impl<'a> ::types::GslMatrixMut<$rust_ty> for [<$rust_name ViewMut>]<'a> {
+    fn unwrap_unique(&mut self) -> *mut sys::$name {
+        &mut self.m
+    }
+}
+
+matrix_ops!($name, $rust_ty, $rust_name, [<$rust_name View>], [<$rust_name ViewMut>]);
 } // end of paste! block
 
     ); // end of the gsl_matrix macro
@@ -712,3 +856,32 @@ gsl_matrix!(
 gsl_matrix!(MatrixF64, gsl_matrix, f64, VectorF64, gsl_vector);
 gsl_matrix!(MatrixI32, gsl_matrix_int, i32, VectorI32, gsl_vector_int);
 gsl_matrix!(MatrixU32, gsl_matrix_uint, u32, VectorU32, gsl_vector_uint);
+
+matrix_ops!(@neg gsl_matrix_float, f32, MatrixF32, MatrixF32View, MatrixF32ViewMut);
+matrix_ops!(@neg gsl_matrix, f64, MatrixF64, MatrixF64View, MatrixF64ViewMut);
+matrix_ops!(@neg gsl_matrix_int, i32, MatrixI32, MatrixI32View, MatrixI32ViewMut);
+
+matrix_product!(
+    f32,
+    MatrixF32,
+    VectorF32,
+    ::blas::level3::sgemm,
+    ::blas::level2::sgemv,
+    1.,
+    0.,
+    [([] MatrixF32) (['a,] MatrixF32View<'a>) (['a,] MatrixF32ViewMut<'a>)],
+    [([] MatrixF32) (['b,] MatrixF32View<'b>) (['b,] MatrixF32ViewMut<'b>)],
+    [([] VectorF32) (['b,] VectorF32View<'b>) (['b,] VectorF32ViewMut<'b>)]
+);
+matrix_product!(
+    f64,
+    MatrixF64,
+    VectorF64,
+    ::blas::level3::dgemm,
+    ::blas::level2::dgemv,
+    1.,
+    0.,
+    [([] MatrixF64) (['a,] MatrixF64View<'a>) (['a,] MatrixF64ViewMut<'a>)],
+    [([] MatrixF64) (['b,] MatrixF64View<'b>) (['b,] MatrixF64ViewMut<'b>)],
+    [([] VectorF64) (['b,] VectorF64View<'b>) (['b,] VectorF64ViewMut<'b>)]
+);
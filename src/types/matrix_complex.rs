@@ -6,12 +6,70 @@ use crate::paste::paste;
 use crate::Value;
 use ffi::FFI;
 use std::fmt::{self, Debug, Formatter};
+use std::marker::PhantomData;
+use types::complex::CFFI;
+
+macro_rules! matrix_complex_view_common {
+    ($view:ident, $rust_name:ident, $name:ident, $complex:ident) => {
+        paste! {
+        impl<'a> $view<'a> {
+            pub fn size1(&self) -> usize {
+                self.m.size1
+            }
+
+            pub fn size2(&self) -> usize {
+                self.m.size2
+            }
+
+            /// This function returns the (i,j)-th element of the view. If y or x lie outside the
+            /// allowed range then the error handler is invoked and 0 is returned.
+            #[doc(alias = $name _get)]
+            pub fn get(&self, y: usize, x: usize) -> $complex {
+                unsafe { CFFI::wrap(sys::[<$name _get>](&self.m, y, x)) }
+            }
+
+            #[doc = "Copies the elements of the view into a new " $rust_name "."]
+            pub fn to_matrix(&self) -> Option<$rust_name> {
+                let mut m = $rust_name::new(self.size1(), self.size2())?;
+                m.copy_from(self);
+                Some(m)
+            }
+        }
+
+        impl<'a> Debug for $view<'a> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                let mut list = f.debug_list();
+                for y in 0..self.size1() {
+                    list.entry(&(0..self.size2()).map(|x| self.get(y, x)).collect::<Vec<_>>());
+                }
+                list.finish()
+            }
+        }
+
+        impl<'a> ::types::container::sealed::Sealed for $view<'a> {}
+
+        impl<'a> ::types::GslMatrix<$complex> for $view<'a> {
+            fn unwrap_shared(&self) -> *const sys::$name {
+                &self.m
+            }
+
+            fn size1(&self) -> usize {
+                self.m.size1
+            }
+
+            fn size2(&self) -> usize {
+                self.m.size2
+            }
+        }
+        }
+    };
+}
 
 macro_rules! gsl_matrix_complex {
-    ($rust_name:ident, $name:ident, $complex:ident, $complex_c:ident) => (
+    ($rust_name:ident, $name:ident, $complex:ident, $complex_c:ident, $real:ident) => (
 paste! {
 
-use types::{$complex, [<Vector $complex>], [<Vector $complex View>]};
+use types::{$complex, [<Vector $complex>], [<Vector $complex View>], [<Vector $complex ViewMut>]};
 
 ffi_wrapper!(
     $rust_name,
@@ -42,6 +100,35 @@ impl $rust_name {
         }
     }
 
+    /// Returns the elements of the matrix in row-major order as `num_complex` numbers, or `None`
+    /// if the rows aren't contiguous in memory.
+    #[cfg(feature = "num-complex")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "num-complex")))]
+    pub fn as_num_complex_slice(&self) -> Option<&[::num_complex::Complex<$real>]> {
+        let m = unsafe { &*self.unwrap_shared() };
+        if m.data.is_null() || (m.tda != m.size2 && m.size1 > 1) {
+            None
+        } else {
+            Some(unsafe {
+                ::std::slice::from_raw_parts(m.data as *const _, m.size1 * m.size2)
+            })
+        }
+    }
+
+    /// Mutable version of [`as_num_complex_slice`](Self::as_num_complex_slice).
+    #[cfg(feature = "num-complex")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "num-complex")))]
+    pub fn as_num_complex_slice_mut(&mut self) -> Option<&mut [::num_complex::Complex<$real>]> {
+        let m = unsafe { &*self.unwrap_unique() };
+        if m.data.is_null() || (m.tda != m.size2 && m.size1 > 1) {
+            None
+        } else {
+            Some(unsafe {
+                ::std::slice::from_raw_parts_mut(m.data as *mut _, m.size1 * m.size2)
+            })
+        }
+    }
+
     /// This function returns the (i,j)-th element of the matrix.
     /// If y or x lie outside the allowed range of 0 to n1-1 and 0 to n2-1 then the error handler is
     /// invoked and 0 is returned.
@@ -81,7 +168,7 @@ impl $rust_name {
 
     /// This function copies the elements of the other matrix into the self matrix. The two matrices
     /// must have the same size.
-    pub fn copy_from(&mut self, other: &$rust_name) -> Value {
+    pub fn copy_from(&mut self, other: &impl ::types::GslMatrix<$complex>) -> Value {
         Value::from(unsafe {
             sys::[<$name _memcpy>](self.unwrap_unique(), other.unwrap_shared())
         })
@@ -89,7 +176,7 @@ impl $rust_name {
 
     /// This function copies the elements of the self matrix into the other matrix. The two matrices
     /// must have the same size.
-    pub fn copy_to(&self, other: &mut $rust_name) -> Value {
+    pub fn copy_to(&self, other: &mut impl ::types::GslMatrixMut<$complex>) -> Value {
         Value::from(unsafe {
             sys::[<$name _memcpy>](other.unwrap_unique(), self.unwrap_shared())
         })
@@ -97,7 +184,7 @@ impl $rust_name {
 
     /// This function exchanges the elements of the matrices self and other by copying. The two
     /// matrices must have the same size.
-    pub fn swap(&mut self, other: &mut $rust_name) -> Value {
+    pub fn swap(&mut self, other: &mut impl ::types::GslMatrixMut<$complex>) -> Value {
         Value::from(unsafe {
             sys::[<$name _swap>](self.unwrap_unique(), other.unwrap_unique())
         })
@@ -131,7 +218,7 @@ impl $rust_name {
 
     /// This function copies the elements of the vector v into the y-th row of the matrix.
     /// The length of the vector must be the same as the length of the row.
-    pub fn set_row(&mut self, y: usize, v: &[<Vector $complex>]) -> Value {
+    pub fn set_row(&mut self, y: usize, v: &impl ::types::GslVector<$complex>) -> Value {
         Value::from(unsafe {
             sys::[<$name _set_row>](self.unwrap_unique(), y, v.unwrap_shared())
         })
@@ -139,7 +226,7 @@ impl $rust_name {
 
     /// This function copies the elements of the vector v into the x-th column of the matrix.
     /// The length of the vector must be the same as the length of the column.
-    pub fn set_col(&mut self, x: usize, v: &[<Vector $complex>]) -> Value {
+    pub fn set_col(&mut self, x: usize, v: &impl ::types::GslVector<$complex>) -> Value {
         Value::from(unsafe {
             sys::[<$name _set_col>](self.unwrap_unique(), x, v.unwrap_shared())
         })
@@ -186,7 +273,7 @@ impl $rust_name {
     /// This function adds the elements of the other matrix to the elements of the `self` matrix.
     /// The result self(i,j) <- self(i,j) + other(i,j) is stored in `self` and other remains
     /// unchanged. The two matrices must have the same dimensions.
-    pub fn add(&mut self, other: &$rust_name) -> Value {
+    pub fn add(&mut self, other: &impl ::types::GslMatrix<$complex>) -> Value {
         Value::from(unsafe {
             sys::[<$name _add>](self.unwrap_unique(), other.unwrap_shared())
         })
@@ -195,7 +282,7 @@ impl $rust_name {
     /// This function subtracts the elements of the other matrix from the elements of the `self`
     /// matrix. The result self(i,j) <- self(i,j) - other(i,j) is stored in `self` and other remains
     /// unchanged. The two matrices must have the same dimensions.
-    pub fn sub(&mut self, other: &$rust_name) -> Value {
+    pub fn sub(&mut self, other: &impl ::types::GslMatrix<$complex>) -> Value {
         Value::from(unsafe {
             sys::[<$name _sub>](self.unwrap_unique(), other.unwrap_shared())
         })
@@ -204,7 +291,7 @@ impl $rust_name {
     /// This function multiplies the elements of the self matrix by the elements of the other
     /// matrix. The result self(i,j) <- self(i,j) * other(i,j) is stored in self and other remains
     /// unchanged. The two matrices must have the same dimensions.
-    pub fn mul_elements(&mut self, other: &$rust_name) -> Value {
+    pub fn mul_elements(&mut self, other: &impl ::types::GslMatrix<$complex>) -> Value {
         Value::from(unsafe {
             sys::[<$name _mul_elements>](self.unwrap_unique(), other.unwrap_shared())
         })
@@ -213,7 +300,7 @@ impl $rust_name {
     /// This function divides the elements of the self matrix by the elements of the other matrix.
     /// The result self(i,j) <- self(i,j) / other(i,j) is stored in self and other remains
     /// unchanged. The two matrices must have the same dimensions.
-    pub fn div_elements(&mut self, other: &$rust_name) -> Value {
+    pub fn div_elements(&mut self, other: &impl ::types::GslMatrix<$complex>) -> Value {
         Value::from(unsafe {
             sys::[<$name _div_elements>](self.unwrap_unique(), other.unwrap_shared())
         })
@@ -256,38 +343,10 @@ impl $rust_name {
     }
 
     /// This function returns true if all elements of the two matrix are equal.
-    pub fn equal(&self, other: &$rust_name) -> bool {
+    pub fn equal(&self, other: &impl ::types::GslMatrix<$complex>) -> bool {
         unsafe { sys::[<$name _equal>](self.unwrap_shared(), other.unwrap_shared()) == 1 }
     }
 
-    pub fn row<F: FnOnce(Option<[<Vector $complex View>]>)>(&mut self, i: usize, f: F) {
-        [<Vector $complex View>]::wrap(unsafe { sys::[<$name _row>](self.unwrap_unique(), i) }, f)
-    }
-
-    pub fn column<F: FnOnce(Option<[<Vector $complex View>]>)>(&mut self, j: usize, f: F) {
-        [<Vector $complex View>]::wrap(unsafe { sys::[<$name _column>](self.unwrap_unique(), j) }, f)
-    }
-
-    pub fn diagonal<F: FnOnce(Option<[<Vector $complex View>]>)>(&mut self, f: F) {
-        [<Vector $complex View>]::wrap(unsafe { sys::[<$name _diagonal>](self.unwrap_unique()) }, f)
-    }
-
-    pub fn subdiagonal<F: FnOnce(Option<[<Vector $complex View>]>)>(&mut self, k: usize, f: F) {
-        [<Vector $complex View>]::wrap(unsafe { sys::[<$name _subdiagonal>](self.unwrap_unique(), k) }, f)
-    }
-
-    pub fn superdiagonal<F: FnOnce(Option<[<Vector $complex View>]>)>(&mut self, k: usize, f: F) {
-        [<Vector $complex View>]::wrap(unsafe { sys::[<$name _superdiagonal>](self.unwrap_unique(), k) }, f)
-    }
-
-    pub fn subrow<F: FnOnce(Option<[<Vector $complex View>]>)>(&mut self, i: usize, offset: usize, n: usize, f: F) {
-        [<Vector $complex View>]::wrap(unsafe { sys::[<$name _subrow>](self.unwrap_unique(), i, offset, n) }, f)
-    }
-
-    pub fn subcolumn<F: FnOnce(Option<[<Vector $complex View>]>)>(&mut self, i: usize, offset: usize, n: usize, f: F) {
-        [<Vector $complex View>]::wrap(unsafe { sys::[<$name _subcolumn>](self.unwrap_unique(), i, offset, n) }, f)
-    }
-
     pub fn size1(&self) -> usize {
         if self.unwrap_shared().is_null() {
             0
@@ -317,6 +376,47 @@ impl $rust_name {
             }
         }
     }
+
+    /// Returns a view of the whole matrix.
+    pub fn view(&self) -> [<$rust_name View>]<'_> {
+        [<$rust_name View>] {
+            m: unsafe { *self.unwrap_shared() },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns a mutable view of the whole matrix.
+    pub fn view_mut(&mut self) -> [<$rust_name ViewMut>]<'_> {
+        [<$rust_name ViewMut>] {
+            m: unsafe { *self.unwrap_unique() },
+            phantom: PhantomData,
+        }
+    }
+
+    shared_matrix_views!($name, $complex, [<Vector $complex View>], [<$rust_name View>]);
+    mut_matrix_views!($name, $complex, 2, [<Vector $complex ViewMut>], [<$rust_name ViewMut>]);
+}
+
+impl ::types::container::sealed::Sealed for $rust_name {}
+
+impl ::types::GslMatrix<$complex> for $rust_name {
+    fn unwrap_shared(&self) -> *const sys::$name {
+        self.inner as *const _
+    }
+
+    fn size1(&self) -> usize {
+        $rust_name::size1(self)
+    }
+
+    fn size2(&self) -> usize {
+        $rust_name::size2(self)
+    }
+}
+
+impl ::types::GslMatrixMut<$complex> for $rust_name {
+    fn unwrap_unique(&mut self) -> *mut sys::$name {
+        self.inner
+    }
 }
 
 impl Debug for $rust_name {
@@ -344,6 +444,150 @@ impl Debug for $rust_name {
     }
 }
 
+/// A view of the elements of a matrix, an array or a vector which doesn't allow to modify them.
+///
+/// It can be passed to all the functions expecting a matrix.
+pub struct [<$rust_name View>]<'a> {
+    m: sys::$name,
+    phantom: PhantomData<&'a $complex>,
+}
+
+/// A view of the elements of a matrix, an array or a vector which allows to modify them.
+///
+/// It can be passed to all the functions expecting a (mutable) matrix.
+pub struct [<$rust_name ViewMut>]<'a> {
+    m: sys::$name,
+    phantom: PhantomData<&'a mut $complex>,
+}
+
+impl<'a> [<$rust_name View>]<'a> {
+    pub(crate) fn from_raw(m: sys::$name) -> Option<Self> {
+        if m.data.is_null() {
+            None
+        } else {
+            Some(Self {
+                m,
+                phantom: PhantomData,
+            })
+        }
+    }
+
+    /// These functions return a matrix view of the array base. The matrix has n1 rows and n2
+    /// columns. The physical number of columns in memory is also given by n2. Mathematically, the
+    /// (i,j)-th element of the new matrix is given by,
+    ///
+    /// m'(i,j) = base[i*n2 + j]
+    ///
+    /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
+    #[doc(alias = $name _const_view_array)]
+    pub fn from_array(base: &'a [$complex], n1: usize, n2: usize) -> Self {
+        assert!(n1 * n2 <= base.len(), "n1 * n2 cannot be longer than base");
+        Self {
+            m: unsafe {
+                sys::[<$name _const_view_array>](base.as_ptr() as *const _, n1, n2).matrix
+            },
+            phantom: PhantomData,
+        }
+    }
+
+    /// These functions return a matrix view of the vector v. The matrix has n1 rows and n2 columns.
+    /// The vector must have unit stride.
+    ///
+    /// Returns `None` if the vector doesn't have unit stride or is too short.
+    #[doc(alias = $name _const_view_vector)]
+    pub fn from_vector(v: &'a impl ::types::GslVector<$complex>, n1: usize, n2: usize) -> Option<Self> {
+        Self::from_raw(unsafe { sys::[<$name _const_view_vector>](v.unwrap_shared(), n1, n2).matrix })
+    }
+
+    shared_matrix_views!($name, $complex, [<Vector $complex View>], [<$rust_name View>]);
+}
+
+impl<'a> [<$rust_name ViewMut>]<'a> {
+    pub(crate) fn from_raw(m: sys::$name) -> Option<Self> {
+        if m.data.is_null() {
+            None
+        } else {
+            Some(Self {
+                m,
+                phantom: PhantomData,
+            })
+        }
+    }
+
+    /// These functions return a matrix view of the array base. The matrix has n1 rows and n2
+    /// columns. The physical number of columns in memory is also given by n2. Mathematically, the
+    /// (i,j)-th element of the new matrix is given by,
+    ///
+    /// m'(i,j) = base[i*n2 + j]
+    ///
+    /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
+    #[doc(alias = $name _view_array)]
+    pub fn from_array(base: &'a mut [$complex], n1: usize, n2: usize) -> Self {
+        assert!(n1 * n2 <= base.len(), "n1 * n2 cannot be longer than base");
+        Self {
+            m: unsafe {
+                sys::[<$name _view_array>](base.as_mut_ptr() as *mut _, n1, n2).matrix
+            },
+            phantom: PhantomData,
+        }
+    }
+
+    /// These functions return a matrix view of the vector v. The matrix has n1 rows and n2 columns.
+    /// The vector must have unit stride.
+    ///
+    /// Returns `None` if the vector doesn't have unit stride or is too short.
+    #[doc(alias = $name _view_vector)]
+    pub fn from_vector(
+        v: &'a mut impl ::types::GslVectorMut<$complex>,
+        n1: usize,
+        n2: usize,
+    ) -> Option<Self> {
+        Self::from_raw(unsafe { sys::[<$name _view_vector>](v.unwrap_unique(), n1, n2).matrix })
+    }
+
+    /// This function sets the value of the (i,j)-th element of the view to value.
+    #[doc(alias = $name _set)]
+    pub fn set(&mut self, y: usize, x: usize, value: &$complex) -> &mut Self {
+        unsafe { sys::[<$name _set>](&mut self.m, y, x, CFFI::unwrap(*value)) };
+        self
+    }
+
+    /// This function sets all the elements of the view to the value x.
+    #[doc(alias = $name _set_all)]
+    pub fn set_all(&mut self, x: &$complex) -> &mut Self {
+        unsafe { sys::[<$name _set_all>](&mut self.m, CFFI::unwrap(*x)) };
+        self
+    }
+
+    /// This function sets all the elements of the view to zero.
+    #[doc(alias = $name _set_zero)]
+    pub fn set_zero(&mut self) -> &mut Self {
+        unsafe { sys::[<$name _set_zero>](&mut self.m) };
+        self
+    }
+
+    /// This function copies the elements of the other matrix into the view. The two matrices must
+    /// have the same size.
+    #[doc(alias = $name _memcpy)]
+    pub fn copy_from(&mut self, other: &impl ::types::GslMatrix<$complex>) -> Value {
+        Value::from(unsafe { sys::[<$name _memcpy>](&mut self.m, other.unwrap_shared()) })
+    }
+
+    shared_matrix_views!($name, $complex, [<Vector $complex View>], [<$rust_name View>]);
+    mut_matrix_views!($name, $complex, 2, [<Vector $complex ViewMut>], [<$rust_name ViewMut>]);
+}
+
+matrix_complex_view_common!([<$rust_name View>], $rust_name, $name, $complex);
+matrix_complex_view_common!([<$rust_name ViewMut>], $rust_name, $name, $complex);
+
+impl<'a> ::types::GslMatrixMut<$complex> for [<$rust_name ViewMut>]<'a> {
+    fn unwrap_unique(&mut self) -> *mut sys::$name {
+        &mut self.m
+    }
+}
+
+matrix_ops!($name, $complex, $rust_name, [<$rust_name View>], [<$rust_name ViewMut>]);
+matrix_ops!(@neg $name, $complex, $rust_name, [<$rust_name View>], [<$rust_name ViewMut>]);
 } // end of paste! block
 ); // end of macro block
 }
@@ -352,11 +596,62 @@ gsl_matrix_complex!(
     MatrixComplexF64,
     gsl_matrix_complex,
     ComplexF64,
-    gsl_vector_complex
+    gsl_vector_complex,
+    f64
 );
 gsl_matrix_complex!(
     MatrixComplexF32,
     gsl_matrix_complex_float,
     ComplexF32,
-    gsl_vector_complex_float
+    gsl_vector_complex_float,
+    f32
+);
+
+matrix_product!(
+    ComplexF64,
+    MatrixComplexF64,
+    VectorComplexF64,
+    ::blas::level3::zgemm,
+    ::blas::level2::zgemv,
+    &ComplexF64::rect(1., 0.),
+    &ComplexF64::rect(0., 0.),
+    [
+        ([] MatrixComplexF64)
+        (['a,] MatrixComplexF64View<'a>)
+        (['a,] MatrixComplexF64ViewMut<'a>)
+    ],
+    [
+        ([] MatrixComplexF64)
+        (['b,] MatrixComplexF64View<'b>)
+        (['b,] MatrixComplexF64ViewMut<'b>)
+    ],
+    [
+        ([] VectorComplexF64)
+        (['b,] VectorComplexF64View<'b>)
+        (['b,] VectorComplexF64ViewMut<'b>)
+    ]
+);
+matrix_product!(
+    ComplexF32,
+    MatrixComplexF32,
+    VectorComplexF32,
+    ::blas::level3::cgemm,
+    ::blas::level2::cgemv,
+    &ComplexF32::rect(1., 0.),
+    &ComplexF32::rect(0., 0.),
+    [
+        ([] MatrixComplexF32)
+        (['a,] MatrixComplexF32View<'a>)
+        (['a,] MatrixComplexF32ViewMut<'a>)
+    ],
+    [
+        ([] MatrixComplexF32)
+        (['b,] MatrixComplexF32View<'b>)
+        (['b,] MatrixComplexF32ViewMut<'b>)
+    ],
+    [
+        ([] VectorComplexF32)
+        (['b,] VectorComplexF32View<'b>)
+        (['b,] VectorComplexF32ViewMut<'b>)
+    ]
 );
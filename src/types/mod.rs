@@ -7,6 +7,7 @@ pub use self::basis_spline::BSpLineWorkspace;
 pub use self::chebyshev::ChebSeries;
 pub use self::combination::Combination;
 pub use self::complex::{ComplexF32, ComplexF64};
+pub use self::container::{Element, GslMatrix, GslMatrixMut, GslVector, GslVectorMut};
 pub use self::discrete_hankel::DiscreteHankel;
 pub use self::eigen_symmetric_workspace::{
     EigenGenHermVWorkspace, EigenGenHermWorkspace, EigenGenSymmVWorkspace, EigenGenSymmWorkspace,
@@ -23,20 +24,29 @@ pub use self::filter::{
     FilterGaussianWorkspace, FilterImpulseWorkspace, FilterMedianWorkspace, FilterRMedianWorkspace,
 };
 pub use self::histograms::{Histogram, Histogram2D, Histogram2DPdf, HistogramPdf};
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+pub use self::integration::RombergWorkspace;
 pub use self::integration::{
-    CquadWorkspace, GLFixedTable, IntegrationFixedType, IntegrationFixedWorkspace,
-    IntegrationQawoTable, IntegrationQawsTable, IntegrationWorkspace,
+    CquadWorkspace, FixedIntegrator, GLFixedTable, IntegrationEstimate, IntegrationFixedType,
+    IntegrationFixedWorkspace, IntegrationLevel, IntegrationOutcome, IntegrationQawoTable,
+    IntegrationQawsTable, IntegrationSubinterval, IntegrationTolerance, IntegrationWorkspace,
+    Integrator, QngIntegrator,
 };
 pub use self::interpolation::{Interp, InterpAccel, InterpType, Spline};
 pub use self::mathieu::MathieuWorkspace;
 pub use self::matrix::{
-    MatrixF32, MatrixF32View, MatrixF64, MatrixF64View, MatrixI32, MatrixI32View, MatrixU32,
-    MatrixU32View,
+    MatrixF32, MatrixF32View, MatrixF32ViewMut, MatrixF64, MatrixF64View, MatrixF64ViewMut,
+    MatrixI32, MatrixI32View, MatrixI32ViewMut, MatrixU32, MatrixU32View, MatrixU32ViewMut,
+};
+pub use self::matrix_complex::{
+    MatrixComplexF32, MatrixComplexF32View, MatrixComplexF32ViewMut, MatrixComplexF64,
+    MatrixComplexF64View, MatrixComplexF64ViewMut,
 };
-pub use self::matrix_complex::{MatrixComplexF32, MatrixComplexF64};
 pub use self::minimizer::{Minimizer, MinimizerType};
 pub use self::monte_carlo::{
-    MiserMonteCarlo, MiserParams, PlainMonteCarlo, VegasMonteCarlo, VegasParams,
+    MiserMonteCarlo, MiserParams, PlainMonteCarlo, VegasIteration, VegasMonteCarlo, VegasOutcome,
+    VegasParams,
 };
 pub use self::multifit_linear::MultifitLinearWorkspace;
 pub use self::multifit_solver::{
@@ -48,24 +58,27 @@ pub use self::multilarge_linear::{MultilargeLinearType, MultilargeLinearWorkspac
 pub use self::multiset::MultiSet;
 pub use self::n_tuples::{ReadNTuples, WriteNTuples};
 pub use self::ordinary_differential_equations::{
-    ODEiv2Control, ODEiv2Driver, ODEiv2Evolve, ODEiv2Step, ODEiv2StepType, ODEiv2System,
+    DenseOutput, ODEiv2Control, ODEiv2Driver, ODEiv2Event, ODEiv2EventDirection, ODEiv2EventHit,
+    ODEiv2Evolve, ODEiv2Mode, ODEiv2NumericalJacobian, ODEiv2OwnedDriver, ODEiv2OwnedSystem,
+    ODEiv2Stats, ODEiv2Step, ODEiv2StepType, ODEiv2System, Trajectory,
 };
-pub use self::permutation::Permutation;
+pub use self::permutation::{Permutation, Permutations};
 pub use self::polynomial::PolyComplexWorkspace;
 pub use self::qrng::{QRng, QRngType};
 pub use self::ran_discrete::RanDiscrete;
 pub use self::result::{Result, ResultE10};
-pub use self::rng::{Rng, RngType};
+pub use self::rng::{Rng, RngAlgorithm, RngType};
 pub use self::roots::{RootFSolver, RootFSolverType, RootFdfSolver, RootFdfSolverType};
 pub use self::rstat::{RStatQuantileWorkspace, RStatWorkspace};
 pub use self::series_acceleration::{LevinUTruncWorkspace, LevinUWorkspace};
 pub use self::siman::{SimAnnealing, SimAnnealingParams};
 pub use self::vector::{
-    VectorF32, VectorF32View, VectorF64, VectorF64View, VectorI32, VectorI32View, VectorU32,
-    VectorU32View,
+    VectorF32, VectorF32View, VectorF32ViewMut, VectorF64, VectorF64View, VectorF64ViewMut,
+    VectorI32, VectorI32View, VectorI32ViewMut, VectorU32, VectorU32View, VectorU32ViewMut,
 };
 pub use self::vector_complex::{
-    VectorComplexF32, VectorComplexF32View, VectorComplexF64, VectorComplexF64View,
+    VectorComplexF32, VectorComplexF32View, VectorComplexF32ViewMut, VectorComplexF64,
+    VectorComplexF64View, VectorComplexF64ViewMut,
 };
 pub use self::wavelet_transforms::{Wavelet, WaveletType, WaveletWorkspace};
 
@@ -73,6 +86,7 @@ pub mod basis_spline;
 pub mod chebyshev;
 pub mod combination;
 pub mod complex;
+pub mod container;
 pub mod discrete_hankel;
 pub mod eigen_symmetric_workspace;
 pub mod fast_fourier_transforms;
@@ -75,7 +75,6 @@ The estimates are averaged using the arithmetic mean, but no error is computed.
 
 use ffi::FFI;
 use std::marker::PhantomData;
-use std::mem::transmute;
 use std::os::raw::c_void;
 use std::slice;
 
@@ -142,13 +141,13 @@ impl PlainMonteCarlo {
         assert!(xl.len() == xu.len());
         let mut result = 0f64;
         let mut abserr = 0f64;
-        let f: Box<F> = Box::new(f);
+        let mut f = f;
+        let func = sys::gsl_monte_function {
+            f: Some(monte_trampoline::<F>),
+            dim: xl.len() as _,
+            params: &mut f as *mut F as *mut _,
+        };
         let ret = unsafe {
-            let func = sys::gsl_monte_function {
-                f: transmute(monte_trampoline::<F> as usize),
-                dim: xl.len() as _,
-                params: Box::into_raw(f) as *mut _,
-            };
             sys::gsl_monte_plain_integrate(
                 &func,
                 xl.as_ptr(),
@@ -242,13 +241,13 @@ impl MiserMonteCarlo {
         assert!(xl.len() == xu.len());
         let mut result = 0f64;
         let mut abserr = 0f64;
-        let f: Box<F> = Box::new(f);
+        let mut f = f;
+        let mut func = sys::gsl_monte_function {
+            f: Some(monte_trampoline::<F>),
+            dim: xl.len() as _,
+            params: &mut f as *mut F as *mut _,
+        };
         let ret = unsafe {
-            let mut func = sys::gsl_monte_function {
-                f: transmute(monte_trampoline::<F> as usize),
-                dim: xl.len() as _,
-                params: Box::into_raw(f) as *mut _,
-            };
             sys::gsl_monte_miser_integrate(
                 &mut func,
                 xl.as_ptr(),
@@ -401,13 +400,13 @@ impl VegasMonteCarlo {
         assert!(xl.len() == xu.len());
         let mut result = 0f64;
         let mut abserr = 0f64;
-        let f: Box<F> = Box::new(f);
+        let mut f = f;
+        let mut func = sys::gsl_monte_function {
+            f: Some(monte_trampoline::<F>),
+            dim: xl.len() as _,
+            params: &mut f as *mut F as *mut _,
+        };
         let ret = unsafe {
-            let mut func = sys::gsl_monte_function {
-                f: transmute(monte_trampoline::<F> as usize),
-                dim: xl.len() as _,
-                params: Box::into_raw(f) as *mut _,
-            };
             sys::gsl_monte_vegas_integrate(
                 &mut func,
                 xl.as_ptr() as usize as *mut _,
@@ -423,6 +422,128 @@ impl VegasMonteCarlo {
         (::Value::from(ret), result, abserr)
     }
 
+    /// Runs VEGAS until the estimate of the integral is both precise and reliable, as in the
+    /// example of the GSL documentation: a first integration with `warmup_calls` calls adapts the
+    /// grid, then integrations of `calls` calls are done until the relative error `sigma /
+    /// |result|` is below `epsrel` and the chi-squared per degree of freedom is consistent with 1
+    /// (within 0.5), or until `max_iterations` integrations have been done.
+    ///
+    /// The integrations following the first one after the warm-up continue its weighted average
+    /// (stage 3, see [`VegasParams`]), so that the error decreases with the iterations. The
+    /// estimate after each integration is given to `progress` and kept in the returned history.
+    /// The parameters of `self` are restored before returning.
+    ///
+    /// The status is [`Value::MaxIteration`](::Value::MaxIteration) if the estimate didn't
+    /// converge, or the status of the first integration which failed.
+    ///
+    /// ```no_run
+    /// use rgsl::{Rng, RngType, VegasMonteCarlo};
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut r = Rng::new(RngType::default()).unwrap();
+    /// let mut s = VegasMonteCarlo::new(3).unwrap();
+    /// let outcome = s.integrate_until(
+    ///     |k| 1. / (PI * PI * PI) / (1. - k[0].cos() * k[1].cos() * k[2].cos()),
+    ///     &[0.; 3],
+    ///     &[PI; 3],
+    ///     10000,
+    ///     100000,
+    ///     1e-3,
+    ///     100,
+    ///     &mut r,
+    ///     |it| println!("result = {} sigma = {} chisq/dof = {}", it.result, it.sigma, it.chisq),
+    /// );
+    /// println!("{} +/- {} ({:?})", outcome.result, outcome.sigma, outcome.status);
+    /// ```
+    pub fn integrate_until<F: FnMut(&[f64]) -> f64, P: FnMut(&VegasIteration)>(
+        &mut self,
+        f: F,
+        xl: &[f64],
+        xu: &[f64],
+        warmup_calls: usize,
+        calls: usize,
+        epsrel: f64,
+        max_iterations: usize,
+        r: &mut ::Rng,
+        progress: P,
+    ) -> VegasOutcome {
+        let mut saved = VegasParams::default();
+        unsafe { sys::gsl_monte_vegas_params_get(self.unwrap_shared(), &mut saved.inner) };
+        let outcome = self.iterate_until(
+            f,
+            xl,
+            xu,
+            warmup_calls,
+            calls,
+            epsrel,
+            max_iterations,
+            r,
+            progress,
+        );
+        unsafe { sys::gsl_monte_vegas_params_set(self.unwrap_unique(), &saved.inner) };
+        outcome
+    }
+
+    fn iterate_until<F: FnMut(&[f64]) -> f64, P: FnMut(&VegasIteration)>(
+        &mut self,
+        f: F,
+        xl: &[f64],
+        xu: &[f64],
+        warmup_calls: usize,
+        calls: usize,
+        epsrel: f64,
+        max_iterations: usize,
+        r: &mut ::Rng,
+        mut progress: P,
+    ) -> VegasOutcome {
+        let mut f = f;
+        let mut outcome = VegasOutcome {
+            status: ::Value::MaxIteration,
+            result: 0.,
+            sigma: 0.,
+            history: Vec::new(),
+        };
+
+        if warmup_calls > 0 {
+            let (status, _, _) = self.integrate(&mut f, xl, xu, warmup_calls, r);
+            if status != ::Value::Success {
+                outcome.status = status;
+                return outcome;
+            }
+        }
+
+        for i in 0..max_iterations {
+            if i >= 1 {
+                let mut params = VegasParams::default();
+                unsafe {
+                    sys::gsl_monte_vegas_params_get(self.unwrap_shared(), &mut params.inner);
+                    params.inner.stage = 3;
+                    sys::gsl_monte_vegas_params_set(self.unwrap_unique(), &params.inner);
+                }
+            }
+            let (status, result, sigma) = self.integrate(&mut f, xl, xu, calls, r);
+            if status != ::Value::Success {
+                outcome.status = status;
+                break;
+            }
+            let iteration = VegasIteration {
+                result,
+                sigma,
+                chisq: self.chisq(),
+            };
+            progress(&iteration);
+            outcome.history.push(iteration);
+            outcome.result = result;
+            outcome.sigma = sigma;
+
+            if sigma <= epsrel * result.abs() && (iteration.chisq - 1.).abs() <= 0.5 {
+                outcome.status = ::Value::Success;
+                break;
+            }
+        }
+        outcome
+    }
+
     /// This function returns the chi-squared per degree of freedom for the weighted estimate of the integral.
     /// The returned value should be close to 1. A value which differs significantly from 1 indicates that
     /// the values from different iterations are inconsistent. In this case the weighted error will be
@@ -461,8 +582,33 @@ impl VegasMonteCarlo {
     }
 }
 
+/// The estimate after one of the integrations of [`VegasMonteCarlo::integrate_until`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VegasIteration {
+    /// The weighted average of the estimates of the integral since the warm-up.
+    pub result: f64,
+    /// The estimate of the error of `result`.
+    pub sigma: f64,
+    /// The chi-squared per degree of freedom of the weighted average.
+    pub chisq: f64,
+}
+
+/// The outcome of [`VegasMonteCarlo::integrate_until`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct VegasOutcome {
+    /// [`Value::Success`](::Value::Success) if the estimate converged, see
+    /// [`VegasMonteCarlo::integrate_until`].
+    pub status: ::Value,
+    /// The estimate of the integral after the last integration.
+    pub result: f64,
+    /// The estimate of the error of `result`.
+    pub sigma: f64,
+    /// The estimates after each integration following the warm-up.
+    pub history: Vec<VegasIteration>,
+}
+
 pub struct VegasParams<'a> {
-    inner: sys::gsl_monte_vegas_params,
+    pub(crate) inner: sys::gsl_monte_vegas_params,
     lt: PhantomData<&'a ()>,
 }
 
@@ -805,3 +951,30 @@ fn vegas() {
         assert_eq!(&format!("{:.6}", err), "0.000335");
     }
 }
+
+#[test]
+fn vegas_integrate_until() {
+    use std::f64::consts::PI;
+    fn g(k: &[f64]) -> f64 {
+        let a = 1. / (PI * PI * PI);
+
+        a / (1. - k[0].cos() * k[1].cos() * k[2].cos())
+    }
+
+    let exact = 1.393_203_929_685_676_9;
+    let xl: [f64; 3] = [0f64; 3];
+    let xu: [f64; 3] = [PI, PI, PI];
+
+    ::RngType::env_setup();
+    let mut r = ::Rng::new(::RngType::default()).unwrap();
+
+    let mut s = VegasMonteCarlo::new(3).unwrap();
+    let mut iterations = 0;
+    let outcome = s.integrate_until(g, &xl, &xu, 10000, 100000, 1e-3, 50, &mut r, |_| {
+        iterations += 1
+    });
+    assert_eq!(outcome.status, ::Value::Success);
+    assert_eq!(outcome.history.len(), iterations);
+    assert!(outcome.sigma <= 1e-3 * outcome.result);
+    assert!((outcome.result - exact).abs() < 0.01);
+}
@@ -2,8 +2,11 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::{MatrixF64, Value, VectorF64};
+use crate::Value;
 use ffi::FFI;
+use types::{GslMatrix, GslMatrixMut, GslVector, GslVectorMut};
+#[cfg(feature = "v2_7")]
+use types::{MatrixF64View, VectorF64View};
 
 ffi_wrapper!(MultilargeLinearType, *const sys::gsl_multilarge_linear_type);
 
@@ -57,7 +60,11 @@ impl MultilargeLinearWorkspace {
     }
 
     #[doc(alias = "gsl_multilarge_linear_accumulate")]
-    pub fn accumulate(&mut self, x: &mut MatrixF64, y: &mut VectorF64) -> Value {
+    pub fn accumulate(
+        &mut self,
+        x: &mut impl GslMatrixMut<f64>,
+        y: &mut impl GslVectorMut<f64>,
+    ) -> Value {
         unsafe {
             Value::from(sys::gsl_multilarge_linear_accumulate(
                 x.unwrap_unique(),
@@ -69,7 +76,7 @@ impl MultilargeLinearWorkspace {
 
     /// Returns `(Value, rnorm, snorm)`.
     #[doc(alias = "gsl_multilarge_linear_solve")]
-    pub fn solve(&mut self, lambda: f64, c: &mut VectorF64) -> (Value, f64, f64) {
+    pub fn solve(&mut self, lambda: f64, c: &mut impl GslVectorMut<f64>) -> (Value, f64, f64) {
         let mut rnorm = 0.;
         let mut snorm = 0.;
         let ret = unsafe {
@@ -97,9 +104,9 @@ impl MultilargeLinearWorkspace {
     #[doc(alias = "gsl_multilarge_linear_lcurve")]
     pub fn lcurve(
         &mut self,
-        reg_param: &mut VectorF64,
-        rho: &mut VectorF64,
-        eta: &mut VectorF64,
+        reg_param: &mut impl GslVectorMut<f64>,
+        rho: &mut impl GslVectorMut<f64>,
+        eta: &mut impl GslVectorMut<f64>,
     ) -> Value {
         unsafe {
             Value::from(sys::gsl_multilarge_linear_lcurve(
@@ -114,12 +121,12 @@ impl MultilargeLinearWorkspace {
     #[doc(alias = "gsl_multilarge_linear_wstdform1")]
     pub fn wstdform1(
         &mut self,
-        L: &VectorF64,
-        X: &MatrixF64,
-        w: &VectorF64,
-        y: &VectorF64,
-        Xs: &mut MatrixF64,
-        ys: &mut VectorF64,
+        L: &impl GslVector<f64>,
+        X: &impl GslMatrix<f64>,
+        w: &impl GslVector<f64>,
+        y: &impl GslVector<f64>,
+        Xs: &mut impl GslMatrixMut<f64>,
+        ys: &mut impl GslVectorMut<f64>,
     ) -> Value {
         unsafe {
             Value::from(sys::gsl_multilarge_linear_wstdform1(
@@ -137,11 +144,11 @@ impl MultilargeLinearWorkspace {
     #[doc(alias = "gsl_multilarge_linear_stdform1")]
     pub fn stdform1(
         &mut self,
-        L: &VectorF64,
-        X: &MatrixF64,
-        y: &VectorF64,
-        Xs: &mut MatrixF64,
-        ys: &mut VectorF64,
+        L: &impl GslVector<f64>,
+        X: &impl GslMatrix<f64>,
+        y: &impl GslVector<f64>,
+        Xs: &mut impl GslMatrixMut<f64>,
+        ys: &mut impl GslVectorMut<f64>,
     ) -> Value {
         unsafe {
             Value::from(sys::gsl_multilarge_linear_stdform1(
@@ -158,13 +165,13 @@ impl MultilargeLinearWorkspace {
     #[doc(alias = "gsl_multilarge_linear_wstdform2")]
     pub fn wstdform2(
         &mut self,
-        LQR: &MatrixF64,
-        Ltau: &VectorF64,
-        X: &MatrixF64,
-        w: &VectorF64,
-        y: &VectorF64,
-        Xs: &mut MatrixF64,
-        ys: &mut VectorF64,
+        LQR: &impl GslMatrix<f64>,
+        Ltau: &impl GslVector<f64>,
+        X: &impl GslMatrix<f64>,
+        w: &impl GslVector<f64>,
+        y: &impl GslVector<f64>,
+        Xs: &mut impl GslMatrixMut<f64>,
+        ys: &mut impl GslVectorMut<f64>,
     ) -> Value {
         unsafe {
             Value::from(sys::gsl_multilarge_linear_wstdform2(
@@ -183,12 +190,12 @@ impl MultilargeLinearWorkspace {
     #[doc(alias = "gsl_multilarge_linear_stdform2")]
     pub fn stdform2(
         &mut self,
-        LQR: &MatrixF64,
-        Ltau: &VectorF64,
-        X: &MatrixF64,
-        y: &VectorF64,
-        Xs: &mut MatrixF64,
-        ys: &mut VectorF64,
+        LQR: &impl GslMatrix<f64>,
+        Ltau: &impl GslVector<f64>,
+        X: &impl GslMatrix<f64>,
+        y: &impl GslVector<f64>,
+        Xs: &mut impl GslMatrixMut<f64>,
+        ys: &mut impl GslVectorMut<f64>,
     ) -> Value {
         unsafe {
             Value::from(sys::gsl_multilarge_linear_stdform2(
@@ -204,7 +211,12 @@ impl MultilargeLinearWorkspace {
     }
 
     #[doc(alias = "gsl_multilarge_linear_genform1")]
-    pub fn genform1(&mut self, L: &VectorF64, cs: &VectorF64, c: &mut VectorF64) -> Value {
+    pub fn genform1(
+        &mut self,
+        L: &impl GslVector<f64>,
+        cs: &impl GslVector<f64>,
+        c: &mut impl GslVectorMut<f64>,
+    ) -> Value {
         Value::from(unsafe {
             sys::gsl_multilarge_linear_genform1(
                 L.unwrap_shared(),
@@ -218,10 +230,10 @@ impl MultilargeLinearWorkspace {
     #[doc(alias = "gsl_multilarge_linear_genform2")]
     pub fn genform2(
         &mut self,
-        LQR: &MatrixF64,
-        Ltau: &VectorF64,
-        cs: &VectorF64,
-        c: &mut VectorF64,
+        LQR: &impl GslMatrix<f64>,
+        Ltau: &impl GslVector<f64>,
+        cs: &impl GslVector<f64>,
+        c: &mut impl GslVectorMut<f64>,
     ) -> Value {
         Value::from(unsafe {
             sys::gsl_multilarge_linear_genform2(
@@ -237,18 +249,24 @@ impl MultilargeLinearWorkspace {
     #[cfg(feature = "v2_7")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     #[doc(alias = "gsl_multilarge_linear_matrix_ptr")]
-    pub fn matrix<F: FnOnce(&MatrixF64)>(&self, f: F) {
-        f(&MatrixF64::soft_wrap(unsafe {
-            sys::gsl_multilarge_linear_matrix_ptr(self.unwrap_shared()) as _
-        }))
+    pub fn matrix(&self) -> Option<MatrixF64View<'_>> {
+        let ptr = unsafe { sys::gsl_multilarge_linear_matrix_ptr(self.unwrap_shared()) };
+        if ptr.is_null() {
+            None
+        } else {
+            MatrixF64View::from_raw(unsafe { *ptr })
+        }
     }
 
     #[cfg(feature = "v2_7")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
     #[doc(alias = "gsl_multilarge_linear_rhs_ptr")]
-    pub fn rhs<F: FnOnce(&VectorF64)>(&self, f: F) {
-        f(&VectorF64::soft_wrap(unsafe {
-            sys::gsl_multilarge_linear_rhs_ptr(self.unwrap_shared()) as _
-        }))
+    pub fn rhs(&self) -> Option<VectorF64View<'_>> {
+        let ptr = unsafe { sys::gsl_multilarge_linear_rhs_ptr(self.unwrap_shared()) };
+        if ptr.is_null() {
+            None
+        } else {
+            VectorF64View::from_raw(unsafe { *ptr })
+        }
     }
 }
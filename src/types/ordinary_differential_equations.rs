@@ -63,7 +63,10 @@ Differential/Algebraic Equation Solvers.”, ACM Trans. Math. Software 31, 363
 
 use crate::Value;
 use ffi::FFI;
+use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
 use std::os::raw::{c_int, c_void};
+use types::{MatrixF64, RootFSolver, RootFSolverType, VectorF64};
 
 /// Description of a system of ODEs.
 ///
@@ -75,7 +78,7 @@ use std::os::raw::{c_int, c_void};
 /// to the GSL standard, being a continuous range of floating point values, in row-order.
 pub struct ODEiv2System<'a> {
     function: &'a mut dyn FnMut(f64, &[f64], &mut [f64]) -> Value,
-    jacobian: Option<&'a mut dyn FnMut(f64, &[f64], &mut [f64], &mut [f64]) -> Value>,
+    jacobian: Jacobian<&'a mut dyn FnMut(f64, &[f64], &mut [f64], &mut [f64]) -> Value>,
     dimension: usize,
 }
 
@@ -87,7 +90,7 @@ impl<'a> ODEiv2System<'a> {
     ) -> ODEiv2System<'a> {
         ODEiv2System {
             function,
-            jacobian: None,
+            jacobian: Jacobian::None,
             dimension,
         }
     }
@@ -100,7 +103,41 @@ impl<'a> ODEiv2System<'a> {
     ) -> ODEiv2System<'a> {
         ODEiv2System {
             function,
-            jacobian: Some(jacobian),
+            jacobian: Jacobian::Function(jacobian),
+            dimension,
+        }
+    }
+
+    /// Returns a new ODEiv2System whose jacobian is computed by finite differences of
+    /// `function`, for the stepping algorithms which need it (`bsimp`, `rk1imp`, `rk2imp`,
+    /// `rk4imp` and `msbdf`).
+    ///
+    /// ```no_run
+    /// use rgsl::{ODEiv2Driver, ODEiv2NumericalJacobian, ODEiv2StepType, ODEiv2System, Value};
+    ///
+    /// // Van der Pol oscillator, stiff for large mu.
+    /// let mu = 1000.;
+    /// let mut f = |_t: f64, y: &[f64], dydt: &mut [f64]| {
+    ///     dydt[0] = y[1];
+    ///     dydt[1] = mu * (1. - y[0] * y[0]) * y[1] - y[0];
+    ///     Value::Success
+    /// };
+    /// let mut sys =
+    ///     ODEiv2System::with_numerical_jacobian(2, &mut f, ODEiv2NumericalJacobian::default());
+    /// let mut driver =
+    ///     ODEiv2Driver::alloc_y_new(&mut sys, &ODEiv2StepType::msbdf(), 1e-6, 1e-6, 0.)
+    ///         .expect("failed to allocate the driver");
+    /// let (mut t, mut y) = (0., [2., 0.]);
+    /// assert_eq!(driver.apply(&mut t, 3000., &mut y), Value::Success);
+    /// ```
+    pub fn with_numerical_jacobian(
+        dimension: usize,
+        function: &'a mut dyn FnMut(f64, &[f64], &mut [f64]) -> Value,
+        jacobian: ODEiv2NumericalJacobian,
+    ) -> ODEiv2System<'a> {
+        ODEiv2System {
+            function,
+            jacobian: Jacobian::FiniteDifference(jacobian),
             dimension,
         }
     }
@@ -108,53 +145,302 @@ impl<'a> ODEiv2System<'a> {
     /// Returns `sys::gsl_odeiv2_system` structure.
     #[allow(clippy::wrong_self_convention)]
     fn to_raw(&mut self) -> sys::gsl_odeiv2_system {
-        sys::gsl_odeiv2_system {
-            function: Some(function_handler),
-            jacobian: if self.jacobian.is_some() {
-                Some(jacobian_handler)
-            } else {
-                None
-            },
-            dimension: self.dimension,
-            params: self as *mut _ as *mut c_void,
+        raw_system(self)
+    }
+}
+
+/// Description of a system of ODEs owning its right-hand side and jacobian closures.
+///
+/// Unlike [`ODEiv2System`], it doesn't borrow anything, so that an [`ODEiv2OwnedDriver`] built
+/// from it can be stored in a struct, returned from a function or moved to another thread:
+///
+/// ```no_run
+/// use rgsl::{ODEiv2OwnedDriver, ODEiv2OwnedSystem, ODEiv2StepType, Value};
+/// use std::thread;
+///
+/// let k = 0.5;
+/// let sys = ODEiv2OwnedSystem::new(1, move |_t, y, dydt| {
+///     dydt[0] = -k * y[0];
+///     Value::Success
+/// });
+/// let mut driver = ODEiv2OwnedDriver::alloc_y_new(sys, &ODEiv2StepType::rk8pd(), 1e-6, 1e-8, 0.)
+///     .expect("failed to allocate the driver");
+/// let worker = thread::spawn(move || {
+///     let (mut t, mut y) = (0., [1.]);
+///     driver.apply(&mut t, 1., &mut y);
+///     // The driver can keep integrating from where it stopped.
+///     driver.apply(&mut t, 2., &mut y);
+///     y[0]
+/// });
+/// println!("y(2) = {}", worker.join().unwrap());
+/// ```
+pub struct ODEiv2OwnedSystem {
+    function: Box<dyn FnMut(f64, &[f64], &mut [f64]) -> Value + Send>,
+    jacobian: Jacobian<Box<dyn FnMut(f64, &[f64], &mut [f64], &mut [f64]) -> Value + Send>>,
+    dimension: usize,
+}
+
+impl ODEiv2OwnedSystem {
+    /// Returns a new ODEiv2OwnedSystem with a given dimension and right-hand side.
+    pub fn new<F>(dimension: usize, function: F) -> ODEiv2OwnedSystem
+    where
+        F: FnMut(f64, &[f64], &mut [f64]) -> Value + Send + 'static,
+    {
+        ODEiv2OwnedSystem {
+            function: Box::new(function),
+            jacobian: Jacobian::None,
+            dimension,
+        }
+    }
+
+    /// Returns a new ODEiv2OwnedSystem with a jacobian function provided.
+    pub fn with_jacobian<F, J>(dimension: usize, function: F, jacobian: J) -> ODEiv2OwnedSystem
+    where
+        F: FnMut(f64, &[f64], &mut [f64]) -> Value + Send + 'static,
+        J: FnMut(f64, &[f64], &mut [f64], &mut [f64]) -> Value + Send + 'static,
+    {
+        ODEiv2OwnedSystem {
+            function: Box::new(function),
+            jacobian: Jacobian::Function(Box::new(jacobian)),
+            dimension,
+        }
+    }
+
+    /// Returns a new ODEiv2OwnedSystem whose jacobian is computed by finite differences of
+    /// `function` (see [`ODEiv2System::with_numerical_jacobian`]).
+    pub fn with_numerical_jacobian<F>(
+        dimension: usize,
+        function: F,
+        jacobian: ODEiv2NumericalJacobian,
+    ) -> ODEiv2OwnedSystem
+    where
+        F: FnMut(f64, &[f64], &mut [f64]) -> Value + Send + 'static,
+    {
+        ODEiv2OwnedSystem {
+            function: Box::new(function),
+            jacobian: Jacobian::FiniteDifference(jacobian),
+            dimension,
+        }
+    }
+
+    /// Returns the number of equations of the system.
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+}
+
+// The jacobian of a system.
+enum Jacobian<J> {
+    None,
+    Function(J),
+    FiniteDifference(ODEiv2NumericalJacobian),
+}
+
+/// Settings of the finite difference approximation of the jacobian of a system, used by
+/// [`ODEiv2System::with_numerical_jacobian`].
+///
+/// Each column `j` of dfdy is computed by a forward difference of the right-hand side, `y_j`
+/// being moved by `max(rel_step * |y_j|, abs_step)`, and dfdt by a forward difference in `t`
+/// with the same rule. This costs `n + 2` evaluations of the system of dimension `n`.
+///
+/// For a banded system, where `df_i/dy_j` is zero when `i - j > lower` or `j - i > upper`, the
+/// columns which don't share any row are moved together, so that only
+/// `lower + upper + 3` evaluations are needed whatever the dimension:
+///
+/// ```no_run
+/// use rgsl::ODEiv2NumericalJacobian;
+///
+/// // A tridiagonal system, such as a discretized diffusion equation.
+/// let jacobian = ODEiv2NumericalJacobian::banded(1, 1);
+/// assert_eq!(jacobian.bandwidth, Some((1, 1)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ODEiv2NumericalJacobian {
+    /// Step relative to the magnitude of the variable. Defaults to the square root of the
+    /// machine epsilon.
+    pub rel_step: f64,
+    /// Smallest step, used for the variables close to zero. Defaults to the square root of the
+    /// machine epsilon.
+    pub abs_step: f64,
+    /// The lower and upper bandwidths of dfdy, or `None` if it is dense. The elements outside
+    /// of the band are set to zero.
+    pub bandwidth: Option<(usize, usize)>,
+}
+
+impl Default for ODEiv2NumericalJacobian {
+    fn default() -> ODEiv2NumericalJacobian {
+        ODEiv2NumericalJacobian {
+            rel_step: f64::EPSILON.sqrt(),
+            abs_step: f64::EPSILON.sqrt(),
+            bandwidth: None,
+        }
+    }
+}
+
+impl ODEiv2NumericalJacobian {
+    /// Returns the default settings for a system whose jacobian has `lower` non-zero diagonals
+    /// below the main one and `upper` above it.
+    pub fn banded(lower: usize, upper: usize) -> ODEiv2NumericalJacobian {
+        ODEiv2NumericalJacobian {
+            bandwidth: Some((lower, upper)),
+            ..ODEiv2NumericalJacobian::default()
+        }
+    }
+
+    // Returns the point at which the function is evaluated to differentiate it at `x`.
+    fn shift(&self, x: f64) -> f64 {
+        x + (self.rel_step * x.abs()).max(self.abs_step)
+    }
+
+    // Computes the jacobian of `function` at `(t, y)`.
+    fn compute(
+        &self,
+        function: &mut dyn FnMut(f64, &[f64], &mut [f64]) -> Value,
+        t: f64,
+        y: &[f64],
+        dfdy: &mut [f64],
+        dfdt: &mut [f64],
+    ) -> Value {
+        let n = y.len();
+        if n == 0 {
+            return Value::Success;
+        }
+        let mut f0 = vec![0.; n];
+        let mut f1 = vec![0.; n];
+        let status = function(t, y, &mut f0);
+        if status != Value::Success {
+            return status;
+        }
+
+        let (lower, upper) = match self.bandwidth {
+            Some((lower, upper)) => (lower.min(n - 1), upper.min(n - 1)),
+            None => (n - 1, n - 1),
+        };
+        // Columns `width` apart have no row in common.
+        let width = (lower + upper + 1).min(n);
+        if width < n {
+            for x in dfdy.iter_mut() {
+                *x = 0.;
+            }
+        }
+        let mut yp = y.to_vec();
+        for first in 0..width {
+            for j in (first..n).step_by(width) {
+                yp[j] = self.shift(y[j]);
+            }
+            let status = function(t, &yp, &mut f1);
+            if status != Value::Success {
+                return status;
+            }
+            for j in (first..n).step_by(width) {
+                // The step actually taken, after rounding.
+                let h = yp[j] - y[j];
+                for i in j.saturating_sub(upper)..(j + lower + 1).min(n) {
+                    dfdy[i * n + j] = (f1[i] - f0[i]) / h;
+                }
+                yp[j] = y[j];
+            }
         }
+
+        let tp = self.shift(t);
+        let status = function(tp, y, &mut f1);
+        if status != Value::Success {
+            return status;
+        }
+        for i in 0..n {
+            dfdt[i] = (f1[i] - f0[i]) / (tp - t);
+        }
+        Value::Success
+    }
+}
+
+// Gives the C handlers access to the closures of `ODEiv2System` and `ODEiv2OwnedSystem`.
+trait SystemCallbacks {
+    fn dimension(&self) -> usize;
+
+    fn has_jacobian(&self) -> bool;
+
+    fn function(&mut self, t: f64, y: &[f64], dydt: &mut [f64]) -> Value;
+
+    fn jacobian(&mut self, t: f64, y: &[f64], dfdy: &mut [f64], dfdt: &mut [f64]) -> Value;
+}
+
+macro_rules! system_callbacks {
+    ($name:ty $(, $lt:lifetime)?) => {
+        impl<$($lt)?> SystemCallbacks for $name {
+            fn dimension(&self) -> usize {
+                self.dimension
+            }
+
+            fn has_jacobian(&self) -> bool {
+                !matches!(self.jacobian, Jacobian::None)
+            }
+
+            fn function(&mut self, t: f64, y: &[f64], dydt: &mut [f64]) -> Value {
+                (self.function)(t, y, dydt)
+            }
+
+            fn jacobian(&mut self, t: f64, y: &[f64], dfdy: &mut [f64], dfdt: &mut [f64]) -> Value {
+                match self.jacobian {
+                    Jacobian::Function(ref mut j) => j(t, y, dfdy, dfdt),
+                    Jacobian::FiniteDifference(ref fd) => {
+                        fd.compute(&mut *self.function, t, y, dfdy, dfdt)
+                    }
+                    Jacobian::None => Value::BadFunction,
+                }
+            }
+        }
+    };
+}
+
+system_callbacks!(ODEiv2System<'a>, 'a);
+system_callbacks!(ODEiv2OwnedSystem);
+
+// Returns the `sys::gsl_odeiv2_system` calling the closures of `s`, which must not move while
+// it is in use.
+fn raw_system<S: SystemCallbacks>(s: &mut S) -> sys::gsl_odeiv2_system {
+    sys::gsl_odeiv2_system {
+        function: Some(function_handler::<S>),
+        jacobian: if s.has_jacobian() {
+            Some(jacobian_handler::<S>)
+        } else {
+            None
+        },
+        dimension: s.dimension(),
+        params: s as *mut S as *mut c_void,
     }
 }
 
 /// Default handler for calling the function closure.
-extern "C" fn function_handler(
+extern "C" fn function_handler<S: SystemCallbacks>(
     t: f64,
     t_y: *const f64,
     t_f: *mut f64,
     params: *mut c_void,
 ) -> c_int {
-    let sys = unsafe { &mut *(params as *mut ODEiv2System) };
-    let n = sys.dimension as usize;
+    let sys = unsafe { &mut *(params as *mut S) };
+    let n = sys.dimension();
     let t_y = unsafe { ::std::slice::from_raw_parts(t_y, n) };
     let t_f = unsafe { ::std::slice::from_raw_parts_mut(t_f, n) };
 
-    (sys.function)(t, t_y, t_f).into()
+    sys.function(t, t_y, t_f).into()
 }
 
 /// Default handler for calling the jacobian closure.
-extern "C" fn jacobian_handler(
+extern "C" fn jacobian_handler<S: SystemCallbacks>(
     t: f64,
     t_y: *const f64,
     t_dfdy: *mut f64,
     t_dfdt: *mut f64,
     params: *mut c_void,
 ) -> c_int {
-    let sys = unsafe { &mut *(params as *mut ODEiv2System) };
-    let n = sys.dimension as usize;
+    let sys = unsafe { &mut *(params as *mut S) };
+    let n = sys.dimension();
     let t_y = unsafe { ::std::slice::from_raw_parts(t_y, n) };
     let t_dfdy = unsafe { ::std::slice::from_raw_parts_mut(t_dfdy, n * n) };
     let t_dfdt = unsafe { ::std::slice::from_raw_parts_mut(t_dfdt, n) };
 
-    match sys.jacobian {
-        Some(ref mut j) => j(t, t_y, t_dfdy, t_dfdt),
-        None => Value::BadFunction,
-    }
-    .into()
+    sys.jacobian(t, t_y, t_dfdy, t_dfdt).into()
 }
 
 ffi_wrapper!(ODEiv2Step, *mut sys::gsl_odeiv2_step, gsl_odeiv2_step_free);
@@ -657,6 +943,65 @@ impl ODEiv2Evolve {
     }
 }
 
+// The methods shared by `ODEiv2Driver` and `ODEiv2OwnedDriver`, which both store the driver in a
+// `d` field.
+macro_rules! driver_methods {
+    () => {
+        /// The function sets a minimum for allowed step size hmin for driver self. Default value is 0.
+        #[doc(alias = "gsl_odeiv2_driver_set_hmin")]
+        pub fn set_hmin(&mut self, hmin: f64) -> Value {
+            Value::from(unsafe { sys::gsl_odeiv2_driver_set_hmin(self.d, hmin) })
+        }
+
+        /// The function sets a maximum for allowed step size hmax for driver self. Default value is ::DBL_MAX.
+        #[doc(alias = "gsl_odeiv2_driver_set_hmax")]
+        pub fn set_hmax(&mut self, hmax: f64) -> Value {
+            Value::from(unsafe { sys::gsl_odeiv2_driver_set_hmax(self.d, hmax) })
+        }
+
+        /// The function sets a maximum for allowed number of steps nmax for driver self. Default value of 0 sets no limit for steps.
+        #[doc(alias = "gsl_odeiv2_driver_set_nmax")]
+        pub fn set_nmax(&mut self, nmax: usize) -> Value {
+            Value::from(unsafe { sys::gsl_odeiv2_driver_set_nmax(self.d, nmax as _) })
+        }
+
+        /// This function evolves the driver system d from t to t1. Initially vector y should contain the values of dependent variables at
+        /// point t. If the function is unable to complete the calculation, an error code from gsl_odeiv2_evolve_apply is returned, and t and
+        /// y contain the values from last successful step.
+        ///
+        /// If maximum number of steps is reached, a value of Value::MaxIteration is returned. If the step size drops below minimum value, the
+        /// function returns with ::NoProg. If the user-supplied functions defined in the system sys returns Value::BadFunc, the function
+        /// returns immediately with the same return code. In this case the user must call gsl_odeiv2_driver_reset before calling this
+        /// function again.
+        #[doc(alias = "gsl_odeiv2_driver_apply")]
+        pub fn apply(&mut self, t: &mut f64, t1: f64, y: &mut [f64]) -> Value {
+            Value::from(unsafe { sys::gsl_odeiv2_driver_apply(self.d, t, t1, y.as_mut_ptr()) })
+        }
+
+        /// This function evolves the driver system d from t with n steps of size h. If the function is unable to complete the calculation, an
+        /// error code from gsl_odeiv2_evolve_apply_fixed_step is returned, and t and y contain the values from last successful step.
+        #[doc(alias = "gsl_odeiv2_driver_apply_fixed_step")]
+        pub fn apply_fixed_step(&mut self, t: &mut f64, h: f64, n: usize, y: &mut [f64]) -> Value {
+            Value::from(unsafe {
+                sys::gsl_odeiv2_driver_apply_fixed_step(self.d, t, h, n as _, y.as_mut_ptr())
+            })
+        }
+
+        /// This function resets the evolution and stepper objects.
+        #[doc(alias = "gsl_odeiv2_driver_reset")]
+        pub fn reset(&mut self) -> Value {
+            Value::from(unsafe { sys::gsl_odeiv2_driver_reset(self.d) })
+        }
+
+        /// The routine resets the evolution and stepper objects and sets new initial step size to hstart. This function can be used e.g. to
+        /// change the direction of integration.
+        #[doc(alias = "gsl_odeiv2_driver_reset_hstart")]
+        pub fn reset_hstart(&mut self, hstart: f64) -> Value {
+            Value::from(unsafe { sys::gsl_odeiv2_driver_reset_hstart(self.d, hstart) })
+        }
+    };
+}
+
 pub struct ODEiv2Driver<'a> {
     d: *mut sys::gsl_odeiv2_driver,
     /// `sys::gsl_odeiv2_system` provided when constructing `d`.
@@ -802,61 +1147,139 @@ impl<'a> ODEiv2Driver<'a> {
         }
     }
 
-    /// The function sets a minimum for allowed step size hmin for driver self. Default value is 0.
-    #[doc(alias = "gsl_odeiv2_driver_set_hmin")]
-    pub fn set_hmin(&mut self, hmin: f64) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_driver_set_hmin(self.d, hmin) })
+    driver_methods!();
+}
+
+impl<'a> Drop for ODEiv2Driver<'a> {
+    #[doc(alias = "gsl_odeiv2_driver_free")]
+    fn drop(&mut self) {
+        unsafe { sys::gsl_odeiv2_driver_free(self.d) };
+        self.d = ::std::ptr::null_mut();
+    }
+}
+
+/// A driver owning its [`ODEiv2OwnedSystem`], which can be stored, returned from functions and
+/// moved to other threads. It provides the same methods as [`ODEiv2Driver`].
+pub struct ODEiv2OwnedDriver {
+    d: *mut sys::gsl_odeiv2_driver,
+    /// `sys::gsl_odeiv2_system` provided when constructing `d`.
+    #[allow(dead_code)]
+    raw_system: Box<sys::gsl_odeiv2_system>,
+    /// The system called through `raw_system`, boxed so that it doesn't move.
+    system: Box<ODEiv2OwnedSystem>,
+}
+
+// The driver, the objects it allocated and the system are only reachable through this struct,
+// and the closures of the system are `Send`.
+unsafe impl Send for ODEiv2OwnedDriver {}
+
+impl ODEiv2OwnedDriver {
+    fn alloc<F: FnOnce(*const sys::gsl_odeiv2_system) -> *mut sys::gsl_odeiv2_driver>(
+        sys: ODEiv2OwnedSystem,
+        alloc: F,
+    ) -> Option<ODEiv2OwnedDriver> {
+        let mut system = Box::new(sys);
+        let raw_system = Box::new(raw_system(&mut *system));
+        let d = alloc(&*raw_system);
+
+        if d.is_null() {
+            None
+        } else {
+            Some(ODEiv2OwnedDriver {
+                d,
+                raw_system,
+                system,
+            })
+        }
     }
 
-    /// The function sets a maximum for allowed step size hmax for driver self. Default value is ::DBL_MAX.
-    #[doc(alias = "gsl_odeiv2_driver_set_hmax")]
-    pub fn set_hmax(&mut self, hmax: f64) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_driver_set_hmax(self.d, hmax) })
+    /// Same as [`ODEiv2Driver::alloc_y_new`], taking ownership of `sys`.
+    #[doc(alias = "gsl_odeiv2_driver_alloc_y_new")]
+    pub fn alloc_y_new(
+        sys: ODEiv2OwnedSystem,
+        t: &ODEiv2StepType,
+        hstart: f64,
+        epsabs: f64,
+        epsrel: f64,
+    ) -> Option<ODEiv2OwnedDriver> {
+        Self::alloc(sys, |psys| unsafe {
+            sys::gsl_odeiv2_driver_alloc_y_new(psys, t.unwrap_shared(), hstart, epsabs, epsrel)
+        })
     }
 
-    /// The function sets a maximum for allowed number of steps nmax for driver self. Default value of 0 sets no limit for steps.
-    #[doc(alias = "gsl_odeiv2_driver_set_nmax")]
-    pub fn set_nmax(&mut self, nmax: usize) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_driver_set_nmax(self.d, nmax as _) })
+    /// Same as [`ODEiv2Driver::alloc_yp_new`], taking ownership of `sys`.
+    #[doc(alias = "gsl_odeiv2_driver_alloc_yp_new")]
+    pub fn alloc_yp_new(
+        sys: ODEiv2OwnedSystem,
+        t: &ODEiv2StepType,
+        hstart: f64,
+        epsabs: f64,
+        epsrel: f64,
+    ) -> Option<ODEiv2OwnedDriver> {
+        Self::alloc(sys, |psys| unsafe {
+            sys::gsl_odeiv2_driver_alloc_yp_new(psys, t.unwrap_shared(), hstart, epsabs, epsrel)
+        })
     }
 
-    /// This function evolves the driver system d from t to t1. Initially vector y should contain the values of dependent variables at
-    /// point t. If the function is unable to complete the calculation, an error code from gsl_odeiv2_evolve_apply is returned, and t and
-    /// y contain the values from last successful step.
-    ///
-    /// If maximum number of steps is reached, a value of Value::MaxIteration is returned. If the step size drops below minimum value, the
-    /// function returns with ::NoProg. If the user-supplied functions defined in the system sys returns Value::BadFunc, the function
-    /// returns immediately with the same return code. In this case the user must call gsl_odeiv2_driver_reset before calling this
-    /// function again.
-    #[doc(alias = "gsl_odeiv2_driver_apply")]
-    pub fn apply(&mut self, t: &mut f64, t1: f64, y: &mut [f64]) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_driver_apply(self.d, t, t1, y.as_mut_ptr()) })
-    }
-
-    /// This function evolves the driver system d from t with n steps of size h. If the function is unable to complete the calculation, an
-    /// error code from gsl_odeiv2_evolve_apply_fixed_step is returned, and t and y contain the values from last successful step.
-    #[doc(alias = "gsl_odeiv2_driver_apply_fixed_step")]
-    pub fn apply_fixed_step(&mut self, t: &mut f64, h: f64, n: usize, y: &mut [f64]) -> Value {
-        Value::from(unsafe {
-            sys::gsl_odeiv2_driver_apply_fixed_step(self.d, t, h, n as _, y.as_mut_ptr())
+    /// Same as [`ODEiv2Driver::alloc_standard_new`], taking ownership of `sys`.
+    #[doc(alias = "gsl_odeiv2_driver_alloc_standard_new")]
+    pub fn alloc_standard_new(
+        sys: ODEiv2OwnedSystem,
+        t: &ODEiv2StepType,
+        hstart: f64,
+        epsabs: f64,
+        epsrel: f64,
+        a_y: f64,
+        a_dydt: f64,
+    ) -> Option<ODEiv2OwnedDriver> {
+        Self::alloc(sys, |psys| unsafe {
+            sys::gsl_odeiv2_driver_alloc_standard_new(
+                psys,
+                t.unwrap_shared(),
+                hstart,
+                epsabs,
+                epsrel,
+                a_y,
+                a_dydt,
+            )
         })
     }
 
-    /// This function resets the evolution and stepper objects.
-    #[doc(alias = "gsl_odeiv2_driver_reset")]
-    pub fn reset(&mut self) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_driver_reset(self.d) })
+    /// Same as [`ODEiv2Driver::alloc_scaled_new`], taking ownership of `sys`.
+    #[doc(alias = "gsl_odeiv2_driver_alloc_scaled_new")]
+    pub fn alloc_scaled_new(
+        sys: ODEiv2OwnedSystem,
+        t: &ODEiv2StepType,
+        hstart: f64,
+        epsabs: f64,
+        epsrel: f64,
+        a_y: f64,
+        a_dydt: f64,
+        scale_abs: &[f64],
+    ) -> Option<ODEiv2OwnedDriver> {
+        Self::alloc(sys, |psys| unsafe {
+            sys::gsl_odeiv2_driver_alloc_scaled_new(
+                psys,
+                t.unwrap_shared(),
+                hstart,
+                epsabs,
+                epsrel,
+                a_y,
+                a_dydt,
+                scale_abs.as_ptr(),
+            )
+        })
     }
 
-    /// The routine resets the evolution and stepper objects and sets new initial step size to hstart. This function can be used e.g. to
-    /// change the direction of integration.
-    #[doc(alias = "gsl_odeiv2_driver_reset_hstart")]
-    pub fn reset_hstart(&mut self, hstart: f64) -> Value {
-        Value::from(unsafe { sys::gsl_odeiv2_driver_reset_hstart(self.d, hstart) })
+    /// Returns the system integrated by the driver.
+    pub fn system(&self) -> &ODEiv2OwnedSystem {
+        &self.system
     }
+
+    driver_methods!();
 }
 
-impl<'a> Drop for ODEiv2Driver<'a> {
+impl Drop for ODEiv2OwnedDriver {
     #[doc(alias = "gsl_odeiv2_driver_free")]
     fn drop(&mut self) {
         unsafe { sys::gsl_odeiv2_driver_free(self.d) };
@@ -877,3 +1300,1175 @@ impl<'a> Drop for ODEiv2Driver<'a> {
 //         d.d
 //     }
 // }
+
+/// How [`solve_ivp`] advances the solution between two output times.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ODEiv2Mode {
+    /// Adaptive step-size control (see [`ODEiv2Evolve::apply`]), starting with a step of
+    /// `hstart`. The steps are shortened to stop at every output time.
+    Adaptive { hstart: f64 },
+    /// Adaptive step-size control starting with a step of `hstart`, without stopping at the
+    /// output times: the solution there is interpolated with a [`DenseOutput`], which is kept in
+    /// [`Trajectory::dense`].
+    Dense { hstart: f64 },
+    /// Steps of at most `h` (see [`ODEiv2Evolve::apply_fixed_step`]), shortened so that every
+    /// output time is reached exactly. A step whose estimated error exceeds the tolerances is
+    /// not taken and stops the integration with [`Value::Failure`].
+    FixedStep { h: f64 },
+}
+
+/// Work done by [`solve_ivp`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ODEiv2Stats {
+    /// Number of accepted steps.
+    pub steps: usize,
+    /// Number of accepted steps which were first rejected by the step-size control and retried
+    /// with a smaller step, plus the step which stopped the integration in
+    /// [`ODEiv2Mode::FixedStep`] mode.
+    pub rejected_steps: usize,
+    /// Number of evaluations of the right-hand side of the system.
+    pub function_evals: usize,
+    /// Number of evaluations of the jacobian of the system.
+    pub jacobian_evals: usize,
+}
+
+// Evaluates at `t` the cubic Hermite polynomial matching the values `y0`, `y1` and the
+// derivatives `f0`, `f1` at `t0` and `t1`.
+fn hermite(
+    (t0, y0, f0): (f64, &[f64], &[f64]),
+    (t1, y1, f1): (f64, &[f64], &[f64]),
+    t: f64,
+    out: &mut [f64],
+) {
+    let h = t1 - t0;
+    let s = (t - t0) / h;
+    let s2 = s * s;
+    let s3 = s2 * s;
+    let h00 = 2. * s3 - 3. * s2 + 1.;
+    let h10 = (s3 - 2. * s2 + s) * h;
+    let h01 = -2. * s3 + 3. * s2;
+    let h11 = (s3 - s2) * h;
+    for (i, x) in out.iter_mut().enumerate() {
+        *x = h00 * y0[i] + h10 * f0[i] + h01 * y1[i] + h11 * f1[i];
+    }
+}
+
+/// Continuous approximation of the solution of an ODE system over the integrated range, built
+/// from the state and the derivatives at the end of each accepted step. Between two steps, the
+/// solution is interpolated by the cubic Hermite polynomial matching the states and
+/// derivatives at both ends, which is accurate to O(h^4) for a step of size h.
+///
+/// It is returned in [`Trajectory::dense`] by [`solve_ivp`] in [`ODEiv2Mode::Dense`] mode, and
+/// can also be filled while stepping manually, for example with the `dydt_out` derivatives
+/// computed by [`ODEiv2Step::apply`]:
+///
+/// ```no_run
+/// use rgsl::ordinary_differential_equations::DenseOutput;
+///
+/// let mut dense = DenseOutput::new(1);
+/// // y = exp(t)
+/// dense.push(0., &[1.], &[1.]);
+/// dense.push(0.1, &[0.1f64.exp()], &[0.1f64.exp()]);
+/// let mut y = [0.];
+/// dense.eval(0.05, &mut y);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct DenseOutput {
+    dimension: usize,
+    t: Vec<f64>,
+    y: Vec<f64>,
+    dydt: Vec<f64>,
+}
+
+impl DenseOutput {
+    /// Returns an empty dense output for a system of `dimension` equations.
+    pub fn new(dimension: usize) -> DenseOutput {
+        DenseOutput {
+            dimension,
+            t: Vec::new(),
+            y: Vec::new(),
+            dydt: Vec::new(),
+        }
+    }
+
+    /// Returns the number of equations of the system.
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Returns the times of the steps, in the direction of integration.
+    pub fn times(&self) -> &[f64] {
+        &self.t
+    }
+
+    /// Returns the state at the end of the `i`-th step.
+    pub fn state(&self, i: usize) -> &[f64] {
+        &self.y[i * self.dimension..(i + 1) * self.dimension]
+    }
+
+    /// Returns the derivatives at the end of the `i`-th step.
+    pub fn derivatives(&self, i: usize) -> &[f64] {
+        &self.dydt[i * self.dimension..(i + 1) * self.dimension]
+    }
+
+    /// Adds the state `y` and its derivatives `dydt` at time `t`, which must be past the times
+    /// already added in the direction of integration.
+    ///
+    /// Returns [`Value::BadLength`] if `y` or `dydt` don't have the dimension of the system,
+    /// and [`Value::Invalid`] if `t` goes back or is NaN.
+    pub fn push(&mut self, t: f64, y: &[f64], dydt: &[f64]) -> Value {
+        if y.len() != self.dimension || dydt.len() != self.dimension {
+            return Value::BadLength;
+        }
+        let n = self.t.len();
+        if t.is_nan()
+            || n >= 2 && (t - self.t[n - 1]) * (self.t[n - 1] - self.t[0]) <= 0.
+            || n == 1 && t == self.t[0]
+        {
+            return Value::Invalid;
+        }
+        self.t.push(t);
+        self.y.extend_from_slice(y);
+        self.dydt.extend_from_slice(dydt);
+        Value::Success
+    }
+
+    /// Writes the solution at time `t` into `y`.
+    ///
+    /// Returns [`Value::Domain`] if `t` is outside of the integrated range or is NaN, and
+    /// [`Value::BadLength`] if `y` doesn't have the dimension of the system.
+    pub fn eval(&self, t: f64, y: &mut [f64]) -> Value {
+        if y.len() != self.dimension {
+            return Value::BadLength;
+        }
+        let n = self.t.len();
+        if n == 0 || t.is_nan() {
+            return Value::Domain;
+        }
+        let direction = if self.t[n - 1] < self.t[0] { -1. } else { 1. };
+        if (t - self.t[0]) * direction < 0. || (t - self.t[n - 1]) * direction > 0. {
+            return Value::Domain;
+        }
+        // Index of the first step ending at or after `t`.
+        let i = self.t.partition_point(|&ti| (ti - t) * direction < 0.);
+        if self.t[i] == t {
+            y.copy_from_slice(self.state(i));
+        } else {
+            hermite(
+                (self.t[i - 1], self.state(i - 1), self.derivatives(i - 1)),
+                (self.t[i], self.state(i), self.derivatives(i)),
+                t,
+                y,
+            );
+        }
+        Value::Success
+    }
+}
+
+/// Sign changes of an event function which trigger an [`ODEiv2Event`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ODEiv2EventDirection {
+    /// From negative to positive.
+    Rising,
+    /// From positive to negative.
+    Falling,
+    /// Both ways.
+    Both,
+}
+
+/// A condition monitored by [`solve_ivp_with_events`]: the event occurs when `g(t, y)` changes
+/// sign in `direction`. A terminal event stops the integration.
+pub struct ODEiv2Event<'a> {
+    function: &'a mut dyn FnMut(f64, &[f64]) -> f64,
+    direction: ODEiv2EventDirection,
+    terminal: bool,
+}
+
+impl<'a> ODEiv2Event<'a> {
+    /// Returns a new event occurring at the zeros of `function` crossed in `direction`.
+    pub fn new(
+        function: &'a mut dyn FnMut(f64, &[f64]) -> f64,
+        direction: ODEiv2EventDirection,
+        terminal: bool,
+    ) -> ODEiv2Event<'a> {
+        ODEiv2Event {
+            function,
+            direction,
+            terminal,
+        }
+    }
+
+    // Returns `true` if going from `g0` to `g1` triggers the event.
+    fn crossed(&self, g0: f64, g1: f64) -> bool {
+        let rising = g0 < 0. && g1 >= 0.;
+        let falling = g0 > 0. && g1 <= 0.;
+        match self.direction {
+            ODEiv2EventDirection::Rising => rising,
+            ODEiv2EventDirection::Falling => falling,
+            ODEiv2EventDirection::Both => rising || falling,
+        }
+    }
+}
+
+/// An occurrence of an [`ODEiv2Event`] found by [`solve_ivp_with_events`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ODEiv2EventHit {
+    /// Index of the event in the slice given to [`solve_ivp_with_events`].
+    pub event: usize,
+    /// Time of the event.
+    pub t: f64,
+    /// State of the system at `t`.
+    pub y: Vec<f64>,
+}
+
+/// The solution of an ODE system at the output times given to [`solve_ivp`].
+pub struct Trajectory {
+    /// The output times.
+    pub t: VectorF64,
+    /// The solution: row `i` holds `y(t[i])`. If the integration stopped early, because of an
+    /// error or of a terminal event, the rows of the output times which weren't reached are
+    /// filled with NaN.
+    pub y: MatrixF64,
+    /// The events which occurred, in chronological order.
+    pub events: Vec<ODEiv2EventHit>,
+    /// The continuous solution over the integrated range, in [`ODEiv2Mode::Dense`] mode.
+    pub dense: Option<DenseOutput>,
+    /// [`Value::Success`], or the error which stopped the integration.
+    pub status: Value,
+    /// The work done by the solver.
+    pub stats: ODEiv2Stats,
+}
+
+/// Integrates `sys` from `t0`, where its state is `y0`, and returns its solution at each of the
+/// `t_out` times, which must be ordered in the direction of integration (forward or backward).
+/// An output time equal to `t0` gives `y0`.
+///
+/// The solution is advanced by the stepping algorithm `step_type`, keeping the local error of
+/// each step below `epsabs + epsrel * |y|` (see [`ODEiv2Control::y_new`]).
+///
+/// ```no_run
+/// use rgsl::ordinary_differential_equations::{solve_ivp, ODEiv2Mode};
+/// use rgsl::{ODEiv2StepType, ODEiv2System, Value};
+///
+/// // Harmonic oscillator: y'' = -y.
+/// let mut f = |_t: f64, y: &[f64], dydt: &mut [f64]| {
+///     dydt[0] = y[1];
+///     dydt[1] = -y[0];
+///     Value::Success
+/// };
+/// let mut sys = ODEiv2System::new(2, &mut f);
+/// let times: Vec<f64> = (1..=100).map(|i| i as f64 * 0.1).collect();
+/// let trajectory = solve_ivp(
+///     &mut sys,
+///     &ODEiv2StepType::rk8pd(),
+///     ODEiv2Mode::Adaptive { hstart: 1e-6 },
+///     1e-8,
+///     1e-8,
+///     0.,
+///     &[1., 0.],
+///     &times,
+/// )
+/// .expect("invalid arguments");
+/// assert_eq!(trajectory.status, Value::Success);
+/// println!("y(10) = {}", trajectory.y.get(99, 0));
+/// ```
+///
+/// Returns `None` if `t_out` is empty or not ordered, if `y0` doesn't have the dimension of the
+/// system, if the step of `mode` is zero or not finite, or if the allocation of the solver or of
+/// the result fails.
+pub fn solve_ivp(
+    sys: &mut ODEiv2System,
+    step_type: &ODEiv2StepType,
+    mode: ODEiv2Mode,
+    epsabs: f64,
+    epsrel: f64,
+    t0: f64,
+    y0: &[f64],
+    t_out: &[f64],
+) -> Option<Trajectory> {
+    solve_ivp_with_events(sys, step_type, mode, epsabs, epsrel, t0, y0, t_out, &mut [])
+}
+
+/// Same as [`solve_ivp`], also monitoring `events`.
+///
+/// The event functions are evaluated after each step. When one of them changes sign, its zero
+/// is located within the step with the Brent solver of [`RootFSolverType::brent`], using the
+/// cubic Hermite interpolation of [`DenseOutput`] for the state inside the step, which costs one
+/// more evaluation of the system per step. The occurrences are reported in
+/// [`Trajectory::events`]; the integration stops at the first terminal one.
+///
+/// ```no_run
+/// use rgsl::ordinary_differential_equations::{
+///     solve_ivp_with_events, ODEiv2Event, ODEiv2EventDirection, ODEiv2Mode,
+/// };
+/// use rgsl::{ODEiv2StepType, ODEiv2System, Value};
+///
+/// // A ball thrown upwards: y = (height, velocity).
+/// let mut f = |_t: f64, y: &[f64], dydt: &mut [f64]| {
+///     dydt[0] = y[1];
+///     dydt[1] = -9.81;
+///     Value::Success
+/// };
+/// let mut height = |_t: f64, y: &[f64]| y[0];
+/// let mut sys = ODEiv2System::new(2, &mut f);
+/// let mut events = [ODEiv2Event::new(&mut height, ODEiv2EventDirection::Falling, true)];
+/// let trajectory = solve_ivp_with_events(
+///     &mut sys,
+///     &ODEiv2StepType::rk8pd(),
+///     ODEiv2Mode::Adaptive { hstart: 1e-3 },
+///     1e-10,
+///     1e-10,
+///     0.,
+///     &[0., 10.],
+///     &[100.],
+///     &mut events,
+/// )
+/// .expect("invalid arguments");
+/// println!("impact at t = {}", trajectory.events[0].t);
+/// ```
+pub fn solve_ivp_with_events(
+    sys: &mut ODEiv2System,
+    step_type: &ODEiv2StepType,
+    mode: ODEiv2Mode,
+    epsabs: f64,
+    epsrel: f64,
+    t0: f64,
+    y0: &[f64],
+    t_out: &[f64],
+    events: &mut [ODEiv2Event],
+) -> Option<Trajectory> {
+    let dimension = sys.dimension;
+    let last = *t_out.last()?;
+    let direction = if last < t0 { -1. } else { 1. };
+    let mut prev = t0;
+    for &t in t_out {
+        if (t - prev) * direction < 0. || t.is_nan() {
+            return None;
+        }
+        prev = t;
+    }
+    if y0.len() != dimension {
+        return None;
+    }
+    let h0 = match mode {
+        ODEiv2Mode::Adaptive { hstart } | ODEiv2Mode::Dense { hstart } => hstart,
+        ODEiv2Mode::FixedStep { h } => h,
+    };
+    if h0 == 0. || !h0.is_finite() {
+        return None;
+    }
+
+    let dense = matches!(mode, ODEiv2Mode::Dense { .. });
+    let mut trajectory = Trajectory {
+        t: VectorF64::from_slice(t_out)?,
+        y: MatrixF64::new(t_out.len(), dimension)?,
+        events: Vec::new(),
+        dense: if dense {
+            Some(DenseOutput::new(dimension))
+        } else {
+            None
+        },
+        status: Value::Success,
+        stats: ODEiv2Stats::default(),
+    };
+    trajectory.y.set_all(f64::NAN);
+
+    let function_evals = Cell::new(0);
+    let jacobian_evals = Cell::new(0);
+    let function = RefCell::new(&mut *sys.function);
+    let mut counted_function = |t: f64, y: &[f64], dydt: &mut [f64]| {
+        function_evals.set(function_evals.get() + 1);
+        (function.borrow_mut())(t, y, dydt)
+    };
+    let mut counted_jacobian;
+    let mut counted_numerical_jacobian;
+    let mut counted = match sys.jacobian {
+        Jacobian::Function(ref mut jacobian) => {
+            let jacobian = &mut **jacobian;
+            let jacobian_evals = &jacobian_evals;
+            counted_jacobian = move |t: f64, y: &[f64], dfdy: &mut [f64], dfdt: &mut [f64]| {
+                jacobian_evals.set(jacobian_evals.get() + 1);
+                jacobian(t, y, dfdy, dfdt)
+            };
+            ODEiv2System::with_jacobian(dimension, &mut counted_function, &mut counted_jacobian)
+        }
+        Jacobian::FiniteDifference(fd) => {
+            let (function, function_evals) = (&function, &function_evals);
+            let jacobian_evals = &jacobian_evals;
+            // The right-hand side is never called by GSL while the jacobian is computed, so
+            // `function` is only borrowed once at a time.
+            counted_numerical_jacobian =
+                move |t: f64, y: &[f64], dfdy: &mut [f64], dfdt: &mut [f64]| {
+                    jacobian_evals.set(jacobian_evals.get() + 1);
+                    let mut f = |t: f64, y: &[f64], dydt: &mut [f64]| {
+                        function_evals.set(function_evals.get() + 1);
+                        (function.borrow_mut())(t, y, dydt)
+                    };
+                    fd.compute(&mut f, t, y, dfdy, dfdt)
+                };
+            ODEiv2System::with_jacobian(
+                dimension,
+                &mut counted_function,
+                &mut counted_numerical_jacobian,
+            )
+        }
+        Jacobian::None => ODEiv2System::new(dimension, &mut counted_function),
+    };
+
+    let mut h = h0.abs() * direction;
+    // The driver isn't used to integrate: it only gives the steppers which need one (such as
+    // `msadams` and `msbdf`) access to a control object with the same tolerances.
+    let driver = ODEiv2Driver::alloc_y_new(&mut counted, step_type, h, epsabs, epsrel)?;
+    let mut step = ODEiv2Step::new(*step_type, dimension)?;
+    step.set_driver(&driver);
+    let mut control = ODEiv2Control::y_new(epsabs, epsrel)?;
+    let mut evolve = ODEiv2Evolve::new(dimension)?;
+    let raw_sys: *const sys::gsl_odeiv2_system = &*driver.raw_system;
+    let (mut steps, mut rejected_steps) = (0, 0);
+    let derivatives = |t: f64, y: &[f64], dydt: &mut [f64]| unsafe {
+        let sys = &*raw_sys;
+        let f = sys.function.expect("rust-GSL: missing ODE function");
+        Value::from(f(t, y.as_ptr(), dydt.as_mut_ptr(), sys.params))
+    };
+    // The derivatives at the ends of the steps are only needed for the interpolation.
+    let interpolate = dense || !events.is_empty();
+    let mut solver = if events.is_empty() {
+        None
+    } else {
+        Some(RootFSolver::new(&RootFSolverType::brent())?)
+    };
+
+    let mut t = t0;
+    let mut y = y0.to_vec();
+    let mut dydt = vec![0.; dimension];
+    if interpolate {
+        let status = derivatives(t, &y, &mut dydt);
+        if status != Value::Success {
+            trajectory.status = status;
+        }
+    }
+    if let Some(ref mut dense) = trajectory.dense {
+        dense.push(t, &y, &dydt);
+    }
+    let mut g: Vec<f64> = events.iter_mut().map(|e| (e.function)(t, &y)).collect();
+    let mut next = 0;
+    while next < t_out.len() && t_out[next] == t {
+        set_row(&mut trajectory.y, next, &y);
+        next += 1;
+    }
+
+    let (mut y_prev, mut dydt_prev) = (y.clone(), dydt.clone());
+    let mut y_event = vec![0.; dimension];
+    'outer: while next < t_out.len() && trajectory.status == Value::Success {
+        let target = if dense { last } else { t_out[next] };
+        let mut remaining = match mode {
+            // A tiny margin avoids an extra step when `target - t` is a multiple of `h` up to
+            // rounding errors.
+            ODEiv2Mode::FixedStep { h: step } => {
+                let n = ((((target - t) / step).abs() * (1. - 1e-12)).ceil()).max(1.);
+                h = (target - t) / n;
+                n as usize
+            }
+            _ => 0,
+        };
+        while t != target {
+            let t_prev = t;
+            y_prev.copy_from_slice(&y);
+            dydt_prev.copy_from_slice(&dydt);
+            let h_try = h;
+            let status = Value::from(unsafe {
+                match mode {
+                    ODEiv2Mode::FixedStep { .. } => sys::gsl_odeiv2_evolve_apply_fixed_step(
+                        evolve.unwrap_unique(),
+                        control.unwrap_unique(),
+                        step.unwrap_unique(),
+                        raw_sys,
+                        &mut t,
+                        h,
+                        y.as_mut_ptr(),
+                    ),
+                    _ => sys::gsl_odeiv2_evolve_apply(
+                        evolve.unwrap_unique(),
+                        control.unwrap_unique(),
+                        step.unwrap_unique(),
+                        raw_sys,
+                        &mut t,
+                        target,
+                        &mut h,
+                        y.as_mut_ptr(),
+                    ),
+                }
+            });
+            if status != Value::Success {
+                if let (ODEiv2Mode::FixedStep { .. }, Value::Failure) = (mode, status) {
+                    rejected_steps += 1;
+                }
+                trajectory.status = status;
+                break 'outer;
+            }
+            steps += 1;
+            // The step is only shorter than the one tried, and than the distance to the target,
+            // if the step-size control rejected it.
+            let taken = (t - t_prev).abs();
+            if taken < h_try.abs().min((target - t_prev).abs()) {
+                rejected_steps += 1;
+            }
+            if let ODEiv2Mode::FixedStep { .. } = mode {
+                remaining -= 1;
+                if remaining == 0 {
+                    t = target;
+                }
+            }
+            if interpolate {
+                let status = derivatives(t, &y, &mut dydt);
+                if status != Value::Success {
+                    trajectory.status = status;
+                    break 'outer;
+                }
+            }
+            let start = (t_prev, &y_prev[..], &dydt_prev[..]);
+            let end = (t, &y[..], &dydt[..]);
+
+            // Events of the step, ordered by time, up to the first terminal one.
+            let mut stop = None;
+            if let Some(ref mut solver) = solver {
+                let mut hits = Vec::new();
+                for (k, event) in events.iter_mut().enumerate() {
+                    let g1 = (event.function)(t, &y);
+                    if event.crossed(g[k], g1) {
+                        let te = if g1 == 0. {
+                            t
+                        } else {
+                            locate_event(solver, &mut *event.function, start, end, &mut y_event)
+                        };
+                        hits.push((te, k));
+                    }
+                    g[k] = g1;
+                }
+                hits.sort_by(|a, b| {
+                    (a.0 * direction)
+                        .partial_cmp(&(b.0 * direction))
+                        .unwrap_or(Ordering::Equal)
+                });
+                for (te, k) in hits {
+                    hermite(start, end, te, &mut y_event);
+                    trajectory.events.push(ODEiv2EventHit {
+                        event: k,
+                        t: te,
+                        y: y_event.clone(),
+                    });
+                    if events[k].terminal {
+                        stop = Some(te);
+                        break;
+                    }
+                }
+            }
+
+            let t_end = stop.unwrap_or(t);
+            while next < t_out.len() && (t_out[next] - t_end) * direction <= 0. {
+                if t_out[next] == t {
+                    set_row(&mut trajectory.y, next, &y);
+                } else {
+                    hermite(start, end, t_out[next], &mut y_event);
+                    set_row(&mut trajectory.y, next, &y_event);
+                }
+                next += 1;
+            }
+
+            if let Some(te) = stop {
+                if te != t {
+                    hermite(start, end, te, &mut y_event);
+                    t = te;
+                    y.copy_from_slice(&y_event);
+                    if dense {
+                        let status = derivatives(t, &y, &mut dydt);
+                        if status != Value::Success {
+                            trajectory.status = status;
+                            break 'outer;
+                        }
+                    }
+                }
+            }
+            if let Some(ref mut dense) = trajectory.dense {
+                dense.push(t, &y, &dydt);
+            }
+            if stop.is_some() {
+                break 'outer;
+            }
+        }
+    }
+
+    drop((evolve, control, step, driver));
+    trajectory.stats = ODEiv2Stats {
+        steps,
+        rejected_steps,
+        function_evals: function_evals.get(),
+        jacobian_evals: jacobian_evals.get(),
+    };
+    Some(trajectory)
+}
+
+fn set_row(m: &mut MatrixF64, i: usize, y: &[f64]) {
+    for (j, &yj) in y.iter().enumerate() {
+        m.set(i, j, yj);
+    }
+}
+
+// Returns the zero of `g(t, y(t))` inside a step, `y` being interpolated between its `start`
+// and its `end`. `y` is used as buffer.
+fn locate_event(
+    solver: &mut RootFSolver,
+    g: &mut dyn FnMut(f64, &[f64]) -> f64,
+    start: (f64, &[f64], &[f64]),
+    end: (f64, &[f64], &[f64]),
+    y: &mut [f64],
+) -> f64 {
+    unsafe extern "C" fn trampoline(t: f64, params: *mut c_void) -> f64 {
+        let f = &mut *(params as *mut &mut dyn FnMut(f64) -> f64);
+        f(t)
+    }
+
+    let mut f = |t: f64| {
+        hermite(start, end, t, y);
+        g(t, y)
+    };
+    let mut f: &mut dyn FnMut(f64) -> f64 = &mut f;
+    let mut function = sys::gsl_function {
+        function: Some(trampoline),
+        params: &mut f as *mut _ as *mut c_void,
+    };
+    let (t0, t1) = (start.0, end.0);
+    let (lower, upper) = if t0 < t1 { (t0, t1) } else { (t1, t0) };
+    // `RootFSolver::set` doesn't keep the function alive after returning, so the solver is set
+    // up directly.
+    let solver = solver.unwrap_unique();
+    if unsafe { sys::gsl_root_fsolver_set(solver, &mut function, lower, upper) } != 0 {
+        return t1;
+    }
+    let tol = 1e-12 * (t1 - t0).abs();
+    for _ in 0..100 {
+        unsafe { sys::gsl_root_fsolver_iterate(solver) };
+        let (lower, upper) = unsafe {
+            (
+                sys::gsl_root_fsolver_x_lower(solver),
+                sys::gsl_root_fsolver_x_upper(solver),
+            )
+        };
+        if ::roots::test_interval(lower, upper, tol, 1e-14) == Value::Success {
+            break;
+        }
+    }
+    unsafe { sys::gsl_root_fsolver_root(solver) }
+}
+
+#[test]
+fn dense_output_cubic() {
+    // The Hermite interpolation is exact for a cubic: y = t^3 - 2t.
+    let y = |t: f64| t * t * t - 2. * t;
+    let dydt = |t: f64| 3. * t * t - 2.;
+    let mut dense = DenseOutput::new(1);
+    for &t in &[0., 0.5, 2., 3.] {
+        assert_eq!(dense.push(t, &[y(t)], &[dydt(t)]), Value::Success);
+    }
+
+    let mut out = [0.];
+    for &t in &[0., 0.1, 0.5, 1.3, 2.9, 3.] {
+        assert_eq!(dense.eval(t, &mut out), Value::Success);
+        assert!(
+            (out[0] - y(t)).abs() < 1e-12,
+            "t = {}: {} != {}",
+            t,
+            out[0],
+            y(t)
+        );
+    }
+    assert_eq!(dense.eval(-0.1, &mut out), Value::Domain);
+    assert_eq!(dense.eval(3.1, &mut out), Value::Domain);
+    assert_eq!(dense.eval(f64::NAN, &mut out), Value::Domain);
+    assert_eq!(dense.eval(1., &mut [0.; 2]), Value::BadLength);
+}
+
+#[test]
+fn dense_output_push() {
+    let mut dense = DenseOutput::new(1);
+    assert_eq!(dense.eval(0., &mut [0.]), Value::Domain);
+    assert_eq!(dense.push(1., &[0.], &[0.]), Value::Success);
+    assert_eq!(dense.push(1., &[0.], &[0.]), Value::Invalid);
+    // The second time sets the direction of integration, backward here.
+    assert_eq!(dense.push(0., &[0.], &[0.]), Value::Success);
+    assert_eq!(dense.push(0.5, &[0.], &[0.]), Value::Invalid);
+    assert_eq!(dense.push(0., &[0.], &[0.]), Value::Invalid);
+    assert_eq!(dense.push(f64::NAN, &[0.], &[0.]), Value::Invalid);
+    assert_eq!(dense.push(-1., &[0., 0.], &[0.]), Value::BadLength);
+    assert_eq!(dense.push(-1., &[0.], &[0.]), Value::Success);
+    assert_eq!(dense.times(), &[1., 0., -1.]);
+}
+
+#[test]
+fn banded_numerical_jacobian() {
+    // A discretized diffusion equation with a source term depending on t.
+    let n = 6;
+    let mut evals = 0;
+    let mut f = |t: f64, y: &[f64], dydt: &mut [f64]| {
+        evals += 1;
+        for (i, d) in dydt.iter_mut().enumerate() {
+            let left = if i > 0 { y[i - 1] } else { 0. };
+            let right = if i + 1 < y.len() { y[i + 1] } else { 0. };
+            *d = left - 2. * y[i] + right + y[i] * y[i] + t * i as f64;
+        }
+        Value::Success
+    };
+    let y: Vec<f64> = (0..n).map(|i| 0.5 + i as f64).collect();
+
+    let mut dense = (vec![0.; n * n], vec![0.; n]);
+    let status =
+        ODEiv2NumericalJacobian::default().compute(&mut f, 1., &y, &mut dense.0, &mut dense.1);
+    assert_eq!(status, Value::Success);
+    let mut banded = (vec![f64::NAN; n * n], vec![0.; n]);
+    let status =
+        ODEiv2NumericalJacobian::banded(1, 1).compute(&mut f, 1., &y, &mut banded.0, &mut banded.1);
+    assert_eq!(status, Value::Success);
+    // n + 2 evaluations for the dense jacobian, 1 + 3 + 1 for the banded one.
+    assert_eq!(evals, n + 2 + 5);
+
+    for (i, &yi) in y.iter().enumerate() {
+        for j in 0..n {
+            let exact = match (i as isize - j as isize).abs() {
+                0 => 2. * yi - 2.,
+                1 => 1.,
+                _ => 0.,
+            };
+            assert!((dense.0[i * n + j] - exact).abs() < 1e-6);
+            assert!((banded.0[i * n + j] - dense.0[i * n + j]).abs() < 1e-6);
+        }
+        assert!((dense.1[i] - i as f64).abs() < 1e-5);
+        assert_eq!(banded.1[i], dense.1[i]);
+    }
+}
+
+#[cfg(test)]
+fn decay(_t: f64, y: &[f64], dydt: &mut [f64]) -> Value {
+    dydt[0] = -y[0];
+    Value::Success
+}
+
+#[test]
+fn solve_ivp_decay() {
+    let mut f = decay;
+    let mut sys = ODEiv2System::new(1, &mut f);
+    let times: Vec<f64> = (0..=20).map(|i| i as f64 * 0.5).collect();
+    for &(step_type, mode, tolerance) in &[
+        (
+            ODEiv2StepType::rk8pd(),
+            ODEiv2Mode::Adaptive { hstart: 1e-3 },
+            1e-8,
+        ),
+        (
+            ODEiv2StepType::rk45(),
+            ODEiv2Mode::Dense { hstart: 1e-3 },
+            1e-6,
+        ),
+        (
+            ODEiv2StepType::rk4(),
+            ODEiv2Mode::FixedStep { h: 0.01 },
+            1e-8,
+        ),
+    ] {
+        let trajectory =
+            solve_ivp(&mut sys, &step_type, mode, 1e-10, 1e-10, 0., &[1.], &times).unwrap();
+        assert_eq!(trajectory.status, Value::Success);
+        assert_eq!(
+            trajectory.dense.is_some(),
+            mode == ODEiv2Mode::Dense { hstart: 1e-3 }
+        );
+        assert_eq!(trajectory.t.as_slice(), Some(&times[..]));
+        assert_eq!(trajectory.y.get(0, 0), 1.);
+        for (i, &t) in times.iter().enumerate() {
+            let y = trajectory.y.get(i, 0);
+            assert!(
+                (y - (-t).exp()).abs() < tolerance,
+                "{:?}: y({}) = {}",
+                mode,
+                t,
+                y
+            );
+        }
+        assert!(trajectory.events.is_empty());
+    }
+}
+
+#[test]
+fn solve_ivp_backward() {
+    let mut f = decay;
+    let mut sys = ODEiv2System::new(1, &mut f);
+    let trajectory = solve_ivp(
+        &mut sys,
+        &ODEiv2StepType::rk8pd(),
+        ODEiv2Mode::Adaptive { hstart: 1e-3 },
+        1e-10,
+        1e-10,
+        1.,
+        &[(-1f64).exp()],
+        &[0.5, 0.],
+    )
+    .unwrap();
+    assert_eq!(trajectory.status, Value::Success);
+    assert!((trajectory.y.get(0, 0) - (-0.5f64).exp()).abs() < 1e-8);
+    assert!((trajectory.y.get(1, 0) - 1.).abs() < 1e-8);
+}
+
+#[test]
+fn solve_ivp_invalid_arguments() {
+    let mut f = decay;
+    let mut sys = ODEiv2System::new(1, &mut f);
+    let rk8pd = ODEiv2StepType::rk8pd();
+    let adaptive = ODEiv2Mode::Adaptive { hstart: 1e-3 };
+    assert!(solve_ivp(&mut sys, &rk8pd, adaptive, 1e-8, 1e-8, 0., &[1.], &[]).is_none());
+    assert!(solve_ivp(
+        &mut sys,
+        &rk8pd,
+        adaptive,
+        1e-8,
+        1e-8,
+        0.,
+        &[1.],
+        &[1., 0.5]
+    )
+    .is_none());
+    assert!(solve_ivp(
+        &mut sys,
+        &rk8pd,
+        adaptive,
+        1e-8,
+        1e-8,
+        0.,
+        &[1.],
+        &[f64::NAN]
+    )
+    .is_none());
+    assert!(solve_ivp(&mut sys, &rk8pd, adaptive, 1e-8, 1e-8, 0., &[1., 0.], &[1.]).is_none());
+    for &mode in &[
+        ODEiv2Mode::Adaptive { hstart: 0. },
+        ODEiv2Mode::Dense { hstart: f64::NAN },
+        ODEiv2Mode::FixedStep { h: f64::INFINITY },
+    ] {
+        assert!(solve_ivp(&mut sys, &rk8pd, mode, 1e-8, 1e-8, 0., &[1.], &[1.]).is_none());
+    }
+}
+
+#[test]
+fn solve_ivp_stats() {
+    let mut f = decay;
+    let mut sys = ODEiv2System::new(1, &mut f);
+    let trajectory = solve_ivp(
+        &mut sys,
+        &ODEiv2StepType::rk4(),
+        ODEiv2Mode::FixedStep { h: 0.01 },
+        1e-10,
+        1e-10,
+        0.,
+        &[1.],
+        &[1., 2.],
+    )
+    .unwrap();
+    assert_eq!(trajectory.stats.steps, 200);
+    assert_eq!(trajectory.stats.rejected_steps, 0);
+    assert!(trajectory.stats.function_evals >= 4 * 200);
+    assert_eq!(trajectory.stats.jacobian_evals, 0);
+
+    // The first step of 1 is much too long for y' = -50 y and is rejected.
+    let mut f = |_t: f64, y: &[f64], dydt: &mut [f64]| {
+        dydt[0] = -50. * y[0];
+        Value::Success
+    };
+    let mut sys = ODEiv2System::new(1, &mut f);
+    let trajectory = solve_ivp(
+        &mut sys,
+        &ODEiv2StepType::rk45(),
+        ODEiv2Mode::Adaptive { hstart: 1. },
+        1e-10,
+        1e-8,
+        0.,
+        &[1.],
+        &[0.1],
+    )
+    .unwrap();
+    assert_eq!(trajectory.status, Value::Success);
+    assert!((trajectory.y.get(0, 0) - (-5f64).exp()).abs() < 1e-8);
+    assert!(trajectory.stats.rejected_steps > 0);
+    assert!(trajectory.stats.steps > trajectory.stats.rejected_steps);
+
+    // In fixed step mode, a step with a too large error stops the integration.
+    let mut f = decay;
+    let mut sys = ODEiv2System::new(1, &mut f);
+    let trajectory = solve_ivp(
+        &mut sys,
+        &ODEiv2StepType::rk4(),
+        ODEiv2Mode::FixedStep { h: 1. },
+        1e-12,
+        1e-12,
+        0.,
+        &[1.],
+        &[1., 2.],
+    )
+    .unwrap();
+    assert_eq!(trajectory.status, Value::Failure);
+    assert_eq!(trajectory.stats.steps, 0);
+    assert_eq!(trajectory.stats.rejected_steps, 1);
+    assert!(trajectory.y.get(0, 0).is_nan());
+    assert!(trajectory.y.get(1, 0).is_nan());
+}
+
+#[test]
+fn solve_ivp_jacobian_evals() {
+    let mut f = decay;
+    let mut jacobian = |_t: f64, _y: &[f64], dfdy: &mut [f64], dfdt: &mut [f64]| {
+        dfdy[0] = -1.;
+        dfdt[0] = 0.;
+        Value::Success
+    };
+    let mut sys = ODEiv2System::with_jacobian(1, &mut f, &mut jacobian);
+    let trajectory = solve_ivp(
+        &mut sys,
+        &ODEiv2StepType::bsimp(),
+        ODEiv2Mode::Adaptive { hstart: 1e-3 },
+        1e-10,
+        1e-10,
+        0.,
+        &[1.],
+        &[1.],
+    )
+    .unwrap();
+    assert_eq!(trajectory.status, Value::Success);
+    assert!((trajectory.y.get(0, 0) - (-1f64).exp()).abs() < 1e-8);
+    assert!(trajectory.stats.steps > 0);
+    assert!(trajectory.stats.jacobian_evals >= trajectory.stats.steps);
+}
+
+#[test]
+fn solve_ivp_terminal_event() {
+    // A ball thrown upwards at 10 m/s: y = (height, velocity).
+    let g = 9.81;
+    let mut f = |_t: f64, y: &[f64], dydt: &mut [f64]| {
+        dydt[0] = y[1];
+        dydt[1] = -g;
+        Value::Success
+    };
+    let mut height = |_t: f64, y: &[f64]| y[0];
+    let mut above_5m = |_t: f64, y: &[f64]| y[0] - 5.;
+    let mut sys = ODEiv2System::new(2, &mut f);
+    let mut events = [
+        ODEiv2Event::new(&mut height, ODEiv2EventDirection::Falling, true),
+        ODEiv2Event::new(&mut above_5m, ODEiv2EventDirection::Both, false),
+    ];
+    let trajectory = solve_ivp_with_events(
+        &mut sys,
+        &ODEiv2StepType::rk8pd(),
+        ODEiv2Mode::Adaptive { hstart: 1e-3 },
+        1e-10,
+        1e-10,
+        0.,
+        &[0., 10.],
+        &[1., 3., 100.],
+        &mut events,
+    )
+    .unwrap();
+    assert_eq!(trajectory.status, Value::Success);
+
+    // The height is 5 m at (10 -+ sqrt(100 - 10 g)) / g, and 0 at 20 / g.
+    let delta = (100. - 10. * g).sqrt();
+    let expected = [(1, (10. - delta) / g), (1, (10. + delta) / g), (0, 20. / g)];
+    assert_eq!(trajectory.events.len(), expected.len());
+    for (hit, &(event, t)) in trajectory.events.iter().zip(&expected) {
+        assert_eq!(hit.event, event);
+        assert!((hit.t - t).abs() < 1e-8, "{} != {}", hit.t, t);
+        assert!((hit.y[1] - (10. - g * t)).abs() < 1e-8);
+    }
+    assert!(trajectory.events[2].y[0].abs() < 1e-8);
+
+    assert!((trajectory.y.get(0, 0) - (10. - g / 2.)).abs() < 1e-8);
+    // The integration stopped at the impact, before the other output times.
+    assert!(trajectory.y.get(1, 0).is_nan());
+    assert!(trajectory.y.get(2, 1).is_nan());
+}
+
+#[test]
+fn solve_ivp_event_directions() {
+    // y = (sin t, cos t), whose first component crosses zero downwards at pi and 3 pi, and
+    // upwards at 2 pi.
+    let mut f = |_t: f64, y: &[f64], dydt: &mut [f64]| {
+        dydt[0] = y[1];
+        dydt[1] = -y[0];
+        Value::Success
+    };
+    let mut rising = |_t: f64, y: &[f64]| y[0];
+    let mut falling = |_t: f64, y: &[f64]| y[0];
+    let mut both = |_t: f64, y: &[f64]| y[0];
+    let mut sys = ODEiv2System::new(2, &mut f);
+    let mut events = [
+        ODEiv2Event::new(&mut rising, ODEiv2EventDirection::Rising, false),
+        ODEiv2Event::new(&mut falling, ODEiv2EventDirection::Falling, false),
+        ODEiv2Event::new(&mut both, ODEiv2EventDirection::Both, false),
+    ];
+    let trajectory = solve_ivp_with_events(
+        &mut sys,
+        &ODEiv2StepType::rk45(),
+        ODEiv2Mode::Adaptive { hstart: 1e-3 },
+        1e-10,
+        1e-10,
+        0.,
+        &[0., 1.],
+        &[10.],
+        &mut events,
+    )
+    .unwrap();
+    assert_eq!(trajectory.status, Value::Success);
+    assert!((trajectory.y.get(0, 0) - 10f64.sin()).abs() < 1e-6);
+
+    let pi = ::std::f64::consts::PI;
+    for &(event, ref times) in &[
+        (0, vec![2. * pi]),
+        (1, vec![pi, 3. * pi]),
+        (2, vec![pi, 2. * pi, 3. * pi]),
+    ] {
+        let hits: Vec<_> = trajectory
+            .events
+            .iter()
+            .filter(|hit| hit.event == event)
+            .collect();
+        assert_eq!(hits.len(), times.len(), "event {}", event);
+        for (hit, &t) in hits.iter().zip(times) {
+            assert!(
+                (hit.t - t).abs() < 1e-6,
+                "event {}: {} != {}",
+                event,
+                hit.t,
+                t
+            );
+            assert!(hit.y[0].abs() < 1e-6);
+            assert!((hit.y[1] - t.cos()).abs() < 1e-6);
+        }
+    }
+    assert!(trajectory
+        .events
+        .windows(2)
+        .all(|hits| hits[0].t <= hits[1].t + 1e-9));
+}
+
+#[test]
+fn owned_driver_across_threads() {
+    fn assert_send<T: Send>() {}
+    assert_send::<ODEiv2OwnedSystem>();
+    assert_send::<ODEiv2OwnedDriver>();
+
+    let k = 0.5;
+    let sys = ODEiv2OwnedSystem::new(1, move |_t, y, dydt| {
+        dydt[0] = -k * y[0];
+        Value::Success
+    });
+    let mut driver =
+        ODEiv2OwnedDriver::alloc_y_new(sys, &ODEiv2StepType::rk8pd(), 1e-6, 1e-10, 1e-10).unwrap();
+    assert_eq!(driver.system().dimension(), 1);
+
+    let (mut t, mut y) = (0., [1.]);
+    assert_eq!(driver.apply(&mut t, 1., &mut y), Value::Success);
+    let (mut driver, t, y) = ::std::thread::spawn(move || {
+        // The driver keeps integrating from where it stopped on the other thread.
+        assert_eq!(driver.apply(&mut t, 2., &mut y), Value::Success);
+        (driver, t, y)
+    })
+    .join()
+    .unwrap();
+    assert_eq!(t, 2.);
+    assert!((y[0] - (-k * 2.).exp()).abs() < 1e-8);
+
+    let (mut t, mut y) = (t, y);
+    assert_eq!(driver.apply(&mut t, 3., &mut y), Value::Success);
+    assert!((y[0] - (-k * 3.).exp()).abs() < 1e-8);
+}
+
+#[test]
+fn owned_system_with_jacobian() {
+    let sys = ODEiv2OwnedSystem::with_jacobian(1, decay, |_t, _y, dfdy, dfdt| {
+        dfdy[0] = -1.;
+        dfdt[0] = 0.;
+        Value::Success
+    });
+    let mut driver = ::std::thread::spawn(move || {
+        ODEiv2OwnedDriver::alloc_y_new(sys, &ODEiv2StepType::bsimp(), 1e-3, 1e-10, 1e-10).unwrap()
+    })
+    .join()
+    .unwrap();
+    let (mut t, mut y) = (0., [1.]);
+    assert_eq!(driver.apply(&mut t, 1., &mut y), Value::Success);
+    assert!((y[0] - (-1f64).exp()).abs() < 1e-8);
+}
+
+// A stiff linear system: y0' = -y0 + y1, y1' = -100 y1.
+#[cfg(test)]
+fn stiff(_t: f64, y: &[f64], dydt: &mut [f64]) -> Value {
+    dydt[0] = -y[0] + y[1];
+    dydt[1] = -100. * y[1];
+    Value::Success
+}
+
+// The solution of `stiff` at `t` from y = (1, 1) at 0.
+#[cfg(test)]
+fn stiff_solution(t: f64) -> [f64; 2] {
+    let fast = (-100. * t).exp();
+    [(1. + 1. / 99.) * (-t).exp() - fast / 99., fast]
+}
+
+#[test]
+fn solve_ivp_numerical_jacobian() {
+    let expected = stiff_solution(1.);
+    for &(name, step_type) in &[
+        ("bsimp", ODEiv2StepType::bsimp()),
+        ("rk2imp", ODEiv2StepType::rk2imp()),
+        ("rk4imp", ODEiv2StepType::rk4imp()),
+        ("msbdf", ODEiv2StepType::msbdf()),
+    ] {
+        for &jacobian in &[
+            ODEiv2NumericalJacobian::default(),
+            // df1/dy0 is zero.
+            ODEiv2NumericalJacobian::banded(0, 1),
+        ] {
+            let mut f = stiff;
+            let mut sys = ODEiv2System::with_numerical_jacobian(2, &mut f, jacobian);
+            let trajectory = solve_ivp(
+                &mut sys,
+                &step_type,
+                ODEiv2Mode::Adaptive { hstart: 1e-4 },
+                1e-10,
+                1e-10,
+                0.,
+                &[1., 1.],
+                &[0.5, 1.],
+            )
+            .unwrap();
+            assert_eq!(trajectory.status, Value::Success, "{}", name);
+            for (j, &y) in expected.iter().enumerate() {
+                let found = trajectory.y.get(1, j);
+                assert!((found - y).abs() < 1e-6, "{}: {} != {}", name, found, y);
+            }
+            let stats = trajectory.stats;
+            assert!(stats.jacobian_evals > 0, "{}", name);
+            // Each jacobian costs at least 3 evaluations of the system.
+            assert!(stats.function_evals >= 3 * stats.jacobian_evals, "{}", name);
+        }
+    }
+}
+
+#[test]
+fn owned_system_numerical_jacobian() {
+    let sys =
+        ODEiv2OwnedSystem::with_numerical_jacobian(2, stiff, ODEiv2NumericalJacobian::default());
+    let mut driver =
+        ODEiv2OwnedDriver::alloc_y_new(sys, &ODEiv2StepType::msbdf(), 1e-4, 1e-10, 1e-10).unwrap();
+    let (mut t, mut y) = (0., [1., 1.]);
+    assert_eq!(driver.apply(&mut t, 1., &mut y), Value::Success);
+    for (found, expected) in y.iter().zip(&stiff_solution(1.)) {
+        assert!((found - expected).abs() < 1e-6, "{} != {}", found, expected);
+    }
+}
@@ -2,16 +2,63 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-#[cfg(feature = "v2_2")]
-use crate::MatrixF64;
 use crate::Value;
-use crate::{MatrixComplexF32, MatrixComplexF64, MatrixF32, VectorF64};
 use ffi::FFI;
 use std::fmt;
 use std::fmt::{Debug, Formatter};
+use std::os::raw::c_int;
+use types::{ComplexF32, ComplexF64, Element, GslMatrixMut, GslVectorMut};
 
 ffi_wrapper!(Permutation, *mut sys::gsl_permutation, gsl_permutation_free);
 
+/// Element types which can be rearranged by a [`Permutation`]: `f64`, `f32`, `i32`, `u32`, `i16`,
+/// `u16`, `u8`, `ComplexF64`, `ComplexF32` and, where the C `long` type is 64 bits wide, `i64` and
+/// `u64`. Vectors and matrices can be permuted for the types which have a vector or matrix type
+/// (see [`Element`]).
+pub trait PermuteElement: Copy {
+    #[doc(hidden)]
+    unsafe fn permute(p: *const usize, data: *mut Self, stride: usize, n: usize) -> c_int;
+    #[doc(hidden)]
+    unsafe fn permute_inverse(p: *const usize, data: *mut Self, stride: usize, n: usize) -> c_int;
+}
+
+macro_rules! impl_permute_element {
+    ($ty:ty, $permute:ident, $permute_inverse:ident) => {
+        impl PermuteElement for $ty {
+            unsafe fn permute(p: *const usize, data: *mut Self, stride: usize, n: usize) -> c_int {
+                sys::$permute(p, data as *mut _, stride, n)
+            }
+
+            unsafe fn permute_inverse(
+                p: *const usize,
+                data: *mut Self,
+                stride: usize,
+                n: usize,
+            ) -> c_int {
+                sys::$permute_inverse(p, data as *mut _, stride, n)
+            }
+        }
+    };
+}
+
+impl_permute_element!(f64, gsl_permute, gsl_permute_inverse);
+impl_permute_element!(f32, gsl_permute_float, gsl_permute_float_inverse);
+impl_permute_element!(i32, gsl_permute_int, gsl_permute_int_inverse);
+impl_permute_element!(u32, gsl_permute_uint, gsl_permute_uint_inverse);
+impl_permute_element!(i16, gsl_permute_short, gsl_permute_short_inverse);
+impl_permute_element!(u16, gsl_permute_ushort, gsl_permute_ushort_inverse);
+impl_permute_element!(u8, gsl_permute_uchar, gsl_permute_uchar_inverse);
+#[cfg(all(target_pointer_width = "64", not(windows)))]
+impl_permute_element!(i64, gsl_permute_long, gsl_permute_long_inverse);
+#[cfg(all(target_pointer_width = "64", not(windows)))]
+impl_permute_element!(u64, gsl_permute_ulong, gsl_permute_ulong_inverse);
+impl_permute_element!(ComplexF64, gsl_permute_complex, gsl_permute_complex_inverse);
+impl_permute_element!(
+    ComplexF32,
+    gsl_permute_complex_float,
+    gsl_permute_complex_float_inverse
+);
+
 /// ##Permutations in cyclic form
 ///
 /// A permutation can be represented in both linear and cyclic notations. The functions described in this section convert between the two forms.
@@ -149,21 +196,37 @@ impl Permutation {
         Value::from(unsafe { sys::gsl_permutation_prev(self.unwrap_unique()) })
     }
 
-    /// This function applies the permutation to the array data of size n with stride stride.
-    #[doc(alias = "gsl_permutation_data")]
-    pub fn permute(&mut self, data: &mut [f64], stride: usize) -> Value {
+    /// This function applies the permutation to the array data of size n with stride stride, where
+    /// n is the size of the permutation.
+    ///
+    /// Panics if `data` is too short to hold n elements with the given stride.
+    #[doc(alias = "gsl_permute")]
+    pub fn permute<T: PermuteElement>(&self, data: &mut [T], stride: usize) -> Value {
+        let n = self.size();
+        assert!(
+            n == 0 || matches!((n - 1).checked_mul(stride), Some(last) if last < data.len()),
+            "data is too short for the permutation"
+        );
         Value::from(unsafe {
             let data_ptr = sys::gsl_permutation_data(self.unwrap_shared());
-            sys::gsl_permute(data_ptr, data.as_mut_ptr(), stride, data.len() as _)
+            T::permute(data_ptr, data.as_mut_ptr(), stride, n)
         })
     }
 
-    /// This function applies the inverse of the permutation p to the array data of size n with stride stride.
-    #[doc(alias = "gsl_permutation_data")]
-    pub fn permute_inverse(&mut self, data: &mut [f64], stride: usize) -> Value {
+    /// This function applies the inverse of the permutation p to the array data of size n with
+    /// stride stride, where n is the size of the permutation.
+    ///
+    /// Panics if `data` is too short to hold n elements with the given stride.
+    #[doc(alias = "gsl_permute_inverse")]
+    pub fn permute_inverse<T: PermuteElement>(&self, data: &mut [T], stride: usize) -> Value {
+        let n = self.size();
+        assert!(
+            n == 0 || matches!((n - 1).checked_mul(stride), Some(last) if last < data.len()),
+            "data is too short for the permutation"
+        );
         Value::from(unsafe {
             let data_ptr = sys::gsl_permutation_data(self.unwrap_shared());
-            sys::gsl_permute_inverse(data_ptr, data.as_mut_ptr(), stride, data.len() as _)
+            T::permute_inverse(data_ptr, data.as_mut_ptr(), stride, n)
         })
     }
 
@@ -171,46 +234,83 @@ impl Permutation {
     /// matrix from the right, v' = v P. The j-th column of the permutation matrix P is given by the p_j-th column of the identity matrix.
     /// The permutation p and the vector v must have the same length.
     #[doc(alias = "gsl_permute_vector")]
-    pub fn permute_vector(&mut self, v: &mut VectorF64) -> Value {
-        Value::from(unsafe { sys::gsl_permute_vector(self.unwrap_unique(), v.unwrap_unique()) })
+    pub fn permute_vector<T: PermuteElement + Element>(
+        &self,
+        v: &mut impl GslVectorMut<T>,
+    ) -> Value {
+        let (data, stride, n) = unsafe { T::vector_parts(v.unwrap_unique()) };
+        if n != self.size() {
+            return Value::BadLength;
+        }
+        Value::from(unsafe {
+            T::permute(
+                sys::gsl_permutation_data(self.unwrap_shared()),
+                data,
+                stride,
+                n,
+            )
+        })
     }
 
     /// This function applies the inverse of the permutation p to the elements of the vector v, considered as a row-vector acted on by an inverse permutation
     /// matrix from the right, v' = v P^T. Note that for permutation matrices the inverse is the same as the transpose. The j-th column of the permutation
     /// matrix P is given by the p_j-th column of the identity matrix. The permutation p and the vector v must have the same length.
     #[doc(alias = "gsl_permute_vector_inverse")]
-    pub fn permute_vector_inverse(&self, v: &mut VectorF64) -> Value {
+    pub fn permute_vector_inverse<T: PermuteElement + Element>(
+        &self,
+        v: &mut impl GslVectorMut<T>,
+    ) -> Value {
+        let (data, stride, n) = unsafe { T::vector_parts(v.unwrap_unique()) };
+        if n != self.size() {
+            return Value::BadLength;
+        }
         Value::from(unsafe {
-            sys::gsl_permute_vector_inverse(self.unwrap_shared(), v.unwrap_unique())
+            T::permute_inverse(
+                sys::gsl_permutation_data(self.unwrap_shared()),
+                data,
+                stride,
+                n,
+            )
         })
     }
 
-    #[cfg(feature = "v2_2")]
-    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    /// This function applies the permutation p to the matrix A from the right, A' = A P. The j-th
+    /// column of the permutation matrix P is given by the p_j-th column of the identity matrix.
+    /// This effectively permutes the columns of A according to the permutation p, and so the number
+    /// of columns of A must equal the size of the permutation p.
     #[doc(alias = "gsl_permute_matrix")]
-    pub fn permute_matrix(&self, A: &mut MatrixF64) -> Value {
-        Value::from(unsafe { sys::gsl_permute_matrix(self.unwrap_shared(), A.unwrap_unique()) })
-    }
-
-    #[doc(alias = "gsl_permute_matrix_float")]
-    pub fn permute_matrix_float(&self, A: &mut MatrixF32) -> Value {
-        Value::from(unsafe {
-            sys::gsl_permute_matrix_float(self.unwrap_shared(), A.unwrap_unique())
-        })
-    }
-
-    #[doc(alias = "gsl_permute_matrix_complex")]
-    pub fn permute_matrix_complex(&self, A: &mut MatrixComplexF64) -> Value {
-        Value::from(unsafe {
-            sys::gsl_permute_matrix_complex(self.unwrap_shared(), A.unwrap_unique())
-        })
+    pub fn permute_matrix<T: PermuteElement + Element>(
+        &self,
+        A: &mut impl GslMatrixMut<T>,
+    ) -> Value {
+        let (data, size1, size2, tda) = unsafe { T::matrix_parts(A.unwrap_unique()) };
+        if size2 != self.size() {
+            return Value::BadLength;
+        }
+        let p = unsafe { sys::gsl_permutation_data(self.unwrap_shared()) };
+        for i in 0..size1 {
+            let ret = Value::from(unsafe { T::permute(p, data.add(i * tda), 1, size2) });
+            if ret != Value::Success {
+                return ret;
+            }
+        }
+        Value::Success
     }
 
-    #[doc(alias = "gsl_permute_matrix_complex_float")]
-    pub fn permute_matrix_complex_float(&self, A: &mut MatrixComplexF32) -> Value {
-        Value::from(unsafe {
-            sys::gsl_permute_matrix_complex_float(self.unwrap_shared(), A.unwrap_unique())
-        })
+    /// Returns an iterator over all the permutations of size n, in lexicographic order, starting
+    /// with the identity.
+    ///
+    /// ```
+    /// use rgsl::Permutation;
+    ///
+    /// let all: Vec<_> = Permutation::all(3).map(|p| p.as_slice().to_vec()).collect();
+    /// assert_eq!(all.len(), 6);
+    /// assert_eq!(all[1], [0, 2, 1]);
+    /// ```
+    pub fn all(n: usize) -> Permutations {
+        Permutations {
+            current: Permutation::new_with_init(n),
+        }
     }
 
     /// This function combines the two permutations pa and pb into a single permutation p, where p = pa * pb. The permutation p is equivalent to applying pb
@@ -267,3 +367,152 @@ impl Debug for Permutation {
         }
     }
 }
+
+/// Iterator over all the permutations of a given size, created by [`Permutation::all`].
+///
+/// The iteration stops early if a permutation cannot be allocated.
+pub struct Permutations {
+    current: Option<Permutation>,
+}
+
+impl Iterator for Permutations {
+    type Item = Permutation;
+
+    fn next(&mut self) -> Option<Permutation> {
+        let current = self.current.take()?;
+        if let Some(mut next) = Permutation::new(current.size()) {
+            current.copy(&mut next);
+            if next.next() == Value::Success {
+                self.current = Some(next);
+            }
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+fn permutation(p: &[usize]) -> Permutation {
+    let mut permutation = Permutation::new(p.len()).unwrap();
+    permutation.as_mut_slice().copy_from_slice(p);
+    assert!(permutation.is_valid());
+    permutation
+}
+
+#[test]
+fn permute_slices() {
+    let p = permutation(&[2, 0, 1]);
+
+    let mut x = [1., 2., 3.];
+    assert_eq!(p.permute(&mut x, 1), Value::Success);
+    assert_eq!(x, [3., 1., 2.]);
+    assert_eq!(p.permute_inverse(&mut x, 1), Value::Success);
+    assert_eq!(x, [1., 2., 3.]);
+
+    // Only the elements at even indices are permuted.
+    let mut x = [1u8, 0, 2, 0, 3];
+    assert_eq!(p.permute(&mut x, 2), Value::Success);
+    assert_eq!(x, [3, 0, 1, 0, 2]);
+
+    let mut x = [
+        ComplexF64::rect(1., -1.),
+        ComplexF64::rect(2., -2.),
+        ComplexF64::rect(3., -3.),
+    ];
+    assert_eq!(p.permute(&mut x, 1), Value::Success);
+    assert_eq!(x[0], ComplexF64::rect(3., -3.));
+    assert_eq!(x[1], ComplexF64::rect(1., -1.));
+}
+
+#[test]
+#[should_panic(expected = "data is too short for the permutation")]
+fn permute_too_short() {
+    let p = permutation(&[2, 0, 1]);
+    p.permute(&mut [1., 2., 3., 4.], 2);
+}
+
+#[test]
+#[should_panic(expected = "data is too short for the permutation")]
+fn permute_stride_overflow() {
+    let p = permutation(&[2, 0, 1]);
+    p.permute_inverse(&mut [1., 2., 3.], usize::MAX);
+}
+
+#[test]
+fn permute_vectors() {
+    use types::{VectorComplexF64, VectorF32, VectorF64ViewMut, VectorI32};
+
+    let p = permutation(&[2, 0, 1]);
+
+    let mut v = VectorF32::from_slice(&[1., 2., 3.]).unwrap();
+    assert_eq!(p.permute_vector(&mut v), Value::Success);
+    assert_eq!(v.as_slice(), Some(&[3., 1., 2.][..]));
+    assert_eq!(p.permute_vector_inverse(&mut v), Value::Success);
+    assert_eq!(v.as_slice(), Some(&[1., 2., 3.][..]));
+
+    let mut v = VectorI32::from_slice(&[1, 2, 3]).unwrap();
+    assert_eq!(p.permute_vector(&mut v), Value::Success);
+    assert_eq!(v.as_slice(), Some(&[3, 1, 2][..]));
+
+    let mut v = VectorComplexF64::from_slice(&[
+        ComplexF64::rect(1., 0.),
+        ComplexF64::rect(2., 0.),
+        ComplexF64::rect(3., 0.),
+    ])
+    .unwrap();
+    assert_eq!(p.permute_vector(&mut v), Value::Success);
+    assert_eq!(v.get(0), ComplexF64::rect(3., 0.));
+
+    let mut data = [1., 0., 2., 0., 3.];
+    let mut view = VectorF64ViewMut::from_array_with_stride(&mut data, 2);
+    assert_eq!(p.permute_vector(&mut view), Value::Success);
+    assert_eq!(data, [3., 0., 1., 0., 2.]);
+
+    let mut v = VectorI32::from_slice(&[1, 2]).unwrap();
+    assert_eq!(p.permute_vector(&mut v), Value::BadLength);
+    assert_eq!(p.permute_vector_inverse(&mut v), Value::BadLength);
+}
+
+#[test]
+fn permute_matrices() {
+    use types::{MatrixF64ViewMut, MatrixI32};
+
+    let p = permutation(&[2, 0, 1]);
+
+    // Two rows of three columns, with a padding column.
+    let mut data = [1., 2., 3., 0., 4., 5., 6., 0.];
+    let mut m = MatrixF64ViewMut::from_array_with_tda(&mut data, 2, 3, 4);
+    assert_eq!(p.permute_matrix(&mut m), Value::Success);
+    assert_eq!(data, [3., 1., 2., 0., 6., 4., 5., 0.]);
+
+    let mut m = MatrixI32::new(3, 3).unwrap();
+    for i in 0..3 {
+        for j in 0..3 {
+            m.set(i, j, (3 * i + j) as i32);
+        }
+    }
+    assert_eq!(p.permute_matrix(&mut m), Value::Success);
+    assert_eq!(m.get(1, 0), 5);
+    assert_eq!(m.get(1, 1), 3);
+
+    let mut m = MatrixI32::new(3, 2).unwrap();
+    assert_eq!(p.permute_matrix(&mut m), Value::BadLength);
+}
+
+#[test]
+fn permutations_iterator() {
+    let all: Vec<_> = Permutation::all(3).map(|p| p.as_slice().to_vec()).collect();
+    assert_eq!(
+        all,
+        [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ]
+    );
+    assert_eq!(Permutation::all(4).count(), 24);
+    assert!(Permutation::all(4).all(|p| p.is_valid()));
+    assert_eq!(Permutation::all(1).count(), 1);
+}
@@ -20,9 +20,21 @@ Transactions on Mathematical Software, Vol. 20, No. 4, December, 1994, p. 494–
 
 use crate::Value;
 use ffi::FFI;
+use std::io::{self, Read, Write};
+use std::os::raw::{c_uint, c_void};
+use types::rng::{
+    read_data_exact, read_name, read_u64, state_error_value, write_data, write_name, write_u64,
+};
 
 ffi_wrapper!(QRng, *mut sys::gsl_qrng, gsl_qrng_free);
 
+// The beginning of the `gsl_qrng` struct, which is opaque in the bindings.
+#[repr(C)]
+struct RawQRng {
+    type_: *const c_void,
+    dimension: c_uint,
+}
+
 impl QRng {
     /// This function returns a pointer to a newly-created instance of a quasi-random sequence
     /// generator of type T and dimension d. If there is insufficient memory to create the generator
@@ -70,6 +82,11 @@ impl QRng {
         }
     }
 
+    /// Returns the dimension of the points of the sequence, given to [`new`](Self::new).
+    pub fn dimension(&self) -> u32 {
+        unsafe { (*(self.unwrap_shared() as *const RawQRng)).dimension }
+    }
+
     /// These functions return a pointer to the state of generator r and its size.
     #[doc(alias = "gsl_qrng_size")]
     pub fn size(&self) -> usize {
@@ -100,6 +117,80 @@ impl QRng {
         }
     }
 
+    /// Returns a copy of the state of the generator, which can be given to
+    /// [`restore_state`](Self::restore_state) to continue the same sequence later, for example
+    /// after a restart of the program.
+    ///
+    /// The data starts with the name and the dimension of the generator, used to check that it is
+    /// restored into a generator of the same type and dimension. The state itself is stored as in
+    /// memory, so it can only be restored on the same platform.
+    pub fn save_state(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write_state(&mut out)
+            .expect("rust-GSL: writing to a Vec cannot fail");
+        out
+    }
+
+    /// Restores a state saved with [`save_state`](Self::save_state). Returns [`Value::Invalid`]
+    /// if `data` wasn't saved from a generator of the same type and dimension, and
+    /// [`Value::BadLength`] if it is truncated or too long. The generator is left unchanged on
+    /// error.
+    pub fn restore_state(&mut self, mut data: &[u8]) -> Value {
+        match self.read_saved_state(&mut data) {
+            Ok(saved) if data.is_empty() => {
+                self.set_saved_state(&saved);
+                Value::Success
+            }
+            Ok(_) => Value::BadLength,
+            Err(e) => state_error_value(&e),
+        }
+    }
+
+    /// Writes the state of the generator to `w`, in the format of [`save_state`](Self::save_state).
+    pub fn write_state<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let state = unsafe {
+            ::std::slice::from_raw_parts(
+                sys::gsl_qrng_state(self.unwrap_shared()) as *const u8,
+                self.size(),
+            )
+        };
+        write_name(w, &self.name().unwrap_or_default())?;
+        write_u64(w, u64::from(self.dimension()))?;
+        write_data(w, state)
+    }
+
+    /// Reads a state written by [`write_state`](Self::write_state) from `r`. An error of kind
+    /// [`io::ErrorKind::InvalidData`] is returned if it wasn't saved from a generator of the same
+    /// type and dimension. The generator is left unchanged on error.
+    pub fn read_state<R: Read>(&mut self, r: &mut R) -> io::Result<()> {
+        let saved = self.read_saved_state(r)?;
+        self.set_saved_state(&saved);
+        Ok(())
+    }
+
+    // Reads the state data written by `write_state`, without changing the generator.
+    fn read_saved_state<R: Read>(&self, r: &mut R) -> io::Result<Vec<u8>> {
+        read_name(r, &self.name().unwrap_or_default())?;
+        if read_u64(r)? != u64::from(self.dimension()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "rust-GSL: the saved state wasn't saved from a generator of the same dimension",
+            ));
+        }
+        read_data_exact(r, self.size())
+    }
+
+    // Replaces the state of the generator by data returned by `read_saved_state`.
+    fn set_saved_state(&mut self, saved: &[u8]) {
+        unsafe {
+            ::std::slice::from_raw_parts_mut(
+                sys::gsl_qrng_state(self.unwrap_unique()) as *mut u8,
+                self.size(),
+            )
+        }
+        .copy_from_slice(saved);
+    }
+
     /// This function copies the quasi-random sequence generator src into the pre-existing generator
     /// `dest`, making dest into an exact copy of `self`. The two generators must be of the same
     /// type.
@@ -144,3 +235,45 @@ impl QRngType {
         ffi_wrap!(gsl_qrng_reversehalton)
     }
 }
+
+#[test]
+fn state_round_trip() {
+    let mut q = QRng::new(QRngType::sobol(), 2).unwrap();
+    assert_eq!(q.dimension(), 2);
+    let mut x = [0.; 2];
+    q.get(&mut x);
+    let saved = q.save_state();
+    let mut expected = [0.; 2];
+    q.get(&mut expected);
+    q.get(&mut x);
+
+    assert_eq!(q.restore_state(&saved), Value::Success);
+    q.get(&mut x);
+    assert_eq!(x, expected);
+}
+
+#[test]
+fn state_restore_errors() {
+    let mut q = QRng::new(QRngType::sobol(), 2).unwrap();
+    let mut x = [0.; 2];
+    q.get(&mut x);
+    let saved = q.save_state();
+    q.get(&mut x);
+
+    let mut expected = [0.; 2];
+    let reference = q.clone();
+    reference.get(&mut expected);
+    assert_eq!(q.restore_state(&saved[..saved.len() - 1]), Value::BadLength);
+    let mut too_long = saved.clone();
+    too_long.push(0);
+    assert_eq!(q.restore_state(&too_long), Value::BadLength);
+    let other = QRng::new(QRngType::niederreiter_2(), 2)
+        .unwrap()
+        .save_state();
+    assert_eq!(q.restore_state(&other), Value::Invalid);
+    q.get(&mut x);
+    assert_eq!(x, expected);
+
+    let mut q = QRng::new(QRngType::sobol(), 3).unwrap();
+    assert_eq!(q.restore_state(&saved), Value::Invalid);
+}
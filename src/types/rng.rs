@@ -72,7 +72,14 @@ Thanks to Makoto Matsumoto, Takuji Nishimura and Yoshiharu Kurita for making the
 
 use crate::Value;
 use ffi::FFI;
-use std::os::raw::c_ulong;
+use std::any::TypeId;
+use std::collections::HashSet;
+use std::ffi::CString;
+use std::io::{self, Read, Write};
+use std::os::raw::{c_ulong, c_void};
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::Mutex;
 
 ffi_wrapper!(Rng, *mut sys::gsl_rng, gsl_rng_free);
 
@@ -221,11 +228,168 @@ impl Rng {
         unsafe { sys::gsl_rng_size(self.unwrap_shared()) }
     }
 
+    /// Returns a copy of the state of the generator, which can be given to
+    /// [`restore_state`](Self::restore_state) to continue the same stream of random numbers later,
+    /// for example after a restart of the program.
+    ///
+    /// The data starts with the name of the generator, used to check that it is restored into a
+    /// generator of the same type. The state of the GSL generators is stored as in memory, so it
+    /// can only be restored on the same platform. The state of the generators running a
+    /// [`RngAlgorithm`] is stored by [`RngAlgorithm::save_state`].
+    ///
+    /// Returns `None` if the generator runs a [`RngAlgorithm`] which doesn't support saving its
+    /// state.
+    pub fn save_state(&self) -> Option<Vec<u8>> {
+        let mut out = Vec::new();
+        self.write_state(&mut out).ok().map(|()| out)
+    }
+
+    /// Restores a state saved with [`save_state`](Self::save_state). Returns [`Value::Invalid`]
+    /// if `data` wasn't saved from a generator of the same type, [`Value::BadLength`] if it is
+    /// truncated or too long, and [`Value::Unsupported`] if the generator runs a
+    /// [`RngAlgorithm`] which doesn't support saving its state. The generator is left unchanged on
+    /// error.
+    pub fn restore_state(&mut self, mut data: &[u8]) -> Value {
+        let saved = match self.read_saved_state(&mut data) {
+            Ok(saved) if data.is_empty() => saved,
+            Ok(_) => return Value::BadLength,
+            Err(e) => return state_error_value(&e),
+        };
+        match self.set_saved_state(&saved) {
+            Ok(()) => Value::Success,
+            Err(e) => state_error_value(&e),
+        }
+    }
+
+    /// Writes the state of the generator to `w`, in the format of [`save_state`](Self::save_state).
+    /// An error of kind [`io::ErrorKind::Unsupported`] is returned if the generator runs a
+    /// [`RngAlgorithm`] which doesn't support saving its state.
+    pub fn write_state<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let state = unsafe { sys::gsl_rng_state(self.unwrap_shared()) };
+        if let Some(hooks) = self.algorithm_hooks() {
+            let data = unsafe { (hooks.save)(state) }.ok_or_else(unsupported_state)?;
+            write_name(w, &self.get_name())?;
+            return write_data(w, &data);
+        }
+        let state = unsafe { ::std::slice::from_raw_parts(state as *const u8, self.size()) };
+        write_name(w, &self.get_name())?;
+        write_data(w, state)
+    }
+
+    /// Reads a state written by [`write_state`](Self::write_state) from `r`. An error of kind
+    /// [`io::ErrorKind::InvalidData`] is returned if it wasn't saved from a generator of the same
+    /// type, and of kind [`io::ErrorKind::Unsupported`] if the generator runs a [`RngAlgorithm`]
+    /// which doesn't support saving its state. The generator is left unchanged on error.
+    pub fn read_state<R: Read>(&mut self, r: &mut R) -> io::Result<()> {
+        let saved = self.read_saved_state(r)?;
+        self.set_saved_state(&saved)
+    }
+
+    // Reads the state data written by `write_state`, without changing the generator.
+    fn read_saved_state<R: Read>(&self, r: &mut R) -> io::Result<Vec<u8>> {
+        match self.algorithm_hooks() {
+            Some(hooks) => {
+                let state = unsafe { sys::gsl_rng_state(self.unwrap_shared()) };
+                if unsafe { (hooks.save)(state) }.is_none() {
+                    return Err(unsupported_state());
+                }
+                read_name(r, &self.get_name())?;
+                read_data(r)
+            }
+            None => {
+                read_name(r, &self.get_name())?;
+                read_data_exact(r, self.size())
+            }
+        }
+    }
+
+    // Replaces the state of the generator by data returned by `read_saved_state`.
+    fn set_saved_state(&mut self, saved: &[u8]) -> io::Result<()> {
+        let hooks = self.algorithm_hooks();
+        let state = unsafe { sys::gsl_rng_state(self.unwrap_unique()) };
+        if let Some(hooks) = hooks {
+            return if unsafe { (hooks.restore)(state, saved) } {
+                Ok(())
+            } else {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "rust-GSL: the saved state was rejected by the generator",
+                ))
+            };
+        }
+        unsafe { ::std::slice::from_raw_parts_mut(state as *mut u8, self.size()) }
+            .copy_from_slice(saved);
+        Ok(())
+    }
+
+    // Returns the state hooks of the generator if it runs a `RngAlgorithm`.
+    fn algorithm_hooks(&self) -> Option<StateHooks> {
+        let name = unsafe { sys::gsl_rng_name(self.unwrap_shared()) } as usize;
+        let registry = algorithms().lock().unwrap_or_else(|e| e.into_inner());
+        registry.iter().find(|a| a.name == name).map(|a| a.hooks)
+    }
+
     /// Equivalent to DefaultRngSeed
     pub fn default_seed() -> usize {
         unsafe { sys::gsl_rng_default_seed as _ }
     }
 
+    /// Returns `n` new generators of the same type as `self`, seeded from its output, to be used
+    /// as independent streams by parallel workers. `self` is advanced, so calling this function
+    /// again returns different streams.
+    ///
+    /// The seeds are distinct, non-zero (so none of them is the default seed of the algorithm) and
+    /// lower than `2^32`, so that no generator truncates them. The same master generator state
+    /// always gives the same streams, which keeps parallel runs reproducible. Returns `None` if
+    /// the output of `self` repeats too often to find `n` distinct seeds:
+    ///
+    /// ```no_run
+    /// use rgsl::rng::algorithms;
+    /// use rgsl::Rng;
+    /// use std::thread;
+    ///
+    /// let mut master = Rng::new(algorithms::ranlxd2()).unwrap();
+    /// master.set(42);
+    /// let workers: Vec<_> = master
+    ///     .spawn_streams(4)
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(|mut r| thread::spawn(move || (0..1000).map(|_| r.uniform()).sum::<f64>()))
+    ///     .collect();
+    /// let total: f64 = workers.into_iter().map(|w| w.join().unwrap()).sum();
+    /// ```
+    ///
+    /// How independent the streams are depends on the algorithm. The RANLUX family (`ranlxd2`,
+    /// `ranlxs2`, `ranlux389`) is designed so that distinct seeds give decorrelated sequences,
+    /// with the luxury level guaranteeing the decorrelation, which makes `ranlxd2` the recommended
+    /// type for parallel streams. For the other algorithms, distinct seeds only guarantee that the
+    /// streams start from different states: with a long period generator such as `mt19937` an
+    /// overlap is very unlikely, but no independence is proven.
+    pub fn spawn_streams(&mut self, n: usize) -> Option<Vec<Rng>> {
+        let mut seeds = HashSet::with_capacity(n);
+        let mut streams = Vec::with_capacity(n);
+        // A collision is very unlikely unless the output of `self` repeats, which would otherwise
+        // loop forever.
+        let mut attempts = n.saturating_mul(16).saturating_add(64);
+        while streams.len() < n {
+            if attempts == 0 {
+                return None;
+            }
+            attempts -= 1;
+            // Two outputs are hashed together so that generators returning fewer than 32 bits
+            // still reach every seed.
+            let x = ((self.get() as u64) << 32) ^ self.get() as u64;
+            let seed = (mix_seed(x ^ streams.len() as u64) & 0xffff_ffff) as usize;
+            if seed == 0 || !seeds.insert(seed) {
+                continue;
+            }
+            let mut r = self.clone();
+            r.set(seed);
+            streams.push(r);
+        }
+        Some(streams)
+    }
+
     /// This function randomly shuffles the order of n objects, each of size size, stored in the array base[0..n-1]. The output of the random number generator r is used to
     /// produce the permutation. The algorithm generates all possible n! permutations with equal probability, assuming a perfect source of random numbers.
     ///
@@ -821,6 +985,18 @@ impl Rng {
     }
 }
 
+// A generator only accesses its own state, which it owns: it can be moved to another thread. The
+// state of user-defined algorithms is `Send` as required by `RngAlgorithm`.
+unsafe impl Send for Rng {}
+
+// The SplitMix64 finalizer, which spreads every bit of `x` over the whole result.
+fn mix_seed(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
 impl Clone for Rng {
     /// This function returns a pointer to a newly created generator which is an exact copy of the generator r.
     #[doc(alias = "gsl_rng_clone")]
@@ -829,8 +1005,242 @@ impl Clone for Rng {
     }
 }
 
+// The saved states of `Rng` and `QRng` are the name of the generator and the state data, each
+// preceded by its length as a little-endian `u64`. The state of a `QRng` is also preceded by its
+// dimension, as a `u64` too.
+pub(crate) fn write_name<W: Write>(w: &mut W, name: &str) -> io::Result<()> {
+    write_u64(w, name.len() as u64)?;
+    w.write_all(name.as_bytes())
+}
+
+pub(crate) fn write_data<W: Write>(w: &mut W, data: &[u8]) -> io::Result<()> {
+    write_u64(w, data.len() as u64)?;
+    w.write_all(data)
+}
+
+pub(crate) fn write_u64<W: Write>(w: &mut W, x: u64) -> io::Result<()> {
+    w.write_all(&x.to_le_bytes())
+}
+
+pub(crate) fn read_u64<R: Read>(r: &mut R) -> io::Result<u64> {
+    let mut x = [0; 8];
+    r.read_exact(&mut x)?;
+    Ok(u64::from_le_bytes(x))
+}
+
+pub(crate) fn read_name<R: Read>(r: &mut R, name: &str) -> io::Result<()> {
+    if read_u64(r)? != name.len() as u64 {
+        return Err(wrong_generator(name));
+    }
+    let mut saved_name = vec![0; name.len()];
+    r.read_exact(&mut saved_name)?;
+    if saved_name != name.as_bytes() {
+        return Err(wrong_generator(name));
+    }
+    Ok(())
+}
+
+// Reads state data which must have the size `len` of the state of the generator.
+pub(crate) fn read_data_exact<R: Read>(r: &mut R, len: usize) -> io::Result<Vec<u8>> {
+    if read_u64(r)? != len as u64 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "rust-GSL: the saved state doesn't have the size of the generator state",
+        ));
+    }
+    let mut saved = vec![0; len];
+    r.read_exact(&mut saved)?;
+    Ok(saved)
+}
+
+// Reads state data of any size, as saved by `RngAlgorithm::save_state`.
+fn read_data<R: Read>(r: &mut R) -> io::Result<Vec<u8>> {
+    let len = read_u64(r)?;
+    let mut saved = Vec::new();
+    r.take(len).read_to_end(&mut saved)?;
+    if saved.len() as u64 != len {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(saved)
+}
+
+fn unsupported_state() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        "rust-GSL: the generator doesn't support saving its state",
+    )
+}
+
+fn wrong_generator(name: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!(
+            "rust-GSL: the saved state wasn't saved from a {} generator",
+            name
+        ),
+    )
+}
+
+pub(crate) fn state_error_value(e: &io::Error) -> Value {
+    match e.kind() {
+        io::ErrorKind::UnexpectedEof => Value::BadLength,
+        io::ErrorKind::Unsupported => Value::Unsupported,
+        _ => Value::Invalid,
+    }
+}
+
+/// A random number generator algorithm implemented in Rust. [`RngType::from_algorithm`] turns it
+/// into a [`RngType`], so that it can drive every GSL function using an [`Rng`].
+///
+/// The state is stored and copied by GSL as plain bytes (for example by [`Rng::clone`]) and is
+/// never dropped, hence the `Copy` bound. It is saved and restored by [`Rng::save_state`] and
+/// [`Rng::restore_state`] only through [`save_state`](Self::save_state) and
+/// [`restore_state`](Self::restore_state), which aren't supported by default.
+///
+/// ```
+/// use rgsl::{Rng, RngAlgorithm, RngType};
+///
+/// #[derive(Clone, Copy)]
+/// struct SplitMix64(u64);
+///
+/// impl RngAlgorithm for SplitMix64 {
+///     const NAME: &'static str = "splitmix64";
+///
+///     fn from_seed(seed: u64) -> Self {
+///         SplitMix64(seed)
+///     }
+///
+///     fn next_u64(&mut self) -> u64 {
+///         self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+///         let mut z = self.0;
+///         z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+///         z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+///         z ^ (z >> 31)
+///     }
+///
+///     fn save_state(&self) -> Option<Vec<u8>> {
+///         Some(self.0.to_le_bytes().to_vec())
+///     }
+///
+///     fn restore_state(data: &[u8]) -> Option<Self> {
+///         let mut state = [0; 8];
+///         if data.len() != state.len() {
+///             return None;
+///         }
+///         state.copy_from_slice(data);
+///         Some(SplitMix64(u64::from_le_bytes(state)))
+///     }
+/// }
+///
+/// let mut r = Rng::new(RngType::from_algorithm::<SplitMix64>()).unwrap();
+/// r.set(42);
+/// let saved = r.save_state().unwrap();
+/// let x = r.gaussian(1.);
+/// r.restore_state(&saved);
+/// assert_eq!(r.gaussian(1.), x);
+/// ```
+pub trait RngAlgorithm: Copy + Send + 'static {
+    /// Name of the generator, returned by [`RngType::name`] and [`Rng::get_name`].
+    const NAME: &'static str;
+
+    /// Creates the state of the generator from `seed`. As for the GSL generators, `0` is used when
+    /// no seed was provided and should give the default stream.
+    fn from_seed(seed: u64) -> Self;
+
+    /// Returns the next 64 uniformly distributed random bits.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a number uniformly distributed in `[0, 1)`. By default, the 53 high bits of
+    /// [`next_u64`](Self::next_u64) are used.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1. / (1u64 << 53) as f64)
+    }
+
+    /// Returns the state as bytes, to be given back to [`restore_state`](Self::restore_state).
+    /// By default `None` is returned: the state can't be saved.
+    fn save_state(&self) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Rebuilds a state returned by [`save_state`](Self::save_state), or returns `None` if `data`
+    /// isn't a valid state.
+    fn restore_state(data: &[u8]) -> Option<Self> {
+        let _ = data;
+        None
+    }
+}
+
+unsafe extern "C" fn algorithm_set<T: RngAlgorithm>(state: *mut c_void, seed: c_ulong) {
+    ptr::write(state as *mut T, T::from_seed(seed as _));
+}
+
+unsafe extern "C" fn algorithm_get<T: RngAlgorithm>(state: *mut c_void) -> c_ulong {
+    ((*(state as *mut T)).next_u64() >> 32) as c_ulong
+}
+
+unsafe extern "C" fn algorithm_get_double<T: RngAlgorithm>(state: *mut c_void) -> f64 {
+    (*(state as *mut T)).next_f64()
+}
+
+unsafe fn algorithm_save<T: RngAlgorithm>(state: *const c_void) -> Option<Vec<u8>> {
+    (*(state as *const T)).save_state()
+}
+
+unsafe fn algorithm_restore<T: RngAlgorithm>(state: *mut c_void, data: &[u8]) -> bool {
+    match T::restore_state(data) {
+        Some(restored) => {
+            ptr::write(state as *mut T, restored);
+            true
+        }
+        None => false,
+    }
+}
+
+// Gives access to the state saving functions of a `RngAlgorithm` from a type-erased generator.
+#[derive(Clone, Copy)]
+struct StateHooks {
+    save: unsafe fn(*const c_void) -> Option<Vec<u8>>,
+    restore: unsafe fn(*mut c_void, &[u8]) -> bool,
+}
+
+struct Algorithm {
+    id: TypeId,
+    ty: usize,
+    // The name pointer of the type, returned by `gsl_rng_name` for its generators.
+    name: usize,
+    hooks: StateHooks,
+}
+
+// `gsl_rng_type`s created by `RngType::from_algorithm`, which live until the end of the program.
+// `Mutex::new` can't be used in statics before Rust 1.63.
+static ALGORITHMS: AtomicPtr<Mutex<Vec<Algorithm>>> = AtomicPtr::new(ptr::null_mut());
+
+fn algorithms() -> &'static Mutex<Vec<Algorithm>> {
+    let mut registry = ALGORITHMS.load(Ordering::Acquire);
+    if registry.is_null() {
+        let new = Box::into_raw(Box::new(Mutex::new(Vec::new())));
+        registry = match ALGORITHMS.compare_exchange(
+            ptr::null_mut(),
+            new,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => new,
+            Err(current) => {
+                drop(unsafe { Box::from_raw(new) });
+                current
+            }
+        };
+    }
+    unsafe { &*registry }
+}
+
 ffi_wrapper!(RngType, *const sys::gsl_rng_type);
 
+// Generator types are never modified once created.
+unsafe impl Send for RngType {}
+unsafe impl Sync for RngType {}
+
 impl RngType {
     /// wrapper for name element
     pub fn name(&self) -> String {
@@ -849,6 +1259,46 @@ impl RngType {
         ffi_wrap!(gsl_rng_default)
     }
 
+    /// Returns the generator type running the Rust algorithm `T`. Its `get` function returns the
+    /// 32 high bits of [`RngAlgorithm::next_u64`], so [`max`](Self::max) is `2^32 - 1` and
+    /// [`min`](Self::min) is `0`.
+    ///
+    /// The type is created on the first call for a given `T` and reused afterwards.
+    pub fn from_algorithm<T: RngAlgorithm>() -> RngType {
+        assert!(
+            ::std::mem::align_of::<T>() <= 2 * ::std::mem::size_of::<usize>(),
+            "rust-GSL: the generator state is over-aligned for malloc"
+        );
+        let mut registry = algorithms().lock().unwrap_or_else(|e| e.into_inner());
+        let id = TypeId::of::<T>();
+        if let Some(a) = registry.iter().find(|a| a.id == id) {
+            return RngType::wrap(a.ty as *mut sys::gsl_rng_type);
+        }
+        let name = CString::new(T::NAME)
+            .expect("rust-GSL: generator name contains a nul byte")
+            .into_raw();
+        let ty = Box::into_raw(Box::new(sys::gsl_rng_type {
+            name,
+            max: 0xffff_ffff,
+            min: 0,
+            // `malloc(0)` may return a null pointer, which GSL treats as a failure.
+            size: ::std::mem::size_of::<T>().max(1),
+            set: Some(algorithm_set::<T>),
+            get: Some(algorithm_get::<T>),
+            get_double: Some(algorithm_get_double::<T>),
+        }));
+        registry.push(Algorithm {
+            id,
+            ty: ty as usize,
+            name: name as usize,
+            hooks: StateHooks {
+                save: algorithm_save::<T>,
+                restore: algorithm_restore::<T>,
+            },
+        });
+        RngType::wrap(ty)
+    }
+
     /// wrapper for max element
     pub fn max(&self) -> usize {
         let ptr = self.unwrap_shared();
@@ -1475,3 +1925,188 @@ pub mod other {
         ffi_wrap!(gsl_rng_coveyou)
     }
 }
+
+#[test]
+fn state_round_trip() {
+    let mut r = Rng::new(RngType::default()).unwrap();
+    r.set(42);
+    let saved = r.save_state().unwrap();
+    let x = r.uniform();
+    r.uniform();
+    assert_eq!(r.restore_state(&saved), Value::Success);
+    assert_eq!(r.uniform(), x);
+
+    let mut out = Vec::new();
+    r.write_state(&mut out).unwrap();
+    let x = r.uniform();
+    r.read_state(&mut out.as_slice()).unwrap();
+    assert_eq!(r.uniform(), x);
+}
+
+#[test]
+fn state_restore_errors() {
+    let mut r = Rng::new(RngType::default()).unwrap();
+    r.set(42);
+    let saved = r.save_state().unwrap();
+    r.set(7);
+
+    let mut expected = r.clone();
+    assert_eq!(r.restore_state(&saved[..saved.len() - 1]), Value::BadLength);
+    let mut too_long = saved.clone();
+    too_long.push(0);
+    assert_eq!(r.restore_state(&too_long), Value::BadLength);
+    let other = Rng::new(algorithms::taus()).unwrap().save_state().unwrap();
+    assert_eq!(r.restore_state(&other), Value::Invalid);
+    assert_eq!(r.uniform(), expected.uniform());
+}
+
+#[cfg(test)]
+#[derive(Clone, Copy)]
+struct TestSplitMix64(u64);
+
+#[cfg(test)]
+impl RngAlgorithm for TestSplitMix64 {
+    const NAME: &'static str = "test-splitmix64";
+
+    fn from_seed(seed: u64) -> Self {
+        TestSplitMix64(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn save_state(&self) -> Option<Vec<u8>> {
+        Some(self.0.to_le_bytes().to_vec())
+    }
+
+    fn restore_state(data: &[u8]) -> Option<Self> {
+        let mut state = [0; 8];
+        if data.len() != state.len() {
+            return None;
+        }
+        state.copy_from_slice(data);
+        Some(TestSplitMix64(u64::from_le_bytes(state)))
+    }
+}
+
+#[test]
+fn algorithm_state_round_trip() {
+    let mut r = Rng::new(RngType::from_algorithm::<TestSplitMix64>()).unwrap();
+    r.set(42);
+    let saved = r.save_state().unwrap();
+    let x = r.uniform();
+    r.uniform();
+    assert_eq!(r.restore_state(&saved), Value::Success);
+    assert_eq!(r.uniform(), x);
+
+    let mut expected = r.clone();
+    assert_eq!(r.restore_state(&saved[..saved.len() - 1]), Value::BadLength);
+    let mut too_long = saved.clone();
+    too_long.push(0);
+    assert_eq!(r.restore_state(&too_long), Value::BadLength);
+    // A state of 4 bytes is rejected by `TestSplitMix64::restore_state`.
+    let mut rejected = Vec::new();
+    write_name(&mut rejected, TestSplitMix64::NAME).unwrap();
+    write_data(&mut rejected, &[0; 4]).unwrap();
+    assert_eq!(r.restore_state(&rejected), Value::Invalid);
+    let other = Rng::new(RngType::default()).unwrap().save_state().unwrap();
+    assert_eq!(r.restore_state(&other), Value::Invalid);
+    assert_eq!(r.uniform(), expected.uniform());
+}
+
+#[test]
+fn algorithm_state_unsupported() {
+    #[derive(Clone, Copy)]
+    struct Counter(u64);
+
+    impl RngAlgorithm for Counter {
+        const NAME: &'static str = "test-counter";
+
+        fn from_seed(seed: u64) -> Self {
+            Counter(seed)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(1);
+            self.0
+        }
+    }
+
+    let mut r = Rng::new(RngType::from_algorithm::<Counter>()).unwrap();
+    assert_eq!(r.save_state(), None);
+    let mut saved = Vec::new();
+    write_name(&mut saved, Counter::NAME).unwrap();
+    write_data(&mut saved, &[0; 8]).unwrap();
+    assert_eq!(r.restore_state(&saved), Value::Unsupported);
+    assert_eq!(
+        r.read_state(&mut saved.as_slice()).unwrap_err().kind(),
+        io::ErrorKind::Unsupported
+    );
+}
+
+#[test]
+fn spawn_streams_distinct_and_reproducible() {
+    let mut master = Rng::new(algorithms::ranlxd2()).unwrap();
+    master.set(42);
+    let mut same_master = master.clone();
+
+    let mut streams = master.spawn_streams(8).unwrap();
+    let mut same_streams = same_master.spawn_streams(8).unwrap();
+    assert_eq!(streams.len(), 8);
+    let firsts: Vec<Vec<usize>> = streams
+        .iter_mut()
+        .map(|r| (0..4).map(|_| r.get()).collect())
+        .collect();
+    for (i, first) in firsts.iter().enumerate() {
+        assert!(firsts[..i].iter().all(|other| other != first));
+    }
+    let same_firsts: Vec<Vec<usize>> = same_streams
+        .iter_mut()
+        .map(|r| (0..4).map(|_| r.get()).collect())
+        .collect();
+    assert_eq!(firsts, same_firsts);
+    assert!(streams.iter().all(|r| r.get_name() == "ranlxd2"));
+
+    // The master was advanced, so the next streams are different.
+    let mut next = master.spawn_streams(1).unwrap();
+    let next_first: Vec<usize> = (0..4).map(|_| next[0].get()).collect();
+    assert!(firsts.iter().all(|first| *first != next_first));
+
+    assert_eq!(master.spawn_streams(0).map(|s| s.len()), Some(0));
+}
+
+#[test]
+fn spawn_streams_repeating_master() {
+    // Returns 0 and 0, then 0 and 2^32 repeatedly. The first seed is drawn from the outputs of
+    // `get` (0, 0) and every following one from (0, 1), which gives the first seed again once
+    // the number of streams is mixed in.
+    #[derive(Clone, Copy)]
+    struct Stuck(u64);
+
+    impl RngAlgorithm for Stuck {
+        const NAME: &'static str = "test-stuck";
+
+        fn from_seed(_seed: u64) -> Self {
+            Stuck(0)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 += 1;
+            if self.0 > 3 && self.0 & 1 == 0 {
+                1 << 32
+            } else {
+                0
+            }
+        }
+    }
+
+    let mut master = Rng::new(RngType::from_algorithm::<Stuck>()).unwrap();
+    assert_eq!(master.spawn_streams(1).map(|s| s.len()), Some(1));
+    master.set(0);
+    assert!(master.spawn_streams(2).is_none());
+}
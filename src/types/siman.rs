@@ -482,6 +482,7 @@ where
     }
 }
 
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct SimAnnealingParams {
     n_tries: usize,
     iters_fixed_T: usize,
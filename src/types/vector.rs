@@ -38,6 +38,71 @@ use std::marker::PhantomData;
 
 use crate::paste::paste;
 
+macro_rules! vector_view_common {
+    ($view:ident, $rust_name:ident, $name:ident, $rust_ty:ident) => {
+        paste! {
+        impl<'a> $view<'a> {
+            pub fn len(&self) -> usize {
+                self.v.size
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Returns the step-size from one element to the next in physical memory.
+            pub fn stride(&self) -> usize {
+                self.v.stride
+            }
+
+            /// This function returns the i-th element of the view. If i lies outside the allowed
+            /// range of 0 to n-1 then the error handler is invoked and 0 is returned.
+            #[doc(alias = $name _get)]
+            pub fn get(&self, i: usize) -> $rust_ty {
+                unsafe { sys::[<$name _get>](&self.v, i) }
+            }
+
+            /// Returns the elements of the view as a slice if they are contiguous in memory (i.e.
+            /// if the stride is 1).
+            pub fn as_slice(&self) -> Option<&[$rust_ty]> {
+                if self.v.stride == 1 {
+                    Some(unsafe { ::std::slice::from_raw_parts(self.v.data, self.v.size) })
+                } else {
+                    None
+                }
+            }
+
+            #[doc = "Copies the elements of the view into a new " $rust_name "."]
+            pub fn to_vector(&self) -> Option<$rust_name> {
+                let mut v = $rust_name::new(self.len())?;
+                v.copy_from(self);
+                Some(v)
+            }
+        }
+
+        impl<'a> Debug for $view<'a> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.debug_list()
+                    .entries((0..self.len()).map(|i| self.get(i)))
+                    .finish()
+            }
+        }
+
+        impl<'a> ::types::container::sealed::Sealed for $view<'a> {}
+
+        impl<'a> ::types::GslVector<$rust_ty> for $view<'a> {
+            fn unwrap_shared(&self) -> *const sys::$name {
+                &self.v
+            }
+
+            fn len(&self) -> usize {
+                self.v.size
+            }
+        }
+        }
+    };
+}
+
 macro_rules! gsl_vec {
     ($rust_name:ident, $name:ident, $rust_ty:ident) => (
 paste! {
@@ -192,7 +257,7 @@ impl $rust_name {
     /// This function copies the elements of the other vector into the self vector. The two vectors
     /// must have the same length.
     #[doc(alias = $name _memcpy)]
-    pub fn copy_from(&mut self, other: &$rust_name) -> Value {
+    pub fn copy_from(&mut self, other: &impl ::types::GslVector<$rust_ty>) -> Value {
         Value::from(
             unsafe { sys::[<$name _memcpy>](
                 self.unwrap_unique(),
@@ -202,14 +267,14 @@ impl $rust_name {
     /// This function copies the elements of the self vector into the other vector. The two vectors
     /// must have the same length.
     #[doc(alias = $name _memcpy)]
-    pub fn copy_to(&self, other: &mut $rust_name) -> Value {
+    pub fn copy_to(&self, other: &mut impl ::types::GslVectorMut<$rust_ty>) -> Value {
         Value::from(unsafe { sys::[<$name _memcpy>](other.unwrap_unique(), self.unwrap_shared()) })
     }
 
     /// This function exchanges the elements of the vectors by copying. The two vectors must have
     /// the same length.
     #[doc(alias = $name _swap)]
-    pub fn swap(&mut self, other: &mut $rust_name) -> Value {
+    pub fn swap(&mut self, other: &mut impl ::types::GslVectorMut<$rust_ty>) -> Value {
         Value::from(unsafe { sys::[<$name _swap>](other.unwrap_unique(), self.unwrap_unique()) })
     }
 
@@ -229,7 +294,7 @@ impl $rust_name {
     /// The result a_i <- a_i + b_i is stored in self and other remains unchanged. The two vectors
     /// must have the same length.
     #[doc(alias = $name _add)]
-    pub fn add(&mut self, other: &$rust_name) -> Value {
+    pub fn add(&mut self, other: &impl ::types::GslVector<$rust_ty>) -> Value {
         Value::from(unsafe { sys::[<$name _add>](self.unwrap_unique(), other.unwrap_shared()) })
     }
 
@@ -237,7 +302,7 @@ impl $rust_name {
     /// vector. The result a_i <- a_i - b_i is stored in self and other remains unchanged. The two
     /// vectors must have the same length.
     #[doc(alias = $name _sub)]
-    pub fn sub(&mut self, other: &$rust_name) -> Value {
+    pub fn sub(&mut self, other: &impl ::types::GslVector<$rust_ty>) -> Value {
         Value::from(unsafe { sys::[<$name _sub>](self.unwrap_unique(), other.unwrap_shared()) })
     }
 
@@ -245,7 +310,7 @@ impl $rust_name {
     /// vector. The result `a_i <- a_i * b_i` is stored in self and other remains unchanged. The two
     /// vectors must have the same length.
     #[doc(alias = $name _mul)]
-    pub fn mul(&mut self, other: &$rust_name) -> Value {
+    pub fn mul(&mut self, other: &impl ::types::GslVector<$rust_ty>) -> Value {
         Value::from(unsafe { sys::[<$name _mul>](self.unwrap_unique(), other.unwrap_shared()) })
     }
 
@@ -253,7 +318,7 @@ impl $rust_name {
     /// The result `a_i <- a_i / b_i` is stored in self and other remains unchanged. The two vectors
     /// must have the same length.
     #[doc(alias = $name _div)]
-    pub fn div(&mut self, other: &$rust_name) -> Value {
+    pub fn div(&mut self, other: &impl ::types::GslVector<$rust_ty>) -> Value {
         Value::from(unsafe { sys::[<$name _div>](self.unwrap_unique(), other.unwrap_shared()) })
     }
 
@@ -347,7 +412,7 @@ impl $rust_name {
     }
 
     #[doc(alias = $name _equal)]
-    pub fn equal(&self, other: &$rust_name) -> bool {
+    pub fn equal(&self, other: &impl ::types::GslVector<$rust_ty>) -> bool {
         unsafe { sys::[<$name _equal>](self.unwrap_shared(), other.unwrap_shared()) == 1 }
     }
 
@@ -365,107 +430,127 @@ impl $rust_name {
         }
     }
 
-    #[doc(alias = $name _subvector)]
-    pub fn subvector<'a>(&'a mut self, offset: usize, n: usize) -> [<$rust_name View>]<'a> {
-        [<$rust_name View>]::from_vector(self, offset, n)
+    /// Returns a view of the whole vector.
+    pub fn view(&self) -> [<$rust_name View>]<'_> {
+        [<$rust_name View>] {
+            v: unsafe { *self.unwrap_shared() },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns a mutable view of the whole vector.
+    pub fn view_mut(&mut self) -> [<$rust_name ViewMut>]<'_> {
+        [<$rust_name ViewMut>] {
+            v: unsafe { *self.unwrap_unique() },
+            phantom: PhantomData,
+        }
     }
+
+    shared_vector_views!($name, $rust_ty, [<$rust_name View>]);
+    mut_vector_views!($name, $rust_ty, 1, [<$rust_name ViewMut>]);
 }
 
+impl ::types::container::sealed::Sealed for $rust_name {}
+
+impl ::types::GslVector<$rust_ty> for $rust_name {
+    fn unwrap_shared(&self) -> *const sys::$name {
+        self.vec as *const _
+    }
+
+    fn len(&self) -> usize {
+        $rust_name::len(self)
+    }
+}
+
+impl ::types::GslVectorMut<$rust_ty> for $rust_name {
+    fn unwrap_unique(&mut self) -> *mut sys::$name {
+        self.vec
+    }
+}
+
+/// A view of the elements of a vector, an array or a matrix which doesn't allow to modify them.
+///
+/// It can be passed to all the functions expecting a vector.
 pub struct [<$rust_name View>]<'a> {
-    v: sys::[<$name _view>],
-    #[allow(dead_code)]
-    phantom: PhantomData<&'a ()>,
+    v: sys::$name,
+    phantom: PhantomData<&'a $rust_ty>,
+}
+
+/// A view of the elements of a vector, an array or a matrix which allows to modify them.
+///
+/// It can be passed to all the functions expecting a (mutable) vector.
+pub struct [<$rust_name ViewMut>]<'a> {
+    v: sys::$name,
+    phantom: PhantomData<&'a mut $rust_ty>,
 }
 
 impl<'a> [<$rust_name View>]<'a> {
-    #[doc(hidden)]
-    pub(crate) fn wrap<F: FnOnce(Option<Self>)>(v: sys::[<$name _view>], f: F) {
-        let tmp = Self {
-            v,
-            phantom: PhantomData,
-        };
-        let is_none = {
-            let v = &tmp.v.vector;
-            let tmp = $rust_name::soft_wrap(v as *const _ as usize as *mut _);
-            tmp.as_slice().is_none()
-        };
-        if is_none {
-            f(None)
+    pub(crate) fn from_raw(v: sys::$name) -> Option<Self> {
+        if v.data.is_null() {
+            None
         } else {
-            f(Some(tmp))
+            Some(Self {
+                v,
+                phantom: PhantomData,
+            })
         }
     }
 
-    /// These functions return a vector view of a subvector of another vector v. The start of the
-    /// new vector is offset by offset elements from the start of the original vector. The new
-    /// vector has n elements. Mathematically, the i-th element of the new vector v’ is given by,
+    /// These functions return a vector view of an array. The start of the new vector is given by
+    /// base and has n elements. Mathematically, the i-th element of the new vector v’ is given by,
     ///
-    /// v'(i) = v->data[(offset + i)*v->stride]
+    /// ```text
+    /// v'(i) = base[i]
+    /// ```
     ///
     /// where the index i runs from 0 to n-1.
     ///
-    /// The data pointer of the returned vector struct is set to null if the combined parameters
-    /// (offset,n) overrun the end of the original vector.
-    ///
-    /// The new vector is only a view of the block underlying the original vector, v. The block
-    /// containing the elements of v is not owned by the new vector. When the view goes out of scope
-    /// the original vector v and its block will continue to exist. The original memory can only be
-    /// deallocated by freeing the original vector. Of course, the original vector should not be
-    /// deallocated while the view is still in use.
-    ///
-    /// The function gsl_vector_const_subvector is equivalent to gsl_vector_subvector but can be
-    /// used for vectors which are declared const.
-    #[doc(alias = $name _subvector)]
-    pub fn from_vector(v: &'a mut $rust_name, offset: usize, n: usize) -> Self {
-        unsafe {
-            Self {
-                v: sys::[<$name _subvector>](v.unwrap_unique(), offset, n),
-                phantom: PhantomData,
-            }
+    /// The array containing the elements of v is not owned by the new vector view. The view
+    /// borrows the array so it cannot outlive it.
+    #[doc(alias = $name _const_view_array)]
+    pub fn from_array(base: &'a [$rust_ty]) -> Self {
+        Self {
+            v: unsafe { sys::[<$name _const_view_array>](base.as_ptr(), base.len() as _).vector },
+            phantom: PhantomData,
         }
     }
 
-    /// These functions return a vector view of a subvector of another vector v with an additional
-    /// stride argument. The subvector is formed in the same way as for gsl_vector_subvector but the
-    /// new vector has n elements with a step-size of stride from one element to the next in the
-    /// original vector. Mathematically, the i-th element of the new vector v’ is given by,
-    ///
-    /// v'(i) = v->data[(offset + i*stride)*v->stride]
-    /// where the index i runs from 0 to n-1.
-    ///
-    /// Note that subvector views give direct access to the underlying elements of the original
-    /// vector. For example, the following code will zero the even elements of the vector v of
-    /// length n, while leaving the odd elements untouched,
+    /// These functions return a vector view of an array base with an additional stride argument.
+    /// The subvector is formed in the same way as for gsl_vector_view_array but the new vector has
+    /// n elements with a step-size of stride from one element to the next in the original
+    /// array. Mathematically, the i-th element of the new vector v’ is given by,
     ///
-    /// ```C
-    /// gsl_vector_view v_even
-    ///   = gsl_vector_subvector_with_stride (v, 0, 2, n/2);
-    /// gsl_vector_set_zero (&v_even.vector);
-    /// ```
-    /// A vector view can be passed to any subroutine which takes a vector argument just as a
-    /// directly allocated vector would be, using &view.vector.
-    /// For example, the following code computes the norm of the odd elements of v using the BLAS
-    /// routine DNRM2,
+    /// v'(i) = base[i*stride]
     ///
-    /// ```C
-    /// gsl_vector_view v_odd
-    ///   = gsl_vector_subvector_with_stride (v, 1, 2, n/2);
-    /// double r = gsl_blas_dnrm2 (&v_odd.vector);
-    /// ```
-    /// The function gsl_vector_const_subvector_with_stride is equivalent to
-    /// gsl_vector_subvector_with_stride but can be used for vectors which are declared const.
-    #[doc(alias = $name _subvector_with_stride)]
-    pub fn from_vector_with_stride(
-        v: &'a mut $rust_name,
-        offset: usize,
-        stride: usize,
-        n: usize,
-    ) -> Self {
-        unsafe {
-            Self {
-                v: sys::[<$name _subvector_with_stride>](v.vec, offset, stride, n),
+    /// where the index i runs from 0 to n-1 and n is the number of such elements in base.
+    #[doc(alias = $name _const_view_array_with_stride)]
+    pub fn from_array_with_stride(base: &'a [$rust_ty], stride: usize) -> Self {
+        assert!(stride > 0, "stride cannot be 0");
+        Self {
+            v: unsafe {
+                sys::[<$name _const_view_array_with_stride>](
+                    base.as_ptr(),
+                    stride,
+                    if base.is_empty() { 0 } else { (base.len() - 1) / stride + 1 },
+                )
+                .vector
+            },
+            phantom: PhantomData,
+        }
+    }
+
+    shared_vector_views!($name, $rust_ty, [<$rust_name View>]);
+}
+
+impl<'a> [<$rust_name ViewMut>]<'a> {
+    pub(crate) fn from_raw(v: sys::$name) -> Option<Self> {
+        if v.data.is_null() {
+            None
+        } else {
+            Some(Self {
+                v,
                 phantom: PhantomData,
-            }
+            })
         }
     }
 
@@ -478,20 +563,13 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// where the index i runs from 0 to n-1.
     ///
-    /// The array containing the elements of v is not owned by the new vector view. When the view
-    /// goes out of scope the original array will continue to exist. The original memory can only be
-    /// deallocated by freeing the original pointer base. Of course, the original array should not
-    /// be deallocated while the view is still in use.
-    ///
-    /// The function gsl_vector_const_view_array is equivalent to gsl_vector_view_array but can be
-    /// used for arrays which are declared const.
+    /// The array containing the elements of v is not owned by the new vector view. The view
+    /// borrows the array so it cannot outlive it.
     #[doc(alias = $name _view_array)]
-    pub fn from_array(base: &'a mut [f64]) -> Self {
-        unsafe {
-            Self {
-                v: sys::[<$name _view_array>](base.as_mut_ptr() as _, base.len() as _),
-                phantom: PhantomData,
-            }
+    pub fn from_array(base: &'a mut [$rust_ty]) -> Self {
+        Self {
+            v: unsafe { sys::[<$name _view_array>](base.as_mut_ptr(), base.len() as _).vector },
+            phantom: PhantomData,
         }
     }
 
@@ -502,48 +580,88 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// v'(i) = base[i*stride]
     ///
-    /// where the index i runs from 0 to n-1.
-    ///
-    /// Note that the view gives direct access to the underlying elements of the original array. A
-    /// vector view can be passed to any subroutine which takes a vector argument just as a directly
-    /// allocated vector would be, using &view.vector.
-    ///
-    /// The function gsl_vector_const_view_array_with_stride is equivalent to
-    /// gsl_vector_view_array_with_stride but can be used for arrays which are declared const.
+    /// where the index i runs from 0 to n-1 and n is the number of such elements in base.
     #[doc(alias = $name _view_array_with_stride)]
     pub fn from_array_with_stride(base: &'a mut [$rust_ty], stride: usize) -> Self {
-        unsafe {
-            Self {
-                v: sys::[<$name _view_array_with_stride>](
+        assert!(stride > 0, "stride cannot be 0");
+        Self {
+            v: unsafe {
+                sys::[<$name _view_array_with_stride>](
                     base.as_mut_ptr(),
                     stride,
-                    base.len() as _,
-                ),
-                phantom: PhantomData,
-            }
+                    if base.is_empty() { 0 } else { (base.len() - 1) / stride + 1 },
+                )
+                .vector
+            },
+            phantom: PhantomData,
         }
     }
 
-    pub fn vector<F: FnOnce(Option<&$rust_name>)>(&self, f: F) {
-        let v = &self.v.vector;
-        let tmp = $rust_name::soft_wrap(v as *const _ as usize as *mut _);
-        if tmp.as_slice().is_none() {
-            f(None)
-        } else {
-            f(Some(&tmp))
-        }
+    /// This function sets the value of the i-th element of the view to x. If i lies outside the
+    /// allowed range of 0 to n-1 then the error handler is invoked.
+    #[doc(alias = $name _set)]
+    pub fn set(&mut self, i: usize, x: $rust_ty) -> &mut Self {
+        unsafe { sys::[<$name _set>](&mut self.v, i, x) };
+        self
     }
 
-    pub fn vector_mut<F: FnOnce(Option<&mut $rust_name>)>(&mut self, f: F) {
-        let v = &mut self.v.vector;
-        let mut tmp = $rust_name::soft_wrap(v as *mut _);
-        if tmp.as_slice().is_none() {
-            f(None)
+    /// This function sets all the elements of the view to the value x.
+    #[doc(alias = $name _set_all)]
+    pub fn set_all(&mut self, x: $rust_ty) -> &mut Self {
+        unsafe { sys::[<$name _set_all>](&mut self.v, x) };
+        self
+    }
+
+    /// This function sets all the elements of the view to zero.
+    #[doc(alias = $name _set_zero)]
+    pub fn set_zero(&mut self) -> &mut Self {
+        unsafe { sys::[<$name _set_zero>](&mut self.v) };
+        self
+    }
+
+    /// This function copies the elements of the other vector into the view. The two vectors must
+    /// have the same length.
+    #[doc(alias = $name _memcpy)]
+    pub fn copy_from(&mut self, other: &impl ::types::GslVector<$rust_ty>) -> Value {
+        Value::from(unsafe { sys::[<$name _memcpy>](&mut self.v, other.unwrap_shared()) })
+    }
+
+    /// This function multiplies the elements of the view by the constant factor x.
+    #[doc(alias = $name _scale)]
+    pub fn scale(&mut self, x: $rust_ty) -> Value {
+        Value::from(unsafe { sys::[<$name _scale>](&mut self.v, x) })
+    }
+
+    /// This function adds the constant value x to the elements of the view.
+    #[doc(alias = $name _add_constant)]
+    pub fn add_constant(&mut self, x: f64) -> Value {
+        Value::from(unsafe { sys::[<$name _add_constant>](&mut self.v, x) })
+    }
+
+    /// Returns the elements of the view as a mutable slice if they are contiguous in memory (i.e.
+    /// if the stride is 1).
+    pub fn as_slice_mut(&mut self) -> Option<&mut [$rust_ty]> {
+        if self.v.stride == 1 {
+            Some(unsafe { ::std::slice::from_raw_parts_mut(self.v.data, self.v.size) })
         } else {
-            f(Some(&mut tmp))
+            None
         }
     }
-} // end of impl block
+
+    shared_vector_views!($name, $rust_ty, [<$rust_name View>]);
+    mut_vector_views!($name, $rust_ty, 1, [<$rust_name ViewMut>]);
+}
+
+vector_view_common!([<$rust_name View>], $rust_name, $name, $rust_ty);
+vector_view_common!([<$rust_name ViewMut>], $rust_name, $name, $rust_ty);
+
+impl<'a> ::types::GslVectorMut<$rust_ty> for [<$rust_name ViewMut>]<'a> {
+    fn unwrap_unique(&mut self) -> *mut sys::$name {
+        &mut self.v
+    }
+}
+
+vector_ops!($name, $rust_ty, $rust_name, [<$rust_name View>], [<$rust_name ViewMut>]);
 
 } // end of paste! block
 ); // end of gsl_vec macro
@@ -553,3 +671,7 @@ gsl_vec!(VectorF32, gsl_vector_float, f32);
 gsl_vec!(VectorF64, gsl_vector, f64);
 gsl_vec!(VectorI32, gsl_vector_int, i32);
 gsl_vec!(VectorU32, gsl_vector_uint, u32);
+
+vector_ops!(@neg gsl_vector_float, f32, VectorF32, VectorF32View, VectorF32ViewMut);
+vector_ops!(@neg gsl_vector, f64, VectorF64, VectorF64View, VectorF64ViewMut);
+vector_ops!(@neg gsl_vector_int, i32, VectorI32, VectorI32View, VectorI32ViewMut);